@@ -34,6 +34,8 @@ namespace Riftcaller.Masonry
     public List<string> HideIndicatorChildren { get; set; }
     public Node? CustomDragIndicator { get; set; }
     public ClientAction? OnDragDetected { get; set; }
+    public CommandList? DragDetectedUpdate { get; set; }
+    public CommandList? DragEndedUpdate { get; set; }
     bool _firedDragDetected;
 
     public static void Apply(Registry registry, Draggable view, Node data)
@@ -48,6 +50,8 @@ namespace Riftcaller.Masonry
       view.HideIndicatorChildren = data.NodeType.DraggableNode.HideIndicatorChildren.ToList();
       view.CustomDragIndicator = data.NodeType.DraggableNode.CustomDragIndicator;
       view.OnDragDetected = data.NodeType.DraggableNode.OnDragDetected;
+      view.DragDetectedUpdate = data.NodeType.DraggableNode.DragDetectedUpdate;
+      view.DragEndedUpdate = data.NodeType.DraggableNode.DragEndedUpdate;
     }
 
     public Draggable()
@@ -85,6 +89,7 @@ namespace Riftcaller.Masonry
           }
         }
 
+        ApplyUpdate(DragDetectedUpdate);
         if (OnDragDetected != null)
         {
           Registry.ActionService.HandleAction(OnDragDetected);
@@ -92,5 +97,24 @@ namespace Riftcaller.Masonry
         _firedDragDetected = true;
       }
     }
+
+    public void OnDragEnded()
+    {
+      ApplyUpdate(DragEndedUpdate);
+    }
+
+    void ApplyUpdate(CommandList? update)
+    {
+      if (update != null)
+      {
+        Registry.ActionService.HandleAction(new ClientAction
+        {
+          StandardAction = new StandardAction
+          {
+            Update = update
+          }
+        });
+      }
+    }
   }
 }
\ No newline at end of file
@@ -138,7 +138,7 @@ namespace Riftcaller.Protos {
             "ci5TY3JvbGxCYXISRwoedmVydGljYWxfc2Nyb2xsX2Jhcl92aXNpYmlsaXR5",
             "GAkgASgOMh8ucmlmdGNhbGxlci5TY3JvbGxCYXJWaXNpYmlsaXR5EjwKF21v",
             "dXNlX3doZWVsX3Njcm9sbF9zaXplGAogASgLMhsuZ29vZ2xlLnByb3RvYnVm",
-            "LkZsb2F0VmFsdWUi8QIKDURyYWdnYWJsZU5vZGUSHwoXZHJvcF90YXJnZXRf",
+            "LkZsb2F0VmFsdWUi3AMKDURyYWdnYWJsZU5vZGUSHwoXZHJvcF90YXJnZXRf",
             "aWRlbnRpZmllcnMYASADKAkSLwoVb3Zlcl90YXJnZXRfaW5kaWNhdG9yGAIg",
             "ASgLMhAucmlmdGNhbGxlci5Ob2RlEikKB29uX2Ryb3AYAyABKAsyGC5yaWZ0",
             "Y2FsbGVyLkNsaWVudEFjdGlvbhJECh5ob3Jpem9udGFsX2RyYWdfc3RhcnRf",
@@ -146,612 +146,620 @@ namespace Riftcaller.Protos {
             "FwoPcmVtb3ZlX29yaWdpbmFsGAUgASgIEh8KF2hpZGVfaW5kaWNhdG9yX2No",
             "aWxkcmVuGAYgAygJEi8KFWN1c3RvbV9kcmFnX2luZGljYXRvchgHIAEoCzIQ",
             "LnJpZnRjYWxsZXIuTm9kZRIyChBvbl9kcmFnX2RldGVjdGVkGAggASgLMhgu",
-            "cmlmdGNhbGxlci5DbGllbnRBY3Rpb24iEAoORHJvcFRhcmdldE5vZGUi9gEK",
-            "DVRleHRGaWVsZE5vZGUSGQoRZ2xvYmFsX2lkZW50aWZpZXIYASABKAkSFAoM",
-            "aW5pdGlhbF90ZXh0GAIgASgJEhEKCW11bHRpbGluZRgDIAEoCBIUCgxpc19y",
-            "ZWFkX29ubHkYBCABKAgSEgoKbWF4X2xlbmd0aBgFIAEoDRIZChFpc19wYXNz",
-            "d29yZF9maWVsZBgGIAEoCBIhChlkb3VibGVfY2xpY2tfc2VsZWN0c193b3Jk",
-            "GAcgASgIEiEKGXRyaXBsZV9jbGlja19zZWxlY3RzX2xpbmUYCCABKAgSFgoO",
-            "bWFza19jaGFyYWN0ZXIYCSABKAki0gMKClNsaWRlck5vZGUSFQoNaW5pdGlh",
-            "bF92YWx1ZRgBIAEoAhINCgVsYWJlbBgCIAEoCRIWCg5wcmVmZXJlbmNlX2tl",
-            "eRgDIAEoCRIuCglkaXJlY3Rpb24YBCABKA4yGy5yaWZ0Y2FsbGVyLlNsaWRl",
-            "ckRpcmVjdGlvbhISCgpoaWdoX3ZhbHVlGAUgASgCEhEKCWxvd192YWx1ZRgG",
-            "IAEoAhIQCghpbnZlcnRlZBgHIAEoCBIRCglwYWdlX3NpemUYCCABKAISGAoQ",
-            "c2hvd19pbnB1dF9maWVsZBgJIAEoCBIqCgtsYWJlbF9zdHlsZRgKIAEoCzIV",
-            "LnJpZnRjYWxsZXIuRmxleFN0eWxlEjMKFGRyYWdfY29udGFpbmVyX3N0eWxl",
-            "GAsgASgLMhUucmlmdGNhbGxlci5GbGV4U3R5bGUSLAoNdHJhY2tlcl9zdHls",
-            "ZRgMIAEoCzIVLnJpZnRjYWxsZXIuRmxleFN0eWxlEiwKDWRyYWdnZXJfc3R5",
-            "bGUYDSABKAsyFS5yaWZ0Y2FsbGVyLkZsZXhTdHlsZRIzChRkcmFnZ2VyX2Jv",
-            "cmRlcl9zdHlsZRgOIAEoCzIVLnJpZnRjYWxsZXIuRmxleFN0eWxlIsMCCghO",
-            "b2RlVHlwZRIgCgR0ZXh0GAEgASgLMhAucmlmdGNhbGxlci5UZXh0SAASNgoQ",
-            "c2Nyb2xsX3ZpZXdfbm9kZRgCIAEoCzIaLnJpZnRjYWxsZXIuU2Nyb2xsVmll",
-            "d05vZGVIABIzCg5kcmFnZ2FibGVfbm9kZRgDIAEoCzIZLnJpZnRjYWxsZXIu",
-            "RHJhZ2dhYmxlTm9kZUgAEjYKEGRyb3BfdGFyZ2V0X25vZGUYBCABKAsyGi5y",
-            "aWZ0Y2FsbGVyLkRyb3BUYXJnZXROb2RlSAASNAoPdGV4dF9maWVsZF9ub2Rl",
-            "GAUgASgLMhkucmlmdGNhbGxlci5UZXh0RmllbGROb2RlSAASLQoLc2xpZGVy",
-            "X25vZGUYBiABKAsyFi5yaWZ0Y2FsbGVyLlNsaWRlck5vZGVIAEILCglub2Rl",
-            "X3R5cGUi5AIKDUV2ZW50SGFuZGxlcnMSKgoIb25fY2xpY2sYASABKAsyGC5y",
-            "aWZ0Y2FsbGVyLkNsaWVudEFjdGlvbhIvCg1vbl9sb25nX3ByZXNzGAIgASgL",
-            "MhgucmlmdGNhbGxlci5DbGllbnRBY3Rpb24SMAoOb25fbW91c2VfZW50ZXIY",
-            "AyABKAsyGC5yaWZ0Y2FsbGVyLkNsaWVudEFjdGlvbhIwCg5vbl9tb3VzZV9s",
-            "ZWF2ZRgEIAEoCzIYLnJpZnRjYWxsZXIuQ2xpZW50QWN0aW9uEi8KDW9uX21v",
-            "dXNlX2Rvd24YBSABKAsyGC5yaWZ0Y2FsbGVyLkNsaWVudEFjdGlvbhItCgtv",
-            "bl9tb3VzZV91cBgGIAEoCzIYLnJpZnRjYWxsZXIuQ2xpZW50QWN0aW9uEjIK",
-            "EG9uX2ZpZWxkX2NoYW5nZWQYByABKAsyGC5yaWZ0Y2FsbGVyLkNsaWVudEFj",
-            "dGlvbiLEAgoETm9kZRIMCgRuYW1lGAEgASgJEicKCW5vZGVfdHlwZRgCIAEo",
-            "CzIULnJpZnRjYWxsZXIuTm9kZVR5cGUSIgoIY2hpbGRyZW4YAyADKAsyEC5y",
-            "aWZ0Y2FsbGVyLk5vZGUSMQoOZXZlbnRfaGFuZGxlcnMYBCABKAsyGS5yaWZ0",
-            "Y2FsbGVyLkV2ZW50SGFuZGxlcnMSJAoFc3R5bGUYBSABKAsyFS5yaWZ0Y2Fs",
-            "bGVyLkZsZXhTdHlsZRIqCgtob3Zlcl9zdHlsZRgGIAEoCzIVLnJpZnRjYWxs",
-            "ZXIuRmxleFN0eWxlEiwKDXByZXNzZWRfc3R5bGUYByABKAsyFS5yaWZ0Y2Fs",
-            "bGVyLkZsZXhTdHlsZRIuCg9vbl9hdHRhY2hfc3R5bGUYCCABKAsyFS5yaWZ0",
-            "Y2FsbGVyLkZsZXhTdHlsZSIgChBQbGF5ZXJJZGVudGlmaWVyEgwKBHVsaWQY",
-            "ASABKAkiqgEKDkNhcmRJZGVudGlmaWVyEiQKBHNpZGUYASABKA4yFi5yaWZ0",
-            "Y2FsbGVyLlBsYXllclNpZGUSDQoFaW5kZXgYAiABKA0SMAoKYWJpbGl0eV9p",
-            "ZBgDIAEoCzIcLmdvb2dsZS5wcm90b2J1Zi5VSW50MzJWYWx1ZRIxCgtnYW1l",
-            "X2FjdGlvbhgEIAEoCzIcLmdvb2dsZS5wcm90b2J1Zi5VSW50MzJWYWx1ZSLQ",
-            "AQoUR2FtZU9iamVjdElkZW50aWZpZXISLQoHY2FyZF9pZBgBIAEoCzIaLnJp",
-            "ZnRjYWxsZXIuQ2FyZElkZW50aWZpZXJIABIrCgljaGFyYWN0ZXIYAiABKA4y",
-            "Fi5yaWZ0Y2FsbGVyLlBsYXllck5hbWVIABImCgRkZWNrGAMgASgOMhYucmlm",
-            "dGNhbGxlci5QbGF5ZXJOYW1lSAASLgoMZGlzY2FyZF9waWxlGAQgASgOMhYu",
-            "cmlmdGNhbGxlci5QbGF5ZXJOYW1lSABCBAoCaWQinAEKCENhcmRJY29uEi0K",
-            "CmJhY2tncm91bmQYASABKAsyGS5yaWZ0Y2FsbGVyLlNwcml0ZUFkZHJlc3MS",
-            "KgoEdGV4dBgCIAEoCzIcLmdvb2dsZS5wcm90b2J1Zi5TdHJpbmdWYWx1ZRI1",
-            "ChBiYWNrZ3JvdW5kX3NjYWxlGAMgASgLMhsuZ29vZ2xlLnByb3RvYnVmLkZs",
-            "b2F0VmFsdWUi8QEKCUNhcmRJY29ucxIrCg10b3BfbGVmdF9pY29uGAEgASgL",
-            "MhQucmlmdGNhbGxlci5DYXJkSWNvbhIsCg50b3BfcmlnaHRfaWNvbhgCIAEo",
-            "CzIULnJpZnRjYWxsZXIuQ2FyZEljb24SLwoRYm90dG9tX3JpZ2h0X2ljb24Y",
-            "AyABKAsyFC5yaWZ0Y2FsbGVyLkNhcmRJY29uEi4KEGJvdHRvbV9sZWZ0X2lj",
-            "b24YBCABKAsyFC5yaWZ0Y2FsbGVyLkNhcmRJY29uEigKCmFyZW5hX2ljb24Y",
-            "BSABKAsyFC5yaWZ0Y2FsbGVyLkNhcmRJY29uIkQKCUNhcmRUaXRsZRIMCgR0",
-            "ZXh0GAEgASgJEikKCnRleHRfY29sb3IYAiABKAsyFS5yaWZ0Y2FsbGVyLkZs",
-            "ZXhDb2xvciIZCglSdWxlc1RleHQSDAoEdGV4dBgBIAEoCSIfCgtOb1Rhcmdl",
-            "dGluZxIQCghjYW5fcGxheRgBIAEoCCI9CgpQbGF5SW5Sb29tEi8KC3ZhbGlk",
-            "X3Jvb21zGAEgAygOMhoucmlmdGNhbGxlci5Sb29tSWRlbnRpZmllciJtCg9B",
-            "cnJvd1RhcmdldFJvb20SLwoLdmFsaWRfcm9vbXMYASADKA4yGi5yaWZ0Y2Fs",
-            "bGVyLlJvb21JZGVudGlmaWVyEikKBWFycm93GAIgASgOMhoucmlmdGNhbGxl",
-            "ci5UYXJnZXRpbmdBcnJvdyK3AQoNQ2FyZFRhcmdldGluZxIvCgxub190YXJn",
-            "ZXRpbmcYASABKAsyFy5yaWZ0Y2FsbGVyLk5vVGFyZ2V0aW5nSAASLgoMcGxh",
-            "eV9pbl9yb29tGAIgASgLMhYucmlmdGNhbGxlci5QbGF5SW5Sb29tSAASOAoR",
-            "YXJyb3dfdGFyZ2V0X3Jvb20YAyABKAsyGy5yaWZ0Y2FsbGVyLkFycm93VGFy",
-            "Z2V0Um9vbUgAQgsKCXRhcmdldGluZyIZChdPYmplY3RQb3NpdGlvbk9mZnNj",
-            "cmVlbiJ4ChJPYmplY3RQb3NpdGlvblJvb20SKwoHcm9vbV9pZBgBIAEoDjIa",
-            "LnJpZnRjYWxsZXIuUm9vbUlkZW50aWZpZXISNQoNcm9vbV9sb2NhdGlvbhgC",
-            "IAEoDjIeLnJpZnRjYWxsZXIuQ2xpZW50Um9vbUxvY2F0aW9uIksKEk9iamVj",
-            "dFBvc2l0aW9uSXRlbRI1Cg1pdGVtX2xvY2F0aW9uGAEgASgOMh4ucmlmdGNh",
-            "bGxlci5DbGllbnRJdGVtTG9jYXRpb24iFwoVT2JqZWN0UG9zaXRpb25TdGFn",
-            "aW5nIjsKEk9iamVjdFBvc2l0aW9uSGFuZBIlCgVvd25lchgBIAEoDjIWLnJp",
-            "ZnRjYWxsZXIuUGxheWVyTmFtZSI7ChJPYmplY3RQb3NpdGlvbkRlY2sSJQoF",
-            "b3duZXIYASABKA4yFi5yaWZ0Y2FsbGVyLlBsYXllck5hbWUiRAobT2JqZWN0",
-            "UG9zaXRpb25EZWNrQ29udGFpbmVyEiUKBW93bmVyGAEgASgOMhYucmlmdGNh",
-            "bGxlci5QbGF5ZXJOYW1lIkIKGU9iamVjdFBvc2l0aW9uRGlzY2FyZFBpbGUS",
-            "JQoFb3duZXIYASABKA4yFi5yaWZ0Y2FsbGVyLlBsYXllck5hbWUiSwoiT2Jq",
-            "ZWN0UG9zaXRpb25EaXNjYXJkUGlsZUNvbnRhaW5lchIlCgVvd25lchgBIAEo",
-            "DjIWLnJpZnRjYWxsZXIuUGxheWVyTmFtZSIeChxPYmplY3RQb3NpdGlvblNj",
-            "b3JlQW5pbWF0aW9uIhQKEk9iamVjdFBvc2l0aW9uUmFpZCIXChVPYmplY3RQ",
-            "b3NpdGlvbkJyb3dzZXIiQAoXT2JqZWN0UG9zaXRpb25DaGFyYWN0ZXISJQoF",
-            "b3duZXIYASABKA4yFi5yaWZ0Y2FsbGVyLlBsYXllck5hbWUiSQogT2JqZWN0",
-            "UG9zaXRpb25DaGFyYWN0ZXJDb250YWluZXISJQoFb3duZXIYASABKA4yFi5y",
-            "aWZ0Y2FsbGVyLlBsYXllck5hbWUiGwoZT2JqZWN0UG9zaXRpb25SZXdhcmRD",
-            "aGVzdCJFChZPYmplY3RQb3NpdGlvbkludG9DYXJkEisKB2NhcmRfaWQYASAB",
-            "KAsyGi5yaWZ0Y2FsbGVyLkNhcmRJZGVudGlmaWVyIk4KH09iamVjdFBvc2l0",
-            "aW9uU3RhY2tlZEJlaGluZENhcmQSKwoHY2FyZF9pZBgBIAEoCzIaLnJpZnRj",
-            "YWxsZXIuQ2FyZElkZW50aWZpZXIiUQobT2JqZWN0UG9zaXRpb25SZXZlYWxl",
-            "ZENhcmRzEjIKBHNpemUYASABKA4yJC5yaWZ0Y2FsbGVyLlJldmVhbGVkQ2Fy",
-            "ZHNCcm93c2VyU2l6ZSJCChlPYmplY3RQb3NpdGlvblJpZnRjYWxsZXJzEiUK",
-            "BW93bmVyGAEgASgOMhYucmlmdGNhbGxlci5QbGF5ZXJOYW1lIiEKH09iamVj",
-            "dFBvc2l0aW9uQnJvd3NlckRyYWdUYXJnZXQiIQofT2JqZWN0UG9zaXRpb25D",
-            "YXJkQ2hvaWNlQnJvd3NlciIbChlPYmplY3RQb3NpdGlvbkhhbmRTdG9yYWdl",
-            "IhcKFU9iamVjdFBvc2l0aW9uU2NvcmluZyLGCgoOT2JqZWN0UG9zaXRpb24S",
-            "EwoLc29ydGluZ19rZXkYASABKA0SFgoOc29ydGluZ19zdWJrZXkYAiABKA0S",
-            "OAoJb2Zmc2NyZWVuGAMgASgLMiMucmlmdGNhbGxlci5PYmplY3RQb3NpdGlv",
-            "bk9mZnNjcmVlbkgAEi4KBHJvb20YBCABKAsyHi5yaWZ0Y2FsbGVyLk9iamVj",
-            "dFBvc2l0aW9uUm9vbUgAEi4KBGl0ZW0YBSABKAsyHi5yaWZ0Y2FsbGVyLk9i",
-            "amVjdFBvc2l0aW9uSXRlbUgAEjQKB3N0YWdpbmcYBiABKAsyIS5yaWZ0Y2Fs",
-            "bGVyLk9iamVjdFBvc2l0aW9uU3RhZ2luZ0gAEi4KBGhhbmQYByABKAsyHi5y",
-            "aWZ0Y2FsbGVyLk9iamVjdFBvc2l0aW9uSGFuZEgAEi4KBGRlY2sYCCABKAsy",
-            "Hi5yaWZ0Y2FsbGVyLk9iamVjdFBvc2l0aW9uRGVja0gAEkEKDmRlY2tfY29u",
-            "dGFpbmVyGAkgASgLMicucmlmdGNhbGxlci5PYmplY3RQb3NpdGlvbkRlY2tD",
-            "b250YWluZXJIABI9CgxkaXNjYXJkX3BpbGUYCiABKAsyJS5yaWZ0Y2FsbGVy",
-            "Lk9iamVjdFBvc2l0aW9uRGlzY2FyZFBpbGVIABJQChZkaXNjYXJkX3BpbGVf",
-            "Y29udGFpbmVyGAsgASgLMi4ucmlmdGNhbGxlci5PYmplY3RQb3NpdGlvbkRp",
-            "c2NhcmRQaWxlQ29udGFpbmVySAASLgoEcmFpZBgMIAEoCzIeLnJpZnRjYWxs",
-            "ZXIuT2JqZWN0UG9zaXRpb25SYWlkSAASNAoHYnJvd3NlchgNIAEoCzIhLnJp",
-            "ZnRjYWxsZXIuT2JqZWN0UG9zaXRpb25Ccm93c2VySAASOAoJY2hhcmFjdGVy",
-            "GA4gASgLMiMucmlmdGNhbGxlci5PYmplY3RQb3NpdGlvbkNoYXJhY3RlckgA",
-            "EksKE2NoYXJhY3Rlcl9jb250YWluZXIYDyABKAsyLC5yaWZ0Y2FsbGVyLk9i",
-            "amVjdFBvc2l0aW9uQ2hhcmFjdGVyQ29udGFpbmVySAASOwoIcmV2ZWFsZWQY",
-            "ECABKAsyJy5yaWZ0Y2FsbGVyLk9iamVjdFBvc2l0aW9uUmV2ZWFsZWRDYXJk",
-            "c0gAEjsKCnJpZnRjYWxsZXIYESABKAsyJS5yaWZ0Y2FsbGVyLk9iamVjdFBv",
-            "c2l0aW9uUmlmdGNhbGxlcnNIABJKChNicm93c2VyX2RyYWdfdGFyZ2V0GBIg",
-            "ASgLMisucmlmdGNhbGxlci5PYmplY3RQb3NpdGlvbkJyb3dzZXJEcmFnVGFy",
-            "Z2V0SAASSgoTY2FyZF9jaG9pY2VfYnJvd3NlchgTIAEoCzIrLnJpZnRjYWxs",
-            "ZXIuT2JqZWN0UG9zaXRpb25DYXJkQ2hvaWNlQnJvd3NlckgAEj0KDGhhbmRf",
-            "c3RvcmFnZRgUIAEoCzIlLnJpZnRjYWxsZXIuT2JqZWN0UG9zaXRpb25IYW5k",
-            "U3RvcmFnZUgAEjQKB3Njb3JpbmcYFSABKAsyIS5yaWZ0Y2FsbGVyLk9iamVj",
-            "dFBvc2l0aW9uU2NvcmluZ0gAEjcKCWludG9fY2FyZBgWIAEoCzIiLnJpZnRj",
-            "YWxsZXIuT2JqZWN0UG9zaXRpb25JbnRvQ2FyZEgAEkoKE3N0YWNrZWRfYmVo",
-            "aW5kX2NhcmQYFyABKAsyKy5yaWZ0Y2FsbGVyLk9iamVjdFBvc2l0aW9uU3Rh",
-            "Y2tlZEJlaGluZENhcmRIAEIKCghwb3NpdGlvbiJ4ChFJbmZvWm9vbUhpZ2hs",
-            "aWdodBIqCgRjYXJkGAEgASgLMhoucmlmdGNhbGxlci5DYXJkSWRlbnRpZmll",
-            "ckgAEioKBHJvb20YAiABKA4yGi5yaWZ0Y2FsbGVyLlJvb21JZGVudGlmaWVy",
-            "SABCCwoJaGlnaGxpZ2h0IloKDkNhcmRNb3ZlVGFyZ2V0EjMKD3RhcmdldF9w",
-            "b3NpdGlvbhgBIAEoCzIaLnJpZnRjYWxsZXIuT2JqZWN0UG9zaXRpb24SEwoL",
-            "Y2FuX3Jlb3JkZXIYAiABKAgiiwUKEFJldmVhbGVkQ2FyZFZpZXcSLQoKY2Fy",
-            "ZF9mcmFtZRgBIAEoCzIZLnJpZnRjYWxsZXIuU3ByaXRlQWRkcmVzcxIzChB0",
-            "aXRsZV9iYWNrZ3JvdW5kGAIgASgLMhkucmlmdGNhbGxlci5TcHJpdGVBZGRy",
-            "ZXNzEigKBWpld2VsGAMgASgLMhkucmlmdGNhbGxlci5TcHJpdGVBZGRyZXNz",
-            "EigKBWltYWdlGAQgASgLMhkucmlmdGNhbGxlci5TcHJpdGVBZGRyZXNzEiQK",
-            "BXRpdGxlGAUgASgLMhUucmlmdGNhbGxlci5DYXJkVGl0bGUSKQoKcnVsZXNf",
-            "dGV4dBgGIAEoCzIVLnJpZnRjYWxsZXIuUnVsZXNUZXh0EiwKCXRhcmdldGlu",
-            "ZxgHIAEoCzIZLnJpZnRjYWxsZXIuQ2FyZFRhcmdldGluZxI3ChNvbl9yZWxl",
-            "YXNlX3Bvc2l0aW9uGAggASgLMhoucmlmdGNhbGxlci5PYmplY3RQb3NpdGlv",
-            "bhIrChFzdXBwbGVtZW50YWxfaW5mbxgJIAEoCzIQLnJpZnRjYWxsZXIuTm9k",
-            "ZRIzChBpbWFnZV9iYWNrZ3JvdW5kGAogASgLMhkucmlmdGNhbGxlci5TcHJp",
-            "dGVBZGRyZXNzEjQKEGNhcmRfbW92ZV90YXJnZXQYCyABKAsyGi5yaWZ0Y2Fs",
-            "bGVyLkNhcmRNb3ZlVGFyZ2V0EjMKD3BvaW50X3RvX3BhcmVudBgMIAEoCzIa",
-            "LnJpZnRjYWxsZXIuQ2FyZElkZW50aWZpZXISOgoTaW5mb196b29tX2hpZ2hs",
-            "aWdodBgNIAEoCzIdLnJpZnRjYWxsZXIuSW5mb1pvb21IaWdobGlnaHQibAoL",
-            "Q2FyZEVmZmVjdHMSLAoNb3V0bGluZV9jb2xvchgBIAEoCzIVLnJpZnRjYWxs",
-            "ZXIuRmxleENvbG9yEi8KDGFyZW5hX2VmZmVjdBgCIAEoCzIZLnJpZnRjYWxs",
-            "ZXIuRWZmZWN0QWRkcmVzcyL+BAoIQ2FyZFZpZXcSKwoHY2FyZF9pZBgBIAEo",
-            "CzIaLnJpZnRjYWxsZXIuQ2FyZElkZW50aWZpZXISMQoNY2FyZF9wb3NpdGlv",
-            "bhgCIAEoCzIaLnJpZnRjYWxsZXIuT2JqZWN0UG9zaXRpb24SJgoGcHJlZmFi",
-            "GAMgASgOMhYucmlmdGNhbGxlci5DYXJkUHJlZmFiEiwKCWNhcmRfYmFjaxgE",
-            "IAEoCzIZLnJpZnRjYWxsZXIuU3ByaXRlQWRkcmVzcxIaChJyZXZlYWxlZF90",
-            "b192aWV3ZXIYBSABKAgSEgoKaXNfZmFjZV91cBgGIAEoCBIpCgpjYXJkX2lj",
-            "b25zGAcgASgLMhUucmlmdGNhbGxlci5DYXJkSWNvbnMSLgoLYXJlbmFfZnJh",
-            "bWUYCCABKAsyGS5yaWZ0Y2FsbGVyLlNwcml0ZUFkZHJlc3MSOAoVZmFjZV9k",
-            "b3duX2FyZW5hX2ZyYW1lGAkgASgLMhkucmlmdGNhbGxlci5TcHJpdGVBZGRy",
-            "ZXNzEi0KDW93bmluZ19wbGF5ZXIYCiABKA4yFi5yaWZ0Y2FsbGVyLlBsYXll",
-            "ck5hbWUSMwoNcmV2ZWFsZWRfY2FyZBgLIAEoCzIcLnJpZnRjYWxsZXIuUmV2",
-            "ZWFsZWRDYXJkVmlldxIzCg9jcmVhdGVfcG9zaXRpb24YDCABKAsyGi5yaWZ0",
-            "Y2FsbGVyLk9iamVjdFBvc2l0aW9uEjQKEGRlc3Ryb3lfcG9zaXRpb24YDSAB",
-            "KAsyGi5yaWZ0Y2FsbGVyLk9iamVjdFBvc2l0aW9uEigKB2VmZmVjdHMYDiAB",
-            "KAsyFy5yaWZ0Y2FsbGVyLkNhcmRFZmZlY3RzIn4KClBsYXllckluZm8SOAoU",
-            "dmFsaWRfcm9vbXNfdG9fdmlzaXQYASADKA4yGi5yaWZ0Y2FsbGVyLlJvb21J",
-            "ZGVudGlmaWVyEjYKCmFwcGVhcmFuY2UYAiABKAsyIi5yaWZ0Y2FsbGVyLkNo",
-            "YXJhY3RlclByZXNldEFkZHJlc3MiVAoITWFuYVZpZXcSEQoJYmFzZV9tYW5h",
-            "GAEgASgNEhIKCmJvbnVzX21hbmEYAiABKA0SIQoZY2FuX3Rha2VfZ2Fpbl9t",
-            "YW5hX2FjdGlvbhgDIAEoCCIaCglTY29yZVZpZXcSDQoFc2NvcmUYASABKA0i",
-            "UQoRQWN0aW9uVHJhY2tlclZpZXcSHgoWYXZhaWxhYmxlX2FjdGlvbl9jb3Vu",
-            "dBgBIAEoDRIcChRkZWZhdWx0X2FjdGlvbl9jb3VudBgCIAEoDSJvCghEZWNr",
-            "VmlldxISCgpjYXJkX2NvdW50GAEgASgNEiwKCWNhcmRfYmFjaxgCIAEoCzIZ",
-            "LnJpZnRjYWxsZXIuU3ByaXRlQWRkcmVzcxIhChljYW5fdGFrZV9kcmF3X2Nh",
-            "cmRfYWN0aW9uGAMgASgIIqICCgpQbGF5ZXJWaWV3EiQKBHNpZGUYASABKA4y",
-            "Fi5yaWZ0Y2FsbGVyLlBsYXllclNpZGUSKwoLcGxheWVyX2luZm8YAiABKAsy",
-            "Fi5yaWZ0Y2FsbGVyLlBsYXllckluZm8SJAoFc2NvcmUYAyABKAsyFS5yaWZ0",
-            "Y2FsbGVyLlNjb3JlVmlldxIiCgRtYW5hGAQgASgLMhQucmlmdGNhbGxlci5N",
-            "YW5hVmlldxI1Cg5hY3Rpb25fdHJhY2tlchgFIAEoCzIdLnJpZnRjYWxsZXIu",
-            "QWN0aW9uVHJhY2tlclZpZXcSJwoJZGVja192aWV3GAYgASgLMhQucmlmdGNh",
-            "bGxlci5EZWNrVmlldxIXCg9jYW5fdGFrZV9hY3Rpb24YByABKAgi4QMKE0dh",
-            "bWVPYmplY3RQb3NpdGlvbnMSLQoJdXNlcl9kZWNrGAEgASgLMhoucmlmdGNh",
-            "bGxlci5PYmplY3RQb3NpdGlvbhIxCg1vcHBvbmVudF9kZWNrGAIgASgLMhou",
-            "cmlmdGNhbGxlci5PYmplY3RQb3NpdGlvbhIyCg51c2VyX2NoYXJhY3RlchgD",
-            "IAEoCzIaLnJpZnRjYWxsZXIuT2JqZWN0UG9zaXRpb24SNgoSb3Bwb25lbnRf",
-            "Y2hhcmFjdGVyGAQgASgLMhoucmlmdGNhbGxlci5PYmplY3RQb3NpdGlvbhJH",
-            "ChV1c2VyX2NoYXJhY3Rlcl9mYWNpbmcYBSABKA4yKC5yaWZ0Y2FsbGVyLkdh",
-            "bWVDaGFyYWN0ZXJGYWNpbmdEaXJlY3Rpb24SSwoZb3Bwb25lbnRfY2hhcmFj",
-            "dGVyX2ZhY2luZxgGIAEoDjIoLnJpZnRjYWxsZXIuR2FtZUNoYXJhY3RlckZh",
-            "Y2luZ0RpcmVjdGlvbhIwCgx1c2VyX2Rpc2NhcmQYByABKAsyGi5yaWZ0Y2Fs",
-            "bGVyLk9iamVjdFBvc2l0aW9uEjQKEG9wcG9uZW50X2Rpc2NhcmQYCCABKAsy",
-            "Gi5yaWZ0Y2FsbGVyLk9iamVjdFBvc2l0aW9uItgBChFBcnJvd0J1YmJsZUFu",
-            "Y2hvchIoCgZwbGF5ZXIYASABKA4yFi5yaWZ0Y2FsbGVyLlBsYXllck5hbWVI",
-            "ABIqCgRyb29tGAIgASgOMhoucmlmdGNhbGxlci5Sb29tSWRlbnRpZmllckgA",
-            "Ei0KC3BsYXllcl9kZWNrGAMgASgOMhYucmlmdGNhbGxlci5QbGF5ZXJOYW1l",
-            "SAASLQoLcGxheWVyX21hbmEYBCABKA4yFi5yaWZ0Y2FsbGVyLlBsYXllck5h",
-            "bWVIAEIPCg1idWJibGVfYW5jaG9yIoUDCg9TaG93QXJyb3dCdWJibGUSDAoE",
-            "dGV4dBgBIAEoCRIpCgppZGxlX3RpbWVyGAIgASgLMhUucmlmdGNhbGxlci5U",
-            "aW1lVmFsdWUSKAoJaGlkZV90aW1lGAMgASgLMhUucmlmdGNhbGxlci5UaW1l",
-            "VmFsdWUSJAoFY29sb3IYBCABKAsyFS5yaWZ0Y2FsbGVyLkZsZXhDb2xvchIu",
-            "Cglmb250X3NpemUYBSABKAsyGy5nb29nbGUucHJvdG9idWYuRmxvYXRWYWx1",
-            "ZRIpCgpmb250X2NvbG9yGAYgASgLMhUucmlmdGNhbGxlci5GbGV4Q29sb3IS",
-            "KgoFc2NhbGUYByABKAsyGy5nb29nbGUucHJvdG9idWYuRmxvYXRWYWx1ZRIz",
-            "CgxhcnJvd19jb3JuZXIYCCABKA4yHS5yaWZ0Y2FsbGVyLkFycm93QnViYmxl",
-            "Q29ybmVyEi0KBmFuY2hvchgJIAEoCzIdLnJpZnRjYWxsZXIuQXJyb3dCdWJi",
-            "bGVBbmNob3IigAEKCVNob3dUb2FzdBIeCgRub2RlGAEgASgLMhAucmlmdGNh",
-            "bGxlci5Ob2RlEikKCmlkbGVfdGltZXIYAiABKAsyFS5yaWZ0Y2FsbGVyLlRp",
-            "bWVWYWx1ZRIoCgloaWRlX3RpbWUYAyABKAsyFS5yaWZ0Y2FsbGVyLlRpbWVW",
-            "YWx1ZSKKAQoOVHV0b3JpYWxFZmZlY3QSMwoMYXJyb3dfYnViYmxlGAEgASgL",
-            "MhsucmlmdGNhbGxlci5TaG93QXJyb3dCdWJibGVIABIrCgpzaG93X3RvYXN0",
-            "GAIgASgLMhUucmlmdGNhbGxlci5TaG93VG9hc3RIAEIWChR0dXRvcmlhbF9l",
-            "ZmZlY3RfdHlwZSLEAgoIR2FtZVZpZXcSJAoEdXNlchgBIAEoCzIWLnJpZnRj",
-            "YWxsZXIuUGxheWVyVmlldxIoCghvcHBvbmVudBgCIAEoCzIWLnJpZnRjYWxs",
-            "ZXIuUGxheWVyVmlldxIjCgVjYXJkcxgDIAMoCzIULnJpZnRjYWxsZXIuQ2Fy",
-            "ZFZpZXcSEwoLcmFpZF9hY3RpdmUYBCABKAgSPgoVZ2FtZV9vYmplY3RfcG9z",
-            "aXRpb25zGAUgASgLMh8ucmlmdGNhbGxlci5HYW1lT2JqZWN0UG9zaXRpb25z",
-            "EjgKDW1haW5fY29udHJvbHMYBiABKAsyIS5yaWZ0Y2FsbGVyLkludGVyZmFj",
-            "ZU1haW5Db250cm9scxI0ChB0dXRvcmlhbF9lZmZlY3RzGAcgAygLMhoucmlm",
-            "dGNhbGxlci5UdXRvcmlhbEVmZmVjdCJjChJTdHVkaW9BcHBlYXJFZmZlY3QS",
-            "JAoFZGVsYXkYASABKAsyFS5yaWZ0Y2FsbGVyLlRpbWVWYWx1ZRIWCgxzZXRf",
-            "cmV2ZWFsZWQYAiABKAhIAEIPCg1zdHVkaW9fYXBwZWFyIm8KEVN0dWRpb0Rp",
-            "c3BsYXlDYXJkEiIKBGNhcmQYASABKAsyFC5yaWZ0Y2FsbGVyLkNhcmRWaWV3",
-            "EjYKDmFwcGVhcl9lZmZlY3RzGAIgAygLMh4ucmlmdGNhbGxlci5TdHVkaW9B",
-            "cHBlYXJFZmZlY3QiSQoNU3R1ZGlvRGlzcGxheRItCgRjYXJkGAEgASgLMh0u",
-            "cmlmdGNhbGxlci5TdHVkaW9EaXNwbGF5Q2FyZEgAQgkKB2Rpc3BsYXkixwEK",
-            "DlN0YW5kYXJkQWN0aW9uEg8KB3BheWxvYWQYASABKAwSJwoGdXBkYXRlGAIg",
-            "ASgLMhcucmlmdGNhbGxlci5Db21tYW5kTGlzdBJFCg5yZXF1ZXN0X2ZpZWxk",
-            "cxgDIAMoCzItLnJpZnRjYWxsZXIuU3RhbmRhcmRBY3Rpb24uUmVxdWVzdEZp",
-            "ZWxkc0VudHJ5GjQKElJlcXVlc3RGaWVsZHNFbnRyeRILCgNrZXkYASABKAkS",
-            "DQoFdmFsdWUYAiABKAk6AjgBIhAKDkdhaW5NYW5hQWN0aW9uIhAKDkRyYXdD",
-            "YXJkQWN0aW9uIkEKElByb2dyZXNzUm9vbUFjdGlvbhIrCgdyb29tX2lkGAEg",
-            "ASgOMhoucmlmdGNhbGxlci5Sb29tSWRlbnRpZmllciJKCgpDYXJkVGFyZ2V0",
-            "Ei0KB3Jvb21faWQYASABKA4yGi5yaWZ0Y2FsbGVyLlJvb21JZGVudGlmaWVy",
-            "SABCDQoLY2FyZF90YXJnZXQiZQoOUGxheUNhcmRBY3Rpb24SKwoHY2FyZF9p",
-            "ZBgBIAEoCzIaLnJpZnRjYWxsZXIuQ2FyZElkZW50aWZpZXISJgoGdGFyZ2V0",
-            "GAIgASgLMhYucmlmdGNhbGxlci5DYXJkVGFyZ2V0IkEKEkluaXRpYXRlUmFp",
-            "ZEFjdGlvbhIrCgdyb29tX2lkGAEgASgOMhoucmlmdGNhbGxlci5Sb29tSWRl",
-            "bnRpZmllciJMChBGZXRjaFBhbmVsQWN0aW9uEjgKDXBhbmVsX2FkZHJlc3MY",
-            "ASABKAsyIS5yaWZ0Y2FsbGVyLkludGVyZmFjZVBhbmVsQWRkcmVzcyIYChZT",
-            "cGVuZEFjdGlvblBvaW50QWN0aW9uImoKDk1vdmVDYXJkQWN0aW9uEisKB2Nh",
-            "cmRfaWQYASABKAsyGi5yaWZ0Y2FsbGVyLkNhcmRJZGVudGlmaWVyEisKBWlu",
-            "ZGV4GAIgASgLMhwuZ29vZ2xlLnByb3RvYnVmLlVJbnQzMlZhbHVlIvwDCgxD",
-            "bGllbnRBY3Rpb24SNQoPc3RhbmRhcmRfYWN0aW9uGAEgASgLMhoucmlmdGNh",
-            "bGxlci5TdGFuZGFyZEFjdGlvbkgAEjMKC2ZldGNoX3BhbmVsGAIgASgLMhwu",
-            "cmlmdGNhbGxlci5GZXRjaFBhbmVsQWN0aW9uSAASLwoJZ2Fpbl9tYW5hGAMg",
-            "ASgLMhoucmlmdGNhbGxlci5HYWluTWFuYUFjdGlvbkgAEi8KCWRyYXdfY2Fy",
-            "ZBgEIAEoCzIaLnJpZnRjYWxsZXIuRHJhd0NhcmRBY3Rpb25IABIvCglwbGF5",
-            "X2NhcmQYBSABKAsyGi5yaWZ0Y2FsbGVyLlBsYXlDYXJkQWN0aW9uSAASNwoN",
-            "cHJvZ3Jlc3Nfcm9vbRgGIAEoCzIeLnJpZnRjYWxsZXIuUHJvZ3Jlc3NSb29t",
-            "QWN0aW9uSAASNwoNaW5pdGlhdGVfcmFpZBgHIAEoCzIeLnJpZnRjYWxsZXIu",
-            "SW5pdGlhdGVSYWlkQWN0aW9uSAASQAoSc3BlbmRfYWN0aW9uX3BvaW50GAgg",
-            "ASgLMiIucmlmdGNhbGxlci5TcGVuZEFjdGlvblBvaW50QWN0aW9uSAASLwoJ",
-            "bW92ZV9jYXJkGAkgASgLMhoucmlmdGNhbGxlci5Nb3ZlQ2FyZEFjdGlvbkgA",
-            "QggKBmFjdGlvbiJzCg5DbGllbnRNZXRhZGF0YRIyCgxhZHZlbnR1cmVfaWQY",
-            "AiABKAsyHC5nb29nbGUucHJvdG9idWYuU3RyaW5nVmFsdWUSLQoHZ2FtZV9p",
-            "ZBgBIAEoCzIcLmdvb2dsZS5wcm90b2J1Zi5TdHJpbmdWYWx1ZSJBCg5Db25u",
-            "ZWN0UmVxdWVzdBIvCglwbGF5ZXJfaWQYASABKAsyHC5yaWZ0Y2FsbGVyLlBs",
-            "YXllcklkZW50aWZpZXIiPgoLUG9sbFJlcXVlc3QSLwoJcGxheWVyX2lkGAEg",
-            "ASgLMhwucmlmdGNhbGxlci5QbGF5ZXJJZGVudGlmaWVyIs4BCgtHYW1lUmVx",
-            "dWVzdBIoCgZhY3Rpb24YASABKAsyGC5yaWZ0Y2FsbGVyLkNsaWVudEFjdGlv",
-            "bhIvCglwbGF5ZXJfaWQYAiABKAsyHC5yaWZ0Y2FsbGVyLlBsYXllcklkZW50",
-            "aWZpZXISNgoLb3Blbl9wYW5lbHMYAyADKAsyIS5yaWZ0Y2FsbGVyLkludGVy",
-            "ZmFjZVBhbmVsQWRkcmVzcxIsCghtZXRhZGF0YRgEIAEoCzIaLnJpZnRjYWxs",
-            "ZXIuQ2xpZW50TWV0YWRhdGEiIgoPRGVidWdMb2dDb21tYW5kEg8KB21lc3Nh",
-            "Z2UYASABKAkiNwoMRGVsYXlDb21tYW5kEicKCGR1cmF0aW9uGAEgASgLMhUu",
-            "cmlmdGNhbGxlci5UaW1lVmFsdWUiQQoVSW50ZXJmYWNlUGFuZWxBZGRyZXNz",
-            "EhQKDGRlYnVnX3N0cmluZxgBIAEoCRISCgpzZXJpYWxpemVkGAIgASgMIo4B",
-            "Cg5JbnRlcmZhY2VQYW5lbBIyCgdhZGRyZXNzGAEgASgLMiEucmlmdGNhbGxl",
-            "ci5JbnRlcmZhY2VQYW5lbEFkZHJlc3MSHgoEbm9kZRgCIAEoCzIQLnJpZnRj",
-            "YWxsZXIuTm9kZRIoCg5zY3JlZW5fb3ZlcmxheRgDIAEoCzIQLnJpZnRjYWxs",
-            "ZXIuTm9kZSJqCgpDYXJkQW5jaG9yEi0KC25vZGVfY29ybmVyGAEgASgOMhgu",
-            "cmlmdGNhbGxlci5BbmNob3JDb3JuZXISLQoLY2FyZF9jb3JuZXIYAiABKA4y",
-            "GC5yaWZ0Y2FsbGVyLkFuY2hvckNvcm5lciKGAQoOQ2FyZEFuY2hvck5vZGUS",
-            "KwoHY2FyZF9pZBgBIAEoCzIaLnJpZnRjYWxsZXIuQ2FyZElkZW50aWZpZXIS",
-            "HgoEbm9kZRgCIAEoCzIQLnJpZnRjYWxsZXIuTm9kZRInCgdhbmNob3JzGAMg",
-            "AygLMhYucmlmdGNhbGxlci5DYXJkQW5jaG9yIpEBChVJbnRlcmZhY2VNYWlu",
-            "Q29udHJvbHMSHgoEbm9kZRgBIAEoCzIQLnJpZnRjYWxsZXIuTm9kZRIhCgdv",
-            "dmVybGF5GAIgASgLMhAucmlmdGNhbGxlci5Ob2RlEjUKEWNhcmRfYW5jaG9y",
-            "X25vZGVzGAMgAygLMhoucmlmdGNhbGxlci5DYXJkQW5jaG9yTm9kZSJBChNV",
-            "cGRhdGVQYW5lbHNDb21tYW5kEioKBnBhbmVscxgBIAMoCzIaLnJpZnRjYWxs",
-            "ZXIuSW50ZXJmYWNlUGFuZWwieQoXQWRkcmVzc1dpdGhMb2FkaW5nU3RhdGUS",
-            "NQoKb3Blbl9wYW5lbBgBIAEoCzIhLnJpZnRjYWxsZXIuSW50ZXJmYWNlUGFu",
-            "ZWxBZGRyZXNzEicKDWxvYWRpbmdfc3RhdGUYAiABKAsyEC5yaWZ0Y2FsbGVy",
-            "Lk5vZGUixQEKFlBhbmVsVHJhbnNpdGlvbk9wdGlvbnMSLwoEb3BlbhgBIAEo",
-            "CzIhLnJpZnRjYWxsZXIuSW50ZXJmYWNlUGFuZWxBZGRyZXNzEjAKBWNsb3Nl",
-            "GAIgASgLMiEucmlmdGNhbGxlci5JbnRlcmZhY2VQYW5lbEFkZHJlc3MSMgoH",
-            "bG9hZGluZxgDIAEoCzIhLnJpZnRjYWxsZXIuSW50ZXJmYWNlUGFuZWxBZGRy",
-            "ZXNzEhQKDHdhaXRfdG9fbG9hZBgEIAEoCCLwAgoSVG9nZ2xlUGFuZWxDb21t",
-            "YW5kEjgKCnRyYW5zaXRpb24YASABKAsyIi5yaWZ0Y2FsbGVyLlBhbmVsVHJh",
-            "bnNpdGlvbk9wdGlvbnNIABJGChlvcGVuX2JvdHRvbV9zaGVldF9hZGRyZXNz",
-            "GAIgASgLMiEucmlmdGNhbGxlci5JbnRlcmZhY2VQYW5lbEFkZHJlc3NIABI0",
-            "ChJjbG9zZV9ib3R0b21fc2hlZXQYAyABKAsyFi5nb29nbGUucHJvdG9idWYu",
-            "RW1wdHlIABJGChlwdXNoX2JvdHRvbV9zaGVldF9hZGRyZXNzGAQgASgLMiEu",
-            "cmlmdGNhbGxlci5JbnRlcmZhY2VQYW5lbEFkZHJlc3NIABJIChtwb3BfdG9f",
-            "Ym90dG9tX3NoZWV0X2FkZHJlc3MYBSABKAsyIS5yaWZ0Y2FsbGVyLkludGVy",
-            "ZmFjZVBhbmVsQWRkcmVzc0gAQhAKDnRvZ2dsZV9jb21tYW5kIkwKFVVwZGF0",
-            "ZUdhbWVWaWV3Q29tbWFuZBIiCgRnYW1lGAEgASgLMhQucmlmdGNhbGxlci5H",
-            "YW1lVmlldxIPCgdhbmltYXRlGAIgASgIIpkBChBWaXNpdFJvb21Db21tYW5k",
-            "EikKCWluaXRpYXRvchgBIAEoDjIWLnJpZnRjYWxsZXIuUGxheWVyTmFtZRIr",
-            "Cgdyb29tX2lkGAIgASgOMhoucmlmdGNhbGxlci5Sb29tSWRlbnRpZmllchIt",
-            "Cgp2aXNpdF90eXBlGAMgASgOMhkucmlmdGNhbGxlci5Sb29tVmlzaXRUeXBl",
-            "Ik0KFkNyZWF0ZVRva2VuQ2FyZENvbW1hbmQSIgoEY2FyZBgBIAEoCzIULnJp",
-            "ZnRjYWxsZXIuQ2FyZFZpZXcSDwoHYW5pbWF0ZRgCIAEoCCJ+ChxTZXRDYXJk",
-            "TW92ZW1lbnRFZmZlY3RDb21tYW5kEisKB2NhcmRfaWQYASABKAsyGi5yaWZ0",
-            "Y2FsbGVyLkNhcmRJZGVudGlmaWVyEjEKCnByb2plY3RpbGUYAiABKAsyHS5y",
-            "aWZ0Y2FsbGVyLlByb2plY3RpbGVBZGRyZXNzImwKDkdhbWVPYmplY3RNb3Zl",
-            "EiwKAmlkGAEgASgLMiAucmlmdGNhbGxlci5HYW1lT2JqZWN0SWRlbnRpZmll",
-            "chIsCghwb3NpdGlvbhgCIAEoCzIaLnJpZnRjYWxsZXIuT2JqZWN0UG9zaXRp",
-            "b24ihAEKFk1vdmVHYW1lT2JqZWN0c0NvbW1hbmQSKQoFbW92ZXMYASADKAsy",
-            "Gi5yaWZ0Y2FsbGVyLkdhbWVPYmplY3RNb3ZlEhkKEWRpc2FibGVfYW5pbWF0",
-            "aW9uGAIgASgIEiQKBWRlbGF5GAMgASgLMhUucmlmdGNhbGxlci5UaW1lVmFs",
-            "dWUiPwoQUGxheVNvdW5kQ29tbWFuZBIrCgVzb3VuZBgBIAEoCzIcLnJpZnRj",
-            "YWxsZXIuQXVkaW9DbGlwQWRkcmVzcyI+Cg9TZXRNdXNpY0NvbW1hbmQSKwoL",
-            "bXVzaWNfc3RhdGUYASABKA4yFi5yaWZ0Y2FsbGVyLk11c2ljU3RhdGUiqwQK",
-            "FUZpcmVQcm9qZWN0aWxlQ29tbWFuZBIzCglzb3VyY2VfaWQYASABKAsyIC5y",
-            "aWZ0Y2FsbGVyLkdhbWVPYmplY3RJZGVudGlmaWVyEjMKCXRhcmdldF9pZBgC",
-            "IAEoCzIgLnJpZnRjYWxsZXIuR2FtZU9iamVjdElkZW50aWZpZXISMQoKcHJv",
-            "amVjdGlsZRgDIAEoCzIdLnJpZnRjYWxsZXIuUHJvamVjdGlsZUFkZHJlc3MS",
-            "LgoPdHJhdmVsX2R1cmF0aW9uGAQgASgLMhUucmlmdGNhbGxlci5UaW1lVmFs",
-            "dWUSMAoKZmlyZV9zb3VuZBgFIAEoCzIcLnJpZnRjYWxsZXIuQXVkaW9DbGlw",
-            "QWRkcmVzcxIyCgxpbXBhY3Rfc291bmQYBiABKAsyHC5yaWZ0Y2FsbGVyLkF1",
-            "ZGlvQ2xpcEFkZHJlc3MSMQoOYWRkaXRpb25hbF9oaXQYByABKAsyGS5yaWZ0",
-            "Y2FsbGVyLkVmZmVjdEFkZHJlc3MSMwoUYWRkaXRpb25hbF9oaXRfZGVsYXkY",
-            "CCABKAsyFS5yaWZ0Y2FsbGVyLlRpbWVWYWx1ZRIsCg13YWl0X2R1cmF0aW9u",
-            "GAkgASgLMhUucmlmdGNhbGxlci5UaW1lVmFsdWUSEwoLaGlkZV9vbl9oaXQY",
-            "CiABKAgSNAoQanVtcF90b19wb3NpdGlvbhgLIAEoCzIaLnJpZnRjYWxsZXIu",
-            "T2JqZWN0UG9zaXRpb24iYAoSUGxheUVmZmVjdFBvc2l0aW9uEjcKC2dhbWVf",
-            "b2JqZWN0GAEgASgLMiAucmlmdGNhbGxlci5HYW1lT2JqZWN0SWRlbnRpZmll",
-            "ckgAQhEKD2VmZmVjdF9wb3NpdGlvbiLlAgoRUGxheUVmZmVjdENvbW1hbmQS",
-            "KQoGZWZmZWN0GAEgASgLMhkucmlmdGNhbGxlci5FZmZlY3RBZGRyZXNzEjAK",
-            "CHBvc2l0aW9uGAIgASgLMh4ucmlmdGNhbGxlci5QbGF5RWZmZWN0UG9zaXRp",
-            "b24SKgoFc2NhbGUYAyABKAsyGy5nb29nbGUucHJvdG9idWYuRmxvYXRWYWx1",
-            "ZRInCghkdXJhdGlvbhgEIAEoCzIVLnJpZnRjYWxsZXIuVGltZVZhbHVlEisK",
-            "BXNvdW5kGAUgASgLMhwucmlmdGNhbGxlci5BdWRpb0NsaXBBZGRyZXNzEhQK",
-            "DGFyZW5hX2VmZmVjdBgGIAEoCBIqCgtzdGFydF9jb2xvchgHIAEoCzIVLnJp",
-            "ZnRjYWxsZXIuRmxleENvbG9yEi8KBW93bmVyGAggASgLMiAucmlmdGNhbGxl",
-            "ci5HYW1lT2JqZWN0SWRlbnRpZmllciJOChlEaXNwbGF5R2FtZU1lc3NhZ2VD",
-            "b21tYW5kEjEKDG1lc3NhZ2VfdHlwZRgBIAEoDjIbLnJpZnRjYWxsZXIuR2Ft",
-            "ZU1lc3NhZ2VUeXBlIjwKHFNldEdhbWVPYmplY3RzRW5hYmxlZENvbW1hbmQS",
-            "HAoUZ2FtZV9vYmplY3RzX2VuYWJsZWQYASABKAgiPgoVRGlzcGxheVJld2Fy",
-            "ZHNDb21tYW5kEiUKB3Jld2FyZHMYASADKAsyFC5yaWZ0Y2FsbGVyLkNhcmRW",
-            "aWV3ImgKEExvYWRTY2VuZUNvbW1hbmQSEgoKc2NlbmVfbmFtZRgBIAEoCRIn",
-            "CgRtb2RlGAIgASgOMhkucmlmdGNhbGxlci5TY2VuZUxvYWRNb2RlEhcKD3Nr",
-            "aXBfaWZfY3VycmVudBgDIAEoCCIyChRTZXRCb29sZWFuUHJlZmVyZW5jZRIL",
-            "CgNrZXkYASABKAkSDQoFdmFsdWUYAiABKAgiRgoKTG9nTWVzc2FnZRIMCgR0",
-            "ZXh0GAEgASgJEioKBWxldmVsGAIgASgOMhsucmlmdGNhbGxlci5Mb2dNZXNz",
-            "YWdlTGV2ZWwirgIKEkNsaWVudERlYnVnQ29tbWFuZBIrCglzaG93X2xvZ3MY",
-            "ASABKAsyFi5nb29nbGUucHJvdG9idWYuRW1wdHlIABIxCg1pbnZva2VfYWN0",
-            "aW9uGAIgASgLMhgucmlmdGNhbGxlci5DbGllbnRBY3Rpb25IABItCgtsb2df",
-            "bWVzc2FnZRgDIAEoCzIWLnJpZnRjYWxsZXIuTG9nTWVzc2FnZUgAEkIKFnNl",
-            "dF9ib29sZWFuX3ByZWZlcmVuY2UYBCABKAsyIC5yaWZ0Y2FsbGVyLlNldEJv",
-            "b2xlYW5QcmVmZXJlbmNlSAASNAoSc2hvd19mZWVkYmFja19mb3JtGAUgASgL",
-            "MhYuZ29vZ2xlLnByb3RvYnVmLkVtcHR5SABCDwoNZGVidWdfY29tbWFuZCIj",
-            "CgtNYXBQb3NpdGlvbhIJCgF4GAEgASgFEgkKAXkYAiABKAUiwQEKDldvcmxk",
-            "TWFwU3ByaXRlEjEKDnNwcml0ZV9hZGRyZXNzGAEgASgLMhkucmlmdGNhbGxl",
-            "ci5TcHJpdGVBZGRyZXNzEiQKBWNvbG9yGAIgASgLMhUucmlmdGNhbGxlci5G",
-            "bGV4Q29sb3ISLgoNYW5jaG9yX29mZnNldBgDIAEoCzIXLnJpZnRjYWxsZXIu",
-            "RmxleFZlY3RvcjMSJgoFc2NhbGUYBCABKAsyFy5yaWZ0Y2FsbGVyLkZsZXhW",
-            "ZWN0b3IzIo8BChFXb3JsZE1hcENoYXJhY3RlchI2CgphcHBlYXJhbmNlGAEg",
-            "ASgLMiIucmlmdGNhbGxlci5DaGFyYWN0ZXJQcmVzZXRBZGRyZXNzEkIKEGZh",
-            "Y2luZ19kaXJlY3Rpb24YAiABKA4yKC5yaWZ0Y2FsbGVyLkdhbWVDaGFyYWN0",
-            "ZXJGYWNpbmdEaXJlY3Rpb24i8AEKDFdvcmxkTWFwVGlsZRIrCgdzcHJpdGVz",
-            "GAEgAygLMhoucmlmdGNhbGxlci5Xb3JsZE1hcFNwcml0ZRIpCghwb3NpdGlv",
-            "bhgCIAEoCzIXLnJpZnRjYWxsZXIuTWFwUG9zaXRpb24SKgoIb25fdmlzaXQY",
-            "AyABKAsyGC5yaWZ0Y2FsbGVyLkNsaWVudEFjdGlvbhIqCgl0aWxlX3R5cGUY",
-            "BCABKA4yFy5yaWZ0Y2FsbGVyLk1hcFRpbGVUeXBlEjAKCWNoYXJhY3RlchgF",
-            "IAEoCzIdLnJpZnRjYWxsZXIuV29ybGRNYXBDaGFyYWN0ZXIiQAoVVXBkYXRl",
-            "V29ybGRNYXBDb21tYW5kEicKBXRpbGVzGAEgAygLMhgucmlmdGNhbGxlci5X",
-            "b3JsZE1hcFRpbGUiPAoaUmVuZGVyU2NyZWVuT3ZlcmxheUNvbW1hbmQSHgoE",
-            "bm9kZRgBIAEoCzIQLnJpZnRjYWxsZXIuTm9kZSKBAQoPRWxlbWVudFNlbGVj",
-            "dG9yEhYKDGVsZW1lbnRfbmFtZRgBIAEoCUgAEjAKDmRyYWdfaW5kaWNhdG9y",
-            "GAIgASgLMhYuZ29vZ2xlLnByb3RvYnVmLkVtcHR5SAASGAoOdGFyZ2V0X2Vs",
-            "ZW1lbnQYAyABKAlIAEIKCghzZWxlY3RvciJhChBFbGVtZW50QW5pbWF0aW9u",
-            "EicKCGR1cmF0aW9uGAEgASgLMhUucmlmdGNhbGxlci5UaW1lVmFsdWUSJAoE",
-            "ZWFzZRgCIAEoDjIWLnJpZnRjYWxsZXIuRWFzaW5nTW9kZSK9AQoRQW5pbWF0",
-            "ZVRvUG9zaXRpb24SMAoLZGVzdGluYXRpb24YASABKAsyGy5yaWZ0Y2FsbGVy",
-            "LkVsZW1lbnRTZWxlY3RvchIvCglhbmltYXRpb24YAiABKAsyHC5yaWZ0Y2Fs",
-            "bGVyLkVsZW1lbnRBbmltYXRpb24SIgoaZGlzYWJsZV9oZWlnaHRfaGFsZl9v",
-            "ZmZzZXQYAyABKAgSIQoZZGlzYWJsZV93aWR0aF9oYWxmX29mZnNldBgEIAEo",
-            "CCKcAQoYQ3JlYXRlVGFyZ2V0QXRDaGlsZEluZGV4EisKBnBhcmVudBgBIAEo",
-            "CzIbLnJpZnRjYWxsZXIuRWxlbWVudFNlbGVjdG9yEg0KBWluZGV4GAIgASgN",
-            "EhMKC3RhcmdldF9uYW1lGAMgASgJEi8KCWFuaW1hdGlvbhgEIAEoCzIcLnJp",
-            "ZnRjYWxsZXIuRWxlbWVudEFuaW1hdGlvbiKyAQoTQW5pbWF0ZUVsZW1lbnRT",
-            "dHlsZRIvCglhbmltYXRpb24YASABKAsyHC5yaWZ0Y2FsbGVyLkVsZW1lbnRB",
-            "bmltYXRpb24SEQoHb3BhY2l0eRgCIAEoAkgAEg8KBXdpZHRoGAMgASgCSAAS",
-            "EAoGaGVpZ2h0GAQgASgCSAASKAoFc2NhbGUYBSABKAsyFy5yaWZ0Y2FsbGVy",
-            "LkZsZXhWZWN0b3IySABCCgoIcHJvcGVydHki8wIKD0ludGVyZmFjZVVwZGF0",
-            "ZRIvCg1jbG9uZV9lbGVtZW50GAEgASgLMhYuZ29vZ2xlLnByb3RvYnVmLkVt",
-            "cHR5SAASMQoPZGVzdHJveV9lbGVtZW50GAIgASgLMhYuZ29vZ2xlLnByb3Rv",
-            "YnVmLkVtcHR5SAASPAoTYW5pbWF0ZV90b19wb3NpdGlvbhgDIAEoCzIdLnJp",
-            "ZnRjYWxsZXIuQW5pbWF0ZVRvUG9zaXRpb25IABIsCgthcHBseV9zdHlsZRgE",
-            "IAEoCzIVLnJpZnRjYWxsZXIuRmxleFN0eWxlSAASOAoNYW5pbWF0ZV9zdHls",
-            "ZRgFIAEoCzIfLnJpZnRjYWxsZXIuQW5pbWF0ZUVsZW1lbnRTdHlsZUgAEkwK",
-            "HGNyZWF0ZV90YXJnZXRfYXRfY2hpbGRfaW5kZXgYBiABKAsyJC5yaWZ0Y2Fs",
-            "bGVyLkNyZWF0ZVRhcmdldEF0Q2hpbGRJbmRleEgAQggKBnVwZGF0ZSKbAQoT",
-            "VXBkYXRlSW50ZXJmYWNlU3RlcBIsCgdlbGVtZW50GAEgASgLMhsucmlmdGNh",
-            "bGxlci5FbGVtZW50U2VsZWN0b3ISKwoGdXBkYXRlGAIgASgLMhsucmlmdGNh",
-            "bGxlci5JbnRlcmZhY2VVcGRhdGUSKQoKc3RhcnRfdGltZRgDIAEoCzIVLnJp",
-            "ZnRjYWxsZXIuVGltZVZhbHVlIkgKFlVwZGF0ZUludGVyZmFjZUNvbW1hbmQS",
-            "LgoFc3RlcHMYASADKAsyHy5yaWZ0Y2FsbGVyLlVwZGF0ZUludGVyZmFjZVN0",
-            "ZXAiUgoQQ29uZGl0aW9uYWxRdWVyeRI1Cg5lbGVtZW50X2V4aXN0cxgBIAEo",
-            "CzIbLnJpZnRjYWxsZXIuRWxlbWVudFNlbGVjdG9ySABCBwoFcXVlcnkilgEK",
-            "EkNvbmRpdGlvbmFsQ29tbWFuZBIrCgVxdWVyeRgBIAEoCzIcLnJpZnRjYWxs",
-            "ZXIuQ29uZGl0aW9uYWxRdWVyeRIoCgdpZl90cnVlGAIgASgLMhcucmlmdGNh",
-            "bGxlci5Db21tYW5kTGlzdBIpCghpZl9mYWxzZRgDIAEoCzIXLnJpZnRjYWxs",
-            "ZXIuQ29tbWFuZExpc3QiQwoPSW5mb1pvb21Db21tYW5kEgwKBHNob3cYASAB",
-            "KAgSIgoEY2FyZBgCIAEoCzIULnJpZnRjYWxsZXIuQ2FyZFZpZXciTgoQS2V5",
-            "Ym9hcmRTaG9ydGN1dBIQCghrZXlfbmFtZRgBIAEoCRILCgNhbHQYAiABKAgS",
-            "DAoEY3RybBgDIAEoCBINCgVzaGlmdBgEIAEoCCJrCg9LZXlib2FyZE1hcHBp",
-            "bmcSLgoIc2hvcnRjdXQYASABKAsyHC5yaWZ0Y2FsbGVyLktleWJvYXJkU2hv",
-            "cnRjdXQSKAoGYWN0aW9uGAIgASgLMhgucmlmdGNhbGxlci5DbGllbnRBY3Rp",
-            "b24iUAobU2V0S2V5Ym9hcmRTaG9ydGN1dHNDb21tYW5kEjEKDG1hcHBpbmdf",
-            "bGlzdBgBIAMoCzIbLnJpZnRjYWxsZXIuS2V5Ym9hcmRNYXBwaW5nIlAKIVR1",
-            "cm5GYWNlRG93bkFyZW5hQW5pbWF0aW9uQ29tbWFuZBIrCgdjYXJkX2lkGAEg",
-            "ASgLMhoucmlmdGNhbGxlci5DYXJkSWRlbnRpZmllciJGChNDbGVhckVmZmVj",
-            "dHNDb21tYW5kEi8KBW93bmVyGAEgASgLMiAucmlmdGNhbGxlci5HYW1lT2Jq",
-            "ZWN0SWRlbnRpZmllciKkDAoLR2FtZUNvbW1hbmQSLwoFZGVidWcYASABKAsy",
-            "Hi5yaWZ0Y2FsbGVyLkNsaWVudERlYnVnQ29tbWFuZEgAEikKBWRlbGF5GAIg",
-            "ASgLMhgucmlmdGNhbGxlci5EZWxheUNvbW1hbmRIABI4Cg11cGRhdGVfcGFu",
-            "ZWxzGAMgASgLMh8ucmlmdGNhbGxlci5VcGRhdGVQYW5lbHNDb21tYW5kSAAS",
-            "NgoMdG9nZ2xlX3BhbmVsGAQgASgLMh4ucmlmdGNhbGxlci5Ub2dnbGVQYW5l",
-            "bENvbW1hbmRIABI9ChB1cGRhdGVfZ2FtZV92aWV3GAUgASgLMiEucmlmdGNh",
-            "bGxlci5VcGRhdGVHYW1lVmlld0NvbW1hbmRIABIyCgp2aXNpdF9yb29tGAYg",
-            "ASgLMhwucmlmdGNhbGxlci5WaXNpdFJvb21Db21tYW5kSAASMgoKcGxheV9z",
-            "b3VuZBgHIAEoCzIcLnJpZnRjYWxsZXIuUGxheVNvdW5kQ29tbWFuZEgAEjAK",
-            "CXNldF9tdXNpYxgIIAEoCzIbLnJpZnRjYWxsZXIuU2V0TXVzaWNDb21tYW5k",
-            "SAASPAoPZmlyZV9wcm9qZWN0aWxlGAkgASgLMiEucmlmdGNhbGxlci5GaXJl",
-            "UHJvamVjdGlsZUNvbW1hbmRIABI0CgtwbGF5X2VmZmVjdBgKIAEoCzIdLnJp",
-            "ZnRjYWxsZXIuUGxheUVmZmVjdENvbW1hbmRIABJFChRkaXNwbGF5X2dhbWVf",
-            "bWVzc2FnZRgLIAEoCzIlLnJpZnRjYWxsZXIuRGlzcGxheUdhbWVNZXNzYWdl",
-            "Q29tbWFuZEgAEkwKGHNldF9nYW1lX29iamVjdHNfZW5hYmxlZBgMIAEoCzIo",
-            "LnJpZnRjYWxsZXIuU2V0R2FtZU9iamVjdHNFbmFibGVkQ29tbWFuZEgAEjwK",
-            "D2Rpc3BsYXlfcmV3YXJkcxgNIAEoCzIhLnJpZnRjYWxsZXIuRGlzcGxheVJl",
-            "d2FyZHNDb21tYW5kSAASMgoKbG9hZF9zY2VuZRgOIAEoCzIcLnJpZnRjYWxs",
-            "ZXIuTG9hZFNjZW5lQ29tbWFuZEgAEj8KEW1vdmVfZ2FtZV9vYmplY3RzGA8g",
-            "ASgLMiIucmlmdGNhbGxlci5Nb3ZlR2FtZU9iamVjdHNDb21tYW5kSAASPwoR",
-            "Y3JlYXRlX3Rva2VuX2NhcmQYECABKAsyIi5yaWZ0Y2FsbGVyLkNyZWF0ZVRv",
-            "a2VuQ2FyZENvbW1hbmRIABJMChhzZXRfY2FyZF9tb3ZlbWVudF9lZmZlY3QY",
-            "ESABKAsyKC5yaWZ0Y2FsbGVyLlNldENhcmRNb3ZlbWVudEVmZmVjdENvbW1h",
-            "bmRIABI9ChB1cGRhdGVfd29ybGRfbWFwGBIgASgLMiEucmlmdGNhbGxlci5V",
-            "cGRhdGVXb3JsZE1hcENvbW1hbmRIABJHChVyZW5kZXJfc2NyZWVuX292ZXJs",
-            "YXkYEyABKAsyJi5yaWZ0Y2FsbGVyLlJlbmRlclNjcmVlbk92ZXJsYXlDb21t",
-            "YW5kSAASPgoQdXBkYXRlX2ludGVyZmFjZRgUIAEoCzIiLnJpZnRjYWxsZXIu",
-            "VXBkYXRlSW50ZXJmYWNlQ29tbWFuZEgAEjUKC2NvbmRpdGlvbmFsGBUgASgL",
-            "Mh4ucmlmdGNhbGxlci5Db25kaXRpb25hbENvbW1hbmRIABIwCglpbmZvX3pv",
-            "b20YFiABKAsyGy5yaWZ0Y2FsbGVyLkluZm9ab29tQ29tbWFuZEgAEkkKFnNl",
-            "dF9rZXlib2FyZF9zaG9ydGN1dHMYFyABKAsyJy5yaWZ0Y2FsbGVyLlNldEtl",
-            "eWJvYXJkU2hvcnRjdXRzQ29tbWFuZEgAElcKHnR1cm5fZmFjZV9kb3duX2Fy",
-            "ZW5hX2FuaW1hdGlvbhgYIAEoCzItLnJpZnRjYWxsZXIuVHVybkZhY2VEb3du",
-            "QXJlbmFBbmltYXRpb25Db21tYW5kSAASQwoYY2xlYXJfcGVyc2lzdGVudF9l",
-            "ZmZlY3RzGBkgASgLMh8ucmlmdGNhbGxlci5DbGVhckVmZmVjdHNDb21tYW5k",
-            "SABCCQoHY29tbWFuZCItCg9Mb2dnaW5nTWV0YWRhdGESCwoDa2V5GAEgASgJ",
-            "Eg0KBXZhbHVlGAIgASgJIp0BCgtDb21tYW5kTGlzdBI1ChBsb2dnaW5nX21l",
-            "dGFkYXRhGAEgAygLMhsucmlmdGNhbGxlci5Mb2dnaW5nTWV0YWRhdGESKQoI",
-            "Y29tbWFuZHMYAiADKAsyFy5yaWZ0Y2FsbGVyLkdhbWVDb21tYW5kEiwKCG1l",
-            "dGFkYXRhGAMgASgLMhoucmlmdGNhbGxlci5DbGllbnRNZXRhZGF0YSqfAQoJ",
-            "RmxleEFsaWduEhoKFkZMRVhfQUxJR05fVU5TUEVDSUZJRUQQABITCg9GTEVY",
-            "X0FMSUdOX0FVVE8QARIZChVGTEVYX0FMSUdOX0ZMRVhfU1RBUlQQAhIVChFG",
-            "TEVYX0FMSUdOX0NFTlRFUhADEhcKE0ZMRVhfQUxJR05fRkxFWF9FTkQQBBIW",
-            "ChJGTEVYX0FMSUdOX1NUUkVUQ0gQBSpwChBGbGV4RGlzcGxheVN0eWxlEiIK",
-            "HkZMRVhfRElTUExBWV9TVFlMRV9VTlNQRUNJRklFRBAAEhsKF0ZMRVhfRElT",
-            "UExBWV9TVFlMRV9GTEVYEAESGwoXRkxFWF9ESVNQTEFZX1NUWUxFX05PTkUQ",
-            "AiqlAQoNRmxleERpcmVjdGlvbhIeChpGTEVYX0RJUkVDVElPTl9VTlNQRUNJ",
-            "RklFRBAAEhkKFUZMRVhfRElSRUNUSU9OX0NPTFVNThABEiEKHUZMRVhfRElS",
-            "RUNUSU9OX0NPTFVNTl9SRVZFUlNFEAISFgoSRkxFWF9ESVJFQ1RJT05fUk9X",
-            "EAMSHgoaRkxFWF9ESVJFQ1RJT05fUk9XX1JFVkVSU0UQBCpsCghGbGV4V3Jh",
-            "cBIZChVGTEVYX1dSQVBfVU5TUEVDSUZJRUQQABIVChFGTEVYX1dSQVBfTk9f",
-            "V1JBUBABEhIKDkZMRVhfV1JBUF9XUkFQEAISGgoWRkxFWF9XUkFQX1dSQVBf",
-            "UkVWRVJTRRADKrsBCgtGbGV4SnVzdGlmeRIcChhGTEVYX0pVU1RJRllfVU5T",
-            "UEVDSUZJRUQQABIbChdGTEVYX0pVU1RJRllfRkxFWF9TVEFSVBABEhcKE0ZM",
-            "RVhfSlVTVElGWV9DRU5URVIQAhIZChVGTEVYX0pVU1RJRllfRkxFWF9FTkQQ",
-            "AxIeChpGTEVYX0pVU1RJRllfU1BBQ0VfQkVUV0VFThAEEh0KGUZMRVhfSlVT",
-            "VElGWV9TUEFDRV9BUk9VTkQQBSpiCgxGbGV4T3ZlcmZsb3cSHQoZRkxFWF9P",
-            "VkVSRkxPV19VTlNQRUNJRklFRBAAEhkKFUZMRVhfT1ZFUkZMT1dfVklTSUJM",
-            "RRABEhgKFEZMRVhfT1ZFUkZMT1dfSElEREVOEAIqZQoMRmxleFBvc2l0aW9u",
-            "Eh0KGUZMRVhfUE9TSVRJT05fVU5TUEVDSUZJRUQQABIaChZGTEVYX1BPU0lU",
-            "SU9OX1JFTEFUSVZFEAESGgoWRkxFWF9QT1NJVElPTl9BQlNPTFVURRACKmEK",
-            "DFRleHRPdmVyZmxvdxIdChlURVhUX09WRVJGTE9XX1VOU1BFQ0lGSUVEEAAS",
-            "FgoSVEVYVF9PVkVSRkxPV19DTElQEAESGgoWVEVYVF9PVkVSRkxPV19FTExJ",
-            "UFNJUxACKvMFCgpFYXNpbmdNb2RlEhsKF0VBU0lOR19NT0RFX1VOU1BFQ0lG",
-            "SUVEEAASFAoQRUFTSU5HX01PREVfRUFTRRABEhcKE0VBU0lOR19NT0RFX0VB",
-            "U0VfSU4QAhIYChRFQVNJTkdfTU9ERV9FQVNFX09VVBADEhsKF0VBU0lOR19N",
-            "T0RFX0VBU0VfSU5fT1VUEAQSFgoSRUFTSU5HX01PREVfTElORUFSEAUSHAoY",
-            "RUFTSU5HX01PREVfRUFTRV9JTl9TSU5FEAYSHQoZRUFTSU5HX01PREVfRUFT",
-            "RV9PVVRfU0lORRAHEiAKHEVBU0lOR19NT0RFX0VBU0VfSU5fT1VUX1NJTkUQ",
-            "CBIdChlFQVNJTkdfTU9ERV9FQVNFX0lOX0NVQklDEAkSHgoaRUFTSU5HX01P",
-            "REVfRUFTRV9PVVRfQ1VCSUMQChIhCh1FQVNJTkdfTU9ERV9FQVNFX0lOX09V",
-            "VF9DVUJJQxALEhwKGEVBU0lOR19NT0RFX0VBU0VfSU5fQ0lSQxAMEh0KGUVB",
-            "U0lOR19NT0RFX0VBU0VfT1VUX0NJUkMQDRIgChxFQVNJTkdfTU9ERV9FQVNF",
-            "X0lOX09VVF9DSVJDEA4SHwobRUFTSU5HX01PREVfRUFTRV9JTl9FTEFTVElD",
-            "EA8SIAocRUFTSU5HX01PREVfRUFTRV9PVVRfRUxBU1RJQxAQEiMKH0VBU0lO",
-            "R19NT0RFX0VBU0VfSU5fT1VUX0VMQVNUSUMQERIcChhFQVNJTkdfTU9ERV9F",
-            "QVNFX0lOX0JBQ0sQEhIdChlFQVNJTkdfTU9ERV9FQVNFX09VVF9CQUNLEBMS",
-            "IAocRUFTSU5HX01PREVfRUFTRV9JTl9PVVRfQkFDSxAUEh4KGkVBU0lOR19N",
-            "T0RFX0VBU0VfSU5fQk9VTkNFEBUSHwobRUFTSU5HX01PREVfRUFTRV9PVVRf",
-            "Qk9VTkNFEBYSIgoeRUFTSU5HX01PREVfRUFTRV9JTl9PVVRfQk9VTkNFEBcq",
-            "oAEKDkltYWdlU2NhbGVNb2RlEiAKHElNQUdFX1NDQUxFX01PREVfVU5TUEVD",
-            "SUZJRUQQABIkCiBJTUFHRV9TQ0FMRV9NT0RFX1NUUkVUQ0hfVE9fRklMTBAB",
-            "EiMKH0lNQUdFX1NDQUxFX01PREVfU0NBTEVfQU5EX0NST1AQAhIhCh1JTUFH",
-            "RV9TQ0FMRV9NT0RFX1NDQUxFX1RPX0ZJVBADKooBCglGb250U3R5bGUSGgoW",
-            "Rk9OVF9TVFlMRV9VTlNQRUNJRklFRBAAEhUKEUZPTlRfU1RZTEVfTk9STUFM",
-            "EAESEwoPRk9OVF9TVFlMRV9CT0xEEAISFQoRRk9OVF9TVFlMRV9JVEFMSUMQ",
-            "AxIeChpGT05UX1NUWUxFX0JPTERfQU5EX0lUQUxJQxAEKnoKD092ZXJmbG93",
-            "Q2xpcEJveBIhCh1PVkVSRkxPV19DTElQX0JPWF9VTlNQRUNJRklFRBAAEiEK",
-            "HU9WRVJGTE9XX0NMSVBfQk9YX1BBRERJTkdfQk9YEAESIQodT1ZFUkZMT1df",
-            "Q0xJUF9CT1hfQ09OVEVOVF9CT1gQAiqmAgoJVGV4dEFsaWduEhoKFlRFWFRf",
-            "QUxJR05fVU5TUEVDSUZJRUQQABIZChVURVhUX0FMSUdOX1VQUEVSX0xFRlQQ",
-            "ARIbChdURVhUX0FMSUdOX1VQUEVSX0NFTlRFUhACEhoKFlRFWFRfQUxJR05f",
-            "VVBQRVJfUklHSFQQAxIaChZURVhUX0FMSUdOX01JRERMRV9MRUZUEAQSHAoY",
-            "VEVYVF9BTElHTl9NSURETEVfQ0VOVEVSEAUSGwoXVEVYVF9BTElHTl9NSURE",
-            "TEVfUklHSFQQBhIZChVURVhUX0FMSUdOX0xPV0VSX0xFRlQQBxIbChdURVhU",
-            "X0FMSUdOX0xPV0VSX0NFTlRFUhAIEhoKFlRFWFRfQUxJR05fTE9XRVJfUklH",
-            "SFQQCSqjAQoUVGV4dE92ZXJmbG93UG9zaXRpb24SJgoiVEVYVF9PVkVSRkxP",
-            "V19QT1NJVElPTl9VTlNQRUNJRklFRBAAEh4KGlRFWFRfT1ZFUkZMT1dfUE9T",
-            "SVRJT05fRU5EEAESIAocVEVYVF9PVkVSRkxPV19QT1NJVElPTl9TVEFSVBAC",
-            "EiEKHVRFWFRfT1ZFUkZMT1dfUE9TSVRJT05fTUlERExFEAMqagoORmxleFZp",
-            "c2liaWxpdHkSHwobRkxFWF9WSVNJQklMSVRZX1VOU1BFQ0lGSUVEEAASGwoX",
-            "RkxFWF9WSVNJQklMSVRZX1ZJU0lCTEUQARIaChZGTEVYX1ZJU0lCSUxJVFlf",
-            "SElEREVOEAIqWgoKV2hpdGVTcGFjZRIbChdXSElURV9TUEFDRV9VTlNQRUNJ",
-            "RklFRBAAEhYKEldISVRFX1NQQUNFX05PUk1BTBABEhcKE1dISVRFX1NQQUNF",
-            "X05PX1dSQVAQAiq+AgoNRGltZW5zaW9uVW5pdBIeChpESU1FTlNJT05fVU5J",
-            "VF9VTlNQRUNJRklFRBAAEhkKFURJTUVOU0lPTl9VTklUX1BJWEVMUxABEh0K",
-            "GURJTUVOU0lPTl9VTklUX1BFUkNFTlRBR0UQAhIhCh1ESU1FTlNJT05fVU5J",
-            "VF9WSUVXUE9SVF9XSURUSBADEiIKHkRJTUVOU0lPTl9VTklUX1ZJRVdQT1JU",
-            "X0hFSUdIVBAEEiAKHERJTUVOU0lPTl9VTklUX1NBRkVfQVJFQV9UT1AQBRIi",
-            "Ch5ESU1FTlNJT05fVU5JVF9TQUZFX0FSRUFfUklHSFQQBhIjCh9ESU1FTlNJ",
-            "T05fVU5JVF9TQUZFX0FSRUFfQk9UVE9NEAcSIQodRElNRU5TSU9OX1VOSVRf",
-            "U0FGRV9BUkVBX0xFRlQQCCpyCg9GbGV4UGlja2luZ01vZGUSIQodRkxFWF9Q",
-            "SUNLSU5HX01PREVfVU5TUEVDSUZJRUQQABIeChpGTEVYX1BJQ0tJTkdfTU9E",
-            "RV9QT1NJVElPThABEhwKGEZMRVhfUElDS0lOR19NT0RFX0lHTk9SRRACKpwB",
-            "ChdCYWNrZ3JvdW5kSW1hZ2VBdXRvU2l6ZRIqCiZCQUNLR1JPVU5EX0lNQUdF",
-            "X0FVVE9fU0laRV9VTlNQRUNJRklFRBAAEikKJUJBQ0tHUk9VTkRfSU1BR0Vf",
-            "QVVUT19TSVpFX0ZST01fV0lEVEgQARIqCiZCQUNLR1JPVU5EX0lNQUdFX0FV",
-            "VE9fU0laRV9GUk9NX0hFSUdIVBACKqgBChNTY3JvbGxCYXJWaXNpYmlsaXR5",
-            "EiUKIVNDUk9MTF9CQVJfVklTSUJJTElUWV9VTlNQRUNJRklFRBAAEh4KGlND",
-            "Uk9MTF9CQVJfVklTSUJJTElUWV9BVVRPEAESKAokU0NST0xMX0JBUl9WSVNJ",
-            "QklMSVRZX0FMV0FZU19WSVNJQkxFEAISIAocU0NST0xMX0JBUl9WSVNJQklM",
-            "SVRZX0hJRERFThADKqoBChNUb3VjaFNjcm9sbEJlaGF2aW9yEiUKIVRPVUNI",
-            "X1NDUk9MTF9CRUhBVklPUl9VTlNQRUNJRklFRBAAEiYKIlRPVUNIX1NDUk9M",
-            "TF9CRUhBVklPUl9VTlJFU1RSSUNURUQQARIhCh1UT1VDSF9TQ1JPTExfQkVI",
-            "QVZJT1JfRUxBU1RJQxACEiEKHVRPVUNIX1NDUk9MTF9CRUhBVklPUl9DTEFN",
-            "UEVEEAMqcwoPU2xpZGVyRGlyZWN0aW9uEiAKHFNMSURFUl9ESVJFQ1RJT05f",
-            "VU5TUEVDSUZJRUQQABIfChtTTElERVJfRElSRUNUSU9OX0hPUklaT05UQUwQ",
-            "ARIdChlTTElERVJfRElSRUNUSU9OX1ZFUlRJQ0FMEAIqXwoKUGxheWVyU2lk",
-            "ZRIbChdQTEFZRVJfU0lERV9VTlNQRUNJRklFRBAAEhgKFFBMQVlFUl9TSURF",
-            "X0NPVkVOQU5UEAESGgoWUExBWUVSX1NJREVfUklGVENBTExFUhACKlkKClBs",
-            "YXllck5hbWUSGwoXUExBWUVSX05BTUVfVU5TUEVDSUZJRUQQABIUChBQTEFZ",
-            "RVJfTkFNRV9VU0VSEAESGAoUUExBWUVSX05BTUVfT1BQT05FTlQQAiqQAgoO",
-            "Um9vbUlkZW50aWZpZXISHwobUk9PTV9JREVOVElGSUVSX1VOU1BFQ0lGSUVE",
-            "EAASGQoVUk9PTV9JREVOVElGSUVSX1ZBVUxUEAESGwoXUk9PTV9JREVOVElG",
-            "SUVSX1NBTkNUVU0QAhIZChVST09NX0lERU5USUZJRVJfQ1JZUFQQAxIaChZS",
-            "T09NX0lERU5USUZJRVJfUk9PTV9BEAQSGgoWUk9PTV9JREVOVElGSUVSX1JP",
-            "T01fQhAFEhoKFlJPT01fSURFTlRJRklFUl9ST09NX0MQBhIaChZST09NX0lE",
-            "RU5USUZJRVJfUk9PTV9EEAcSGgoWUk9PTV9JREVOVElGSUVSX1JPT01fRRAI",
-            "Kn8KDlRhcmdldGluZ0Fycm93Eh8KG1RBUkdFVElOR19BUlJPV19VTlNQRUNJ",
-            "RklFRBAAEhcKE1RBUkdFVElOR19BUlJPV19SRUQQARIYChRUQVJHRVRJTkdf",
-            "QVJST1dfQkxVRRACEhkKFVRBUkdFVElOR19BUlJPV19HUkVFThADKnkKEkNs",
-            "aWVudFJvb21Mb2NhdGlvbhIkCiBDTElFTlRfUk9PTV9MT0NBVElPTl9VTlNQ",
-            "RUNJRklFRBAAEh0KGUNMSUVOVF9ST09NX0xPQ0FUSU9OX0JBQ0sQARIeChpD",
-            "TElFTlRfUk9PTV9MT0NBVElPTl9GUk9OVBACKnkKEkNsaWVudEl0ZW1Mb2Nh",
-            "dGlvbhIkCiBDTElFTlRfSVRFTV9MT0NBVElPTl9VTlNQRUNJRklFRBAAEh0K",
-            "GUNMSUVOVF9JVEVNX0xPQ0FUSU9OX0xFRlQQARIeChpDTElFTlRfSVRFTV9M",
-            "T0NBVElPTl9SSUdIVBACKpUBChhSZXZlYWxlZENhcmRzQnJvd3NlclNpemUS",
-            "KwonUkVWRUFMRURfQ0FSRFNfQlJPV1NFUl9TSVpFX1VOU1BFQ0lGSUVEEAAS",
-            "JQohUkVWRUFMRURfQ0FSRFNfQlJPV1NFUl9TSVpFX1NNQUxMEAESJQohUkVW",
-            "RUFMRURfQ0FSRFNfQlJPV1NFUl9TSVpFX0xBUkdFEAIqnwEKCkNhcmRQcmVm",
-            "YWISGwoXQ0FSRF9QUkVGQUJfVU5TUEVDSUZJRUQQABIYChRDQVJEX1BSRUZB",
-            "Ql9TVEFOREFSRBABEhoKFkNBUkRfUFJFRkFCX1RPS0VOX0NBUkQQAhIbChdD",
-            "QVJEX1BSRUZBQl9GVUxMX0hFSUdIVBADEiEKHUNBUkRfUFJFRkFCX0ZVTExf",
-            "SEVJR0hUX1RPS0VOEAQq9gEKHEdhbWVDaGFyYWN0ZXJGYWNpbmdEaXJlY3Rp",
-            "b24SLworR0FNRV9DSEFSQUNURVJfRkFDSU5HX0RJUkVDVElPTl9VTlNQRUNJ",
-            "RklFRBAAEiYKIkdBTUVfQ0hBUkFDVEVSX0ZBQ0lOR19ESVJFQ1RJT05fVVAQ",
-            "ARIoCiRHQU1FX0NIQVJBQ1RFUl9GQUNJTkdfRElSRUNUSU9OX0RPV04QAhIo",
-            "CiRHQU1FX0NIQVJBQ1RFUl9GQUNJTkdfRElSRUNUSU9OX0xFRlQQAxIpCiVH",
-            "QU1FX0NIQVJBQ1RFUl9GQUNJTkdfRElSRUNUSU9OX1JJR0hUEAQqgwEKEUFy",
-            "cm93QnViYmxlQ29ybmVyEiMKH0FSUk9XX0JVQkJMRV9DT1JORVJfVU5TUEVD",
-            "SUZJRUQQABIjCh9BUlJPV19CVUJCTEVfQ09STkVSX0JPVFRPTV9MRUZUEAES",
-            "JAogQVJST1dfQlVCQkxFX0NPUk5FUl9CT1RUT01fUklHSFQQAiqlAQoMQW5j",
-            "aG9yQ29ybmVyEh0KGUFOQ0hPUl9DT1JORVJfVU5TUEVDSUZJRUQQABIaChZB",
-            "TkNIT1JfQ09STkVSX1RPUF9MRUZUEAESGwoXQU5DSE9SX0NPUk5FUl9UT1Bf",
-            "UklHSFQQAhIdChlBTkNIT1JfQ09STkVSX0JPVFRPTV9MRUZUEAMSHgoaQU5D",
-            "SE9SX0NPUk5FUl9CT1RUT01fUklHSFQQBCp2Cg1Sb29tVmlzaXRUeXBlEh8K",
-            "G1JPT01fVklTSVRfVFlQRV9VTlNQRUNJRklFRBAAEiEKHVJPT01fVklTSVRf",
-            "VFlQRV9JTklUSUFURV9SQUlEEAESIQodUk9PTV9WSVNJVF9UWVBFX1BST0dS",
-            "RVNTX1JPT00QAiqVAQoVQ2FyZENyZWF0aW9uQW5pbWF0aW9uEicKI0NBUkRf",
-            "Q1JFQVRJT05fQU5JTUFUSU9OX1VOU1BFQ0lGSUVEEAASJQohQ0FSRF9DUkVB",
-            "VElPTl9BTklNQVRJT05fRFJBV19DQVJEEAESLAooQ0FSRF9DUkVBVElPTl9B",
-            "TklNQVRJT05fRlJPTV9QQVJFTlRfQ0FSRBACKowBCgpNdXNpY1N0YXRlEhsK",
-            "F01VU0lDX1NUQVRFX1VOU1BFQ0lGSUVEEAASFgoSTVVTSUNfU1RBVEVfU0lM",
-            "RU5UEAESGAoUTVVTSUNfU1RBVEVfR0FNRVBMQVkQAhIUChBNVVNJQ19TVEFU",
-            "RV9SQUlEEAMSGQoVTVVTSUNfU1RBVEVfTUFJTl9NRU5VEAQqqQEKD0dhbWVN",
-            "ZXNzYWdlVHlwZRIhCh1HQU1FX01FU1NBR0VfVFlQRV9VTlNQRUNJRklFRBAA",
-            "EhoKFkdBTUVfTUVTU0FHRV9UWVBFX0RBV04QARIaChZHQU1FX01FU1NBR0Vf",
-            "VFlQRV9EVVNLEAISHQoZR0FNRV9NRVNTQUdFX1RZUEVfVklDVE9SWRADEhwK",
-            "GEdBTUVfTUVTU0FHRV9UWVBFX0RFRkVBVBAEKmoKDVNjZW5lTG9hZE1vZGUS",
-            "HwobU0NFTkVfTE9BRF9NT0RFX1VOU1BFQ0lGSUVEEAASGgoWU0NFTkVfTE9B",
-            "RF9NT0RFX1NJTkdMRRABEhwKGFNDRU5FX0xPQURfTU9ERV9BRERJVElWRRAC",
-            "KpABCg9Mb2dNZXNzYWdlTGV2ZWwSIQodTE9HX01FU1NBR0VfTEVWRUxfVU5T",
-            "UEVDSUZJRUQQABIeChpMT0dfTUVTU0FHRV9MRVZFTF9TVEFOREFSRBABEh0K",
-            "GUxPR19NRVNTQUdFX0xFVkVMX1dBUk5JTkcQAhIbChdMT0dfTUVTU0FHRV9M",
-            "RVZFTF9FUlJPUhADKoEBCgtNYXBUaWxlVHlwZRIdChlNQVBfVElMRV9UWVBF",
-            "X1VOU1BFQ0lGSUVEEAASGgoWTUFQX1RJTEVfVFlQRV9PQlNUQUNMRRABEhoK",
-            "Fk1BUF9USUxFX1RZUEVfV0FMS0FCTEUQAhIbChdNQVBfVElMRV9UWVBFX1ZJ",
-            "U0lUQUJMRRADMpEBCgpSaWZ0Y2FsbGVyEkAKB0Nvbm5lY3QSGi5yaWZ0Y2Fs",
-            "bGVyLkNvbm5lY3RSZXF1ZXN0GhcucmlmdGNhbGxlci5Db21tYW5kTGlzdDAB",
-            "EkEKDVBlcmZvcm1BY3Rpb24SFy5yaWZ0Y2FsbGVyLkdhbWVSZXF1ZXN0Ghcu",
-            "cmlmdGNhbGxlci5Db21tYW5kTGlzdEIUqgIRUmlmdGNhbGxlci5Qcm90b3Ni",
-            "BnByb3RvMw=="));
+            "cmlmdGNhbGxlci5DbGllbnRBY3Rpb24SNQoUZHJhZ19kZXRlY3RlZF91cGRh",
+            "dGUYCSABKAsyFy5yaWZ0Y2FsbGVyLkNvbW1hbmRMaXN0EjIKEWRyYWdfZW5k",
+            "ZWRfdXBkYXRlGAogASgLMhcucmlmdGNhbGxlci5Db21tYW5kTGlzdCIQCg5E",
+            "cm9wVGFyZ2V0Tm9kZSL2AQoNVGV4dEZpZWxkTm9kZRIZChFnbG9iYWxfaWRl",
+            "bnRpZmllchgBIAEoCRIUCgxpbml0aWFsX3RleHQYAiABKAkSEQoJbXVsdGls",
+            "aW5lGAMgASgIEhQKDGlzX3JlYWRfb25seRgEIAEoCBISCgptYXhfbGVuZ3Ro",
+            "GAUgASgNEhkKEWlzX3Bhc3N3b3JkX2ZpZWxkGAYgASgIEiEKGWRvdWJsZV9j",
+            "bGlja19zZWxlY3RzX3dvcmQYByABKAgSIQoZdHJpcGxlX2NsaWNrX3NlbGVj",
+            "dHNfbGluZRgIIAEoCBIWCg5tYXNrX2NoYXJhY3RlchgJIAEoCSLSAwoKU2xp",
+            "ZGVyTm9kZRIVCg1pbml0aWFsX3ZhbHVlGAEgASgCEg0KBWxhYmVsGAIgASgJ",
+            "EhYKDnByZWZlcmVuY2Vfa2V5GAMgASgJEi4KCWRpcmVjdGlvbhgEIAEoDjIb",
+            "LnJpZnRjYWxsZXIuU2xpZGVyRGlyZWN0aW9uEhIKCmhpZ2hfdmFsdWUYBSAB",
+            "KAISEQoJbG93X3ZhbHVlGAYgASgCEhAKCGludmVydGVkGAcgASgIEhEKCXBh",
+            "Z2Vfc2l6ZRgIIAEoAhIYChBzaG93X2lucHV0X2ZpZWxkGAkgASgIEioKC2xh",
+            "YmVsX3N0eWxlGAogASgLMhUucmlmdGNhbGxlci5GbGV4U3R5bGUSMwoUZHJh",
+            "Z19jb250YWluZXJfc3R5bGUYCyABKAsyFS5yaWZ0Y2FsbGVyLkZsZXhTdHls",
+            "ZRIsCg10cmFja2VyX3N0eWxlGAwgASgLMhUucmlmdGNhbGxlci5GbGV4U3R5",
+            "bGUSLAoNZHJhZ2dlcl9zdHlsZRgNIAEoCzIVLnJpZnRjYWxsZXIuRmxleFN0",
+            "eWxlEjMKFGRyYWdnZXJfYm9yZGVyX3N0eWxlGA4gASgLMhUucmlmdGNhbGxl",
+            "ci5GbGV4U3R5bGUiwwIKCE5vZGVUeXBlEiAKBHRleHQYASABKAsyEC5yaWZ0",
+            "Y2FsbGVyLlRleHRIABI2ChBzY3JvbGxfdmlld19ub2RlGAIgASgLMhoucmlm",
+            "dGNhbGxlci5TY3JvbGxWaWV3Tm9kZUgAEjMKDmRyYWdnYWJsZV9ub2RlGAMg",
+            "ASgLMhkucmlmdGNhbGxlci5EcmFnZ2FibGVOb2RlSAASNgoQZHJvcF90YXJn",
+            "ZXRfbm9kZRgEIAEoCzIaLnJpZnRjYWxsZXIuRHJvcFRhcmdldE5vZGVIABI0",
+            "Cg90ZXh0X2ZpZWxkX25vZGUYBSABKAsyGS5yaWZ0Y2FsbGVyLlRleHRGaWVs",
+            "ZE5vZGVIABItCgtzbGlkZXJfbm9kZRgGIAEoCzIWLnJpZnRjYWxsZXIuU2xp",
+            "ZGVyTm9kZUgAQgsKCW5vZGVfdHlwZSLkAgoNRXZlbnRIYW5kbGVycxIqCghv",
+            "bl9jbGljaxgBIAEoCzIYLnJpZnRjYWxsZXIuQ2xpZW50QWN0aW9uEi8KDW9u",
+            "X2xvbmdfcHJlc3MYAiABKAsyGC5yaWZ0Y2FsbGVyLkNsaWVudEFjdGlvbhIw",
+            "Cg5vbl9tb3VzZV9lbnRlchgDIAEoCzIYLnJpZnRjYWxsZXIuQ2xpZW50QWN0",
+            "aW9uEjAKDm9uX21vdXNlX2xlYXZlGAQgASgLMhgucmlmdGNhbGxlci5DbGll",
+            "bnRBY3Rpb24SLwoNb25fbW91c2VfZG93bhgFIAEoCzIYLnJpZnRjYWxsZXIu",
+            "Q2xpZW50QWN0aW9uEi0KC29uX21vdXNlX3VwGAYgASgLMhgucmlmdGNhbGxl",
+            "ci5DbGllbnRBY3Rpb24SMgoQb25fZmllbGRfY2hhbmdlZBgHIAEoCzIYLnJp",
+            "ZnRjYWxsZXIuQ2xpZW50QWN0aW9uIsQCCgROb2RlEgwKBG5hbWUYASABKAkS",
+            "JwoJbm9kZV90eXBlGAIgASgLMhQucmlmdGNhbGxlci5Ob2RlVHlwZRIiCghj",
+            "aGlsZHJlbhgDIAMoCzIQLnJpZnRjYWxsZXIuTm9kZRIxCg5ldmVudF9oYW5k",
+            "bGVycxgEIAEoCzIZLnJpZnRjYWxsZXIuRXZlbnRIYW5kbGVycxIkCgVzdHls",
+            "ZRgFIAEoCzIVLnJpZnRjYWxsZXIuRmxleFN0eWxlEioKC2hvdmVyX3N0eWxl",
+            "GAYgASgLMhUucmlmdGNhbGxlci5GbGV4U3R5bGUSLAoNcHJlc3NlZF9zdHls",
+            "ZRgHIAEoCzIVLnJpZnRjYWxsZXIuRmxleFN0eWxlEi4KD29uX2F0dGFjaF9z",
+            "dHlsZRgIIAEoCzIVLnJpZnRjYWxsZXIuRmxleFN0eWxlIiAKEFBsYXllcklk",
+            "ZW50aWZpZXISDAoEdWxpZBgBIAEoCSKqAQoOQ2FyZElkZW50aWZpZXISJAoE",
+            "c2lkZRgBIAEoDjIWLnJpZnRjYWxsZXIuUGxheWVyU2lkZRINCgVpbmRleBgC",
+            "IAEoDRIwCgphYmlsaXR5X2lkGAMgASgLMhwuZ29vZ2xlLnByb3RvYnVmLlVJ",
+            "bnQzMlZhbHVlEjEKC2dhbWVfYWN0aW9uGAQgASgLMhwuZ29vZ2xlLnByb3Rv",
+            "YnVmLlVJbnQzMlZhbHVlItABChRHYW1lT2JqZWN0SWRlbnRpZmllchItCgdj",
+            "YXJkX2lkGAEgASgLMhoucmlmdGNhbGxlci5DYXJkSWRlbnRpZmllckgAEisK",
+            "CWNoYXJhY3RlchgCIAEoDjIWLnJpZnRjYWxsZXIuUGxheWVyTmFtZUgAEiYK",
+            "BGRlY2sYAyABKA4yFi5yaWZ0Y2FsbGVyLlBsYXllck5hbWVIABIuCgxkaXNj",
+            "YXJkX3BpbGUYBCABKA4yFi5yaWZ0Y2FsbGVyLlBsYXllck5hbWVIAEIECgJp",
+            "ZCKcAQoIQ2FyZEljb24SLQoKYmFja2dyb3VuZBgBIAEoCzIZLnJpZnRjYWxs",
+            "ZXIuU3ByaXRlQWRkcmVzcxIqCgR0ZXh0GAIgASgLMhwuZ29vZ2xlLnByb3Rv",
+            "YnVmLlN0cmluZ1ZhbHVlEjUKEGJhY2tncm91bmRfc2NhbGUYAyABKAsyGy5n",
+            "b29nbGUucHJvdG9idWYuRmxvYXRWYWx1ZSLxAQoJQ2FyZEljb25zEisKDXRv",
+            "cF9sZWZ0X2ljb24YASABKAsyFC5yaWZ0Y2FsbGVyLkNhcmRJY29uEiwKDnRv",
+            "cF9yaWdodF9pY29uGAIgASgLMhQucmlmdGNhbGxlci5DYXJkSWNvbhIvChFi",
+            "b3R0b21fcmlnaHRfaWNvbhgDIAEoCzIULnJpZnRjYWxsZXIuQ2FyZEljb24S",
+            "LgoQYm90dG9tX2xlZnRfaWNvbhgEIAEoCzIULnJpZnRjYWxsZXIuQ2FyZElj",
+            "b24SKAoKYXJlbmFfaWNvbhgFIAEoCzIULnJpZnRjYWxsZXIuQ2FyZEljb24i",
+            "RAoJQ2FyZFRpdGxlEgwKBHRleHQYASABKAkSKQoKdGV4dF9jb2xvchgCIAEo",
+            "CzIVLnJpZnRjYWxsZXIuRmxleENvbG9yIhkKCVJ1bGVzVGV4dBIMCgR0ZXh0",
+            "GAEgASgJIh8KC05vVGFyZ2V0aW5nEhAKCGNhbl9wbGF5GAEgASgIIj0KClBs",
+            "YXlJblJvb20SLwoLdmFsaWRfcm9vbXMYASADKA4yGi5yaWZ0Y2FsbGVyLlJv",
+            "b21JZGVudGlmaWVyIm0KD0Fycm93VGFyZ2V0Um9vbRIvCgt2YWxpZF9yb29t",
+            "cxgBIAMoDjIaLnJpZnRjYWxsZXIuUm9vbUlkZW50aWZpZXISKQoFYXJyb3cY",
+            "AiABKA4yGi5yaWZ0Y2FsbGVyLlRhcmdldGluZ0Fycm93IrcBCg1DYXJkVGFy",
+            "Z2V0aW5nEi8KDG5vX3RhcmdldGluZxgBIAEoCzIXLnJpZnRjYWxsZXIuTm9U",
+            "YXJnZXRpbmdIABIuCgxwbGF5X2luX3Jvb20YAiABKAsyFi5yaWZ0Y2FsbGVy",
+            "LlBsYXlJblJvb21IABI4ChFhcnJvd190YXJnZXRfcm9vbRgDIAEoCzIbLnJp",
+            "ZnRjYWxsZXIuQXJyb3dUYXJnZXRSb29tSABCCwoJdGFyZ2V0aW5nIhkKF09i",
+            "amVjdFBvc2l0aW9uT2Zmc2NyZWVuIngKEk9iamVjdFBvc2l0aW9uUm9vbRIr",
+            "Cgdyb29tX2lkGAEgASgOMhoucmlmdGNhbGxlci5Sb29tSWRlbnRpZmllchI1",
+            "Cg1yb29tX2xvY2F0aW9uGAIgASgOMh4ucmlmdGNhbGxlci5DbGllbnRSb29t",
+            "TG9jYXRpb24iSwoST2JqZWN0UG9zaXRpb25JdGVtEjUKDWl0ZW1fbG9jYXRp",
+            "b24YASABKA4yHi5yaWZ0Y2FsbGVyLkNsaWVudEl0ZW1Mb2NhdGlvbiIXChVP",
+            "YmplY3RQb3NpdGlvblN0YWdpbmciOwoST2JqZWN0UG9zaXRpb25IYW5kEiUK",
+            "BW93bmVyGAEgASgOMhYucmlmdGNhbGxlci5QbGF5ZXJOYW1lIjsKEk9iamVj",
+            "dFBvc2l0aW9uRGVjaxIlCgVvd25lchgBIAEoDjIWLnJpZnRjYWxsZXIuUGxh",
+            "eWVyTmFtZSJEChtPYmplY3RQb3NpdGlvbkRlY2tDb250YWluZXISJQoFb3du",
+            "ZXIYASABKA4yFi5yaWZ0Y2FsbGVyLlBsYXllck5hbWUiQgoZT2JqZWN0UG9z",
+            "aXRpb25EaXNjYXJkUGlsZRIlCgVvd25lchgBIAEoDjIWLnJpZnRjYWxsZXIu",
+            "UGxheWVyTmFtZSJLCiJPYmplY3RQb3NpdGlvbkRpc2NhcmRQaWxlQ29udGFp",
+            "bmVyEiUKBW93bmVyGAEgASgOMhYucmlmdGNhbGxlci5QbGF5ZXJOYW1lIh4K",
+            "HE9iamVjdFBvc2l0aW9uU2NvcmVBbmltYXRpb24iFAoST2JqZWN0UG9zaXRp",
+            "b25SYWlkIhcKFU9iamVjdFBvc2l0aW9uQnJvd3NlciJAChdPYmplY3RQb3Np",
+            "dGlvbkNoYXJhY3RlchIlCgVvd25lchgBIAEoDjIWLnJpZnRjYWxsZXIuUGxh",
+            "eWVyTmFtZSJJCiBPYmplY3RQb3NpdGlvbkNoYXJhY3RlckNvbnRhaW5lchIl",
+            "CgVvd25lchgBIAEoDjIWLnJpZnRjYWxsZXIuUGxheWVyTmFtZSIbChlPYmpl",
+            "Y3RQb3NpdGlvblJld2FyZENoZXN0IkUKFk9iamVjdFBvc2l0aW9uSW50b0Nh",
+            "cmQSKwoHY2FyZF9pZBgBIAEoCzIaLnJpZnRjYWxsZXIuQ2FyZElkZW50aWZp",
+            "ZXIiTgofT2JqZWN0UG9zaXRpb25TdGFja2VkQmVoaW5kQ2FyZBIrCgdjYXJk",
+            "X2lkGAEgASgLMhoucmlmdGNhbGxlci5DYXJkSWRlbnRpZmllciJRChtPYmpl",
+            "Y3RQb3NpdGlvblJldmVhbGVkQ2FyZHMSMgoEc2l6ZRgBIAEoDjIkLnJpZnRj",
+            "YWxsZXIuUmV2ZWFsZWRDYXJkc0Jyb3dzZXJTaXplIkIKGU9iamVjdFBvc2l0",
+            "aW9uUmlmdGNhbGxlcnMSJQoFb3duZXIYASABKA4yFi5yaWZ0Y2FsbGVyLlBs",
+            "YXllck5hbWUiIQofT2JqZWN0UG9zaXRpb25Ccm93c2VyRHJhZ1RhcmdldCIh",
+            "Ch9PYmplY3RQb3NpdGlvbkNhcmRDaG9pY2VCcm93c2VyIhsKGU9iamVjdFBv",
+            "c2l0aW9uSGFuZFN0b3JhZ2UiFwoVT2JqZWN0UG9zaXRpb25TY29yaW5nIsYK",
+            "Cg5PYmplY3RQb3NpdGlvbhITCgtzb3J0aW5nX2tleRgBIAEoDRIWCg5zb3J0",
+            "aW5nX3N1YmtleRgCIAEoDRI4CglvZmZzY3JlZW4YAyABKAsyIy5yaWZ0Y2Fs",
+            "bGVyLk9iamVjdFBvc2l0aW9uT2Zmc2NyZWVuSAASLgoEcm9vbRgEIAEoCzIe",
+            "LnJpZnRjYWxsZXIuT2JqZWN0UG9zaXRpb25Sb29tSAASLgoEaXRlbRgFIAEo",
+            "CzIeLnJpZnRjYWxsZXIuT2JqZWN0UG9zaXRpb25JdGVtSAASNAoHc3RhZ2lu",
+            "ZxgGIAEoCzIhLnJpZnRjYWxsZXIuT2JqZWN0UG9zaXRpb25TdGFnaW5nSAAS",
+            "LgoEaGFuZBgHIAEoCzIeLnJpZnRjYWxsZXIuT2JqZWN0UG9zaXRpb25IYW5k",
+            "SAASLgoEZGVjaxgIIAEoCzIeLnJpZnRjYWxsZXIuT2JqZWN0UG9zaXRpb25E",
+            "ZWNrSAASQQoOZGVja19jb250YWluZXIYCSABKAsyJy5yaWZ0Y2FsbGVyLk9i",
+            "amVjdFBvc2l0aW9uRGVja0NvbnRhaW5lckgAEj0KDGRpc2NhcmRfcGlsZRgK",
+            "IAEoCzIlLnJpZnRjYWxsZXIuT2JqZWN0UG9zaXRpb25EaXNjYXJkUGlsZUgA",
+            "ElAKFmRpc2NhcmRfcGlsZV9jb250YWluZXIYCyABKAsyLi5yaWZ0Y2FsbGVy",
+            "Lk9iamVjdFBvc2l0aW9uRGlzY2FyZFBpbGVDb250YWluZXJIABIuCgRyYWlk",
+            "GAwgASgLMh4ucmlmdGNhbGxlci5PYmplY3RQb3NpdGlvblJhaWRIABI0Cgdi",
+            "cm93c2VyGA0gASgLMiEucmlmdGNhbGxlci5PYmplY3RQb3NpdGlvbkJyb3dz",
+            "ZXJIABI4CgljaGFyYWN0ZXIYDiABKAsyIy5yaWZ0Y2FsbGVyLk9iamVjdFBv",
+            "c2l0aW9uQ2hhcmFjdGVySAASSwoTY2hhcmFjdGVyX2NvbnRhaW5lchgPIAEo",
+            "CzIsLnJpZnRjYWxsZXIuT2JqZWN0UG9zaXRpb25DaGFyYWN0ZXJDb250YWlu",
+            "ZXJIABI7CghyZXZlYWxlZBgQIAEoCzInLnJpZnRjYWxsZXIuT2JqZWN0UG9z",
+            "aXRpb25SZXZlYWxlZENhcmRzSAASOwoKcmlmdGNhbGxlchgRIAEoCzIlLnJp",
+            "ZnRjYWxsZXIuT2JqZWN0UG9zaXRpb25SaWZ0Y2FsbGVyc0gAEkoKE2Jyb3dz",
+            "ZXJfZHJhZ190YXJnZXQYEiABKAsyKy5yaWZ0Y2FsbGVyLk9iamVjdFBvc2l0",
+            "aW9uQnJvd3NlckRyYWdUYXJnZXRIABJKChNjYXJkX2Nob2ljZV9icm93c2Vy",
+            "GBMgASgLMisucmlmdGNhbGxlci5PYmplY3RQb3NpdGlvbkNhcmRDaG9pY2VC",
+            "cm93c2VySAASPQoMaGFuZF9zdG9yYWdlGBQgASgLMiUucmlmdGNhbGxlci5P",
+            "YmplY3RQb3NpdGlvbkhhbmRTdG9yYWdlSAASNAoHc2NvcmluZxgVIAEoCzIh",
+            "LnJpZnRjYWxsZXIuT2JqZWN0UG9zaXRpb25TY29yaW5nSAASNwoJaW50b19j",
+            "YXJkGBYgASgLMiIucmlmdGNhbGxlci5PYmplY3RQb3NpdGlvbkludG9DYXJk",
+            "SAASSgoTc3RhY2tlZF9iZWhpbmRfY2FyZBgXIAEoCzIrLnJpZnRjYWxsZXIu",
+            "T2JqZWN0UG9zaXRpb25TdGFja2VkQmVoaW5kQ2FyZEgAQgoKCHBvc2l0aW9u",
+            "IngKEUluZm9ab29tSGlnaGxpZ2h0EioKBGNhcmQYASABKAsyGi5yaWZ0Y2Fs",
+            "bGVyLkNhcmRJZGVudGlmaWVySAASKgoEcm9vbRgCIAEoDjIaLnJpZnRjYWxs",
+            "ZXIuUm9vbUlkZW50aWZpZXJIAEILCgloaWdobGlnaHQiWgoOQ2FyZE1vdmVU",
+            "YXJnZXQSMwoPdGFyZ2V0X3Bvc2l0aW9uGAEgASgLMhoucmlmdGNhbGxlci5P",
+            "YmplY3RQb3NpdGlvbhITCgtjYW5fcmVvcmRlchgCIAEoCCKLBQoQUmV2ZWFs",
+            "ZWRDYXJkVmlldxItCgpjYXJkX2ZyYW1lGAEgASgLMhkucmlmdGNhbGxlci5T",
+            "cHJpdGVBZGRyZXNzEjMKEHRpdGxlX2JhY2tncm91bmQYAiABKAsyGS5yaWZ0",
+            "Y2FsbGVyLlNwcml0ZUFkZHJlc3MSKAoFamV3ZWwYAyABKAsyGS5yaWZ0Y2Fs",
+            "bGVyLlNwcml0ZUFkZHJlc3MSKAoFaW1hZ2UYBCABKAsyGS5yaWZ0Y2FsbGVy",
+            "LlNwcml0ZUFkZHJlc3MSJAoFdGl0bGUYBSABKAsyFS5yaWZ0Y2FsbGVyLkNh",
+            "cmRUaXRsZRIpCgpydWxlc190ZXh0GAYgASgLMhUucmlmdGNhbGxlci5SdWxl",
+            "c1RleHQSLAoJdGFyZ2V0aW5nGAcgASgLMhkucmlmdGNhbGxlci5DYXJkVGFy",
+            "Z2V0aW5nEjcKE29uX3JlbGVhc2VfcG9zaXRpb24YCCABKAsyGi5yaWZ0Y2Fs",
+            "bGVyLk9iamVjdFBvc2l0aW9uEisKEXN1cHBsZW1lbnRhbF9pbmZvGAkgASgL",
+            "MhAucmlmdGNhbGxlci5Ob2RlEjMKEGltYWdlX2JhY2tncm91bmQYCiABKAsy",
+            "GS5yaWZ0Y2FsbGVyLlNwcml0ZUFkZHJlc3MSNAoQY2FyZF9tb3ZlX3Rhcmdl",
+            "dBgLIAEoCzIaLnJpZnRjYWxsZXIuQ2FyZE1vdmVUYXJnZXQSMwoPcG9pbnRf",
+            "dG9fcGFyZW50GAwgASgLMhoucmlmdGNhbGxlci5DYXJkSWRlbnRpZmllchI6",
+            "ChNpbmZvX3pvb21faGlnaGxpZ2h0GA0gASgLMh0ucmlmdGNhbGxlci5JbmZv",
+            "Wm9vbUhpZ2hsaWdodCJsCgtDYXJkRWZmZWN0cxIsCg1vdXRsaW5lX2NvbG9y",
+            "GAEgASgLMhUucmlmdGNhbGxlci5GbGV4Q29sb3ISLwoMYXJlbmFfZWZmZWN0",
+            "GAIgASgLMhkucmlmdGNhbGxlci5FZmZlY3RBZGRyZXNzIv4ECghDYXJkVmll",
+            "dxIrCgdjYXJkX2lkGAEgASgLMhoucmlmdGNhbGxlci5DYXJkSWRlbnRpZmll",
+            "chIxCg1jYXJkX3Bvc2l0aW9uGAIgASgLMhoucmlmdGNhbGxlci5PYmplY3RQ",
+            "b3NpdGlvbhImCgZwcmVmYWIYAyABKA4yFi5yaWZ0Y2FsbGVyLkNhcmRQcmVm",
+            "YWISLAoJY2FyZF9iYWNrGAQgASgLMhkucmlmdGNhbGxlci5TcHJpdGVBZGRy",
+            "ZXNzEhoKEnJldmVhbGVkX3RvX3ZpZXdlchgFIAEoCBISCgppc19mYWNlX3Vw",
+            "GAYgASgIEikKCmNhcmRfaWNvbnMYByABKAsyFS5yaWZ0Y2FsbGVyLkNhcmRJ",
+            "Y29ucxIuCgthcmVuYV9mcmFtZRgIIAEoCzIZLnJpZnRjYWxsZXIuU3ByaXRl",
+            "QWRkcmVzcxI4ChVmYWNlX2Rvd25fYXJlbmFfZnJhbWUYCSABKAsyGS5yaWZ0",
+            "Y2FsbGVyLlNwcml0ZUFkZHJlc3MSLQoNb3duaW5nX3BsYXllchgKIAEoDjIW",
+            "LnJpZnRjYWxsZXIuUGxheWVyTmFtZRIzCg1yZXZlYWxlZF9jYXJkGAsgASgL",
+            "MhwucmlmdGNhbGxlci5SZXZlYWxlZENhcmRWaWV3EjMKD2NyZWF0ZV9wb3Np",
+            "dGlvbhgMIAEoCzIaLnJpZnRjYWxsZXIuT2JqZWN0UG9zaXRpb24SNAoQZGVz",
+            "dHJveV9wb3NpdGlvbhgNIAEoCzIaLnJpZnRjYWxsZXIuT2JqZWN0UG9zaXRp",
+            "b24SKAoHZWZmZWN0cxgOIAEoCzIXLnJpZnRjYWxsZXIuQ2FyZEVmZmVjdHMi",
+            "fgoKUGxheWVySW5mbxI4ChR2YWxpZF9yb29tc190b192aXNpdBgBIAMoDjIa",
+            "LnJpZnRjYWxsZXIuUm9vbUlkZW50aWZpZXISNgoKYXBwZWFyYW5jZRgCIAEo",
+            "CzIiLnJpZnRjYWxsZXIuQ2hhcmFjdGVyUHJlc2V0QWRkcmVzcyJUCghNYW5h",
+            "VmlldxIRCgliYXNlX21hbmEYASABKA0SEgoKYm9udXNfbWFuYRgCIAEoDRIh",
+            "ChljYW5fdGFrZV9nYWluX21hbmFfYWN0aW9uGAMgASgIIhoKCVNjb3JlVmll",
+            "dxINCgVzY29yZRgBIAEoDSJRChFBY3Rpb25UcmFja2VyVmlldxIeChZhdmFp",
+            "bGFibGVfYWN0aW9uX2NvdW50GAEgASgNEhwKFGRlZmF1bHRfYWN0aW9uX2Nv",
+            "dW50GAIgASgNIm8KCERlY2tWaWV3EhIKCmNhcmRfY291bnQYASABKA0SLAoJ",
+            "Y2FyZF9iYWNrGAIgASgLMhkucmlmdGNhbGxlci5TcHJpdGVBZGRyZXNzEiEK",
+            "GWNhbl90YWtlX2RyYXdfY2FyZF9hY3Rpb24YAyABKAgiogIKClBsYXllclZp",
+            "ZXcSJAoEc2lkZRgBIAEoDjIWLnJpZnRjYWxsZXIuUGxheWVyU2lkZRIrCgtw",
+            "bGF5ZXJfaW5mbxgCIAEoCzIWLnJpZnRjYWxsZXIuUGxheWVySW5mbxIkCgVz",
+            "Y29yZRgDIAEoCzIVLnJpZnRjYWxsZXIuU2NvcmVWaWV3EiIKBG1hbmEYBCAB",
+            "KAsyFC5yaWZ0Y2FsbGVyLk1hbmFWaWV3EjUKDmFjdGlvbl90cmFja2VyGAUg",
+            "ASgLMh0ucmlmdGNhbGxlci5BY3Rpb25UcmFja2VyVmlldxInCglkZWNrX3Zp",
+            "ZXcYBiABKAsyFC5yaWZ0Y2FsbGVyLkRlY2tWaWV3EhcKD2Nhbl90YWtlX2Fj",
+            "dGlvbhgHIAEoCCLhAwoTR2FtZU9iamVjdFBvc2l0aW9ucxItCgl1c2VyX2Rl",
+            "Y2sYASABKAsyGi5yaWZ0Y2FsbGVyLk9iamVjdFBvc2l0aW9uEjEKDW9wcG9u",
+            "ZW50X2RlY2sYAiABKAsyGi5yaWZ0Y2FsbGVyLk9iamVjdFBvc2l0aW9uEjIK",
+            "DnVzZXJfY2hhcmFjdGVyGAMgASgLMhoucmlmdGNhbGxlci5PYmplY3RQb3Np",
+            "dGlvbhI2ChJvcHBvbmVudF9jaGFyYWN0ZXIYBCABKAsyGi5yaWZ0Y2FsbGVy",
+            "Lk9iamVjdFBvc2l0aW9uEkcKFXVzZXJfY2hhcmFjdGVyX2ZhY2luZxgFIAEo",
+            "DjIoLnJpZnRjYWxsZXIuR2FtZUNoYXJhY3RlckZhY2luZ0RpcmVjdGlvbhJL",
+            "ChlvcHBvbmVudF9jaGFyYWN0ZXJfZmFjaW5nGAYgASgOMigucmlmdGNhbGxl",
+            "ci5HYW1lQ2hhcmFjdGVyRmFjaW5nRGlyZWN0aW9uEjAKDHVzZXJfZGlzY2Fy",
+            "ZBgHIAEoCzIaLnJpZnRjYWxsZXIuT2JqZWN0UG9zaXRpb24SNAoQb3Bwb25l",
+            "bnRfZGlzY2FyZBgIIAEoCzIaLnJpZnRjYWxsZXIuT2JqZWN0UG9zaXRpb24i",
+            "2AEKEUFycm93QnViYmxlQW5jaG9yEigKBnBsYXllchgBIAEoDjIWLnJpZnRj",
+            "YWxsZXIuUGxheWVyTmFtZUgAEioKBHJvb20YAiABKA4yGi5yaWZ0Y2FsbGVy",
+            "LlJvb21JZGVudGlmaWVySAASLQoLcGxheWVyX2RlY2sYAyABKA4yFi5yaWZ0",
+            "Y2FsbGVyLlBsYXllck5hbWVIABItCgtwbGF5ZXJfbWFuYRgEIAEoDjIWLnJp",
+            "ZnRjYWxsZXIuUGxheWVyTmFtZUgAQg8KDWJ1YmJsZV9hbmNob3IihQMKD1No",
+            "b3dBcnJvd0J1YmJsZRIMCgR0ZXh0GAEgASgJEikKCmlkbGVfdGltZXIYAiAB",
+            "KAsyFS5yaWZ0Y2FsbGVyLlRpbWVWYWx1ZRIoCgloaWRlX3RpbWUYAyABKAsy",
+            "FS5yaWZ0Y2FsbGVyLlRpbWVWYWx1ZRIkCgVjb2xvchgEIAEoCzIVLnJpZnRj",
+            "YWxsZXIuRmxleENvbG9yEi4KCWZvbnRfc2l6ZRgFIAEoCzIbLmdvb2dsZS5w",
+            "cm90b2J1Zi5GbG9hdFZhbHVlEikKCmZvbnRfY29sb3IYBiABKAsyFS5yaWZ0",
+            "Y2FsbGVyLkZsZXhDb2xvchIqCgVzY2FsZRgHIAEoCzIbLmdvb2dsZS5wcm90",
+            "b2J1Zi5GbG9hdFZhbHVlEjMKDGFycm93X2Nvcm5lchgIIAEoDjIdLnJpZnRj",
+            "YWxsZXIuQXJyb3dCdWJibGVDb3JuZXISLQoGYW5jaG9yGAkgASgLMh0ucmlm",
+            "dGNhbGxlci5BcnJvd0J1YmJsZUFuY2hvciKAAQoJU2hvd1RvYXN0Eh4KBG5v",
+            "ZGUYASABKAsyEC5yaWZ0Y2FsbGVyLk5vZGUSKQoKaWRsZV90aW1lchgCIAEo",
+            "CzIVLnJpZnRjYWxsZXIuVGltZVZhbHVlEigKCWhpZGVfdGltZRgDIAEoCzIV",
+            "LnJpZnRjYWxsZXIuVGltZVZhbHVlIooBCg5UdXRvcmlhbEVmZmVjdBIzCgxh",
+            "cnJvd19idWJibGUYASABKAsyGy5yaWZ0Y2FsbGVyLlNob3dBcnJvd0J1YmJs",
+            "ZUgAEisKCnNob3dfdG9hc3QYAiABKAsyFS5yaWZ0Y2FsbGVyLlNob3dUb2Fz",
+            "dEgAQhYKFHR1dG9yaWFsX2VmZmVjdF90eXBlIsQCCghHYW1lVmlldxIkCgR1",
+            "c2VyGAEgASgLMhYucmlmdGNhbGxlci5QbGF5ZXJWaWV3EigKCG9wcG9uZW50",
+            "GAIgASgLMhYucmlmdGNhbGxlci5QbGF5ZXJWaWV3EiMKBWNhcmRzGAMgAygL",
+            "MhQucmlmdGNhbGxlci5DYXJkVmlldxITCgtyYWlkX2FjdGl2ZRgEIAEoCBI+",
+            "ChVnYW1lX29iamVjdF9wb3NpdGlvbnMYBSABKAsyHy5yaWZ0Y2FsbGVyLkdh",
+            "bWVPYmplY3RQb3NpdGlvbnMSOAoNbWFpbl9jb250cm9scxgGIAEoCzIhLnJp",
+            "ZnRjYWxsZXIuSW50ZXJmYWNlTWFpbkNvbnRyb2xzEjQKEHR1dG9yaWFsX2Vm",
+            "ZmVjdHMYByADKAsyGi5yaWZ0Y2FsbGVyLlR1dG9yaWFsRWZmZWN0ImMKElN0",
+            "dWRpb0FwcGVhckVmZmVjdBIkCgVkZWxheRgBIAEoCzIVLnJpZnRjYWxsZXIu",
+            "VGltZVZhbHVlEhYKDHNldF9yZXZlYWxlZBgCIAEoCEgAQg8KDXN0dWRpb19h",
+            "cHBlYXIibwoRU3R1ZGlvRGlzcGxheUNhcmQSIgoEY2FyZBgBIAEoCzIULnJp",
+            "ZnRjYWxsZXIuQ2FyZFZpZXcSNgoOYXBwZWFyX2VmZmVjdHMYAiADKAsyHi5y",
+            "aWZ0Y2FsbGVyLlN0dWRpb0FwcGVhckVmZmVjdCJJCg1TdHVkaW9EaXNwbGF5",
+            "Ei0KBGNhcmQYASABKAsyHS5yaWZ0Y2FsbGVyLlN0dWRpb0Rpc3BsYXlDYXJk",
+            "SABCCQoHZGlzcGxheSLHAQoOU3RhbmRhcmRBY3Rpb24SDwoHcGF5bG9hZBgB",
+            "IAEoDBInCgZ1cGRhdGUYAiABKAsyFy5yaWZ0Y2FsbGVyLkNvbW1hbmRMaXN0",
+            "EkUKDnJlcXVlc3RfZmllbGRzGAMgAygLMi0ucmlmdGNhbGxlci5TdGFuZGFy",
+            "ZEFjdGlvbi5SZXF1ZXN0RmllbGRzRW50cnkaNAoSUmVxdWVzdEZpZWxkc0Vu",
+            "dHJ5EgsKA2tleRgBIAEoCRINCgV2YWx1ZRgCIAEoCToCOAEiEAoOR2Fpbk1h",
+            "bmFBY3Rpb24iEAoORHJhd0NhcmRBY3Rpb24iQQoSUHJvZ3Jlc3NSb29tQWN0",
+            "aW9uEisKB3Jvb21faWQYASABKA4yGi5yaWZ0Y2FsbGVyLlJvb21JZGVudGlm",
+            "aWVyIkoKCkNhcmRUYXJnZXQSLQoHcm9vbV9pZBgBIAEoDjIaLnJpZnRjYWxs",
+            "ZXIuUm9vbUlkZW50aWZpZXJIAEINCgtjYXJkX3RhcmdldCJlCg5QbGF5Q2Fy",
+            "ZEFjdGlvbhIrCgdjYXJkX2lkGAEgASgLMhoucmlmdGNhbGxlci5DYXJkSWRl",
+            "bnRpZmllchImCgZ0YXJnZXQYAiABKAsyFi5yaWZ0Y2FsbGVyLkNhcmRUYXJn",
+            "ZXQiQQoSSW5pdGlhdGVSYWlkQWN0aW9uEisKB3Jvb21faWQYASABKA4yGi5y",
+            "aWZ0Y2FsbGVyLlJvb21JZGVudGlmaWVyIkwKEEZldGNoUGFuZWxBY3Rpb24S",
+            "OAoNcGFuZWxfYWRkcmVzcxgBIAEoCzIhLnJpZnRjYWxsZXIuSW50ZXJmYWNl",
+            "UGFuZWxBZGRyZXNzIhgKFlNwZW5kQWN0aW9uUG9pbnRBY3Rpb24iagoOTW92",
+            "ZUNhcmRBY3Rpb24SKwoHY2FyZF9pZBgBIAEoCzIaLnJpZnRjYWxsZXIuQ2Fy",
+            "ZElkZW50aWZpZXISKwoFaW5kZXgYAiABKAsyHC5nb29nbGUucHJvdG9idWYu",
+            "VUludDMyVmFsdWUi/AMKDENsaWVudEFjdGlvbhI1Cg9zdGFuZGFyZF9hY3Rp",
+            "b24YASABKAsyGi5yaWZ0Y2FsbGVyLlN0YW5kYXJkQWN0aW9uSAASMwoLZmV0",
+            "Y2hfcGFuZWwYAiABKAsyHC5yaWZ0Y2FsbGVyLkZldGNoUGFuZWxBY3Rpb25I",
+            "ABIvCglnYWluX21hbmEYAyABKAsyGi5yaWZ0Y2FsbGVyLkdhaW5NYW5hQWN0",
+            "aW9uSAASLwoJZHJhd19jYXJkGAQgASgLMhoucmlmdGNhbGxlci5EcmF3Q2Fy",
+            "ZEFjdGlvbkgAEi8KCXBsYXlfY2FyZBgFIAEoCzIaLnJpZnRjYWxsZXIuUGxh",
+            "eUNhcmRBY3Rpb25IABI3Cg1wcm9ncmVzc19yb29tGAYgASgLMh4ucmlmdGNh",
+            "bGxlci5Qcm9ncmVzc1Jvb21BY3Rpb25IABI3Cg1pbml0aWF0ZV9yYWlkGAcg",
+            "ASgLMh4ucmlmdGNhbGxlci5Jbml0aWF0ZVJhaWRBY3Rpb25IABJAChJzcGVu",
+            "ZF9hY3Rpb25fcG9pbnQYCCABKAsyIi5yaWZ0Y2FsbGVyLlNwZW5kQWN0aW9u",
+            "UG9pbnRBY3Rpb25IABIvCgltb3ZlX2NhcmQYCSABKAsyGi5yaWZ0Y2FsbGVy",
+            "Lk1vdmVDYXJkQWN0aW9uSABCCAoGYWN0aW9uInMKDkNsaWVudE1ldGFkYXRh",
+            "EjIKDGFkdmVudHVyZV9pZBgCIAEoCzIcLmdvb2dsZS5wcm90b2J1Zi5TdHJp",
+            "bmdWYWx1ZRItCgdnYW1lX2lkGAEgASgLMhwuZ29vZ2xlLnByb3RvYnVmLlN0",
+            "cmluZ1ZhbHVlIkEKDkNvbm5lY3RSZXF1ZXN0Ei8KCXBsYXllcl9pZBgBIAEo",
+            "CzIcLnJpZnRjYWxsZXIuUGxheWVySWRlbnRpZmllciI+CgtQb2xsUmVxdWVz",
+            "dBIvCglwbGF5ZXJfaWQYASABKAsyHC5yaWZ0Y2FsbGVyLlBsYXllcklkZW50",
+            "aWZpZXIizgEKC0dhbWVSZXF1ZXN0EigKBmFjdGlvbhgBIAEoCzIYLnJpZnRj",
+            "YWxsZXIuQ2xpZW50QWN0aW9uEi8KCXBsYXllcl9pZBgCIAEoCzIcLnJpZnRj",
+            "YWxsZXIuUGxheWVySWRlbnRpZmllchI2CgtvcGVuX3BhbmVscxgDIAMoCzIh",
+            "LnJpZnRjYWxsZXIuSW50ZXJmYWNlUGFuZWxBZGRyZXNzEiwKCG1ldGFkYXRh",
+            "GAQgASgLMhoucmlmdGNhbGxlci5DbGllbnRNZXRhZGF0YSIiCg9EZWJ1Z0xv",
+            "Z0NvbW1hbmQSDwoHbWVzc2FnZRgBIAEoCSI3CgxEZWxheUNvbW1hbmQSJwoI",
+            "ZHVyYXRpb24YASABKAsyFS5yaWZ0Y2FsbGVyLlRpbWVWYWx1ZSJBChVJbnRl",
+            "cmZhY2VQYW5lbEFkZHJlc3MSFAoMZGVidWdfc3RyaW5nGAEgASgJEhIKCnNl",
+            "cmlhbGl6ZWQYAiABKAwiwgEKDkludGVyZmFjZVBhbmVsEjIKB2FkZHJlc3MY",
+            "ASABKAsyIS5yaWZ0Y2FsbGVyLkludGVyZmFjZVBhbmVsQWRkcmVzcxIeCgRu",
+            "b2RlGAIgASgLMhAucmlmdGNhbGxlci5Ob2RlEigKDnNjcmVlbl9vdmVybGF5",
+            "GAMgASgLMhAucmlmdGNhbGxlci5Ob2RlEjIKD3ByZWxvYWRfc3ByaXRlcxgE",
+            "IAMoCzIZLnJpZnRjYWxsZXIuU3ByaXRlQWRkcmVzcyJqCgpDYXJkQW5jaG9y",
+            "Ei0KC25vZGVfY29ybmVyGAEgASgOMhgucmlmdGNhbGxlci5BbmNob3JDb3Ju",
+            "ZXISLQoLY2FyZF9jb3JuZXIYAiABKA4yGC5yaWZ0Y2FsbGVyLkFuY2hvckNv",
+            "cm5lciKGAQoOQ2FyZEFuY2hvck5vZGUSKwoHY2FyZF9pZBgBIAEoCzIaLnJp",
+            "ZnRjYWxsZXIuQ2FyZElkZW50aWZpZXISHgoEbm9kZRgCIAEoCzIQLnJpZnRj",
+            "YWxsZXIuTm9kZRInCgdhbmNob3JzGAMgAygLMhYucmlmdGNhbGxlci5DYXJk",
+            "QW5jaG9yIpEBChVJbnRlcmZhY2VNYWluQ29udHJvbHMSHgoEbm9kZRgBIAEo",
+            "CzIQLnJpZnRjYWxsZXIuTm9kZRIhCgdvdmVybGF5GAIgASgLMhAucmlmdGNh",
+            "bGxlci5Ob2RlEjUKEWNhcmRfYW5jaG9yX25vZGVzGAMgAygLMhoucmlmdGNh",
+            "bGxlci5DYXJkQW5jaG9yTm9kZSJBChNVcGRhdGVQYW5lbHNDb21tYW5kEioK",
+            "BnBhbmVscxgBIAMoCzIaLnJpZnRjYWxsZXIuSW50ZXJmYWNlUGFuZWwieQoX",
+            "QWRkcmVzc1dpdGhMb2FkaW5nU3RhdGUSNQoKb3Blbl9wYW5lbBgBIAEoCzIh",
+            "LnJpZnRjYWxsZXIuSW50ZXJmYWNlUGFuZWxBZGRyZXNzEicKDWxvYWRpbmdf",
+            "c3RhdGUYAiABKAsyEC5yaWZ0Y2FsbGVyLk5vZGUixQEKFlBhbmVsVHJhbnNp",
+            "dGlvbk9wdGlvbnMSLwoEb3BlbhgBIAEoCzIhLnJpZnRjYWxsZXIuSW50ZXJm",
+            "YWNlUGFuZWxBZGRyZXNzEjAKBWNsb3NlGAIgASgLMiEucmlmdGNhbGxlci5J",
+            "bnRlcmZhY2VQYW5lbEFkZHJlc3MSMgoHbG9hZGluZxgDIAEoCzIhLnJpZnRj",
+            "YWxsZXIuSW50ZXJmYWNlUGFuZWxBZGRyZXNzEhQKDHdhaXRfdG9fbG9hZBgE",
+            "IAEoCCLwAgoSVG9nZ2xlUGFuZWxDb21tYW5kEjgKCnRyYW5zaXRpb24YASAB",
+            "KAsyIi5yaWZ0Y2FsbGVyLlBhbmVsVHJhbnNpdGlvbk9wdGlvbnNIABJGChlv",
+            "cGVuX2JvdHRvbV9zaGVldF9hZGRyZXNzGAIgASgLMiEucmlmdGNhbGxlci5J",
+            "bnRlcmZhY2VQYW5lbEFkZHJlc3NIABI0ChJjbG9zZV9ib3R0b21fc2hlZXQY",
+            "AyABKAsyFi5nb29nbGUucHJvdG9idWYuRW1wdHlIABJGChlwdXNoX2JvdHRv",
+            "bV9zaGVldF9hZGRyZXNzGAQgASgLMiEucmlmdGNhbGxlci5JbnRlcmZhY2VQ",
+            "YW5lbEFkZHJlc3NIABJIChtwb3BfdG9fYm90dG9tX3NoZWV0X2FkZHJlc3MY",
+            "BSABKAsyIS5yaWZ0Y2FsbGVyLkludGVyZmFjZVBhbmVsQWRkcmVzc0gAQhAK",
+            "DnRvZ2dsZV9jb21tYW5kIkwKFVVwZGF0ZUdhbWVWaWV3Q29tbWFuZBIiCgRn",
+            "YW1lGAEgASgLMhQucmlmdGNhbGxlci5HYW1lVmlldxIPCgdhbmltYXRlGAIg",
+            "ASgIIpkBChBWaXNpdFJvb21Db21tYW5kEikKCWluaXRpYXRvchgBIAEoDjIW",
+            "LnJpZnRjYWxsZXIuUGxheWVyTmFtZRIrCgdyb29tX2lkGAIgASgOMhoucmlm",
+            "dGNhbGxlci5Sb29tSWRlbnRpZmllchItCgp2aXNpdF90eXBlGAMgASgOMhku",
+            "cmlmdGNhbGxlci5Sb29tVmlzaXRUeXBlIk0KFkNyZWF0ZVRva2VuQ2FyZENv",
+            "bW1hbmQSIgoEY2FyZBgBIAEoCzIULnJpZnRjYWxsZXIuQ2FyZFZpZXcSDwoH",
+            "YW5pbWF0ZRgCIAEoCCJ+ChxTZXRDYXJkTW92ZW1lbnRFZmZlY3RDb21tYW5k",
+            "EisKB2NhcmRfaWQYASABKAsyGi5yaWZ0Y2FsbGVyLkNhcmRJZGVudGlmaWVy",
+            "EjEKCnByb2plY3RpbGUYAiABKAsyHS5yaWZ0Y2FsbGVyLlByb2plY3RpbGVB",
+            "ZGRyZXNzImwKDkdhbWVPYmplY3RNb3ZlEiwKAmlkGAEgASgLMiAucmlmdGNh",
+            "bGxlci5HYW1lT2JqZWN0SWRlbnRpZmllchIsCghwb3NpdGlvbhgCIAEoCzIa",
+            "LnJpZnRjYWxsZXIuT2JqZWN0UG9zaXRpb24ihAEKFk1vdmVHYW1lT2JqZWN0",
+            "c0NvbW1hbmQSKQoFbW92ZXMYASADKAsyGi5yaWZ0Y2FsbGVyLkdhbWVPYmpl",
+            "Y3RNb3ZlEhkKEWRpc2FibGVfYW5pbWF0aW9uGAIgASgIEiQKBWRlbGF5GAMg",
+            "ASgLMhUucmlmdGNhbGxlci5UaW1lVmFsdWUiPwoQUGxheVNvdW5kQ29tbWFu",
+            "ZBIrCgVzb3VuZBgBIAEoCzIcLnJpZnRjYWxsZXIuQXVkaW9DbGlwQWRkcmVz",
+            "cyI+Cg9TZXRNdXNpY0NvbW1hbmQSKwoLbXVzaWNfc3RhdGUYASABKA4yFi5y",
+            "aWZ0Y2FsbGVyLk11c2ljU3RhdGUiqwQKFUZpcmVQcm9qZWN0aWxlQ29tbWFu",
+            "ZBIzCglzb3VyY2VfaWQYASABKAsyIC5yaWZ0Y2FsbGVyLkdhbWVPYmplY3RJ",
+            "ZGVudGlmaWVyEjMKCXRhcmdldF9pZBgCIAEoCzIgLnJpZnRjYWxsZXIuR2Ft",
+            "ZU9iamVjdElkZW50aWZpZXISMQoKcHJvamVjdGlsZRgDIAEoCzIdLnJpZnRj",
+            "YWxsZXIuUHJvamVjdGlsZUFkZHJlc3MSLgoPdHJhdmVsX2R1cmF0aW9uGAQg",
+            "ASgLMhUucmlmdGNhbGxlci5UaW1lVmFsdWUSMAoKZmlyZV9zb3VuZBgFIAEo",
+            "CzIcLnJpZnRjYWxsZXIuQXVkaW9DbGlwQWRkcmVzcxIyCgxpbXBhY3Rfc291",
+            "bmQYBiABKAsyHC5yaWZ0Y2FsbGVyLkF1ZGlvQ2xpcEFkZHJlc3MSMQoOYWRk",
+            "aXRpb25hbF9oaXQYByABKAsyGS5yaWZ0Y2FsbGVyLkVmZmVjdEFkZHJlc3MS",
+            "MwoUYWRkaXRpb25hbF9oaXRfZGVsYXkYCCABKAsyFS5yaWZ0Y2FsbGVyLlRp",
+            "bWVWYWx1ZRIsCg13YWl0X2R1cmF0aW9uGAkgASgLMhUucmlmdGNhbGxlci5U",
+            "aW1lVmFsdWUSEwoLaGlkZV9vbl9oaXQYCiABKAgSNAoQanVtcF90b19wb3Np",
+            "dGlvbhgLIAEoCzIaLnJpZnRjYWxsZXIuT2JqZWN0UG9zaXRpb24iYAoSUGxh",
+            "eUVmZmVjdFBvc2l0aW9uEjcKC2dhbWVfb2JqZWN0GAEgASgLMiAucmlmdGNh",
+            "bGxlci5HYW1lT2JqZWN0SWRlbnRpZmllckgAQhEKD2VmZmVjdF9wb3NpdGlv",
+            "biLlAgoRUGxheUVmZmVjdENvbW1hbmQSKQoGZWZmZWN0GAEgASgLMhkucmlm",
+            "dGNhbGxlci5FZmZlY3RBZGRyZXNzEjAKCHBvc2l0aW9uGAIgASgLMh4ucmlm",
+            "dGNhbGxlci5QbGF5RWZmZWN0UG9zaXRpb24SKgoFc2NhbGUYAyABKAsyGy5n",
+            "b29nbGUucHJvdG9idWYuRmxvYXRWYWx1ZRInCghkdXJhdGlvbhgEIAEoCzIV",
+            "LnJpZnRjYWxsZXIuVGltZVZhbHVlEisKBXNvdW5kGAUgASgLMhwucmlmdGNh",
+            "bGxlci5BdWRpb0NsaXBBZGRyZXNzEhQKDGFyZW5hX2VmZmVjdBgGIAEoCBIq",
+            "CgtzdGFydF9jb2xvchgHIAEoCzIVLnJpZnRjYWxsZXIuRmxleENvbG9yEi8K",
+            "BW93bmVyGAggASgLMiAucmlmdGNhbGxlci5HYW1lT2JqZWN0SWRlbnRpZmll",
+            "ciJOChlEaXNwbGF5R2FtZU1lc3NhZ2VDb21tYW5kEjEKDG1lc3NhZ2VfdHlw",
+            "ZRgBIAEoDjIbLnJpZnRjYWxsZXIuR2FtZU1lc3NhZ2VUeXBlIjwKHFNldEdh",
+            "bWVPYmplY3RzRW5hYmxlZENvbW1hbmQSHAoUZ2FtZV9vYmplY3RzX2VuYWJs",
+            "ZWQYASABKAgiPgoVRGlzcGxheVJld2FyZHNDb21tYW5kEiUKB3Jld2FyZHMY",
+            "ASADKAsyFC5yaWZ0Y2FsbGVyLkNhcmRWaWV3ImgKEExvYWRTY2VuZUNvbW1h",
+            "bmQSEgoKc2NlbmVfbmFtZRgBIAEoCRInCgRtb2RlGAIgASgOMhkucmlmdGNh",
+            "bGxlci5TY2VuZUxvYWRNb2RlEhcKD3NraXBfaWZfY3VycmVudBgDIAEoCCIy",
+            "ChRTZXRCb29sZWFuUHJlZmVyZW5jZRILCgNrZXkYASABKAkSDQoFdmFsdWUY",
+            "AiABKAgiRgoKTG9nTWVzc2FnZRIMCgR0ZXh0GAEgASgJEioKBWxldmVsGAIg",
+            "ASgOMhsucmlmdGNhbGxlci5Mb2dNZXNzYWdlTGV2ZWwirgIKEkNsaWVudERl",
+            "YnVnQ29tbWFuZBIrCglzaG93X2xvZ3MYASABKAsyFi5nb29nbGUucHJvdG9i",
+            "dWYuRW1wdHlIABIxCg1pbnZva2VfYWN0aW9uGAIgASgLMhgucmlmdGNhbGxl",
+            "ci5DbGllbnRBY3Rpb25IABItCgtsb2dfbWVzc2FnZRgDIAEoCzIWLnJpZnRj",
+            "YWxsZXIuTG9nTWVzc2FnZUgAEkIKFnNldF9ib29sZWFuX3ByZWZlcmVuY2UY",
+            "BCABKAsyIC5yaWZ0Y2FsbGVyLlNldEJvb2xlYW5QcmVmZXJlbmNlSAASNAoS",
+            "c2hvd19mZWVkYmFja19mb3JtGAUgASgLMhYuZ29vZ2xlLnByb3RvYnVmLkVt",
+            "cHR5SABCDwoNZGVidWdfY29tbWFuZCIjCgtNYXBQb3NpdGlvbhIJCgF4GAEg",
+            "ASgFEgkKAXkYAiABKAUiwQEKDldvcmxkTWFwU3ByaXRlEjEKDnNwcml0ZV9h",
+            "ZGRyZXNzGAEgASgLMhkucmlmdGNhbGxlci5TcHJpdGVBZGRyZXNzEiQKBWNv",
+            "bG9yGAIgASgLMhUucmlmdGNhbGxlci5GbGV4Q29sb3ISLgoNYW5jaG9yX29m",
+            "ZnNldBgDIAEoCzIXLnJpZnRjYWxsZXIuRmxleFZlY3RvcjMSJgoFc2NhbGUY",
+            "BCABKAsyFy5yaWZ0Y2FsbGVyLkZsZXhWZWN0b3IzIo8BChFXb3JsZE1hcENo",
+            "YXJhY3RlchI2CgphcHBlYXJhbmNlGAEgASgLMiIucmlmdGNhbGxlci5DaGFy",
+            "YWN0ZXJQcmVzZXRBZGRyZXNzEkIKEGZhY2luZ19kaXJlY3Rpb24YAiABKA4y",
+            "KC5yaWZ0Y2FsbGVyLkdhbWVDaGFyYWN0ZXJGYWNpbmdEaXJlY3Rpb24i8AEK",
+            "DFdvcmxkTWFwVGlsZRIrCgdzcHJpdGVzGAEgAygLMhoucmlmdGNhbGxlci5X",
+            "b3JsZE1hcFNwcml0ZRIpCghwb3NpdGlvbhgCIAEoCzIXLnJpZnRjYWxsZXIu",
+            "TWFwUG9zaXRpb24SKgoIb25fdmlzaXQYAyABKAsyGC5yaWZ0Y2FsbGVyLkNs",
+            "aWVudEFjdGlvbhIqCgl0aWxlX3R5cGUYBCABKA4yFy5yaWZ0Y2FsbGVyLk1h",
+            "cFRpbGVUeXBlEjAKCWNoYXJhY3RlchgFIAEoCzIdLnJpZnRjYWxsZXIuV29y",
+            "bGRNYXBDaGFyYWN0ZXIimgEKFVVwZGF0ZVdvcmxkTWFwQ29tbWFuZBInCgV0",
+            "aWxlcxgBIAMoCzIYLnJpZnRjYWxsZXIuV29ybGRNYXBUaWxlEisKCm1pbl9i",
+            "b3VuZHMYAiABKAsyFy5yaWZ0Y2FsbGVyLk1hcFBvc2l0aW9uEisKCm1heF9i",
+            "b3VuZHMYAyABKAsyFy5yaWZ0Y2FsbGVyLk1hcFBvc2l0aW9uIjwKGlJlbmRl",
+            "clNjcmVlbk92ZXJsYXlDb21tYW5kEh4KBG5vZGUYASABKAsyEC5yaWZ0Y2Fs",
+            "bGVyLk5vZGUigQEKD0VsZW1lbnRTZWxlY3RvchIWCgxlbGVtZW50X25hbWUY",
+            "ASABKAlIABIwCg5kcmFnX2luZGljYXRvchgCIAEoCzIWLmdvb2dsZS5wcm90",
+            "b2J1Zi5FbXB0eUgAEhgKDnRhcmdldF9lbGVtZW50GAMgASgJSABCCgoIc2Vs",
+            "ZWN0b3IiYQoQRWxlbWVudEFuaW1hdGlvbhInCghkdXJhdGlvbhgBIAEoCzIV",
+            "LnJpZnRjYWxsZXIuVGltZVZhbHVlEiQKBGVhc2UYAiABKA4yFi5yaWZ0Y2Fs",
+            "bGVyLkVhc2luZ01vZGUivQEKEUFuaW1hdGVUb1Bvc2l0aW9uEjAKC2Rlc3Rp",
+            "bmF0aW9uGAEgASgLMhsucmlmdGNhbGxlci5FbGVtZW50U2VsZWN0b3ISLwoJ",
+            "YW5pbWF0aW9uGAIgASgLMhwucmlmdGNhbGxlci5FbGVtZW50QW5pbWF0aW9u",
+            "EiIKGmRpc2FibGVfaGVpZ2h0X2hhbGZfb2Zmc2V0GAMgASgIEiEKGWRpc2Fi",
+            "bGVfd2lkdGhfaGFsZl9vZmZzZXQYBCABKAginAEKGENyZWF0ZVRhcmdldEF0",
+            "Q2hpbGRJbmRleBIrCgZwYXJlbnQYASABKAsyGy5yaWZ0Y2FsbGVyLkVsZW1l",
+            "bnRTZWxlY3RvchINCgVpbmRleBgCIAEoDRITCgt0YXJnZXRfbmFtZRgDIAEo",
+            "CRIvCglhbmltYXRpb24YBCABKAsyHC5yaWZ0Y2FsbGVyLkVsZW1lbnRBbmlt",
+            "YXRpb24isgEKE0FuaW1hdGVFbGVtZW50U3R5bGUSLwoJYW5pbWF0aW9uGAEg",
+            "ASgLMhwucmlmdGNhbGxlci5FbGVtZW50QW5pbWF0aW9uEhEKB29wYWNpdHkY",
+            "AiABKAJIABIPCgV3aWR0aBgDIAEoAkgAEhAKBmhlaWdodBgEIAEoAkgAEigK",
+            "BXNjYWxlGAUgASgLMhcucmlmdGNhbGxlci5GbGV4VmVjdG9yMkgAQgoKCHBy",
+            "b3BlcnR5IvMCCg9JbnRlcmZhY2VVcGRhdGUSLwoNY2xvbmVfZWxlbWVudBgB",
+            "IAEoCzIWLmdvb2dsZS5wcm90b2J1Zi5FbXB0eUgAEjEKD2Rlc3Ryb3lfZWxl",
+            "bWVudBgCIAEoCzIWLmdvb2dsZS5wcm90b2J1Zi5FbXB0eUgAEjwKE2FuaW1h",
+            "dGVfdG9fcG9zaXRpb24YAyABKAsyHS5yaWZ0Y2FsbGVyLkFuaW1hdGVUb1Bv",
+            "c2l0aW9uSAASLAoLYXBwbHlfc3R5bGUYBCABKAsyFS5yaWZ0Y2FsbGVyLkZs",
+            "ZXhTdHlsZUgAEjgKDWFuaW1hdGVfc3R5bGUYBSABKAsyHy5yaWZ0Y2FsbGVy",
+            "LkFuaW1hdGVFbGVtZW50U3R5bGVIABJMChxjcmVhdGVfdGFyZ2V0X2F0X2No",
+            "aWxkX2luZGV4GAYgASgLMiQucmlmdGNhbGxlci5DcmVhdGVUYXJnZXRBdENo",
+            "aWxkSW5kZXhIAEIICgZ1cGRhdGUimwEKE1VwZGF0ZUludGVyZmFjZVN0ZXAS",
+            "LAoHZWxlbWVudBgBIAEoCzIbLnJpZnRjYWxsZXIuRWxlbWVudFNlbGVjdG9y",
+            "EisKBnVwZGF0ZRgCIAEoCzIbLnJpZnRjYWxsZXIuSW50ZXJmYWNlVXBkYXRl",
+            "EikKCnN0YXJ0X3RpbWUYAyABKAsyFS5yaWZ0Y2FsbGVyLlRpbWVWYWx1ZSJI",
+            "ChZVcGRhdGVJbnRlcmZhY2VDb21tYW5kEi4KBXN0ZXBzGAEgAygLMh8ucmlm",
+            "dGNhbGxlci5VcGRhdGVJbnRlcmZhY2VTdGVwIlIKEENvbmRpdGlvbmFsUXVl",
+            "cnkSNQoOZWxlbWVudF9leGlzdHMYASABKAsyGy5yaWZ0Y2FsbGVyLkVsZW1l",
+            "bnRTZWxlY3RvckgAQgcKBXF1ZXJ5IpYBChJDb25kaXRpb25hbENvbW1hbmQS",
+            "KwoFcXVlcnkYASABKAsyHC5yaWZ0Y2FsbGVyLkNvbmRpdGlvbmFsUXVlcnkS",
+            "KAoHaWZfdHJ1ZRgCIAEoCzIXLnJpZnRjYWxsZXIuQ29tbWFuZExpc3QSKQoI",
+            "aWZfZmFsc2UYAyABKAsyFy5yaWZ0Y2FsbGVyLkNvbW1hbmRMaXN0IkMKD0lu",
+            "Zm9ab29tQ29tbWFuZBIMCgRzaG93GAEgASgIEiIKBGNhcmQYAiABKAsyFC5y",
+            "aWZ0Y2FsbGVyLkNhcmRWaWV3Ik4KEEtleWJvYXJkU2hvcnRjdXQSEAoIa2V5",
+            "X25hbWUYASABKAkSCwoDYWx0GAIgASgIEgwKBGN0cmwYAyABKAgSDQoFc2hp",
+            "ZnQYBCABKAgiawoPS2V5Ym9hcmRNYXBwaW5nEi4KCHNob3J0Y3V0GAEgASgL",
+            "MhwucmlmdGNhbGxlci5LZXlib2FyZFNob3J0Y3V0EigKBmFjdGlvbhgCIAEo",
+            "CzIYLnJpZnRjYWxsZXIuQ2xpZW50QWN0aW9uIlAKG1NldEtleWJvYXJkU2hv",
+            "cnRjdXRzQ29tbWFuZBIxCgxtYXBwaW5nX2xpc3QYASADKAsyGy5yaWZ0Y2Fs",
+            "bGVyLktleWJvYXJkTWFwcGluZyJQCiFUdXJuRmFjZURvd25BcmVuYUFuaW1h",
+            "dGlvbkNvbW1hbmQSKwoHY2FyZF9pZBgBIAEoCzIaLnJpZnRjYWxsZXIuQ2Fy",
+            "ZElkZW50aWZpZXIiRgoTQ2xlYXJFZmZlY3RzQ29tbWFuZBIvCgVvd25lchgB",
+            "IAEoCzIgLnJpZnRjYWxsZXIuR2FtZU9iamVjdElkZW50aWZpZXIiSgoWRm9j",
+            "dXNHYW1lT2JqZWN0Q29tbWFuZBIwCgZvYmplY3QYASABKAsyIC5yaWZ0Y2Fs",
+            "bGVyLkdhbWVPYmplY3RJZGVudGlmaWVyIuUMCgtHYW1lQ29tbWFuZBIvCgVk",
+            "ZWJ1ZxgBIAEoCzIeLnJpZnRjYWxsZXIuQ2xpZW50RGVidWdDb21tYW5kSAAS",
+            "KQoFZGVsYXkYAiABKAsyGC5yaWZ0Y2FsbGVyLkRlbGF5Q29tbWFuZEgAEjgK",
+            "DXVwZGF0ZV9wYW5lbHMYAyABKAsyHy5yaWZ0Y2FsbGVyLlVwZGF0ZVBhbmVs",
+            "c0NvbW1hbmRIABI2Cgx0b2dnbGVfcGFuZWwYBCABKAsyHi5yaWZ0Y2FsbGVy",
+            "LlRvZ2dsZVBhbmVsQ29tbWFuZEgAEj0KEHVwZGF0ZV9nYW1lX3ZpZXcYBSAB",
+            "KAsyIS5yaWZ0Y2FsbGVyLlVwZGF0ZUdhbWVWaWV3Q29tbWFuZEgAEjIKCnZp",
+            "c2l0X3Jvb20YBiABKAsyHC5yaWZ0Y2FsbGVyLlZpc2l0Um9vbUNvbW1hbmRI",
+            "ABIyCgpwbGF5X3NvdW5kGAcgASgLMhwucmlmdGNhbGxlci5QbGF5U291bmRD",
+            "b21tYW5kSAASMAoJc2V0X211c2ljGAggASgLMhsucmlmdGNhbGxlci5TZXRN",
+            "dXNpY0NvbW1hbmRIABI8Cg9maXJlX3Byb2plY3RpbGUYCSABKAsyIS5yaWZ0",
+            "Y2FsbGVyLkZpcmVQcm9qZWN0aWxlQ29tbWFuZEgAEjQKC3BsYXlfZWZmZWN0",
+            "GAogASgLMh0ucmlmdGNhbGxlci5QbGF5RWZmZWN0Q29tbWFuZEgAEkUKFGRp",
+            "c3BsYXlfZ2FtZV9tZXNzYWdlGAsgASgLMiUucmlmdGNhbGxlci5EaXNwbGF5",
+            "R2FtZU1lc3NhZ2VDb21tYW5kSAASTAoYc2V0X2dhbWVfb2JqZWN0c19lbmFi",
+            "bGVkGAwgASgLMigucmlmdGNhbGxlci5TZXRHYW1lT2JqZWN0c0VuYWJsZWRD",
+            "b21tYW5kSAASPAoPZGlzcGxheV9yZXdhcmRzGA0gASgLMiEucmlmdGNhbGxl",
+            "ci5EaXNwbGF5UmV3YXJkc0NvbW1hbmRIABIyCgpsb2FkX3NjZW5lGA4gASgL",
+            "MhwucmlmdGNhbGxlci5Mb2FkU2NlbmVDb21tYW5kSAASPwoRbW92ZV9nYW1l",
+            "X29iamVjdHMYDyABKAsyIi5yaWZ0Y2FsbGVyLk1vdmVHYW1lT2JqZWN0c0Nv",
+            "bW1hbmRIABI/ChFjcmVhdGVfdG9rZW5fY2FyZBgQIAEoCzIiLnJpZnRjYWxs",
+            "ZXIuQ3JlYXRlVG9rZW5DYXJkQ29tbWFuZEgAEkwKGHNldF9jYXJkX21vdmVt",
+            "ZW50X2VmZmVjdBgRIAEoCzIoLnJpZnRjYWxsZXIuU2V0Q2FyZE1vdmVtZW50",
+            "RWZmZWN0Q29tbWFuZEgAEj0KEHVwZGF0ZV93b3JsZF9tYXAYEiABKAsyIS5y",
+            "aWZ0Y2FsbGVyLlVwZGF0ZVdvcmxkTWFwQ29tbWFuZEgAEkcKFXJlbmRlcl9z",
+            "Y3JlZW5fb3ZlcmxheRgTIAEoCzImLnJpZnRjYWxsZXIuUmVuZGVyU2NyZWVu",
+            "T3ZlcmxheUNvbW1hbmRIABI+ChB1cGRhdGVfaW50ZXJmYWNlGBQgASgLMiIu",
+            "cmlmdGNhbGxlci5VcGRhdGVJbnRlcmZhY2VDb21tYW5kSAASNQoLY29uZGl0",
+            "aW9uYWwYFSABKAsyHi5yaWZ0Y2FsbGVyLkNvbmRpdGlvbmFsQ29tbWFuZEgA",
+            "EjAKCWluZm9fem9vbRgWIAEoCzIbLnJpZnRjYWxsZXIuSW5mb1pvb21Db21t",
+            "YW5kSAASSQoWc2V0X2tleWJvYXJkX3Nob3J0Y3V0cxgXIAEoCzInLnJpZnRj",
+            "YWxsZXIuU2V0S2V5Ym9hcmRTaG9ydGN1dHNDb21tYW5kSAASVwoedHVybl9m",
+            "YWNlX2Rvd25fYXJlbmFfYW5pbWF0aW9uGBggASgLMi0ucmlmdGNhbGxlci5U",
+            "dXJuRmFjZURvd25BcmVuYUFuaW1hdGlvbkNvbW1hbmRIABJDChhjbGVhcl9w",
+            "ZXJzaXN0ZW50X2VmZmVjdHMYGSABKAsyHy5yaWZ0Y2FsbGVyLkNsZWFyRWZm",
+            "ZWN0c0NvbW1hbmRIABI/ChFmb2N1c19nYW1lX29iamVjdBgaIAEoCzIiLnJp",
+            "ZnRjYWxsZXIuRm9jdXNHYW1lT2JqZWN0Q29tbWFuZEgAQgkKB2NvbW1hbmQi",
+            "LQoPTG9nZ2luZ01ldGFkYXRhEgsKA2tleRgBIAEoCRINCgV2YWx1ZRgCIAEo",
+            "CSKdAQoLQ29tbWFuZExpc3QSNQoQbG9nZ2luZ19tZXRhZGF0YRgBIAMoCzIb",
+            "LnJpZnRjYWxsZXIuTG9nZ2luZ01ldGFkYXRhEikKCGNvbW1hbmRzGAIgAygL",
+            "MhcucmlmdGNhbGxlci5HYW1lQ29tbWFuZBIsCghtZXRhZGF0YRgDIAEoCzIa",
+            "LnJpZnRjYWxsZXIuQ2xpZW50TWV0YWRhdGEqnwEKCUZsZXhBbGlnbhIaChZG",
+            "TEVYX0FMSUdOX1VOU1BFQ0lGSUVEEAASEwoPRkxFWF9BTElHTl9BVVRPEAES",
+            "GQoVRkxFWF9BTElHTl9GTEVYX1NUQVJUEAISFQoRRkxFWF9BTElHTl9DRU5U",
+            "RVIQAxIXChNGTEVYX0FMSUdOX0ZMRVhfRU5EEAQSFgoSRkxFWF9BTElHTl9T",
+            "VFJFVENIEAUqcAoQRmxleERpc3BsYXlTdHlsZRIiCh5GTEVYX0RJU1BMQVlf",
+            "U1RZTEVfVU5TUEVDSUZJRUQQABIbChdGTEVYX0RJU1BMQVlfU1RZTEVfRkxF",
+            "WBABEhsKF0ZMRVhfRElTUExBWV9TVFlMRV9OT05FEAIqpQEKDUZsZXhEaXJl",
+            "Y3Rpb24SHgoaRkxFWF9ESVJFQ1RJT05fVU5TUEVDSUZJRUQQABIZChVGTEVY",
+            "X0RJUkVDVElPTl9DT0xVTU4QARIhCh1GTEVYX0RJUkVDVElPTl9DT0xVTU5f",
+            "UkVWRVJTRRACEhYKEkZMRVhfRElSRUNUSU9OX1JPVxADEh4KGkZMRVhfRElS",
+            "RUNUSU9OX1JPV19SRVZFUlNFEAQqbAoIRmxleFdyYXASGQoVRkxFWF9XUkFQ",
+            "X1VOU1BFQ0lGSUVEEAASFQoRRkxFWF9XUkFQX05PX1dSQVAQARISCg5GTEVY",
+            "X1dSQVBfV1JBUBACEhoKFkZMRVhfV1JBUF9XUkFQX1JFVkVSU0UQAyq7AQoL",
+            "RmxleEp1c3RpZnkSHAoYRkxFWF9KVVNUSUZZX1VOU1BFQ0lGSUVEEAASGwoX",
+            "RkxFWF9KVVNUSUZZX0ZMRVhfU1RBUlQQARIXChNGTEVYX0pVU1RJRllfQ0VO",
+            "VEVSEAISGQoVRkxFWF9KVVNUSUZZX0ZMRVhfRU5EEAMSHgoaRkxFWF9KVVNU",
+            "SUZZX1NQQUNFX0JFVFdFRU4QBBIdChlGTEVYX0pVU1RJRllfU1BBQ0VfQVJP",
+            "VU5EEAUqYgoMRmxleE92ZXJmbG93Eh0KGUZMRVhfT1ZFUkZMT1dfVU5TUEVD",
+            "SUZJRUQQABIZChVGTEVYX09WRVJGTE9XX1ZJU0lCTEUQARIYChRGTEVYX09W",
+            "RVJGTE9XX0hJRERFThACKmUKDEZsZXhQb3NpdGlvbhIdChlGTEVYX1BPU0lU",
+            "SU9OX1VOU1BFQ0lGSUVEEAASGgoWRkxFWF9QT1NJVElPTl9SRUxBVElWRRAB",
+            "EhoKFkZMRVhfUE9TSVRJT05fQUJTT0xVVEUQAiphCgxUZXh0T3ZlcmZsb3cS",
+            "HQoZVEVYVF9PVkVSRkxPV19VTlNQRUNJRklFRBAAEhYKElRFWFRfT1ZFUkZM",
+            "T1dfQ0xJUBABEhoKFlRFWFRfT1ZFUkZMT1dfRUxMSVBTSVMQAirzBQoKRWFz",
+            "aW5nTW9kZRIbChdFQVNJTkdfTU9ERV9VTlNQRUNJRklFRBAAEhQKEEVBU0lO",
+            "R19NT0RFX0VBU0UQARIXChNFQVNJTkdfTU9ERV9FQVNFX0lOEAISGAoURUFT",
+            "SU5HX01PREVfRUFTRV9PVVQQAxIbChdFQVNJTkdfTU9ERV9FQVNFX0lOX09V",
+            "VBAEEhYKEkVBU0lOR19NT0RFX0xJTkVBUhAFEhwKGEVBU0lOR19NT0RFX0VB",
+            "U0VfSU5fU0lORRAGEh0KGUVBU0lOR19NT0RFX0VBU0VfT1VUX1NJTkUQBxIg",
+            "ChxFQVNJTkdfTU9ERV9FQVNFX0lOX09VVF9TSU5FEAgSHQoZRUFTSU5HX01P",
+            "REVfRUFTRV9JTl9DVUJJQxAJEh4KGkVBU0lOR19NT0RFX0VBU0VfT1VUX0NV",
+            "QklDEAoSIQodRUFTSU5HX01PREVfRUFTRV9JTl9PVVRfQ1VCSUMQCxIcChhF",
+            "QVNJTkdfTU9ERV9FQVNFX0lOX0NJUkMQDBIdChlFQVNJTkdfTU9ERV9FQVNF",
+            "X09VVF9DSVJDEA0SIAocRUFTSU5HX01PREVfRUFTRV9JTl9PVVRfQ0lSQxAO",
+            "Eh8KG0VBU0lOR19NT0RFX0VBU0VfSU5fRUxBU1RJQxAPEiAKHEVBU0lOR19N",
+            "T0RFX0VBU0VfT1VUX0VMQVNUSUMQEBIjCh9FQVNJTkdfTU9ERV9FQVNFX0lO",
+            "X09VVF9FTEFTVElDEBESHAoYRUFTSU5HX01PREVfRUFTRV9JTl9CQUNLEBIS",
+            "HQoZRUFTSU5HX01PREVfRUFTRV9PVVRfQkFDSxATEiAKHEVBU0lOR19NT0RF",
+            "X0VBU0VfSU5fT1VUX0JBQ0sQFBIeChpFQVNJTkdfTU9ERV9FQVNFX0lOX0JP",
+            "VU5DRRAVEh8KG0VBU0lOR19NT0RFX0VBU0VfT1VUX0JPVU5DRRAWEiIKHkVB",
+            "U0lOR19NT0RFX0VBU0VfSU5fT1VUX0JPVU5DRRAXKqABCg5JbWFnZVNjYWxl",
+            "TW9kZRIgChxJTUFHRV9TQ0FMRV9NT0RFX1VOU1BFQ0lGSUVEEAASJAogSU1B",
+            "R0VfU0NBTEVfTU9ERV9TVFJFVENIX1RPX0ZJTEwQARIjCh9JTUFHRV9TQ0FM",
+            "RV9NT0RFX1NDQUxFX0FORF9DUk9QEAISIQodSU1BR0VfU0NBTEVfTU9ERV9T",
+            "Q0FMRV9UT19GSVQQAyqKAQoJRm9udFN0eWxlEhoKFkZPTlRfU1RZTEVfVU5T",
+            "UEVDSUZJRUQQABIVChFGT05UX1NUWUxFX05PUk1BTBABEhMKD0ZPTlRfU1RZ",
+            "TEVfQk9MRBACEhUKEUZPTlRfU1RZTEVfSVRBTElDEAMSHgoaRk9OVF9TVFlM",
+            "RV9CT0xEX0FORF9JVEFMSUMQBCp6Cg9PdmVyZmxvd0NsaXBCb3gSIQodT1ZF",
+            "UkZMT1dfQ0xJUF9CT1hfVU5TUEVDSUZJRUQQABIhCh1PVkVSRkxPV19DTElQ",
+            "X0JPWF9QQURESU5HX0JPWBABEiEKHU9WRVJGTE9XX0NMSVBfQk9YX0NPTlRF",
+            "TlRfQk9YEAIqpgIKCVRleHRBbGlnbhIaChZURVhUX0FMSUdOX1VOU1BFQ0lG",
+            "SUVEEAASGQoVVEVYVF9BTElHTl9VUFBFUl9MRUZUEAESGwoXVEVYVF9BTElH",
+            "Tl9VUFBFUl9DRU5URVIQAhIaChZURVhUX0FMSUdOX1VQUEVSX1JJR0hUEAMS",
+            "GgoWVEVYVF9BTElHTl9NSURETEVfTEVGVBAEEhwKGFRFWFRfQUxJR05fTUlE",
+            "RExFX0NFTlRFUhAFEhsKF1RFWFRfQUxJR05fTUlERExFX1JJR0hUEAYSGQoV",
+            "VEVYVF9BTElHTl9MT1dFUl9MRUZUEAcSGwoXVEVYVF9BTElHTl9MT1dFUl9D",
+            "RU5URVIQCBIaChZURVhUX0FMSUdOX0xPV0VSX1JJR0hUEAkqowEKFFRleHRP",
+            "dmVyZmxvd1Bvc2l0aW9uEiYKIlRFWFRfT1ZFUkZMT1dfUE9TSVRJT05fVU5T",
+            "UEVDSUZJRUQQABIeChpURVhUX09WRVJGTE9XX1BPU0lUSU9OX0VORBABEiAK",
+            "HFRFWFRfT1ZFUkZMT1dfUE9TSVRJT05fU1RBUlQQAhIhCh1URVhUX09WRVJG",
+            "TE9XX1BPU0lUSU9OX01JRERMRRADKmoKDkZsZXhWaXNpYmlsaXR5Eh8KG0ZM",
+            "RVhfVklTSUJJTElUWV9VTlNQRUNJRklFRBAAEhsKF0ZMRVhfVklTSUJJTElU",
+            "WV9WSVNJQkxFEAESGgoWRkxFWF9WSVNJQklMSVRZX0hJRERFThACKloKCldo",
+            "aXRlU3BhY2USGwoXV0hJVEVfU1BBQ0VfVU5TUEVDSUZJRUQQABIWChJXSElU",
+            "RV9TUEFDRV9OT1JNQUwQARIXChNXSElURV9TUEFDRV9OT19XUkFQEAIqvgIK",
+            "DURpbWVuc2lvblVuaXQSHgoaRElNRU5TSU9OX1VOSVRfVU5TUEVDSUZJRUQQ",
+            "ABIZChVESU1FTlNJT05fVU5JVF9QSVhFTFMQARIdChlESU1FTlNJT05fVU5J",
+            "VF9QRVJDRU5UQUdFEAISIQodRElNRU5TSU9OX1VOSVRfVklFV1BPUlRfV0lE",
+            "VEgQAxIiCh5ESU1FTlNJT05fVU5JVF9WSUVXUE9SVF9IRUlHSFQQBBIgChxE",
+            "SU1FTlNJT05fVU5JVF9TQUZFX0FSRUFfVE9QEAUSIgoeRElNRU5TSU9OX1VO",
+            "SVRfU0FGRV9BUkVBX1JJR0hUEAYSIwofRElNRU5TSU9OX1VOSVRfU0FGRV9B",
+            "UkVBX0JPVFRPTRAHEiEKHURJTUVOU0lPTl9VTklUX1NBRkVfQVJFQV9MRUZU",
+            "EAgqcgoPRmxleFBpY2tpbmdNb2RlEiEKHUZMRVhfUElDS0lOR19NT0RFX1VO",
+            "U1BFQ0lGSUVEEAASHgoaRkxFWF9QSUNLSU5HX01PREVfUE9TSVRJT04QARIc",
+            "ChhGTEVYX1BJQ0tJTkdfTU9ERV9JR05PUkUQAiqcAQoXQmFja2dyb3VuZElt",
+            "YWdlQXV0b1NpemUSKgomQkFDS0dST1VORF9JTUFHRV9BVVRPX1NJWkVfVU5T",
+            "UEVDSUZJRUQQABIpCiVCQUNLR1JPVU5EX0lNQUdFX0FVVE9fU0laRV9GUk9N",
+            "X1dJRFRIEAESKgomQkFDS0dST1VORF9JTUFHRV9BVVRPX1NJWkVfRlJPTV9I",
+            "RUlHSFQQAiqoAQoTU2Nyb2xsQmFyVmlzaWJpbGl0eRIlCiFTQ1JPTExfQkFS",
+            "X1ZJU0lCSUxJVFlfVU5TUEVDSUZJRUQQABIeChpTQ1JPTExfQkFSX1ZJU0lC",
+            "SUxJVFlfQVVUTxABEigKJFNDUk9MTF9CQVJfVklTSUJJTElUWV9BTFdBWVNf",
+            "VklTSUJMRRACEiAKHFNDUk9MTF9CQVJfVklTSUJJTElUWV9ISURERU4QAyqq",
+            "AQoTVG91Y2hTY3JvbGxCZWhhdmlvchIlCiFUT1VDSF9TQ1JPTExfQkVIQVZJ",
+            "T1JfVU5TUEVDSUZJRUQQABImCiJUT1VDSF9TQ1JPTExfQkVIQVZJT1JfVU5S",
+            "RVNUUklDVEVEEAESIQodVE9VQ0hfU0NST0xMX0JFSEFWSU9SX0VMQVNUSUMQ",
+            "AhIhCh1UT1VDSF9TQ1JPTExfQkVIQVZJT1JfQ0xBTVBFRBADKnMKD1NsaWRl",
+            "ckRpcmVjdGlvbhIgChxTTElERVJfRElSRUNUSU9OX1VOU1BFQ0lGSUVEEAAS",
+            "HwobU0xJREVSX0RJUkVDVElPTl9IT1JJWk9OVEFMEAESHQoZU0xJREVSX0RJ",
+            "UkVDVElPTl9WRVJUSUNBTBACKl8KClBsYXllclNpZGUSGwoXUExBWUVSX1NJ",
+            "REVfVU5TUEVDSUZJRUQQABIYChRQTEFZRVJfU0lERV9DT1ZFTkFOVBABEhoK",
+            "FlBMQVlFUl9TSURFX1JJRlRDQUxMRVIQAipZCgpQbGF5ZXJOYW1lEhsKF1BM",
+            "QVlFUl9OQU1FX1VOU1BFQ0lGSUVEEAASFAoQUExBWUVSX05BTUVfVVNFUhAB",
+            "EhgKFFBMQVlFUl9OQU1FX09QUE9ORU5UEAIqkAIKDlJvb21JZGVudGlmaWVy",
+            "Eh8KG1JPT01fSURFTlRJRklFUl9VTlNQRUNJRklFRBAAEhkKFVJPT01fSURF",
+            "TlRJRklFUl9WQVVMVBABEhsKF1JPT01fSURFTlRJRklFUl9TQU5DVFVNEAIS",
+            "GQoVUk9PTV9JREVOVElGSUVSX0NSWVBUEAMSGgoWUk9PTV9JREVOVElGSUVS",
+            "X1JPT01fQRAEEhoKFlJPT01fSURFTlRJRklFUl9ST09NX0IQBRIaChZST09N",
+            "X0lERU5USUZJRVJfUk9PTV9DEAYSGgoWUk9PTV9JREVOVElGSUVSX1JPT01f",
+            "RBAHEhoKFlJPT01fSURFTlRJRklFUl9ST09NX0UQCCp/Cg5UYXJnZXRpbmdB",
+            "cnJvdxIfChtUQVJHRVRJTkdfQVJST1dfVU5TUEVDSUZJRUQQABIXChNUQVJH",
+            "RVRJTkdfQVJST1dfUkVEEAESGAoUVEFSR0VUSU5HX0FSUk9XX0JMVUUQAhIZ",
+            "ChVUQVJHRVRJTkdfQVJST1dfR1JFRU4QAyp5ChJDbGllbnRSb29tTG9jYXRp",
+            "b24SJAogQ0xJRU5UX1JPT01fTE9DQVRJT05fVU5TUEVDSUZJRUQQABIdChlD",
+            "TElFTlRfUk9PTV9MT0NBVElPTl9CQUNLEAESHgoaQ0xJRU5UX1JPT01fTE9D",
+            "QVRJT05fRlJPTlQQAip5ChJDbGllbnRJdGVtTG9jYXRpb24SJAogQ0xJRU5U",
+            "X0lURU1fTE9DQVRJT05fVU5TUEVDSUZJRUQQABIdChlDTElFTlRfSVRFTV9M",
+            "T0NBVElPTl9MRUZUEAESHgoaQ0xJRU5UX0lURU1fTE9DQVRJT05fUklHSFQQ",
+            "AiqVAQoYUmV2ZWFsZWRDYXJkc0Jyb3dzZXJTaXplEisKJ1JFVkVBTEVEX0NB",
+            "UkRTX0JST1dTRVJfU0laRV9VTlNQRUNJRklFRBAAEiUKIVJFVkVBTEVEX0NB",
+            "UkRTX0JST1dTRVJfU0laRV9TTUFMTBABEiUKIVJFVkVBTEVEX0NBUkRTX0JS",
+            "T1dTRVJfU0laRV9MQVJHRRACKp8BCgpDYXJkUHJlZmFiEhsKF0NBUkRfUFJF",
+            "RkFCX1VOU1BFQ0lGSUVEEAASGAoUQ0FSRF9QUkVGQUJfU1RBTkRBUkQQARIa",
+            "ChZDQVJEX1BSRUZBQl9UT0tFTl9DQVJEEAISGwoXQ0FSRF9QUkVGQUJfRlVM",
+            "TF9IRUlHSFQQAxIhCh1DQVJEX1BSRUZBQl9GVUxMX0hFSUdIVF9UT0tFThAE",
+            "KvYBChxHYW1lQ2hhcmFjdGVyRmFjaW5nRGlyZWN0aW9uEi8KK0dBTUVfQ0hB",
+            "UkFDVEVSX0ZBQ0lOR19ESVJFQ1RJT05fVU5TUEVDSUZJRUQQABImCiJHQU1F",
+            "X0NIQVJBQ1RFUl9GQUNJTkdfRElSRUNUSU9OX1VQEAESKAokR0FNRV9DSEFS",
+            "QUNURVJfRkFDSU5HX0RJUkVDVElPTl9ET1dOEAISKAokR0FNRV9DSEFSQUNU",
+            "RVJfRkFDSU5HX0RJUkVDVElPTl9MRUZUEAMSKQolR0FNRV9DSEFSQUNURVJf",
+            "RkFDSU5HX0RJUkVDVElPTl9SSUdIVBAEKoMBChFBcnJvd0J1YmJsZUNvcm5l",
+            "chIjCh9BUlJPV19CVUJCTEVfQ09STkVSX1VOU1BFQ0lGSUVEEAASIwofQVJS",
+            "T1dfQlVCQkxFX0NPUk5FUl9CT1RUT01fTEVGVBABEiQKIEFSUk9XX0JVQkJM",
+            "RV9DT1JORVJfQk9UVE9NX1JJR0hUEAIqpQEKDEFuY2hvckNvcm5lchIdChlB",
+            "TkNIT1JfQ09STkVSX1VOU1BFQ0lGSUVEEAASGgoWQU5DSE9SX0NPUk5FUl9U",
+            "T1BfTEVGVBABEhsKF0FOQ0hPUl9DT1JORVJfVE9QX1JJR0hUEAISHQoZQU5D",
+            "SE9SX0NPUk5FUl9CT1RUT01fTEVGVBADEh4KGkFOQ0hPUl9DT1JORVJfQk9U",
+            "VE9NX1JJR0hUEAQqdgoNUm9vbVZpc2l0VHlwZRIfChtST09NX1ZJU0lUX1RZ",
+            "UEVfVU5TUEVDSUZJRUQQABIhCh1ST09NX1ZJU0lUX1RZUEVfSU5JVElBVEVf",
+            "UkFJRBABEiEKHVJPT01fVklTSVRfVFlQRV9QUk9HUkVTU19ST09NEAIqlQEK",
+            "FUNhcmRDcmVhdGlvbkFuaW1hdGlvbhInCiNDQVJEX0NSRUFUSU9OX0FOSU1B",
+            "VElPTl9VTlNQRUNJRklFRBAAEiUKIUNBUkRfQ1JFQVRJT05fQU5JTUFUSU9O",
+            "X0RSQVdfQ0FSRBABEiwKKENBUkRfQ1JFQVRJT05fQU5JTUFUSU9OX0ZST01f",
+            "UEFSRU5UX0NBUkQQAiqMAQoKTXVzaWNTdGF0ZRIbChdNVVNJQ19TVEFURV9V",
+            "TlNQRUNJRklFRBAAEhYKEk1VU0lDX1NUQVRFX1NJTEVOVBABEhgKFE1VU0lD",
+            "X1NUQVRFX0dBTUVQTEFZEAISFAoQTVVTSUNfU1RBVEVfUkFJRBADEhkKFU1V",
+            "U0lDX1NUQVRFX01BSU5fTUVOVRAEKqkBCg9HYW1lTWVzc2FnZVR5cGUSIQod",
+            "R0FNRV9NRVNTQUdFX1RZUEVfVU5TUEVDSUZJRUQQABIaChZHQU1FX01FU1NB",
+            "R0VfVFlQRV9EQVdOEAESGgoWR0FNRV9NRVNTQUdFX1RZUEVfRFVTSxACEh0K",
+            "GUdBTUVfTUVTU0FHRV9UWVBFX1ZJQ1RPUlkQAxIcChhHQU1FX01FU1NBR0Vf",
+            "VFlQRV9ERUZFQVQQBCpqCg1TY2VuZUxvYWRNb2RlEh8KG1NDRU5FX0xPQURf",
+            "TU9ERV9VTlNQRUNJRklFRBAAEhoKFlNDRU5FX0xPQURfTU9ERV9TSU5HTEUQ",
+            "ARIcChhTQ0VORV9MT0FEX01PREVfQURESVRJVkUQAiqQAQoPTG9nTWVzc2Fn",
+            "ZUxldmVsEiEKHUxPR19NRVNTQUdFX0xFVkVMX1VOU1BFQ0lGSUVEEAASHgoa",
+            "TE9HX01FU1NBR0VfTEVWRUxfU1RBTkRBUkQQARIdChlMT0dfTUVTU0FHRV9M",
+            "RVZFTF9XQVJOSU5HEAISGwoXTE9HX01FU1NBR0VfTEVWRUxfRVJST1IQAyqB",
+            "AQoLTWFwVGlsZVR5cGUSHQoZTUFQX1RJTEVfVFlQRV9VTlNQRUNJRklFRBAA",
+            "EhoKFk1BUF9USUxFX1RZUEVfT0JTVEFDTEUQARIaChZNQVBfVElMRV9UWVBF",
+            "X1dBTEtBQkxFEAISGwoXTUFQX1RJTEVfVFlQRV9WSVNJVEFCTEUQAzKRAQoK",
+            "UmlmdGNhbGxlchJACgdDb25uZWN0EhoucmlmdGNhbGxlci5Db25uZWN0UmVx",
+            "dWVzdBoXLnJpZnRjYWxsZXIuQ29tbWFuZExpc3QwARJBCg1QZXJmb3JtQWN0",
+            "aW9uEhcucmlmdGNhbGxlci5HYW1lUmVxdWVzdBoXLnJpZnRjYWxsZXIuQ29t",
+            "bWFuZExpc3RCFKoCEVJpZnRjYWxsZXIuUHJvdG9zYgZwcm90bzM="));
       descriptor = pbr::FileDescriptor.FromGeneratedCode(descriptorData,
           new pbr::FileDescriptor[] { global::Google.Protobuf.WellKnownTypes.WrappersReflection.Descriptor, global::Google.Protobuf.WellKnownTypes.EmptyReflection.Descriptor, },
           new pbr::GeneratedClrTypeInfo(new[] {typeof(global::Riftcaller.Protos.FlexAlign), typeof(global::Riftcaller.Protos.FlexDisplayStyle), typeof(global::Riftcaller.Protos.FlexDirection), typeof(global::Riftcaller.Protos.FlexWrap), typeof(global::Riftcaller.Protos.FlexJustify), typeof(global::Riftcaller.Protos.FlexOverflow), typeof(global::Riftcaller.Protos.FlexPosition), typeof(global::Riftcaller.Protos.TextOverflow), typeof(global::Riftcaller.Protos.EasingMode), typeof(global::Riftcaller.Protos.ImageScaleMode), typeof(global::Riftcaller.Protos.FontStyle), typeof(global::Riftcaller.Protos.OverflowClipBox), typeof(global::Riftcaller.Protos.TextAlign), typeof(global::Riftcaller.Protos.TextOverflowPosition), typeof(global::Riftcaller.Protos.FlexVisibility), typeof(global::Riftcaller.Protos.WhiteSpace), typeof(global::Riftcaller.Protos.DimensionUnit), typeof(global::Riftcaller.Protos.FlexPickingMode), typeof(global::Riftcaller.Protos.BackgroundImageAutoSize), typeof(global::Riftcaller.Protos.ScrollBarVisibility), typeof(global::Riftcaller.Protos.TouchScrollBehavior), typeof(global::Riftcaller.Protos.SliderDirection), typeof(global::Riftcaller.Protos.PlayerSide), typeof(global::Riftcaller.Protos.PlayerName), typeof(global::Riftcaller.Protos.RoomIdentifier), typeof(global::Riftcaller.Protos.TargetingArrow), typeof(global::Riftcaller.Protos.ClientRoomLocation), typeof(global::Riftcaller.Protos.ClientItemLocation), typeof(global::Riftcaller.Protos.RevealedCardsBrowserSize), typeof(global::Riftcaller.Protos.CardPrefab), typeof(global::Riftcaller.Protos.GameCharacterFacingDirection), typeof(global::Riftcaller.Protos.ArrowBubbleCorner), typeof(global::Riftcaller.Protos.AnchorCorner), typeof(global::Riftcaller.Protos.RoomVisitType), typeof(global::Riftcaller.Protos.CardCreationAnimation), typeof(global::Riftcaller.Protos.MusicState), typeof(global::Riftcaller.Protos.GameMessageType), typeof(global::Riftcaller.Protos.SceneLoadMode), typeof(global::Riftcaller.Protos.LogMessageLevel), typeof(global::Riftcaller.Protos.MapTileType), }, null, new pbr::GeneratedClrTypeInfo[] {
@@ -782,7 +790,7 @@ namespace Riftcaller.Protos {
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.Text), global::Riftcaller.Protos.Text.Parser, new[]{ "Label" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.ScrollBar), global::Riftcaller.Protos.ScrollBar.Parser, new[]{ "Style" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.ScrollViewNode), global::Riftcaller.Protos.ScrollViewNode.Parser, new[]{ "Elasticity", "HorizontalPageSize", "HorizontalScrollBar", "HorizontalScrollBarVisibility", "ScrollDecelerationRate", "TouchScrollBehavior", "VerticalPageSize", "VerticalScrollBar", "VerticalScrollBarVisibility", "MouseWheelScrollSize" }, null, null, null, null),
-            new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.DraggableNode), global::Riftcaller.Protos.DraggableNode.Parser, new[]{ "DropTargetIdentifiers", "OverTargetIndicator", "OnDrop", "HorizontalDragStartDistance", "RemoveOriginal", "HideIndicatorChildren", "CustomDragIndicator", "OnDragDetected" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.DraggableNode), global::Riftcaller.Protos.DraggableNode.Parser, new[]{ "DropTargetIdentifiers", "OverTargetIndicator", "OnDrop", "HorizontalDragStartDistance", "RemoveOriginal", "HideIndicatorChildren", "CustomDragIndicator", "OnDragDetected", "DragDetectedUpdate", "DragEndedUpdate" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.DropTargetNode), global::Riftcaller.Protos.DropTargetNode.Parser, null, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.TextFieldNode), global::Riftcaller.Protos.TextFieldNode.Parser, new[]{ "GlobalIdentifier", "InitialText", "Multiline", "IsReadOnly", "MaxLength", "IsPasswordField", "DoubleClickSelectsWord", "TripleClickSelectsLine", "MaskCharacter" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.SliderNode), global::Riftcaller.Protos.SliderNode.Parser, new[]{ "InitialValue", "Label", "PreferenceKey", "Direction", "HighValue", "LowValue", "Inverted", "PageSize", "ShowInputField", "LabelStyle", "DragContainerStyle", "TrackerStyle", "DraggerStyle", "DraggerBorderStyle" }, null, null, null, null),
@@ -862,7 +870,7 @@ namespace Riftcaller.Protos {
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.DebugLogCommand), global::Riftcaller.Protos.DebugLogCommand.Parser, new[]{ "Message" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.DelayCommand), global::Riftcaller.Protos.DelayCommand.Parser, new[]{ "Duration" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.InterfacePanelAddress), global::Riftcaller.Protos.InterfacePanelAddress.Parser, new[]{ "DebugString", "Serialized" }, null, null, null, null),
-            new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.InterfacePanel), global::Riftcaller.Protos.InterfacePanel.Parser, new[]{ "Address", "Node", "ScreenOverlay" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.InterfacePanel), global::Riftcaller.Protos.InterfacePanel.Parser, new[]{ "Address", "Node", "ScreenOverlay", "PreloadSprites" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.CardAnchor), global::Riftcaller.Protos.CardAnchor.Parser, new[]{ "NodeCorner", "CardCorner" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.CardAnchorNode), global::Riftcaller.Protos.CardAnchorNode.Parser, new[]{ "CardId", "Node", "Anchors" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.InterfaceMainControls), global::Riftcaller.Protos.InterfaceMainControls.Parser, new[]{ "Node", "Overlay", "CardAnchorNodes" }, null, null, null, null),
@@ -892,7 +900,7 @@ namespace Riftcaller.Protos {
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.WorldMapSprite), global::Riftcaller.Protos.WorldMapSprite.Parser, new[]{ "SpriteAddress", "Color", "AnchorOffset", "Scale" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.WorldMapCharacter), global::Riftcaller.Protos.WorldMapCharacter.Parser, new[]{ "Appearance", "FacingDirection" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.WorldMapTile), global::Riftcaller.Protos.WorldMapTile.Parser, new[]{ "Sprites", "Position", "OnVisit", "TileType", "Character" }, null, null, null, null),
-            new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.UpdateWorldMapCommand), global::Riftcaller.Protos.UpdateWorldMapCommand.Parser, new[]{ "Tiles" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.UpdateWorldMapCommand), global::Riftcaller.Protos.UpdateWorldMapCommand.Parser, new[]{ "Tiles", "MinBounds", "MaxBounds" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.RenderScreenOverlayCommand), global::Riftcaller.Protos.RenderScreenOverlayCommand.Parser, new[]{ "Node" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.ElementSelector), global::Riftcaller.Protos.ElementSelector.Parser, new[]{ "ElementName", "DragIndicator", "TargetElement" }, new[]{ "Selector" }, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.ElementAnimation), global::Riftcaller.Protos.ElementAnimation.Parser, new[]{ "Duration", "Ease" }, null, null, null, null),
@@ -910,7 +918,8 @@ namespace Riftcaller.Protos {
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.SetKeyboardShortcutsCommand), global::Riftcaller.Protos.SetKeyboardShortcutsCommand.Parser, new[]{ "MappingList" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.TurnFaceDownArenaAnimationCommand), global::Riftcaller.Protos.TurnFaceDownArenaAnimationCommand.Parser, new[]{ "CardId" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.ClearEffectsCommand), global::Riftcaller.Protos.ClearEffectsCommand.Parser, new[]{ "Owner" }, null, null, null, null),
-            new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.GameCommand), global::Riftcaller.Protos.GameCommand.Parser, new[]{ "Debug", "Delay", "UpdatePanels", "TogglePanel", "UpdateGameView", "VisitRoom", "PlaySound", "SetMusic", "FireProjectile", "PlayEffect", "DisplayGameMessage", "SetGameObjectsEnabled", "DisplayRewards", "LoadScene", "MoveGameObjects", "CreateTokenCard", "SetCardMovementEffect", "UpdateWorldMap", "RenderScreenOverlay", "UpdateInterface", "Conditional", "InfoZoom", "SetKeyboardShortcuts", "TurnFaceDownArenaAnimation", "ClearPersistentEffects" }, new[]{ "Command" }, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.FocusGameObjectCommand), global::Riftcaller.Protos.FocusGameObjectCommand.Parser, new[]{ "Object" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.GameCommand), global::Riftcaller.Protos.GameCommand.Parser, new[]{ "Debug", "Delay", "UpdatePanels", "TogglePanel", "UpdateGameView", "VisitRoom", "PlaySound", "SetMusic", "FireProjectile", "PlayEffect", "DisplayGameMessage", "SetGameObjectsEnabled", "DisplayRewards", "LoadScene", "MoveGameObjects", "CreateTokenCard", "SetCardMovementEffect", "UpdateWorldMap", "RenderScreenOverlay", "UpdateInterface", "Conditional", "InfoZoom", "SetKeyboardShortcuts", "TurnFaceDownArenaAnimation", "ClearPersistentEffects", "FocusGameObject" }, new[]{ "Command" }, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.LoggingMetadata), global::Riftcaller.Protos.LoggingMetadata.Parser, new[]{ "Key", "Value" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Riftcaller.Protos.CommandList), global::Riftcaller.Protos.CommandList.Parser, new[]{ "LoggingMetadata", "Commands", "Metadata" }, null, null, null, null)
           }));
@@ -9875,6 +9884,8 @@ namespace Riftcaller.Protos {
       hideIndicatorChildren_ = other.hideIndicatorChildren_.Clone();
       customDragIndicator_ = other.customDragIndicator_ != null ? other.customDragIndicator_.Clone() : null;
       onDragDetected_ = other.onDragDetected_ != null ? other.onDragDetected_.Clone() : null;
+      dragDetectedUpdate_ = other.dragDetectedUpdate_ != null ? other.dragDetectedUpdate_.Clone() : null;
+      dragEndedUpdate_ = other.dragEndedUpdate_ != null ? other.dragEndedUpdate_.Clone() : null;
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
@@ -10004,6 +10015,36 @@ namespace Riftcaller.Protos {
       }
     }
 
+    /// <summary>Field number for the "drag_detected_update" field.</summary>
+    public const int DragDetectedUpdateFieldNumber = 9;
+    private global::Riftcaller.Protos.CommandList dragDetectedUpdate_;
+    /// <summary>
+    /// Commands to apply immediately when a drag is detected, in addition to
+    /// invoking 'on_drag_detected'.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public global::Riftcaller.Protos.CommandList DragDetectedUpdate {
+      get { return dragDetectedUpdate_; }
+      set {
+        dragDetectedUpdate_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "drag_ended_update" field.</summary>
+    public const int DragEndedUpdateFieldNumber = 10;
+    private global::Riftcaller.Protos.CommandList dragEndedUpdate_;
+    /// <summary>
+    /// Commands to apply immediately when a drag ends, whether or not the node
+    /// was dropped over a target.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public global::Riftcaller.Protos.CommandList DragEndedUpdate {
+      get { return dragEndedUpdate_; }
+      set {
+        dragEndedUpdate_ = value;
+      }
+    }
+
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
     public override bool Equals(object other) {
       return Equals(other as DraggableNode);
@@ -10025,6 +10066,8 @@ namespace Riftcaller.Protos {
       if(!hideIndicatorChildren_.Equals(other.hideIndicatorChildren_)) return false;
       if (!object.Equals(CustomDragIndicator, other.CustomDragIndicator)) return false;
       if (!object.Equals(OnDragDetected, other.OnDragDetected)) return false;
+      if (!object.Equals(DragDetectedUpdate, other.DragDetectedUpdate)) return false;
+      if (!object.Equals(DragEndedUpdate, other.DragEndedUpdate)) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
@@ -10039,6 +10082,8 @@ namespace Riftcaller.Protos {
       hash ^= hideIndicatorChildren_.GetHashCode();
       if (customDragIndicator_ != null) hash ^= CustomDragIndicator.GetHashCode();
       if (onDragDetected_ != null) hash ^= OnDragDetected.GetHashCode();
+      if (dragDetectedUpdate_ != null) hash ^= DragDetectedUpdate.GetHashCode();
+      if (dragEndedUpdate_ != null) hash ^= DragEndedUpdate.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -10080,6 +10125,14 @@ namespace Riftcaller.Protos {
         output.WriteRawTag(66);
         output.WriteMessage(OnDragDetected);
       }
+      if (dragDetectedUpdate_ != null) {
+        output.WriteRawTag(74);
+        output.WriteMessage(DragDetectedUpdate);
+      }
+      if (dragEndedUpdate_ != null) {
+        output.WriteRawTag(82);
+        output.WriteMessage(DragEndedUpdate);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
@@ -10114,6 +10167,14 @@ namespace Riftcaller.Protos {
         output.WriteRawTag(66);
         output.WriteMessage(OnDragDetected);
       }
+      if (dragDetectedUpdate_ != null) {
+        output.WriteRawTag(74);
+        output.WriteMessage(DragDetectedUpdate);
+      }
+      if (dragEndedUpdate_ != null) {
+        output.WriteRawTag(82);
+        output.WriteMessage(DragEndedUpdate);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(ref output);
       }
@@ -10143,6 +10204,12 @@ namespace Riftcaller.Protos {
       if (onDragDetected_ != null) {
         size += 1 + pb::CodedOutputStream.ComputeMessageSize(OnDragDetected);
       }
+      if (dragDetectedUpdate_ != null) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(DragDetectedUpdate);
+      }
+      if (dragEndedUpdate_ != null) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(DragEndedUpdate);
+      }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
       }
@@ -10188,6 +10255,18 @@ namespace Riftcaller.Protos {
         }
         OnDragDetected.MergeFrom(other.OnDragDetected);
       }
+      if (other.dragDetectedUpdate_ != null) {
+        if (dragDetectedUpdate_ == null) {
+          DragDetectedUpdate = new global::Riftcaller.Protos.CommandList();
+        }
+        DragDetectedUpdate.MergeFrom(other.DragDetectedUpdate);
+      }
+      if (other.dragEndedUpdate_ != null) {
+        if (dragEndedUpdate_ == null) {
+          DragEndedUpdate = new global::Riftcaller.Protos.CommandList();
+        }
+        DragEndedUpdate.MergeFrom(other.DragEndedUpdate);
+      }
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
@@ -10249,6 +10328,20 @@ namespace Riftcaller.Protos {
             input.ReadMessage(OnDragDetected);
             break;
           }
+          case 74: {
+            if (dragDetectedUpdate_ == null) {
+              DragDetectedUpdate = new global::Riftcaller.Protos.CommandList();
+            }
+            input.ReadMessage(DragDetectedUpdate);
+            break;
+          }
+          case 82: {
+            if (dragEndedUpdate_ == null) {
+              DragEndedUpdate = new global::Riftcaller.Protos.CommandList();
+            }
+            input.ReadMessage(DragEndedUpdate);
+            break;
+          }
         }
       }
     #endif
@@ -10310,6 +10403,20 @@ namespace Riftcaller.Protos {
             input.ReadMessage(OnDragDetected);
             break;
           }
+          case 74: {
+            if (dragDetectedUpdate_ == null) {
+              DragDetectedUpdate = new global::Riftcaller.Protos.CommandList();
+            }
+            input.ReadMessage(DragDetectedUpdate);
+            break;
+          }
+          case 82: {
+            if (dragEndedUpdate_ == null) {
+              DragEndedUpdate = new global::Riftcaller.Protos.CommandList();
+            }
+            input.ReadMessage(DragEndedUpdate);
+            break;
+          }
         }
       }
     }
@@ -31660,6 +31767,7 @@ namespace Riftcaller.Protos {
       address_ = other.address_ != null ? other.address_.Clone() : null;
       node_ = other.node_ != null ? other.node_.Clone() : null;
       screenOverlay_ = other.screenOverlay_ != null ? other.screenOverlay_.Clone() : null;
+      preloadSprites_ = other.preloadSprites_.Clone();
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
@@ -31706,6 +31814,20 @@ namespace Riftcaller.Protos {
       }
     }
 
+    /// <summary>Field number for the "preload_sprites" field.</summary>
+    public const int PreloadSpritesFieldNumber = 4;
+    private static readonly pb::FieldCodec<global::Riftcaller.Protos.SpriteAddress> _repeated_preloadSprites_codec
+        = pb::FieldCodec.ForMessage(34, global::Riftcaller.Protos.SpriteAddress.Parser);
+    private readonly pbc::RepeatedField<global::Riftcaller.Protos.SpriteAddress> preloadSprites_ = new pbc::RepeatedField<global::Riftcaller.Protos.SpriteAddress>();
+    /// <summary>
+    /// Sprites displayed by this panel which the client should load ahead of
+    /// time to avoid images appearing late.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public pbc::RepeatedField<global::Riftcaller.Protos.SpriteAddress> PreloadSprites {
+      get { return preloadSprites_; }
+    }
+
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
     public override bool Equals(object other) {
       return Equals(other as InterfacePanel);
@@ -31722,6 +31844,7 @@ namespace Riftcaller.Protos {
       if (!object.Equals(Address, other.Address)) return false;
       if (!object.Equals(Node, other.Node)) return false;
       if (!object.Equals(ScreenOverlay, other.ScreenOverlay)) return false;
+      if(!preloadSprites_.Equals(other.preloadSprites_)) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
@@ -31731,6 +31854,7 @@ namespace Riftcaller.Protos {
       if (address_ != null) hash ^= Address.GetHashCode();
       if (node_ != null) hash ^= Node.GetHashCode();
       if (screenOverlay_ != null) hash ^= ScreenOverlay.GetHashCode();
+      hash ^= preloadSprites_.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -31759,6 +31883,7 @@ namespace Riftcaller.Protos {
         output.WriteRawTag(26);
         output.WriteMessage(ScreenOverlay);
       }
+      preloadSprites_.WriteTo(output, _repeated_preloadSprites_codec);
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
@@ -31780,6 +31905,7 @@ namespace Riftcaller.Protos {
         output.WriteRawTag(26);
         output.WriteMessage(ScreenOverlay);
       }
+      preloadSprites_.WriteTo(ref output, _repeated_preloadSprites_codec);
       if (_unknownFields != null) {
         _unknownFields.WriteTo(ref output);
       }
@@ -31798,6 +31924,7 @@ namespace Riftcaller.Protos {
       if (screenOverlay_ != null) {
         size += 1 + pb::CodedOutputStream.ComputeMessageSize(ScreenOverlay);
       }
+      size += preloadSprites_.CalculateSize(_repeated_preloadSprites_codec);
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
       }
@@ -31827,6 +31954,7 @@ namespace Riftcaller.Protos {
         }
         ScreenOverlay.MergeFrom(other.ScreenOverlay);
       }
+      preloadSprites_.Add(other.preloadSprites_);
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
@@ -31862,6 +31990,10 @@ namespace Riftcaller.Protos {
             input.ReadMessage(ScreenOverlay);
             break;
           }
+          case 34: {
+            preloadSprites_.AddEntriesFrom(input, _repeated_preloadSprites_codec);
+            break;
+          }
         }
       }
     #endif
@@ -31897,6 +32029,10 @@ namespace Riftcaller.Protos {
             input.ReadMessage(ScreenOverlay);
             break;
           }
+          case 34: {
+            preloadSprites_.AddEntriesFrom(ref input, _repeated_preloadSprites_codec);
+            break;
+          }
         }
       }
     }
@@ -39709,6 +39845,8 @@ namespace Riftcaller.Protos {
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
     public UpdateWorldMapCommand(UpdateWorldMapCommand other) : this() {
       tiles_ = other.tiles_.Clone();
+      minBounds_ = other.minBounds_ != null ? other.minBounds_.Clone() : null;
+      maxBounds_ = other.maxBounds_ != null ? other.maxBounds_.Clone() : null;
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
@@ -39727,6 +39865,36 @@ namespace Riftcaller.Protos {
       get { return tiles_; }
     }
 
+    /// <summary>Field number for the "min_bounds" field.</summary>
+    public const int MinBoundsFieldNumber = 2;
+    private global::Riftcaller.Protos.MapPosition minBounds_;
+    /// <summary>
+    /// Minimum tile position among all revealed tiles, used to frame the
+    /// camera.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public global::Riftcaller.Protos.MapPosition MinBounds {
+      get { return minBounds_; }
+      set {
+        minBounds_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "max_bounds" field.</summary>
+    public const int MaxBoundsFieldNumber = 3;
+    private global::Riftcaller.Protos.MapPosition maxBounds_;
+    /// <summary>
+    /// Maximum tile position among all revealed tiles, used to frame the
+    /// camera.
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public global::Riftcaller.Protos.MapPosition MaxBounds {
+      get { return maxBounds_; }
+      set {
+        maxBounds_ = value;
+      }
+    }
+
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
     public override bool Equals(object other) {
       return Equals(other as UpdateWorldMapCommand);
@@ -39741,6 +39909,8 @@ namespace Riftcaller.Protos {
         return true;
       }
       if(!tiles_.Equals(other.tiles_)) return false;
+      if (!object.Equals(MinBounds, other.MinBounds)) return false;
+      if (!object.Equals(MaxBounds, other.MaxBounds)) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
@@ -39748,6 +39918,8 @@ namespace Riftcaller.Protos {
     public override int GetHashCode() {
       int hash = 1;
       hash ^= tiles_.GetHashCode();
+      if (minBounds_ != null) hash ^= MinBounds.GetHashCode();
+      if (maxBounds_ != null) hash ^= MaxBounds.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -39765,6 +39937,14 @@ namespace Riftcaller.Protos {
       output.WriteRawMessage(this);
     #else
       tiles_.WriteTo(output, _repeated_tiles_codec);
+      if (minBounds_ != null) {
+        output.WriteRawTag(18);
+        output.WriteMessage(MinBounds);
+      }
+      if (maxBounds_ != null) {
+        output.WriteRawTag(26);
+        output.WriteMessage(MaxBounds);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
@@ -39775,6 +39955,14 @@ namespace Riftcaller.Protos {
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
     void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
       tiles_.WriteTo(ref output, _repeated_tiles_codec);
+      if (minBounds_ != null) {
+        output.WriteRawTag(18);
+        output.WriteMessage(MinBounds);
+      }
+      if (maxBounds_ != null) {
+        output.WriteRawTag(26);
+        output.WriteMessage(MaxBounds);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(ref output);
       }
@@ -39785,6 +39973,12 @@ namespace Riftcaller.Protos {
     public int CalculateSize() {
       int size = 0;
       size += tiles_.CalculateSize(_repeated_tiles_codec);
+      if (minBounds_ != null) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(MinBounds);
+      }
+      if (maxBounds_ != null) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(MaxBounds);
+      }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
       }
@@ -39797,6 +39991,18 @@ namespace Riftcaller.Protos {
         return;
       }
       tiles_.Add(other.tiles_);
+      if (other.minBounds_ != null) {
+        if (minBounds_ == null) {
+          MinBounds = new global::Riftcaller.Protos.MapPosition();
+        }
+        MinBounds.MergeFrom(other.MinBounds);
+      }
+      if (other.maxBounds_ != null) {
+        if (maxBounds_ == null) {
+          MaxBounds = new global::Riftcaller.Protos.MapPosition();
+        }
+        MaxBounds.MergeFrom(other.MaxBounds);
+      }
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
@@ -39815,6 +40021,20 @@ namespace Riftcaller.Protos {
             tiles_.AddEntriesFrom(input, _repeated_tiles_codec);
             break;
           }
+          case 18: {
+            if (minBounds_ == null) {
+              MinBounds = new global::Riftcaller.Protos.MapPosition();
+            }
+            input.ReadMessage(MinBounds);
+            break;
+          }
+          case 26: {
+            if (maxBounds_ == null) {
+              MaxBounds = new global::Riftcaller.Protos.MapPosition();
+            }
+            input.ReadMessage(MaxBounds);
+            break;
+          }
         }
       }
     #endif
@@ -39833,6 +40053,20 @@ namespace Riftcaller.Protos {
             tiles_.AddEntriesFrom(ref input, _repeated_tiles_codec);
             break;
           }
+          case 18: {
+            if (minBounds_ == null) {
+              MinBounds = new global::Riftcaller.Protos.MapPosition();
+            }
+            input.ReadMessage(MinBounds);
+            break;
+          }
+          case 26: {
+            if (maxBounds_ == null) {
+              MaxBounds = new global::Riftcaller.Protos.MapPosition();
+            }
+            input.ReadMessage(MaxBounds);
+            break;
+          }
         }
       }
     }
@@ -44280,6 +44514,194 @@ namespace Riftcaller.Protos {
 
   }
 
+  /// <summary>
+  /// Requests that the client center its camera on a given game object, e.g. to
+  /// draw attention to a card whose ability has just fired.
+  /// </summary>
+  public sealed partial class FocusGameObjectCommand : pb::IMessage<FocusGameObjectCommand>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<FocusGameObjectCommand> _parser = new pb::MessageParser<FocusGameObjectCommand>(() => new FocusGameObjectCommand());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public static pb::MessageParser<FocusGameObjectCommand> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::Riftcaller.Protos.RiftcallerReflection.Descriptor.MessageTypes[155]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public FocusGameObjectCommand() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public FocusGameObjectCommand(FocusGameObjectCommand other) : this() {
+      object_ = other.object_ != null ? other.object_.Clone() : null;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public FocusGameObjectCommand Clone() {
+      return new FocusGameObjectCommand(this);
+    }
+
+    /// <summary>Field number for the "object" field.</summary>
+    public const int ObjectFieldNumber = 1;
+    private global::Riftcaller.Protos.GameObjectIdentifier object_;
+    /// <summary>
+    /// Object to center the camera on
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public global::Riftcaller.Protos.GameObjectIdentifier Object {
+      get { return object_; }
+      set {
+        object_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public override bool Equals(object other) {
+      return Equals(other as FocusGameObjectCommand);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public bool Equals(FocusGameObjectCommand other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (!object.Equals(Object, other.Object)) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (object_ != null) hash ^= Object.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (object_ != null) {
+        output.WriteRawTag(10);
+        output.WriteMessage(Object);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (object_ != null) {
+        output.WriteRawTag(10);
+        output.WriteMessage(Object);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public int CalculateSize() {
+      int size = 0;
+      if (object_ != null) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Object);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public void MergeFrom(FocusGameObjectCommand other) {
+      if (other == null) {
+        return;
+      }
+      if (other.object_ != null) {
+        if (object_ == null) {
+          Object = new global::Riftcaller.Protos.GameObjectIdentifier();
+        }
+        Object.MergeFrom(other.Object);
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+        switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 10: {
+            if (object_ == null) {
+              Object = new global::Riftcaller.Protos.GameObjectIdentifier();
+            }
+            input.ReadMessage(Object);
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+        switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 10: {
+            if (object_ == null) {
+              Object = new global::Riftcaller.Protos.GameObjectIdentifier();
+            }
+            input.ReadMessage(Object);
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
   public sealed partial class GameCommand : pb::IMessage<GameCommand>
   #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
       , pb::IBufferMessage
@@ -44292,7 +44714,7 @@ namespace Riftcaller.Protos {
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
     public static pbr::MessageDescriptor Descriptor {
-      get { return global::Riftcaller.Protos.RiftcallerReflection.Descriptor.MessageTypes[155]; }
+      get { return global::Riftcaller.Protos.RiftcallerReflection.Descriptor.MessageTypes[156]; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
@@ -44385,6 +44807,9 @@ namespace Riftcaller.Protos {
         case CommandOneofCase.ClearPersistentEffects:
           ClearPersistentEffects = other.ClearPersistentEffects.Clone();
           break;
+        case CommandOneofCase.FocusGameObject:
+          FocusGameObject = other.FocusGameObject.Clone();
+          break;
       }
 
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
@@ -44670,6 +45095,17 @@ namespace Riftcaller.Protos {
       }
     }
 
+    /// <summary>Field number for the "focus_game_object" field.</summary>
+    public const int FocusGameObjectFieldNumber = 26;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    public global::Riftcaller.Protos.FocusGameObjectCommand FocusGameObject {
+      get { return commandCase_ == CommandOneofCase.FocusGameObject ? (global::Riftcaller.Protos.FocusGameObjectCommand) command_ : null; }
+      set {
+        command_ = value;
+        commandCase_ = value == null ? CommandOneofCase.None : CommandOneofCase.FocusGameObject;
+      }
+    }
+
     private object command_;
     /// <summary>Enum of possible cases for the "command" oneof.</summary>
     public enum CommandOneofCase {
@@ -44699,6 +45135,7 @@ namespace Riftcaller.Protos {
       SetKeyboardShortcuts = 23,
       TurnFaceDownArenaAnimation = 24,
       ClearPersistentEffects = 25,
+      FocusGameObject = 26,
     }
     private CommandOneofCase commandCase_ = CommandOneofCase.None;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
@@ -44750,6 +45187,7 @@ namespace Riftcaller.Protos {
       if (!object.Equals(SetKeyboardShortcuts, other.SetKeyboardShortcuts)) return false;
       if (!object.Equals(TurnFaceDownArenaAnimation, other.TurnFaceDownArenaAnimation)) return false;
       if (!object.Equals(ClearPersistentEffects, other.ClearPersistentEffects)) return false;
+      if (!object.Equals(FocusGameObject, other.FocusGameObject)) return false;
       if (CommandCase != other.CommandCase) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
@@ -44782,6 +45220,7 @@ namespace Riftcaller.Protos {
       if (commandCase_ == CommandOneofCase.SetKeyboardShortcuts) hash ^= SetKeyboardShortcuts.GetHashCode();
       if (commandCase_ == CommandOneofCase.TurnFaceDownArenaAnimation) hash ^= TurnFaceDownArenaAnimation.GetHashCode();
       if (commandCase_ == CommandOneofCase.ClearPersistentEffects) hash ^= ClearPersistentEffects.GetHashCode();
+      if (commandCase_ == CommandOneofCase.FocusGameObject) hash ^= FocusGameObject.GetHashCode();
       hash ^= (int) commandCase_;
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
@@ -44899,6 +45338,10 @@ namespace Riftcaller.Protos {
         output.WriteRawTag(202, 1);
         output.WriteMessage(ClearPersistentEffects);
       }
+      if (commandCase_ == CommandOneofCase.FocusGameObject) {
+        output.WriteRawTag(210, 1);
+        output.WriteMessage(FocusGameObject);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
@@ -45008,6 +45451,10 @@ namespace Riftcaller.Protos {
         output.WriteRawTag(202, 1);
         output.WriteMessage(ClearPersistentEffects);
       }
+      if (commandCase_ == CommandOneofCase.FocusGameObject) {
+        output.WriteRawTag(210, 1);
+        output.WriteMessage(FocusGameObject);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(ref output);
       }
@@ -45092,6 +45539,9 @@ namespace Riftcaller.Protos {
       if (commandCase_ == CommandOneofCase.ClearPersistentEffects) {
         size += 2 + pb::CodedOutputStream.ComputeMessageSize(ClearPersistentEffects);
       }
+      if (commandCase_ == CommandOneofCase.FocusGameObject) {
+        size += 2 + pb::CodedOutputStream.ComputeMessageSize(FocusGameObject);
+      }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
       }
@@ -45254,6 +45704,12 @@ namespace Riftcaller.Protos {
           }
           ClearPersistentEffects.MergeFrom(other.ClearPersistentEffects);
           break;
+        case CommandOneofCase.FocusGameObject:
+          if (FocusGameObject == null) {
+            FocusGameObject = new global::Riftcaller.Protos.FocusGameObjectCommand();
+          }
+          FocusGameObject.MergeFrom(other.FocusGameObject);
+          break;
       }
 
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
@@ -45495,6 +45951,15 @@ namespace Riftcaller.Protos {
             ClearPersistentEffects = subBuilder;
             break;
           }
+          case 210: {
+            global::Riftcaller.Protos.FocusGameObjectCommand subBuilder = new global::Riftcaller.Protos.FocusGameObjectCommand();
+            if (commandCase_ == CommandOneofCase.FocusGameObject) {
+              subBuilder.MergeFrom(FocusGameObject);
+            }
+            input.ReadMessage(subBuilder);
+            FocusGameObject = subBuilder;
+            break;
+          }
         }
       }
     #endif
@@ -45734,6 +46199,15 @@ namespace Riftcaller.Protos {
             ClearPersistentEffects = subBuilder;
             break;
           }
+          case 210: {
+            global::Riftcaller.Protos.FocusGameObjectCommand subBuilder = new global::Riftcaller.Protos.FocusGameObjectCommand();
+            if (commandCase_ == CommandOneofCase.FocusGameObject) {
+              subBuilder.MergeFrom(FocusGameObject);
+            }
+            input.ReadMessage(subBuilder);
+            FocusGameObject = subBuilder;
+            break;
+          }
         }
       }
     }
@@ -45758,7 +46232,7 @@ namespace Riftcaller.Protos {
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
     public static pbr::MessageDescriptor Descriptor {
-      get { return global::Riftcaller.Protos.RiftcallerReflection.Descriptor.MessageTypes[156]; }
+      get { return global::Riftcaller.Protos.RiftcallerReflection.Descriptor.MessageTypes[157]; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
@@ -45966,7 +46440,7 @@ namespace Riftcaller.Protos {
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
     public static pbr::MessageDescriptor Descriptor {
-      get { return global::Riftcaller.Protos.RiftcallerReflection.Descriptor.MessageTypes[157]; }
+      get { return global::Riftcaller.Protos.RiftcallerReflection.Descriptor.MessageTypes[158]; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
@@ -305,6 +305,8 @@ namespace Riftcaller.Services
         _overTargetIndicator.RemoveFromHierarchy();
       }
 
+      _originalDragSource?.OnDragEnded();
+
       if (_originalDragSource is { OnDrop: { } } drag && _overTarget)
       {
         // Leave the currently-visible drag object in the hierarchy, the OnDrop action is responsible for removing it.
@@ -26,13 +26,14 @@ use game_data::delegate_data::{
     CardSelectorPromptSubmitted, CardSelectorSubmittedEvent, DrawCardActionEvent,
     GainManaActionEvent, ProgressCardActionEvent,
 };
-use game_data::game_actions::{CardTarget, GameAction, GameStateAction};
+use game_data::game_actions::{CardTarget, DisplayPreference, GameAction, GameStateAction};
 use game_data::game_effect::GameEffect;
 use game_data::game_state::{GamePhase, GameState, MulliganDecision, TurnState};
 use game_data::history_data::HistoryEvent;
 use game_data::prompt_data::{
-    ButtonPrompt, CardSelectorPrompt, CardSelectorPromptValidation, FromZone, GamePrompt,
-    PromptAction, PromptChoice, PromptContext, RoomSelectorPromptEffect, SelectorPromptTarget,
+    ButtonPrompt, CardSelectorPrompt, CardSelectorPromptValidation, ChooseMultiplePromptEffect,
+    DistributePromptEffect, FromZone, GamePrompt, PromptAction, PromptChoice, PromptContext,
+    RoomSelectorPromptEffect, SelectorPromptTarget,
 };
 use game_data::raid_data::RaidJumpRequest;
 use game_data::state_machine_data::PlayCardOptions;
@@ -89,6 +90,10 @@ pub fn handle_game_action(
         }
         GameAction::RaidAction(action) => raid_state::run(game, Some(*action)),
         GameAction::PromptAction(action) => handle_prompt_action(game, user_side, *action),
+        GameAction::SetDisplayPreference(DisplayPreference::SortHand(order)) => {
+            game.player_mut(user_side).hand_sort_order = *order;
+            Ok(())
+        }
         GameAction::SetDisplayPreference(..) => Ok(()),
     }?;
 
@@ -276,7 +281,7 @@ fn dispel_evocation_action(game: &mut GameState, user_side: Side) -> Result<()>
     )?;
 
     let prompt = dispel_evocation_prompt(game);
-    prompts::push_immediate(game, user_side, prompt);
+    prompts::push_immediate(game, user_side, prompt)?;
 
     Ok(())
 }
@@ -373,7 +378,8 @@ fn handle_mulligan_decision(
         MulliganDecision::Keep => {}
         MulliganDecision::Mulligan => {
             mutations::shuffle_into_deck(game, user_side, &hand)?;
-            draw_cards::run(game, user_side, 5, InitiatedBy::GameAction)?;
+            let count = queries::opening_hand_size(game, user_side);
+            draw_cards::run(game, user_side, count, InitiatedBy::GameAction)?;
         }
     }
 
@@ -399,7 +405,7 @@ fn handle_end_turn_action(game: &mut GameState, user_side: Side) -> Result<()> {
     if game.hand(user_side).count() > max_hand_size {
         // Must discard to hand size
         let prompt = discard_to_hand_size_prompt(game, side);
-        prompts::push_immediate(game, side, prompt);
+        prompts::push_immediate(game, side, prompt)?;
         Ok(())
     } else {
         check_start_next_turn(game)
@@ -501,6 +507,56 @@ fn handle_prompt_action(game: &mut GameState, user_side: Side, action: PromptAct
             prompts::pop(game, user_side);
             check_start_next_turn(game)?;
         }
+        (GamePrompt::Distribute(distribute), PromptAction::DistributeAllocate(card_id)) => {
+            verify!(distribute.targets.contains(&card_id), "Invalid distribution target");
+            verify!(distribute.remaining() > 0, "Total has already been allocated");
+            if let Some(GamePrompt::Distribute(distribute)) = prompts::current_mut(game, user_side)
+            {
+                distribute.allocated.push(card_id);
+            }
+        }
+        (GamePrompt::Distribute(_), PromptAction::DistributeReset) => {
+            if let Some(GamePrompt::Distribute(distribute)) = prompts::current_mut(game, user_side)
+            {
+                distribute.allocated.clear();
+            }
+        }
+        (GamePrompt::Distribute(distribute), PromptAction::DistributeSubmit) => {
+            verify!(distribute.remaining() == 0, "Must allocate the full total");
+            handle_distribute_submit(
+                game,
+                user_side,
+                distribute.effect,
+                distribute.targets.clone(),
+                distribute.allocated.clone(),
+            )?;
+        }
+        (GamePrompt::ChooseMultiple(choose), PromptAction::ChooseMultipleToggle(card_id)) => {
+            verify!(choose.targets.contains(&card_id), "Invalid selection target");
+            verify!(
+                choose.selected.contains(&card_id) || !choose.is_full(),
+                "Cannot select more than {} cards",
+                choose.count
+            );
+            if let Some(GamePrompt::ChooseMultiple(choose)) = prompts::current_mut(game, user_side)
+            {
+                if choose.selected.contains(&card_id) {
+                    choose.selected.retain(|id| *id != card_id);
+                } else {
+                    choose.selected.push(card_id);
+                }
+            }
+        }
+        (GamePrompt::ChooseMultiple(choose), PromptAction::ChooseMultipleSubmit) => {
+            verify!(choose.selected.len() <= choose.count, "Too many cards selected");
+            handle_choose_multiple_submit(
+                game,
+                user_side,
+                choose.initiated_by,
+                choose.effect,
+                choose.selected.clone(),
+            )?;
+        }
         _ => fail!("Mismatch between active prompt {prompt:?} and action {action:?}"),
     }
 
@@ -588,3 +644,46 @@ fn handle_room_selector_submit(
     prompts::pop(game, user_side);
     check_start_next_turn(game)
 }
+
+fn handle_distribute_submit(
+    game: &mut GameState,
+    user_side: Side,
+    effect: DistributePromptEffect,
+    targets: Vec<CardId>,
+    allocated: Vec<CardId>,
+) -> Result<()> {
+    for card_id in targets {
+        let amount = allocated.iter().filter(|id| **id == card_id).count() as u32;
+        if amount == 0 {
+            continue;
+        }
+
+        match effect {
+            DistributePromptEffect::AddPowerCharges => {
+                mutations::add_power_charges(game, card_id, amount)?;
+            }
+        }
+    }
+
+    prompts::pop(game, user_side);
+    check_start_next_turn(game)
+}
+
+fn handle_choose_multiple_submit(
+    game: &mut GameState,
+    user_side: Side,
+    initiated_by: AbilityId,
+    effect: ChooseMultiplePromptEffect,
+    selected: Vec<CardId>,
+) -> Result<()> {
+    prompts::pop(game, user_side);
+    if !selected.is_empty() {
+        match effect {
+            ChooseMultiplePromptEffect::DestroyCards => {
+                destroy::run(game, selected, InitiatedBy::Ability(initiated_by))?;
+            }
+        }
+    }
+
+    check_start_next_turn(game)
+}
@@ -37,7 +37,7 @@ pub fn evaluate<'a>(
     side: Side,
 ) -> Result<Box<dyn Iterator<Item = GameAction> + 'a>> {
     match &game.info.phase {
-        GamePhase::ResolveMulligans(data) => {
+        GamePhase::ResolveMulligans(data) if prompts::is_empty(game, side) => {
             return Ok(if data.decision(side).is_some() {
                 fail!("Error: Mulligan decision already submitted")
             } else {
@@ -51,7 +51,7 @@ pub fn evaluate<'a>(
                 )
             });
         }
-        GamePhase::Play => {}
+        GamePhase::ResolveMulligans(_) | GamePhase::Play => {}
         GamePhase::GameOver { .. } => fail!("Game has ended"),
     }
 
@@ -14,7 +14,7 @@
 
 use std::collections::HashMap;
 
-use core_data::game_primitives::Side;
+use core_data::game_primitives::{CardId, Side};
 use game_data::game_actions::DisplayPreference;
 use protos::riftcaller::game_command::Command;
 use protos::riftcaller::{
@@ -45,11 +45,21 @@ pub struct ResponseBuilder {
     /// move cards to the "display" browser when they're already in another
     /// similar card browser.
     pub last_snapshot_positions: HashMap<CardIdentifier, ObjectPosition>,
+
+    /// Sorting keys for cards in the user's hand, computed once per game view
+    /// when the user has selected a hand sort order.
+    pub hand_sorting_keys: HashMap<CardId, u32>,
 }
 
 impl ResponseBuilder {
     pub fn new(user_side: Side, state: ResponseState) -> Self {
-        Self { user_side, state, commands: vec![], last_snapshot_positions: HashMap::default() }
+        Self {
+            user_side,
+            state,
+            commands: vec![],
+            last_snapshot_positions: HashMap::default(),
+            hand_sorting_keys: HashMap::default(),
+        }
     }
 
     pub fn push(&mut self, command: Command) {
@@ -17,6 +17,7 @@
 use adventure_data::adventure::{AdventureScreen, AdventureState};
 use adventure_data::adventure_action::AdventureAction;
 use adventure_data::adventure_effect_data::DeckCardAction;
+use adventure_generator::{card_filter, SHOP_RESTOCK_VISITS};
 use anyhow::Result;
 use card_definition_data::cards;
 use core_data::adventure_primitives::{AdventureOutcome, Coins, TilePosition};
@@ -54,19 +55,80 @@ fn handle_abandon_adventure(state: &mut AdventureState) -> Result<()> {
 }
 
 fn handle_visit_tile(state: &mut AdventureState, position: TilePosition) -> Result<()> {
+    verify!(!state.world_map.tile(position)?.is_on_cooldown(), "Tile is on cooldown");
+    advance_shop_restock_counters(state, position);
+    advance_tile_cooldowns(state, position);
+    state.visiting_position = Some(position);
+
+    if let Some(shop) = &state.world_map.tile(position)?.shop {
+        state.screens.push(AdventureScreen::Shop(shop.clone()));
+        return Ok(());
+    }
+
     if let Some(effect) = &state.world_map.tile(position)?.on_visited {
         adventure_effect::apply(state, effect.clone(), None)?;
     }
 
+    let shop = match state.screens.current() {
+        Some(AdventureScreen::Shop(data)) => Some(data.clone()),
+        _ => None,
+    };
     let tile = state.world_map.tile_mut(position)?;
-    tile.on_visited = None;
-    tile.icons.clear();
+    if shop.is_some() {
+        // Shops remain on the map so they can be revisited
+        tile.shop = shop;
+    } else if let Some(cooldown) = tile.cooldown_visits {
+        tile.cooldown_remaining = cooldown;
+    } else {
+        tile.on_visited = None;
+        tile.icons.clear();
+    }
     Ok(())
 }
 
+/// Increments the restock counter for every shop other than the one at
+/// `visited`, restocking sold-out inventory for shops which have reached
+/// [SHOP_RESTOCK_VISITS].
+fn advance_shop_restock_counters(state: &mut AdventureState, visited: TilePosition) {
+    let positions = state
+        .world_map
+        .tiles
+        .iter()
+        .filter(|(position, tile)| **position != visited && tile.shop.is_some())
+        .map(|(position, _)| *position)
+        .collect::<Vec<_>>();
+    for position in positions {
+        let Some(mut shop) =
+            state.world_map.tiles.get_mut(&position).and_then(|tile| tile.shop.take())
+        else {
+            continue;
+        };
+        shop.restock_counter += 1;
+        if shop.restock_counter >= SHOP_RESTOCK_VISITS {
+            card_filter::restock_shop(state, &mut shop);
+        }
+        if let Some(tile) = state.world_map.tiles.get_mut(&position) {
+            tile.shop = Some(shop);
+        }
+    }
+}
+
+/// Decrements the remaining cooldown for every tile other than the one at
+/// `visited`.
+fn advance_tile_cooldowns(state: &mut AdventureState, visited: TilePosition) {
+    for (position, tile) in state.world_map.tiles.iter_mut() {
+        if *position != visited {
+            tile.cooldown_remaining = tile.cooldown_remaining.saturating_sub(1);
+        }
+    }
+}
+
 fn handle_end_visit(state: &mut AdventureState) -> Result<()> {
     verify!(is_blocking_screen(state) != Some(true), "Cannot end visit on this screen");
     state.screens.pop();
+    if state.screens.is_empty() {
+        state.visiting_position = None;
+    }
     Ok(())
 }
 
@@ -90,17 +152,13 @@ fn handle_draft_choice(state: &mut AdventureState, index: usize) -> Result<()> {
 
     let definition = cards::get(choice.card);
     if definition.card_type == CardType::Riftcaller {
+        state.deck.validate(definition.school)?;
         if !state.deck.schools.contains(&definition.school) {
             state.deck.schools.push(definition.school);
         }
         state.deck.identities.push(definition.variant());
     } else {
-        state
-            .deck
-            .cards
-            .entry(choice.card)
-            .and_modify(|i| *i += choice.quantity)
-            .or_insert(choice.quantity);
+        state.deck.add_card(choice.card, definition.school, choice.quantity)?;
     }
 
     state.screens.pop();
@@ -115,14 +173,19 @@ fn handle_buy_card(state: &mut AdventureState, index: usize) -> Result<()> {
     verify!(index < data.choices.len(), "Index out of bounds!");
     let choice = data.choices[index];
     verify!(!choice.sold, "Item already sold!");
+    let school = cards::get(choice.card).school;
+    state.deck.validate(school)?;
     data.choices[index].sold = true;
 
-    state
-        .deck
-        .cards
-        .entry(choice.card)
-        .and_modify(|i| *i += choice.quantity)
-        .or_insert(choice.quantity);
+    if let Some(shop) = state
+        .visiting_position
+        .and_then(|position| state.world_map.tiles.get_mut(&position))
+        .and_then(|tile| tile.shop.as_mut())
+    {
+        shop.choices[index].sold = true;
+    }
+
+    state.deck.add_card(choice.card, school, choice.quantity)?;
     spend_coins(state, choice.cost)?;
 
     Ok(())
@@ -16,11 +16,12 @@ use std::collections::HashMap;
 
 use adventure_data::adventure::{AdventureScreen, AdventureState};
 use adventure_data::adventure_effect_data::AdventureEffect;
-use adventure_data::narrative_event_data::{NarrativeEventState, NarrativeEventStep};
+use adventure_data::narrative_event_data::NarrativeEventState;
 use adventure_generator::{battle_generator, card_filter};
 use anyhow::Result;
+use core_data::adventure_primitives::Coins;
 use game_data::card_name::CardVariant;
-use with_error::WithError;
+use with_error::{verify, WithError};
 
 pub fn apply(
     state: &mut AdventureState,
@@ -39,13 +40,40 @@ pub fn apply(
         AdventureEffect::NarrativeEvent(id) => {
             state.screens.push(AdventureScreen::NarrativeEvent(NarrativeEventState {
                 id,
-                step: NarrativeEventStep::Introduction,
+                step: game_tables::narrative_event(id).initial_step(),
+                choices: HashMap::new(),
+            }));
+        }
+        AdventureEffect::RandomNarrativeEvent => {
+            let id = state
+                .event_deck
+                .draw(&mut state.config, game_tables::narrative_event_ids())
+                .with_error(|| "No narrative events available")?;
+            state.screens.push(AdventureScreen::NarrativeEvent(NarrativeEventState {
+                id,
+                step: game_tables::narrative_event(id).initial_step(),
                 choices: HashMap::new(),
             }));
         }
         AdventureEffect::Battle => state
             .screens
             .push(AdventureScreen::Battle(battle_generator::create(state.side.opponent()))),
+        AdventureEffect::BossBattle => state
+            .screens
+            .push(AdventureScreen::Battle(battle_generator::create_boss(state.side.opponent()))),
+        AdventureEffect::MiniBoss(reward) => state.screens.push(AdventureScreen::Battle(
+            battle_generator::create_mini_boss(state.side.opponent(), reward),
+        )),
+        AdventureEffect::GainCoins(amount) => {
+            state.coins += amount;
+        }
+        AdventureEffect::LoseCoins(amount) => {
+            verify!(state.coins >= amount, "Insufficient coins for {effect:?}");
+            state.coins -= amount;
+        }
+        AdventureEffect::Hazard(penalty) => {
+            state.coins = Coins(state.coins.0.saturating_sub(penalty.0));
+        }
         AdventureEffect::PickCardForEffect(filter, effect) => {
             state.screens.push(AdventureScreen::ApplyDeckEffect(filter, effect))
         }
@@ -13,11 +13,12 @@
 // limitations under the License.
 
 use adventure_data::adventure::{AdventureScreen, AdventureState};
-use adventure_data::adventure_effect_data::DeckCardAction;
+use adventure_data::adventure_effect_data::{AdventureEffect, DeckCardAction};
 use adventure_data::narrative_event_data::{
     NarrativeChoiceState, NarrativeEventChoice, NarrativeEventStep,
 };
 use adventure_generator::card_filter;
+use core_data::adventure_primitives::Coins;
 use game_data::card_name::CardVariant;
 
 pub fn can_apply_deck_card_effect(option: Option<&AdventureState>, card: CardVariant) -> bool {
@@ -38,6 +39,10 @@ pub fn can_apply_deck_card_effect(option: Option<&AdventureState>, card: CardVar
         return false;
     }
 
+    if effect.cost.is_some_and(|cost| adventure.coins < cost) {
+        return false;
+    }
+
     match effect.action {
         DeckCardAction::DuplicateTo3Copies => {
             let Some(&count) = adventure.deck.cards.get(&card) else {
@@ -46,6 +51,7 @@ pub fn can_apply_deck_card_effect(option: Option<&AdventureState>, card: CardVar
 
             count < 3
         }
+        DeckCardAction::UpgradeAllCopies => !card.metadata.is_upgraded,
         _ => true,
     }
 }
@@ -57,7 +63,7 @@ pub fn can_end_narrative_event(state: &AdventureState) -> bool {
     let data = game_tables::narrative_event(narrative_state.id);
 
     match narrative_state.step {
-        NarrativeEventStep::Introduction => true,
+        NarrativeEventStep::Splash | NarrativeEventStep::Introduction => true,
         NarrativeEventStep::ViewChoices => false,
         NarrativeEventStep::SelectChoice(choice_id) => {
             all_effects_applied(data.choice(choice_id), narrative_state.choice(choice_id))
@@ -73,3 +79,17 @@ pub fn all_effects_applied(data: &NarrativeEventChoice, state: &NarrativeChoiceS
         .chain(data.enumerate_rewards())
         .all(|(i, e)| state.effect(i).applied || e.effect.is_immediate())
 }
+
+/// Returns true if a player with `coins` can pay all of the costs of this
+/// [NarrativeEventChoice].
+pub fn can_pay_narrative_costs(choice: &NarrativeEventChoice, coins: Coins) -> bool {
+    let required = choice
+        .costs
+        .iter()
+        .map(|cost| match cost.effect {
+            AdventureEffect::LoseCoins(amount) => amount,
+            _ => Coins(0),
+        })
+        .sum::<Coins>();
+    coins >= required
+}
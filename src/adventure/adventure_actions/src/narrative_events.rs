@@ -20,7 +20,7 @@ use adventure_data::narrative_event_data::{
 };
 use adventure_generator::card_filter;
 use anyhow::Result;
-use core_data::adventure_primitives::NarrativeChoiceId;
+use core_data::adventure_primitives::{Coins, NarrativeChoiceId};
 use game_data::deck::Deck;
 use with_error::{fail, verify};
 
@@ -29,19 +29,24 @@ use crate::{adventure_effect, adventure_flags};
 /// Handles a request from a user to advance to a given step within a narrative
 /// event screen.
 pub fn set_narrative_step(state: &mut AdventureState, step: NarrativeEventStep) -> Result<()> {
+    let coins = state.coins;
     let Some(AdventureScreen::NarrativeEvent(narrative)) = state.screens.current_mut() else {
         fail!("Expected active narrative event screen");
     };
     let data = game_tables::narrative_event(narrative.id);
 
     match step {
+        NarrativeEventStep::Splash => {
+            verify!(data.splash.is_some(), "Event has no splash screen");
+            narrative.step = NarrativeEventStep::Splash
+        }
         NarrativeEventStep::Introduction => narrative.step = NarrativeEventStep::Introduction,
         NarrativeEventStep::ViewChoices => {
             reify_known_choices(&mut state.config, data, narrative, &state.deck);
             narrative.step = NarrativeEventStep::ViewChoices
         }
         NarrativeEventStep::SelectChoice(index) => {
-            verify!(is_legal_choice(data, index), "Invalid choice!");
+            verify!(is_legal_choice(data, index, coins), "Invalid choice!");
             narrative.choice_mut(index).selected = true;
             narrative.step = step;
 
@@ -89,10 +94,12 @@ pub fn end_narrative_event(state: &mut AdventureState) -> Result<()> {
     Ok(())
 }
 
-/// Returns true if the player is allowed to pick the `index` option within the
-/// provided [NarrativeEventData].
-pub fn is_legal_choice(data: &NarrativeEventData, id: NarrativeChoiceId) -> bool {
-    data.choices.contains_key(&id)
+/// Returns true if a player with `coins` is allowed to pick the `id` option
+/// within the provided [NarrativeEventData].
+pub fn is_legal_choice(data: &NarrativeEventData, id: NarrativeChoiceId, coins: Coins) -> bool {
+    data.choices
+        .get(&id)
+        .is_some_and(|choice| adventure_flags::can_pay_narrative_costs(choice, coins))
 }
 
 /// "Known random" choices are ones that are random each time this narrative
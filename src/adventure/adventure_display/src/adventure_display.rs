@@ -17,19 +17,22 @@
 use adventure_data::adventure::{AdventureState, TileIcon, TileState};
 use adventure_data::adventure_action::AdventureAction;
 use anyhow::Result;
-use core_data::adventure_primitives::TilePosition;
+use core_data::adventure_primitives::{Coins, TilePosition};
 use core_ui::actions::InterfaceAction;
 use core_ui::design;
-use core_ui::panels::Panels;
+use core_ui::interface_animations::{self, AnimateStyle, HasAnimation, InterfaceAnimation};
+use core_ui::panels::{self, Panels};
 use panel_address::{PanelAddress, PlayerPanel};
+use protos::riftcaller::animate_element_style::Property;
 use protos::riftcaller::game_command::Command;
 use protos::riftcaller::{
-    FlexVector3, InterfacePanel, MapTileType, SpriteAddress, UpdateWorldMapCommand, WorldMapSprite,
-    WorldMapTile,
+    FlexVector2, FlexVector3, InterfacePanel, MapTileType, SpriteAddress, UpdateWorldMapCommand,
+    WorldMapSprite, WorldMapTile,
 };
 
 pub mod adventure_over_panel;
 pub mod adventure_panels;
+pub mod adventure_slots_panel;
 pub mod battle_panel;
 pub mod draft_panel;
 pub mod narrative_event_panel;
@@ -38,6 +41,7 @@ pub mod shop_panel;
 /// Returns a sequence of game Commands to display the provided
 /// [AdventureState].
 pub fn render(state: &AdventureState) -> Result<Vec<Command>> {
+    let (min_bounds, max_bounds) = state.bounds();
     let mut commands = vec![Command::UpdateWorldMap(UpdateWorldMapCommand {
         tiles: state
             .world_map
@@ -45,17 +49,79 @@ pub fn render(state: &AdventureState) -> Result<Vec<Command>> {
             .iter()
             .map(|(position, state)| render_tile(*position, state))
             .collect(),
+        min_bounds: Some(adapters::map_position(min_bounds)),
+        max_bounds: Some(adapters::map_position(max_bounds)),
     })];
 
     if let Some(_) = &state.outcome {
         commands.push(Panels::open(PlayerPanel::AdventureOver).into());
     } else if !state.screens.is_empty() {
-        commands.push(Panels::open(PlayerPanel::AdventureScreen(state.screens.count() - 1)).into());
+        let index = state.screens.count() - 1;
+        commands.push(panels::ensure_open(
+            PlayerPanel::AdventureScreen(index),
+            element_names::adventure_screen(index),
+        ));
     }
 
     Ok(commands)
 }
 
+/// Returns a [Command] to animate the player's coin count when it changes
+/// from `previous` to `current`, or None if the value is unchanged.
+///
+/// The screen overlay is re-rendered with the new balance on every request.
+/// This pulses the counter so that the change is visible to the player.
+pub fn coin_count_update(previous: Coins, current: Coins) -> Option<Command> {
+    if previous == current {
+        return None;
+    }
+
+    let scale = if current > previous { 1.25 } else { 0.8 };
+    Some(
+        InterfaceAnimation::new()
+            .start(
+                element_names::COIN_COUNT,
+                AnimateStyle::new(Property::Scale(FlexVector2 { x: scale, y: scale }))
+                    .duration(interface_animations::default_duration()),
+            )
+            .insert(
+                interface_animations::default_duration(),
+                element_names::COIN_COUNT,
+                AnimateStyle::new(Property::Scale(FlexVector2 { x: 1.0, y: 1.0 }))
+                    .duration(interface_animations::default_duration()),
+            )
+            .into(),
+    )
+}
+
+/// Compact summary of a world map tile, used to display an overview of the
+/// map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinimapTile {
+    pub position: TilePosition,
+    /// True if this tile has a road the player can travel along.
+    pub walkable: bool,
+    /// True if this tile contains an entity the player can visit.
+    pub has_entity: bool,
+}
+
+/// Returns a [MinimapTile] for each tile in the world map of the provided
+/// adventure, ordered by row and then by column.
+pub fn minimap(state: &AdventureState) -> Vec<MinimapTile> {
+    let mut result = state
+        .world_map
+        .tiles
+        .iter()
+        .map(|(position, tile)| MinimapTile {
+            position: *position,
+            walkable: tile.road.is_some(),
+            has_entity: tile.on_visited.is_some(),
+        })
+        .collect::<Vec<_>>();
+    result.sort_by_key(|tile| (tile.position.y, tile.position.x));
+    result
+}
+
 pub struct RenderedChoiceScreen {
     pub panel: Option<InterfacePanel>,
     pub address: PanelAddress,
@@ -94,7 +160,7 @@ fn render_tile(position: TilePosition, tile: &TileState) -> WorldMapTile {
 
             sprites.push(WorldMapSprite {
                 sprite_address: Some(sprite),
-                color: Some(design::WHITE),
+                color: Some(if tile.is_on_cooldown() { design::GRAY_500 } else { design::WHITE }),
                 anchor_offset: Some(FlexVector3 { x: 0.0, y: 1.28, z: 0.0 }),
                 scale: Some(FlexVector3 { x: 0.6, y: 0.6, z: 1.0 }),
             });
@@ -104,9 +170,7 @@ fn render_tile(position: TilePosition, tile: &TileState) -> WorldMapTile {
     WorldMapTile {
         sprites,
         position: Some(adapters::map_position(position)),
-        on_visit: tile
-            .on_visited
-            .is_some()
+        on_visit: (tile.on_visited.is_some() && !tile.is_on_cooldown())
             .then(|| AdventureAction::VisitTileEntity(position).build()),
         tile_type: if tile.on_visited.is_some() {
             MapTileType::Visitable.into()
@@ -132,6 +196,12 @@ fn sprite_address_for_icon(icon: TileIcon) -> Option<SpriteAddress> {
         TileIcon::NarrativeEvent => {
             Some("RainbowArt/CleanFlatIcon/png_128/icon/icon_game/icon_game_194.png".to_string())
         }
+        TileIcon::Hazard => {
+            Some("RainbowArt/CleanFlatIcon/png_128/icon/icon_game/icon_game_26.png".to_string())
+        }
+        TileIcon::Coins => {
+            Some("RainbowArt/CleanFlatIcon/png_128/icon/icon_store/icon_store_42.png".to_string())
+        }
     };
 
     address.map(|a| SpriteAddress { address: a })
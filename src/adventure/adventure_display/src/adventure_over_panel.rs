@@ -12,18 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use user_action_data::UserAction;
-
+use core_data::adventure_primitives::AdventureOutcome;
 use core_ui::prelude::*;
 use panel_address::{Panel, PanelAddress, PlayerPanel};
 use panels::button_menu::ButtonMenu;
+use player_data::PlayerState;
+use user_action_data::UserAction;
 
-#[derive(Default)]
-pub struct AdventureOverPanel {}
+pub struct AdventureOverPanel {
+    outcome: Option<AdventureOutcome>,
+}
 
 impl AdventureOverPanel {
-    pub fn new() -> Self {
-        Self::default()
+    pub fn new(player: &PlayerState) -> Self {
+        Self { outcome: player.adventure.as_ref().and_then(|adventure| adventure.outcome.clone()) }
     }
 }
 
@@ -36,7 +38,10 @@ impl Panel for AdventureOverPanel {
 impl Component for AdventureOverPanel {
     fn build(self) -> Option<Node> {
         ButtonMenu::new(self.address())
-            .title("Defeated")
+            .title(match self.outcome {
+                Some(AdventureOutcome::Victory) => "Victory",
+                _ => "Defeated",
+            })
             .button("Main Menu", self.close().action(UserAction::LeaveAdventure))
             .show_close_button(false)
             .build()
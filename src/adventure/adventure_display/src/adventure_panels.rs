@@ -25,12 +25,19 @@ use crate::narrative_event_panel::NarrativeEventPanel;
 use crate::shop_panel::ShopPanel;
 
 /// Builds an [InterfacePanel] for the current adventure screen.
+///
+/// The root node of this panel is named via [element_names::adventure_screen]
+/// so that the client can check whether the screen is already open.
 pub fn tile_entity_panel(player: &PlayerState, index: usize) -> Result<Option<InterfacePanel>> {
     let state = player.adventure()?;
     let Some(screen) = state.screens.get(index) else {
         return Ok(None);
     };
-    build_panel(player, screen, index)
+    let mut panel = build_panel(player, screen, index)?;
+    if let Some(node) = panel.as_mut().and_then(|p| p.node.as_mut()) {
+        node.name = element_names::adventure_screen(index).into();
+    }
+    Ok(panel)
 }
 
 fn build_panel(
@@ -0,0 +1,113 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Panel listing a player's saved adventures
+
+use core_data::adventure_primitives::Coins;
+use core_data::game_primitives::Side;
+use core_ui::button::{Button, ButtonType};
+use core_ui::design::FontSize;
+use core_ui::panel_window::PanelWindow;
+use core_ui::prelude::*;
+use core_ui::text::Text;
+use panel_address::{Panel, PanelAddress, PlayerPanel};
+use player_data::PlayerState;
+use protos::riftcaller::{FlexAlign, FlexJustify};
+use user_action_data::UserAction;
+
+struct SlotSummary {
+    id: u64,
+    side: Side,
+    coins: Coins,
+    selected: bool,
+}
+
+pub struct AdventureSlotsPanel {
+    slots: Vec<SlotSummary>,
+}
+
+impl AdventureSlotsPanel {
+    pub fn new(player: &PlayerState) -> Self {
+        Self {
+            slots: player
+                .all_adventures()
+                .into_iter()
+                .map(|(id, adventure)| SlotSummary {
+                    id,
+                    side: adventure.side,
+                    coins: adventure.coins,
+                    selected: player.adventure.is_some() && player.current_adventure_slot == id,
+                })
+                .collect(),
+        }
+    }
+
+    fn slot_row(slot: &SlotSummary) -> impl Component {
+        Row::new(format!("AdventureSlot{}", slot.id))
+            .style(
+                Style::new()
+                    .align_items(FlexAlign::Center)
+                    .justify_content(FlexJustify::SpaceBetween)
+                    .margin(Edge::All, 16.px()),
+            )
+            .child(Text::new(format!("{:?}", slot.side)).font_size(FontSize::Headline))
+            .child(Text::new(format!("{} coins", slot.coins.0)).font_size(FontSize::Body))
+            .child(
+                Button::new(if slot.selected { "Current" } else { "Resume" })
+                    .action(UserAction::SelectAdventureSlot(slot.id))
+                    .button_type(ButtonType::Secondary),
+            )
+            .child(
+                Button::new("Delete")
+                    .action(UserAction::DeleteAdventureSlot(slot.id))
+                    .button_type(ButtonType::Secondary),
+            )
+    }
+}
+
+impl Panel for AdventureSlotsPanel {
+    fn address(&self) -> PanelAddress {
+        PlayerPanel::AdventureSlots.into()
+    }
+}
+
+impl Component for AdventureSlotsPanel {
+    fn build(self) -> Option<Node> {
+        PanelWindow::new(self.address(), 512.px(), 600.px())
+            .title("Adventures")
+            .content(
+                Column::new("AdventureSlots")
+                    .style(
+                        Style::new()
+                            .width(100.pct())
+                            .align_items(FlexAlign::Stretch)
+                            .justify_content(FlexJustify::FlexStart),
+                    )
+                    .children(self.slots.iter().map(Self::slot_row))
+                    .child(
+                        Row::new("NewAdventureSlot")
+                            .style(Style::new().justify_content(FlexJustify::Center))
+                            .child(
+                                Button::new("New Covenant")
+                                    .action(UserAction::NewAdventureSlot(Side::Covenant)),
+                            )
+                            .child(
+                                Button::new("New Riftcaller")
+                                    .action(UserAction::NewAdventureSlot(Side::Riftcaller)),
+                            ),
+                    ),
+            )
+            .build()
+    }
+}
@@ -76,6 +76,10 @@ impl<'a> Component for BattlePanel<'a> {
                         ))
                         .font_size(FontSize::Body),
                     )
+                    .child(self.data.guaranteed_reward.map(|card| {
+                        Text::new(format!("Guaranteed Card: {}", card.displayed_name()))
+                            .font_size(FontSize::Body)
+                    }))
                     .child(
                         Row::new("Schools")
                             .style(Style::new().margin(Edge::All, 16.px()))
@@ -22,7 +22,7 @@ use adventure_data::narrative_event_data::{
 use core_data::adventure_primitives::NarrativeChoiceId;
 use core_ui::action_builder::ActionBuilder;
 use core_ui::actions::InterfaceAction;
-use core_ui::design::{BackgroundColor, FontSize};
+use core_ui::design::{BackgroundColor, FontColor, FontSize};
 use core_ui::full_screen_image::FullScreenImage;
 use core_ui::interface_animations;
 use core_ui::prelude::*;
@@ -39,6 +39,7 @@ use protos::riftcaller::{
 
 const CONTAINER_WIDTH: i32 = 400;
 const CONTAINER_HEIGHT: i32 = 750;
+const SPLASH_WIDTH: i32 = 800;
 
 pub struct NarrativeEventPanel<'a> {
     pub player: &'a PlayerState,
@@ -48,6 +49,27 @@ pub struct NarrativeEventPanel<'a> {
 }
 
 impl<'a> NarrativeEventPanel<'a> {
+    fn splash(&self, text: String) -> Column {
+        Column::new("NarrativeSplash")
+            .style(
+                Style::new()
+                    .align_self(FlexAlign::Center)
+                    .max_width(SPLASH_WIDTH.px())
+                    .align_items(FlexAlign::Stretch),
+            )
+            .child(
+                Text::new(text)
+                    .layout(Layout::new().margin(Edge::Bottom, 32.px()))
+                    .font_size(FontSize::NarrativeText)
+                    .text_align(TextAlign::MiddleCenter)
+                    .white_space(WhiteSpace::Normal),
+            )
+            .child(Self::button_row(
+                "Continue",
+                AdventureAction::SetNarrativeStep(NarrativeEventStep::Introduction),
+            ))
+    }
+
     fn introduction(&self) -> Column {
         self.container()
             .child(Self::description(self.data.description.clone()))
@@ -121,11 +143,19 @@ impl<'a> NarrativeEventPanel<'a> {
             ),
             Command::InfoZoom(InfoZoomCommand { show: false, card: None }),
         ];
-        Self::button_row(
-            choice.choice_description.clone(),
-            AdventureAction::SetNarrativeStep(NarrativeEventStep::SelectChoice(choice_id)),
-        )
-        .on_mouse_enter(vec![
+        let can_pay = self.player.adventure.as_ref().is_some_and(|adventure| {
+            adventure_flags::can_pay_narrative_costs(choice, adventure.coins)
+        });
+        let row = if can_pay {
+            Self::button_row(
+                choice.choice_description.clone(),
+                AdventureAction::SetNarrativeStep(NarrativeEventStep::SelectChoice(choice_id)),
+            )
+        } else {
+            Self::disabled_row(choice.choice_description.clone())
+        };
+
+        row.on_mouse_enter(vec![
             Some(interface_animations::set_displayed(
                 element_names::narrative_outcome_tooltip(choice_id),
                 true,
@@ -269,6 +299,25 @@ impl<'a> NarrativeEventPanel<'a> {
             )
     }
 
+    fn disabled_row(text: impl Into<String>) -> Row {
+        Row::new("DisabledRow")
+            .style(
+                Style::new()
+                    .margin(Edge::Vertical, 4.px())
+                    .padding(Edge::Horizontal, 8.px())
+                    .background_color(BackgroundColor::NarrativeEventChoice)
+                    .min_height(88.px())
+                    .border_radius(Corner::All, 8.px()),
+            )
+            .child(
+                Text::new(text)
+                    .font_size(FontSize::NarrativeText)
+                    .color(FontColor::ButtonLabelDisabled)
+                    .text_align(TextAlign::MiddleLeft)
+                    .white_space(WhiteSpace::Normal),
+            )
+    }
+
     fn container(&self) -> Column {
         Column::new("NarrativeEventPanel").style(
             Style::new()
@@ -296,11 +345,20 @@ const BACKGROUND: &'static str = "Art/Tithi Luadthong/shutterstock_2018848967";
 
 impl<'a> Component for NarrativeEventPanel<'a> {
     fn build(self) -> Option<Node> {
+        if let (NarrativeEventStep::Splash, Some(text)) = (self.state.step, &self.data.splash) {
+            return FullScreenImage::new()
+                .image(adapters::sprite(&self.data.image))
+                .content(self.splash(text.clone()))
+                .build();
+        }
+
         FullScreenImage::new()
             .image(style::sprite_jpg(BACKGROUND))
             .disable_overlay(true)
             .content(match self.state.step {
-                NarrativeEventStep::Introduction => self.introduction(),
+                NarrativeEventStep::Splash | NarrativeEventStep::Introduction => {
+                    self.introduction()
+                }
                 NarrativeEventStep::ViewChoices => self.view_choices(),
                 NarrativeEventStep::SelectChoice(index) => self.view_outcome(index),
             })
@@ -15,6 +15,7 @@
 use adventure_data::adventure::BattleData;
 use core_data::adventure_primitives::Coins;
 use core_data::game_primitives::Side;
+use game_data::card_name::{CardName, CardVariant};
 use game_data::player_name::AIPlayer;
 
 pub fn create(side: Side) -> BattleData {
@@ -23,5 +24,29 @@ pub fn create(side: Side) -> BattleData {
         opponent_deck: decklists::canonical_deck(side),
         opponent_name: "Cloaked Bandit".to_string(),
         reward: Coins(250),
+        is_final_battle: false,
+        guaranteed_reward: None,
+    }
+}
+
+/// Creates the final battle of an adventure. Winning this battle wins the
+/// adventure.
+pub fn create_boss(side: Side) -> BattleData {
+    BattleData {
+        opponent_name: "Dread Sovereign".to_string(),
+        reward: Coins(500),
+        is_final_battle: true,
+        ..create(side)
+    }
+}
+
+/// Creates a miniboss battle. Winning this battle always adds a copy of
+/// `reward` to the player's deck.
+pub fn create_mini_boss(side: Side, reward: CardName) -> BattleData {
+    BattleData {
+        opponent_name: "Veiled Warden".to_string(),
+        reward: Coins(350),
+        guaranteed_reward: Some(CardVariant::standard(reward)),
+        ..create(side)
     }
 }
@@ -64,13 +64,40 @@ pub fn shop_choices(state: &mut AdventureState, filter: CardFilterId) -> ShopDat
             .config
             .choose_multiple(5, cards.into_iter())
             .into_iter()
-            .map(|name| CardChoice {
-                quantity: state.config.gen_range(1..=3),
-                card: name,
-                cost: Coins(state.config.gen_range(1..=4) * 25),
-                sold: false,
-            })
+            .map(|name| shop_choice(state, name))
             .collect(),
+        filter: Some(filter),
+        restock_counter: 0,
+    }
+}
+
+/// Replaces each sold-out choice in the provided [ShopData] with a new card
+/// selected by the shop's [CardFilterId]. Cards already offered by the shop
+/// are not selected again.
+pub fn restock_shop(state: &mut AdventureState, shop: &mut ShopData) {
+    let Some(filter) = shop.filter else {
+        return;
+    };
+
+    let sold = shop.choices.iter().filter(|choice| choice.sold).count();
+    let cards: Vec<_> = all_cards(state, filter)
+        .filter(|variant| shop.choices.iter().all(|choice| choice.card != *variant))
+        .collect();
+    let mut replacements = state.config.choose_multiple(sold, cards.into_iter()).into_iter();
+    for choice in shop.choices.iter_mut().filter(|choice| choice.sold) {
+        if let Some(variant) = replacements.next() {
+            *choice = shop_choice(state, variant);
+        }
+    }
+    shop.restock_counter = 0;
+}
+
+fn shop_choice(state: &mut AdventureState, card: CardVariant) -> CardChoice {
+    CardChoice {
+        quantity: state.config.gen_range(1..=3),
+        card,
+        cost: Coins(state.config.gen_range(1..=4) * 25),
+        sold: false,
     }
 }
 
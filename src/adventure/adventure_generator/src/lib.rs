@@ -19,3 +19,7 @@ pub mod card_filter;
 pub mod mock_adventure;
 
 pub const STARTING_COINS: Coins = Coins(500);
+
+/// Number of visits to other tiles after which a shop replaces its sold-out
+/// inventory.
+pub const SHOP_RESTOCK_VISITS: u32 = 3;
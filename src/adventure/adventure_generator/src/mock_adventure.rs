@@ -17,10 +17,11 @@
 use std::collections::{HashMap, HashSet};
 
 use adventure_data::adventure::{
-    AdventureConfiguration, AdventureScreens, AdventureState, TileIcon, TileState, WorldMap,
+    AdventureConfiguration, AdventureScreens, AdventureState, EventDeck, TileIcon, TileState,
+    WorldMap,
 };
 use adventure_data::adventure_effect_data::AdventureEffect;
-use core_data::adventure_primitives::{CardFilterId, NarrativeEventId, TilePosition};
+use core_data::adventure_primitives::{CardFilterId, Coins, NarrativeEventId, TilePosition};
 use core_data::game_primitives::{AdventureId, Side};
 use game_data::card_name::{CardName, CardVariant};
 use game_data::deck::Deck;
@@ -103,9 +104,23 @@ pub fn create(config: AdventureConfiguration) -> AdventureState {
         AdventureEffect::Shop(CardFilterId::new(2)),
         TileIcon::Shop,
     );
-    add_tile(&mut tiles, -1, -2, "hexSwamp00");
+    add_with_entity(
+        &mut tiles,
+        -1,
+        -2,
+        "hexSwamp00",
+        AdventureEffect::Hazard(Coins(25)),
+        TileIcon::Hazard,
+    );
     add_tile(&mut tiles, 0, -2, "hexSwamp03");
-    add_tile(&mut tiles, 1, -2, "hexForestBroadleaf00");
+    add_with_entity(
+        &mut tiles,
+        1,
+        -2,
+        "hexForestBroadleaf00",
+        AdventureEffect::GainCoins(Coins(50)),
+        TileIcon::Coins,
+    );
     add_tile(&mut tiles, 2, -2, "hexHills02");
     add_with_road(&mut tiles, 3, -2, "hexPlains00", road(TOP_LEFT | BOTTOM_RIGHT, 1));
     add_tile(&mut tiles, 4, -2, "hexJungle00");
@@ -121,6 +136,8 @@ pub fn create(config: AdventureConfiguration) -> AdventureState {
         coins: crate::STARTING_COINS,
         world_map: WorldMap { tiles },
         screens: AdventureScreens::default(),
+        visiting_position: None,
+        event_deck: EventDeck::default(),
         config,
         deck,
     }
@@ -24,10 +24,8 @@ use game_data::player_name::AIPlayer;
 
 use with_error::fail;
 
-use crate::evaluators::{
-    CardsInHandEvaluator, CardsInPlayEvaluator, ManaDifferenceEvaluator, ProgressCountersEvaluator,
-    ScoreEvaluator,
-};
+use crate::evaluators;
+use crate::evaluators::ScoreEvaluator;
 use crate::state_node::RiftcallerState;
 
 pub fn get(name: AIPlayer) -> Box<dyn Agent<RiftcallerState>> {
@@ -58,15 +56,7 @@ pub fn get(name: AIPlayer) -> Box<dyn Agent<RiftcallerState>> {
         AIPlayer::TestAlphaBetaHeuristics => Box::new(AgentData::omniscient(
             "ALPHA_BETA_HEURISTICS",
             AlphaBetaAlgorithm { search_depth: 4 },
-            CompoundEvaluator {
-                evaluators: vec![
-                    (100_000, Box::new(ScoreEvaluator {})),
-                    (10, Box::new(ManaDifferenceEvaluator {})),
-                    (5, Box::new(CardsInHandEvaluator {})),
-                    (15, Box::new(CardsInPlayEvaluator {})),
-                    (20, Box::new(ProgressCountersEvaluator {})),
-                ],
-            },
+            evaluators::heuristic_evaluator(),
         )),
         AIPlayer::TestUct1 => Box::new(AgentData::omniscient(
             "UCT1",
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use ai_core::compound_evaluator::CompoundEvaluator;
 use ai_core::state_evaluator::StateEvaluator;
 use anyhow::Result;
 use core_data::game_primitives::Side;
@@ -22,6 +23,20 @@ use rules::mana::ManaPurpose;
 
 use crate::state_node::RiftcallerState;
 
+/// Heuristic evaluator for depth-limited search, weighing score difference
+/// most heavily followed by mana, cards in hand, and board presence.
+pub fn heuristic_evaluator() -> CompoundEvaluator<RiftcallerState> {
+    CompoundEvaluator {
+        evaluators: vec![
+            (100_000, Box::new(ScoreEvaluator {})),
+            (10, Box::new(ManaDifferenceEvaluator {})),
+            (5, Box::new(CardsInHandEvaluator {})),
+            (15, Box::new(CardsInPlayEvaluator {})),
+            (20, Box::new(ProgressCountersEvaluator {})),
+        ],
+    }
+}
+
 pub struct ScoreEvaluator {}
 
 impl StateEvaluator<RiftcallerState> for ScoreEvaluator {
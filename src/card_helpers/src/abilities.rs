@@ -14,20 +14,26 @@
 
 //! Helpers for defining common card abilities
 
-use card_definition_data::ability_data::{Ability, AbilityType, Delegate};
-use core_data::game_primitives::{AbilityId, InitiatedBy, ManaValue, INNER_ROOMS};
+use card_definition_data::ability_data::{Ability, AbilityType, ActivatedAbility, Delegate};
+use card_definition_data::card_definition::Keyword;
+use card_definition_data::cards::CardDefinitionExt;
+use core_data::game_primitives::{
+    AbilityId, CardSubtype, HealthValue, InitiatedBy, ManaValue, PointsValue, Side, INNER_ROOMS,
+};
 use game_data::card_configuration::{Cost, TargetRequirement};
 use game_data::card_name::CardMetadata;
 use game_data::card_state::{CardCounter, CardPosition};
 use game_data::custom_card_state::CustomCardState;
-use game_data::delegate_data::{EventDelegate, GameDelegate, QueryDelegate};
+use game_data::delegate_data::{EventDelegate, GameDelegate, QueryDelegate, RaidOutcome};
 use game_data::flag_data::Flag;
-use game_data::game_actions::CardTarget;
+use game_data::game_actions::{ButtonPromptContext, CardTarget};
 use game_data::history_data::{AbilityActivationType, HistoryEvent};
-use game_data::prompt_data::PromptData;
+use game_data::prompt_data::{PromptChoiceLabel, PromptData};
+use game_data::random;
 use game_data::text::TextToken::*;
 use rules::mutations::OnZeroStored;
-use rules::{curses, mana, mutations, prompts};
+use rules::raids::access;
+use rules::{curses, draw_cards, end_raid, mana, mutations, prompts};
 
 use crate::text_macro::text;
 use crate::*;
@@ -62,12 +68,40 @@ pub fn encounter_boost() -> Ability {
     Ability::new(encounter_ability_text(text![EncounterBoostCost], text![EncounterBoostBonus]))
 }
 
+/// Weapon ability which allows the weapon to only be used once during each
+/// raid.
+pub fn use_once_per_raid() -> Ability {
+    Ability::new(text!["Use this weapon only once per raid"])
+        .delegate(this::on_weapon_used(|g, s, used| {
+            g.card_mut(s).custom_state.push(CustomCardState::UsedForRaid { raid_id: used.raid_id });
+            Ok(())
+        }))
+        .delegate(this::can_use_weapon(|g, s, _, flag| {
+            let Some(raid_id) = g.raid_id() else {
+                return flag;
+            };
+            flag.add_constraint(!g.card(s).custom_state.used_for_raid(raid_id))
+        }))
+}
+
 /// The standard weapon breach ability, reads the weapon's breach value from its
 /// definition.
 pub fn breach() -> Ability {
     Ability::new(text![Breach])
 }
 
+/// Ability to gain `N` mana which can only be spent during the current turn
+/// whenever the Riftcaller accesses the vault.
+pub fn gain_turn_mana_on_vault_access<const N: ManaValue>() -> Ability {
+    Ability::new_with_delegate(
+        text!["When you access the vault, gain", Mana(N), "to spend this turn"],
+        in_play::on_vault_access_start(|g, s, _| {
+            mana::add_turn_specific_mana(g, s.side(), N);
+            Ok(())
+        }),
+    )
+}
+
 /// Ability to gain mana when a card is played.
 pub fn gain_mana_on_play<const N: ManaValue>() -> Ability {
     Ability::new_with_delegate(
@@ -127,6 +161,13 @@ pub fn can_progress() -> Ability {
     }))
 }
 
+/// Activated ability with "Sacrifice:" as its cost which draws `N` cards.
+pub fn sacrifice_to_draw_cards<const N: u32>() -> Ability {
+    ActivatedAbility::new(costs::sacrifice(), text!["Draw", N, "cards"])
+        .delegate(this::on_activated(|g, s, _| draw_cards::run(g, s.side(), N, s.initiated_by())))
+        .build()
+}
+
 /// An [AbilityType] for an ability with "Sacrifice:" as its only cost.
 pub fn sacrifice_this() -> AbilityType {
     AbilityType::Activated {
@@ -213,7 +254,7 @@ pub fn play_if_accessed_all_inner_rooms_this_turn() -> Ability {
 pub fn choose_a_minion_in_target_room() -> Ability {
     Ability::new(text![TextElement::NamedTrigger(Play, text!["Choose a minion in target room"])])
         .delegate(this::on_played(|g, s, played| {
-            prompts::push_with_data(g, s.side(), s, PromptData::CardPlay(*played));
+            prompts::push_with_data(g, s.side(), s, PromptData::CardPlay(*played))?;
             Ok(())
         }))
         .delegate(this::prompt(|g, s, source, _| {
@@ -241,6 +282,104 @@ pub fn choose_a_minion_in_target_room() -> Ability {
         }))
 }
 
+/// Ability which prevents the Riftcaller from scoring or razing more than `N`
+/// cards when they access the room this card occupies.
+pub fn limit_access<const N: u32>() -> Ability {
+    Ability::new_with_delegate(
+        text!["When the Riftcaller accesses this room, they may score or raze at most", N, "cards"],
+        delegates::on_raid_access_start(requirements::raid_targets_occupied_room, |g, _, _| {
+            access::limit_access(g, N)
+        }),
+    )
+}
+
+/// Weapon ability which splashes onto neighboring minions. Whenever this weapon
+/// is used, each other defender of the room being raided has `N` less health
+/// for the rest of the turn.
+pub fn splash<const N: HealthValue>() -> Ability {
+    Ability::new(text![
+        "When you use this weapon, other defenders of that room have",
+        N,
+        "less health this turn"
+    ])
+    .delegate(this::on_weapon_used(|g, s, used| {
+        let turn = g.info.turn;
+        let targets = g
+            .defenders_unordered(used.target)
+            .map(|card| card.id)
+            .filter(|id| *id != used.data.target_id)
+            .collect::<Vec<_>>();
+        for target_card in targets {
+            g.card_mut(s.card_id())
+                .custom_state
+                .push(CustomCardState::TargetCardForTurn { target_card, turn });
+        }
+        Ok(())
+    }))
+    .delegate(delegates::health_value(
+        requirements::card_targeted_for_this_turn,
+        |_, _, _, health| health.saturating_sub(N),
+    ))
+}
+
+/// Ability which makes the Riftcaller reveal their hand when they access the
+/// room this card occupies, ending the raid if no weapon is revealed.
+pub fn end_raid_on_access_unless_weapon_revealed() -> Ability {
+    Ability::new_with_delegate(
+        text![
+            "When the Riftcaller accesses this room, they reveal their hand.",
+            "If no weapon is revealed, end the raid"
+        ],
+        delegates::on_raid_access_start(requirements::raid_targets_occupied_room, |g, s, _| {
+            let has_weapon = mutations::reveal_hand(g, Side::Riftcaller, |g, card_id| {
+                g.card(card_id).definition().has_keyword(Keyword::Subtype(CardSubtype::Weapon))
+            })?;
+            if !has_weapon {
+                end_raid::run(g, s.initiated_by(), RaidOutcome::Failure)?;
+            }
+            Ok(())
+        }),
+    )
+}
+
+/// Ability which requires the Riftcaller to either pay `N` mana or discard a
+/// random card from their hand when they access the room this card occupies.
+pub fn pay_or_discard_on_access<const N: ManaValue>() -> Ability {
+    Ability::new(text![
+        "When the Riftcaller accesses this room, they must",
+        PayMana(N),
+        "or discard a random card"
+    ])
+    .delegate(delegates::on_raid_access_start(
+        requirements::raid_targets_occupied_room,
+        |g, s, _| {
+            let data =
+                random::card_in_position(g, Side::Riftcaller, CardPosition::Hand(Side::Riftcaller))
+                    .map_or(PromptData::None, PromptData::Card);
+            prompts::push_with_data(g, Side::Riftcaller, s, data)?;
+            Ok(())
+        },
+    ))
+    .delegate(this::prompt(|_, s, source, _| {
+        let mut choices = vec![PromptChoice::new().effect(GameEffect::ManaCost(
+            Side::Riftcaller,
+            N,
+            s.initiated_by(),
+        ))];
+        if let PromptData::Card(card_id) = source.data {
+            choices.push(
+                PromptChoice::new()
+                    .effect(GameEffect::MoveCard(
+                        card_id,
+                        CardPosition::DiscardPile(Side::Riftcaller),
+                    ))
+                    .custom_label(PromptChoiceLabel::Discard),
+            );
+        }
+        show_prompt::with_context_and_choices(ButtonPromptContext::Card(s.card_id()), choices)
+    }))
+}
+
 /// Adds 1 attack to a weapon per power charge counter on it.
 pub fn plus_1_attack_per_power_charge() -> Ability {
     Ability::new_with_delegate(
@@ -250,3 +389,26 @@ pub fn plus_1_attack_per_power_charge() -> Ability {
         }),
     )
 }
+
+/// At dusk, if this card occupies a room, the Covenant scores `N` points, up to
+/// a total of `MAX` points from this card. Accrued points are tracked via
+/// [CardCounter::Growth].
+pub fn score_points_at_dusk<const N: PointsValue, const MAX: PointsValue>() -> Ability {
+    Ability::new_with_delegate(
+        text_helpers::named_trigger(
+            Dusk,
+            text!["If this card is in a room, score", N, "points (maximum", MAX, ")"],
+        ),
+        this::at_dusk(|g, s, _| {
+            let card = g.card(s.card_id());
+            let accrued = card.counters(CardCounter::Growth);
+            if card.position().occupying_room().is_none() || accrued >= MAX {
+                return Ok(());
+            }
+
+            let points = N.min(MAX - accrued);
+            g.card_mut(s.card_id()).add_counters(CardCounter::Growth, points);
+            mutations::score_bonus_points(g, Side::Covenant, points)
+        }),
+    )
+}
@@ -85,7 +85,7 @@ pub fn lose_action_points<const N: ActionCount>() -> Ability {
 pub fn destroy_artifact() -> Ability {
     Ability::new(text_helpers::named_trigger(Combat, text!["Destroy an artifact"]))
         .delegate(this::combat(|g, s, _| {
-            prompts::push(g, Side::Covenant, s);
+            prompts::push(g, Side::Covenant, s)?;
             Ok(())
         }))
         .delegate(this::prompt(|g, s, _, _| {
@@ -12,11 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use core_data::game_primitives::{AbilityId, ActionCount, CardId, ManaValue};
+use core_data::game_primitives::{AbilityId, ActionCount, CardId, InitiatedBy, ManaValue};
 use game_data::card_configuration::{Cost, CustomCost};
-use game_data::card_state::CardCounter;
+use game_data::card_state::{CardCounter, CardPosition};
+use game_data::custom_card_state::CustomCardState;
+use game_data::game_actions::ButtonPromptContext;
+use game_data::game_effect::GameEffect;
+use game_data::game_state::GameState;
+use game_data::prompt_data::{ButtonPrompt, GamePrompt, PromptChoice, PromptChoiceLabel};
+use game_data::random;
 use game_data::text::{TextElement, TextToken};
-use rules::mutations;
+use rules::mana::ManaPurpose;
+use rules::{mana, mutations, prompts, queries};
 
 use crate::{history, text};
 
@@ -63,6 +70,7 @@ pub fn sacrifice_custom_cost() -> Option<CustomCost<AbilityId>> {
         },
         pay: |game, ability_id| mutations::sacrifice_card(game, ability_id.card_id),
         description: Some(TextElement::Token(TextToken::SacrificeCost)),
+        mana_reduction: None,
     })
 }
 
@@ -81,6 +89,7 @@ pub fn banish_custom_cost() -> Option<CustomCost<AbilityId>> {
         },
         pay: |game, ability_id| mutations::banish_card(game, ability_id.card_id),
         description: Some(TextElement::Children(text![TextToken::Banish, "this card"])),
+        mana_reduction: None,
     })
 }
 
@@ -99,6 +108,7 @@ pub fn power_charges_custom_cost<const N: u32>() -> Option<CustomCost<AbilityId>
         can_pay: |g, id| g.card(id.card_id).counters(CardCounter::PowerCharges) >= N,
         pay: |g, id| mutations::spend_power_charges(g, id.card_id, N),
         description: Some(TextElement::Token(TextToken::PowerCharges(N))),
+        mana_reduction: None,
     })
 }
 
@@ -113,6 +123,38 @@ pub fn progress_counters_custom_cost<const N: u32>() -> Option<CustomCost<Abilit
         can_pay: |g, id| g.card(id.card_id).counters(CardCounter::Progress) >= N,
         pay: |g, id| g.card_mut(id.card_id).remove_counters_or_error(CardCounter::Progress, N),
         description: Some(TextElement::Token(TextToken::ProgressCounters(N))),
+        mana_reduction: None,
+    })
+}
+
+/// Cost for an ability which requires its controller to discard `N` cards at
+/// random from their hand.
+pub fn discard_cards<const N: u32>() -> Cost<AbilityId> {
+    Cost { mana: None, actions: 0, custom_cost: discard_cards_custom_cost::<N>() }
+}
+
+/// Equivalent to [discard_cards] which additionally costs 1 action point.
+pub fn discard_cards_and_action<const N: u32>() -> Cost<AbilityId> {
+    Cost { mana: None, actions: 1, custom_cost: discard_cards_custom_cost::<N>() }
+}
+
+/// A [CustomCost] for an ability which requires discarding `N` random cards
+/// from hand, in the same manner as damage. Cannot be paid with fewer than `N`
+/// cards in hand.
+pub fn discard_cards_custom_cost<const N: u32>() -> Option<CustomCost<AbilityId>> {
+    Some(CustomCost {
+        can_pay: |g, id| g.hand(id.side()).count() >= N as usize,
+        pay: |g, id| {
+            let side = id.side();
+            for _ in 0..N {
+                if let Some(card_id) = random::card_in_position(g, side, CardPosition::Hand(side)) {
+                    mutations::discard_card(g, card_id)?;
+                }
+            }
+            Ok(())
+        },
+        description: Some(TextElement::Children(text!["Discard", N, "random cards"])),
+        mana_reduction: None,
     })
 }
 
@@ -124,6 +166,7 @@ pub fn once_per_turn() -> Option<CustomCost<AbilityId>> {
         },
         pay: |_, _| Ok(()),
         description: None,
+        mana_reduction: None,
     })
 }
 
@@ -131,3 +174,121 @@ pub fn once_per_turn() -> Option<CustomCost<AbilityId>> {
 pub fn actions(actions: ActionCount) -> Cost<AbilityId> {
     Cost { mana: None, actions, custom_cost: None }
 }
+
+/// Cost for an ability which requires the player to either pay `M` mana or
+/// spend `A` action points.
+pub fn mana_or_actions<const M: ManaValue, const A: ActionCount>() -> Cost<AbilityId> {
+    Cost { mana: None, actions: 0, custom_cost: mana_or_actions_custom_cost::<M, A>() }
+}
+
+/// A [CustomCost] which can be paid with either `M` mana or `A` action points.
+///
+/// If the player can afford both options, they are prompted to choose one when
+/// the cost is paid. Otherwise the affordable option is paid automatically.
+pub fn mana_or_actions_custom_cost<const M: ManaValue, const A: ActionCount>(
+) -> Option<CustomCost<AbilityId>> {
+    Some(CustomCost {
+        can_pay: |g, id| can_pay_mana(g, id, M) || g.player(id.side()).actions >= A,
+        pay: |g, id| {
+            let side = id.side();
+            match (can_pay_mana(g, id, M), g.player(side).actions >= A) {
+                (true, true) => {
+                    let prompt = ButtonPrompt {
+                        context: Some(ButtonPromptContext::Card(id.card_id)),
+                        choices: vec![
+                            PromptChoice::new().effect(GameEffect::ManaCost(
+                                side,
+                                M,
+                                InitiatedBy::Ability(id),
+                            )),
+                            PromptChoice::new().effect(GameEffect::ActionCost(side, A)),
+                        ],
+                    };
+                    prompts::push_immediate(g, side, GamePrompt::ButtonPrompt(prompt))?;
+                    Ok(())
+                }
+                (true, false) => rules::mana::spend(
+                    g,
+                    side,
+                    InitiatedBy::Ability(id),
+                    ManaPurpose::ActivateAbility(id),
+                    M,
+                ),
+                (false, _) => mutations::spend_action_points(g, side, A),
+            }
+        },
+        description: Some(TextElement::Children(text![
+            TextToken::Mana(M),
+            "or",
+            TextToken::Actions(A)
+        ])),
+        mana_reduction: None,
+    })
+}
+
+fn can_pay_mana(game: &GameState, ability_id: AbilityId, mana: ManaValue) -> bool {
+    rules::mana::get(game, ability_id.side(), ManaPurpose::ActivateAbility(ability_id)) >= mana
+}
+
+/// Cost for a card which costs `mana` mana and 1 action point, where the owner
+/// may discard another card from their hand while playing it to reduce its
+/// mana cost by `N`.
+pub fn discard_to_reduce<const N: ManaValue>(mana: ManaValue) -> Cost<CardId> {
+    Cost { mana: Some(mana), actions: 1, custom_cost: discard_to_reduce_custom_cost::<N>() }
+}
+
+/// [CustomCost] which prompts the owner of a card to optionally discard a card
+/// from their hand to reduce its mana cost by `N` via
+/// [CustomCardState::ManaCostReduced].
+pub fn discard_to_reduce_custom_cost<const N: ManaValue>() -> Option<CustomCost<CardId>> {
+    Some(CustomCost {
+        can_pay: |_, _| true,
+        pay: |g, card_id| {
+            if g.state_machines.play_card.last().is_some_and(|p| p.options.ignore_mana_cost) {
+                return Ok(());
+            }
+            let Some(play_id) = g.card(card_id).position().card_play_id() else {
+                return Ok(());
+            };
+
+            let side = card_id.side;
+            let mut choices = g
+                .hand(side)
+                .map(|card| {
+                    PromptChoice::new()
+                        .effect(GameEffect::MoveCard(card.id, CardPosition::DiscardPile(side)))
+                        .effect(GameEffect::AppendCustomCardState(
+                            card_id,
+                            CustomCardState::ManaCostReduced { play_id, amount: N },
+                        ))
+                        .anchor_card(card.id)
+                        .custom_label(PromptChoiceLabel::Discard)
+                })
+                .collect::<Vec<_>>();
+            if choices.is_empty() {
+                return Ok(());
+            }
+
+            let full_cost = queries::mana_cost(g, card_id).unwrap_or_default();
+            if full_cost <= mana::get(g, side, ManaPurpose::PayForCard(card_id)) {
+                choices.push(PromptChoice::new_continue());
+            }
+            prompts::push_immediate(
+                g,
+                side,
+                GamePrompt::ButtonPrompt(ButtonPrompt {
+                    context: Some(ButtonPromptContext::DiscardToReduceCost(card_id, N)),
+                    choices,
+                }),
+            )
+        },
+        description: None,
+        mana_reduction: Some(|g, card_id| {
+            if g.hand(card_id.side).any(|card| card.id != card_id) {
+                N
+            } else {
+                0
+            }
+        }),
+    })
+}
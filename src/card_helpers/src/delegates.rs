@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use core_data::game_primitives::{
-    AbilityId, CardId, InitiatedBy, ManaValue, RaidId, Resonance, ShieldValue, Side,
+    AbilityId, CardId, HealthValue, InitiatedBy, ManaValue, RaidId, Resonance, ShieldValue, Side,
 };
 use enumset::EnumSet;
 use game_data::continuous_visual_effect::ContinuousDisplayEffect;
@@ -69,6 +69,13 @@ pub fn shield_value(
     GameDelegate::ShieldValue(QueryDelegate { requirement, transformation })
 }
 
+pub fn health_value(
+    requirement: RequirementFn<CardId>,
+    transformation: TransformationFn<CardId, HealthValue>,
+) -> GameDelegate {
+    GameDelegate::HealthValue(QueryDelegate { requirement, transformation })
+}
+
 pub fn maximum_hand_size(
     requirement: RequirementFn<Side>,
     transformation: TransformationFn<Side, u32>,
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use core_data::game_primitives::{CardId, HasAbilityId, RoomId, Side};
+use std::collections::HashSet;
+
+use core_data::game_primitives::{CardId, HasAbilityId, InitiatedBy, RoomAccessId, RoomId, Side};
 use game_data::delegate_data::{AccessEvent, RaidEvent, UsedWeapon};
 use game_data::game_state::{GameState, TurnData};
 use game_data::history_data::{AbilityActivation, HistoryCounters, HistoryEvent};
@@ -94,6 +96,18 @@ pub fn rooms_raided_this_turn(game: &GameState) -> impl Iterator<Item = RoomId>
     })
 }
 
+/// Returns an iterator over rooms which have been raided in the current
+/// player's turn so far via the basic game action to initiate a raid, i.e.
+/// not including raids initiated by card abilities.
+pub fn rooms_raided_via_action_this_turn(game: &GameState) -> impl Iterator<Item = RoomId> + '_ {
+    current_turn(game).filter_map(move |h| match h {
+        HistoryEvent::RaidBegin(event) if event.data == InitiatedBy::GameAction => {
+            Some(event.target)
+        }
+        _ => None,
+    })
+}
+
 /// Returns an iterator over rooms which have been successfully raided in the
 /// current player's turn so far.
 pub fn rooms_accessed_this_turn(game: &GameState) -> impl Iterator<Item = RoomId> + '_ {
@@ -181,6 +195,20 @@ pub fn minion_combat_abilities_this_turn(
     })
 }
 
+/// Returns an iterator over cards which have been accessed during a raid access
+/// in the current player's turn so far.
+pub fn accessed_cards_this_turn(
+    game: &GameState,
+) -> impl Iterator<Item = &AccessEvent<CardId>> + '_ {
+    current_turn(game).filter_map(move |h| {
+        if let HistoryEvent::AccessCard(event) = h {
+            Some(event)
+        } else {
+            None
+        }
+    })
+}
+
 /// Returns an iterator over cards which have been razed during a raid access in
 /// the current player's turn so far.
 pub fn accessed_cards_razed_this_turn(
@@ -208,3 +236,33 @@ pub fn accessed_cards_scored_this_turn(
         }
     })
 }
+
+/// Number of distinct cards accessed, razed, and scored during a single room
+/// access.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AccessResult {
+    pub accessed: usize,
+    pub razed: usize,
+    pub scored: usize,
+}
+
+/// Returns an [AccessResult] summarizing the cards accessed, razed, and scored
+/// during the room access identified by `room_access_id` in the current turn.
+pub fn room_access_result(game: &GameState, room_access_id: RoomAccessId) -> AccessResult {
+    fn count<'a>(
+        events: impl Iterator<Item = &'a AccessEvent<CardId>>,
+        room_access_id: RoomAccessId,
+    ) -> usize {
+        events
+            .filter(|e| e.room_access_id() == Some(room_access_id))
+            .map(|e| *e.data())
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    AccessResult {
+        accessed: count(accessed_cards_this_turn(game), room_access_id),
+        razed: count(accessed_cards_razed_this_turn(game), room_access_id),
+        scored: count(accessed_cards_scored_this_turn(game), room_access_id),
+    }
+}
@@ -148,6 +148,12 @@ pub fn on_minion_approached(mutation: MutationFn<RaidEvent<CardId>>) -> GameDele
     delegates::on_minion_approached(requirements::face_up_in_play, mutation)
 }
 
+/// A delegate which fires when a card is face up & in play when a minion is
+/// encountered.
+pub fn on_minion_encountered(mutation: MutationFn<CardId>) -> GameDelegate {
+    delegates::on_minion_encountered(requirements::face_up_in_play, mutation)
+}
+
 /// A delegate which fires when a card is face up & in play when a minion is
 /// defeated.
 pub fn on_minion_defeated(mutation: MutationFn<MinionDefeated>) -> GameDelegate {
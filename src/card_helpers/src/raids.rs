@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use anyhow::Result;
-use core_data::game_primitives::{CardId, InitiatedBy, RaidId};
+use core_data::game_primitives::{CardId, InitiatedBy, MinionEncounterId, RaidId, RoomId};
 use game_data::delegate_data::{GameDelegate, QueryDelegate, RequirementFn, Scope};
 use game_data::game_actions::CardTarget;
 use game_data::game_state::GameState;
@@ -58,11 +58,53 @@ pub fn add_sanctum_access<const N: u32>(requirement: RequirementFn<RaidId>) -> G
     })
 }
 
+/// Causes raids matching `requirement` to access the top `N` cards of the
+/// Vault instead of the usual single card. Other effects which grant
+/// additional accesses still apply on top of this.
+pub fn access_top_of_vault<const N: u32>(requirement: RequirementFn<RaidId>) -> GameDelegate {
+    GameDelegate::VaultAccessCount(QueryDelegate {
+        requirement,
+        transformation: |_, _, _, current| (current + N).saturating_sub(1),
+    })
+}
+
+/// Describes the minion encounter currently being resolved during a raid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncounterContext {
+    pub raid_id: RaidId,
+    /// Room being raided
+    pub target: RoomId,
+    /// Position of the minion being encountered within the target room's
+    /// defender list
+    pub defender_index: usize,
+    /// Minion being encountered
+    pub minion_id: CardId,
+    /// Unique identifier for this encounter, if one has been assigned.
+    pub minion_encounter_id: Option<MinionEncounterId>,
+}
+
+/// Returns an [EncounterContext] describing the current encounter if there is
+/// an active raid encounter prompt in this game.
+pub fn current_encounter(game: &GameState) -> Option<EncounterContext> {
+    let raid = game.raid.as_ref()?;
+    let RaidState::Prompt(prompt) = &raid.state else {
+        return None;
+    };
+    if prompt.status != RaidStatus::Encounter {
+        return None;
+    }
+
+    Some(EncounterContext {
+        raid_id: raid.raid_id,
+        target: raid.target,
+        defender_index: raid.encounter,
+        minion_id: game.current_raid_defender()?,
+        minion_encounter_id: raid.minion_encounter_id,
+    })
+}
+
 /// Returns the minion currently being encountered if there is an active raid
 /// encounter prompt in this game.
 pub fn active_encounter_prompt(game: &GameState) -> Option<CardId> {
-    game.raid.as_ref().and_then(|raid| match &raid.state {
-        RaidState::Prompt(p) if p.status == RaidStatus::Encounter => game.current_raid_defender(),
-        _ => None,
-    })
+    current_encounter(game).map(|encounter| encounter.minion_id)
 }
@@ -92,6 +92,15 @@ pub fn matching_raid<T>(game: &GameState, scope: Scope, _: &T) -> bool {
     })
 }
 
+/// A [RequirementFn] which matches if this card is face up and occupying the
+/// target room of the current raid.
+pub fn raid_targets_occupied_room<T>(game: &GameState, scope: Scope, _: &T) -> bool {
+    utils::is_true(|| {
+        let card = game.card(scope.card_id());
+        Some(card.is_face_up() && game.raid.as_ref()?.target == card.position().occupying_room()?)
+    })
+}
+
 /// A [RequirementFn] which matches if this weapon is face up in play and has
 /// been used during the current raid
 pub fn weapon_used_this_raid<T>(game: &GameState, scope: Scope, _: &T) -> bool {
@@ -188,6 +197,12 @@ pub fn any_room<T>() -> TargetRequirement<T> {
     TargetRequirement::TargetRoom(|_, _, _| true)
 }
 
+/// A `TargetRequirement` for a card which can only be played in an inner room
+/// (the sanctum, vault, or crypt)
+pub fn inner_room<T>() -> TargetRequirement<T> {
+    TargetRequirement::TargetRoom(|_, _, room_id| room_id.is_inner_room())
+}
+
 /// A `TargetRequirement` for a card which can target any room which is a valid
 /// raid target
 pub fn any_raid_target<T>() -> TargetRequirement<T> {
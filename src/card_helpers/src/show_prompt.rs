@@ -12,8 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use core_data::game_primitives::{AbilityId, CardId, Side};
 use game_data::game_actions::ButtonPromptContext;
-use game_data::prompt_data::{ButtonPrompt, GamePrompt, PromptChoice, RoomSelectorPrompt};
+use game_data::game_effect::GameEffect;
+use game_data::prompt_data::{
+    ButtonPrompt, ChooseMultiplePrompt, ChooseMultiplePromptEffect, DistributePrompt,
+    DistributePromptEffect, GamePrompt, PromptChoice, PromptChoiceLabel, RoomSelectorPrompt,
+};
 
 /// Adds a choice prompt for the `side` player containing the choices in
 /// `choices`.
@@ -29,6 +34,49 @@ pub fn with_choices(choices: Vec<PromptChoice>) -> Option<GamePrompt> {
     Some(GamePrompt::ButtonPrompt(ButtonPrompt { context: None, choices }))
 }
 
+/// Adds a prompt to choose one of several modes, each of which applies a
+/// sequence of [GameEffect]s when selected. Each mode's button is labeled
+/// with the provided [PromptChoiceLabel].
+pub fn modal(modes: Vec<(PromptChoiceLabel, Vec<GameEffect>)>) -> Option<GamePrompt> {
+    with_choices(
+        modes
+            .into_iter()
+            .map(|(label, effects)| PromptChoice {
+                effects,
+                anchor_card: None,
+                custom_label: Some(label),
+            })
+            .collect(),
+    )
+}
+
+/// Adds a prompt to choose one of the `cards` in a discard pile. The cards are
+/// displayed in a browser, each with an anchored button which applies the
+/// [PromptChoice] returned by `choice` for that card.
+///
+/// Has no effect if `cards` is empty.
+pub fn choose_in_discard(
+    cards: impl Iterator<Item = CardId>,
+    choice: impl Fn(CardId) -> PromptChoice,
+) -> Option<GamePrompt> {
+    let choices = cards.map(|card_id| choice(card_id).anchor_card(card_id)).collect::<Vec<_>>();
+    if choices.is_empty() {
+        return None;
+    }
+
+    with_choices(choices)
+}
+
+/// Adds a prompt to choose one of the `cards` in the `side` player's discard
+/// pile to banish.
+///
+/// Has no effect if `cards` is empty.
+pub fn banish_from_discard(side: Side, cards: impl Iterator<Item = CardId>) -> Option<GamePrompt> {
+    choose_in_discard(cards, |card_id| {
+        PromptChoice::new().effect(GameEffect::BanishFromDiscard(side, card_id))
+    })
+}
+
 /// Show a priority window prompt if one is not already displayed. This prompt
 /// allows a player to activate abilities when they otherwise could not.
 pub fn priority_window() -> Option<GamePrompt> {
@@ -45,3 +93,50 @@ pub fn room_selector(prompt: RoomSelectorPrompt) -> Option<GamePrompt> {
 
     Some(GamePrompt::RoomSelector(prompt))
 }
+
+/// Show a prompt to distribute `total` units of a resource among the cards in
+/// `targets`, applying `effect` to each target once the full total has been
+/// allocated.
+///
+/// Has no effect if `targets` is empty or `total` is 0.
+pub fn distribute(
+    initiated_by: AbilityId,
+    effect: DistributePromptEffect,
+    total: u32,
+    targets: Vec<CardId>,
+) -> Option<GamePrompt> {
+    if targets.is_empty() || total == 0 {
+        return None;
+    }
+
+    Some(GamePrompt::Distribute(DistributePrompt {
+        initiated_by,
+        effect,
+        total,
+        targets,
+        allocated: vec![],
+    }))
+}
+
+/// Show a prompt to select up to `count` of the cards in `targets`, applying
+/// `effect` to each selected card once the selection is submitted.
+///
+/// Has no effect if `targets` is empty or `count` is 0.
+pub fn choose_multiple(
+    initiated_by: AbilityId,
+    effect: ChooseMultiplePromptEffect,
+    targets: Vec<CardId>,
+    count: usize,
+) -> Option<GamePrompt> {
+    if targets.is_empty() || count == 0 {
+        return None;
+    }
+
+    Some(GamePrompt::ChooseMultiple(ChooseMultiplePrompt {
+        initiated_by,
+        effect,
+        count,
+        targets,
+        selected: vec![],
+    }))
+}
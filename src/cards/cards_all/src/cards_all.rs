@@ -69,6 +69,7 @@ pub fn initialize() {
     DEFINITIONS.insert(cards_beryl::allies_beryl::noble_martyr);
     DEFINITIONS.insert(cards_beryl::allies_beryl::rift_adept);
     DEFINITIONS.insert(cards_beryl::allies_beryl::phalanx_guardian);
+    DEFINITIONS.insert(cards_beryl::allies_beryl::vigilant_scout);
     DEFINITIONS.insert(cards_beryl::artifacts_beryl::pathfinder);
     DEFINITIONS.insert(cards_beryl::artifacts_beryl::staff_of_the_valiant);
     DEFINITIONS.insert(cards_beryl::artifacts_beryl::triumph);
@@ -90,6 +91,9 @@ pub fn initialize() {
     DEFINITIONS.insert(cards_beryl::artifacts_beryl::lawbringer);
     DEFINITIONS.insert(cards_beryl::artifacts_beryl::vengeance);
     DEFINITIONS.insert(cards_beryl::artifacts_beryl::summermorn);
+    DEFINITIONS.insert(cards_beryl::artifacts_beryl::waystone);
+    DEFINITIONS.insert(cards_beryl::artifacts_beryl::echoing_mirror);
+    DEFINITIONS.insert(cards_beryl::artifacts_beryl::shatterflail);
     DEFINITIONS.insert(cards_beryl::chapters_beryl::nimbus_enclave);
     DEFINITIONS.insert(cards_beryl::chapters_beryl::enforcers_of_silence);
     DEFINITIONS.insert(cards_beryl::chapters_beryl::keepers_of_the_eye);
@@ -141,6 +145,7 @@ pub fn initialize() {
     DEFINITIONS.insert(cards_beryl::rituals_beryl::foresee);
     DEFINITIONS.insert(cards_beryl::rituals_beryl::dusks_ascension);
     DEFINITIONS.insert(cards_beryl::rituals_beryl::foretell_fate);
+    DEFINITIONS.insert(cards_beryl::rituals_beryl::interrogate);
     DEFINITIONS.insert(cards_beryl::schemes_beryl::ethereal_form);
     DEFINITIONS.insert(cards_beryl::schemes_beryl::echoing_cacophony);
     DEFINITIONS.insert(cards_beryl::schemes_beryl::solidarity);
@@ -161,6 +166,9 @@ pub fn initialize() {
     DEFINITIONS.insert(cards_beryl::spells_beryl::liminal_transposition);
     DEFINITIONS.insert(cards_beryl::spells_beryl::echoing_valor);
     DEFINITIONS.insert(cards_beryl::spells_beryl::condemn_to_eternity);
+    DEFINITIONS.insert(cards_beryl::spells_beryl::overcharge);
+    DEFINITIONS.insert(cards_beryl::spells_beryl::twin_paths);
+    DEFINITIONS.insert(cards_beryl::spells_beryl::forage);
     DEFINITIONS.insert(cards_game_modifier::tutorial_modifiers::covenant_empty_modifier);
     DEFINITIONS.insert(cards_game_modifier::tutorial_modifiers::tutorial_disable_draw_action);
     DEFINITIONS.insert(cards_game_modifier::tutorial_modifiers::tutorial_disable_gain_mana);
@@ -228,4 +236,41 @@ pub fn initialize() {
     DEFINITIONS.insert(cards_test::test_cards::test_ally_access_additional_sanctum_card);
     DEFINITIONS.insert(cards_test::test_cards::test_ally_access_additional_vault_card);
     DEFINITIONS.insert(cards_test::test_cards::test_charge_artifact);
+    DEFINITIONS.insert(cards_test::test_cards::test_spell_reveal_top_card_draw_if_weapon);
+    DEFINITIONS.insert(cards_test::test_cards::test_minion_inner_room_only);
+    DEFINITIONS.insert(cards_test::test_cards::test_project_minions_cost_less_in_defended_rooms);
+    DEFINITIONS.insert(cards_test::test_cards::test_project_pay_or_discard_on_access);
+    DEFINITIONS.insert(cards_test::test_cards::test_universal_weapon);
+    DEFINITIONS.insert(cards_test::test_cards::test_spell_distribute_power_charges);
+    DEFINITIONS.insert(cards_test::test_cards::test_ritual_summon_minion_from_discard);
+    DEFINITIONS.insert(cards_test::test_cards::test_spell_first_play_bonus);
+    DEFINITIONS.insert(cards_test::test_cards::test_ritual_look_at_opponent_top_card);
+    DEFINITIONS.insert(cards_test::test_cards::test_ritual_two_prompt_triggers);
+    DEFINITIONS.insert(cards_test::test_cards::test_ritual_destroy_up_to_two_artifacts);
+    DEFINITIONS.insert(cards_test::test_cards::test_project_limit_access_to_one);
+    DEFINITIONS.insert(cards_test::test_cards::test_spell_return_card_from_discard);
+    DEFINITIONS.insert(cards_test::test_cards::test_weapon_once_per_raid);
+    DEFINITIONS.insert(cards_test::test_cards::test_minion_banish_until_end_of_turn);
+    DEFINITIONS.insert(cards_test::test_cards::test_growth_artifact);
+    DEFINITIONS.insert(cards_test::test_cards::test_spell_optional_pay_to_draw);
+    DEFINITIONS.insert(cards_test::test_cards::test_sacrifice_draw_two_artifact);
+    DEFINITIONS.insert(cards_test::test_cards::test_artifact_turn_mana_on_vault_access);
+    DEFINITIONS.insert(cards_test::test_cards::test_minion_damage_on_encounter);
+    DEFINITIONS.insert(cards_test::test_cards::test_spell_raid_vault_access_top_two);
+    DEFINITIONS.insert(cards_test::test_cards::test_minion_move_on_encounter);
+    DEFINITIONS.insert(cards_test::test_cards::test_project_end_raid_unless_weapon_revealed);
+    DEFINITIONS.insert(cards_test::test_cards::test_prismatic_minion);
+    DEFINITIONS.insert(cards_test::test_cards::test_ritual_banish_from_opponent_discard);
+    DEFINITIONS.insert(cards_test::test_cards::test_spell_repeatedly_push_prompts);
+    DEFINITIONS.insert(cards_test::test_cards::test_spell_discard_to_reduce_cost);
+    DEFINITIONS.insert(cards_test::test_cards::test_spell_dig_4_for_weapon);
+    DEFINITIONS.insert(cards_test::test_cards::test_ritual_draw_card);
+    DEFINITIONS.insert(cards_test::test_cards::test_minion_health_per_other_summoned_minion);
+    DEFINITIONS.insert(cards_test::test_cards::test_evocation_gain_mana_at_dawn);
+    DEFINITIONS.insert(cards_test::test_cards::test_evocation_first_raid_each_turn_free);
+    DEFINITIONS.insert(cards_test::test_cards::test_scheme_score_points_at_dusk);
+    DEFINITIONS.insert(cards_test::test_cards::test_project_discard_to_gain_mana);
+    DEFINITIONS.insert(cards_test::test_cards::test_scheme_draw_card_on_score);
+    DEFINITIONS.insert(cards_test::test_cards::test_project_count_minion_summons);
+    DEFINITIONS.insert(cards_test::test_cards::test_artifact_prompt_on_access);
 }
@@ -49,7 +49,7 @@ pub fn time_golem(_: CardMetadata) -> CardDefinition {
                 text!["End the raid unless the Riftcaller pays", Mana(5), "or", Actions(2)],
             ),
             on_encountered(|g, s, _| {
-                prompts::push(g, Side::Riftcaller, s);
+                prompts::push(g, Side::Riftcaller, s)?;
                 Ok(())
             }),
         )
@@ -190,7 +190,7 @@ pub fn stormcaller(_: CardMetadata) -> CardDefinition {
         ))
         .delegate(combat(|g, s, _| {
             damage::deal(g, s, 2)?;
-            prompts::push(g, Side::Riftcaller, s);
+            prompts::push(g, Side::Riftcaller, s)?;
             Ok(())
         }))
         .delegate(this::prompt(|_, s, _, _| {
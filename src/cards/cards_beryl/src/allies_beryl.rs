@@ -148,7 +148,7 @@ pub fn stalwart_protector(meta: CardMetadata) -> CardDefinition {
                     effect: text!["Prevent receiving a", Curse]
                 }],
                 in_play::on_will_receive_curses(|g, s, _| {
-                    prompts::push(g, Side::Riftcaller, s);
+                    prompts::push(g, Side::Riftcaller, s)?;
                     Ok(())
                 }),
             )
@@ -284,7 +284,7 @@ pub fn blue_warden(meta: CardMetadata) -> CardDefinition {
                 .build(),
             Ability::new(text!["You may activate abilities after being damaged"])
                 .delegate(in_play::on_damage(|g, s, _| {
-                    prompts::push(g, Side::Riftcaller, s);
+                    prompts::push(g, Side::Riftcaller, s)?;
                     Ok(())
                 }))
                 .delegate(this::prompt(|_, _, _, _| show_prompt::priority_window())),
@@ -310,7 +310,7 @@ pub fn noble_martyr(meta: CardMetadata) -> CardDefinition {
                 text!["Choose a minion in target room with", 2, "or fewer", ShieldPoints],
             ))
             .delegate(this::on_played(|g, s, played| {
-                prompts::push_with_data(g, s.side(), s, PromptData::CardPlay(*played));
+                prompts::push_with_data(g, s.side(), s, PromptData::CardPlay(*played))?;
                 Ok(())
             }))
             .delegate(this::prompt(|g, s, source, _| {
@@ -450,3 +450,26 @@ pub fn phalanx_guardian(meta: CardMetadata) -> CardDefinition {
             .build(),
     }
 }
+
+pub fn vigilant_scout(meta: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::VigilantScout,
+        sets: vec![CardSetName::Beryl],
+        cost: costs::mana(meta.upgrade(2, 0)),
+        image: assets::riftcaller_card(meta, "vigilant_scout"),
+        card_type: CardType::Ally,
+        subtypes: vec![CardSubtype::Warrior],
+        side: Side::Riftcaller,
+        school: School::Law,
+        rarity: Rarity::Common,
+        abilities: vec![Ability::new_with_delegate(
+            text!["Whenever the Covenant summons a minion,", GainMana(1)],
+            in_play::on_minion_summoned(|g, s, _| {
+                VisualEffects::new().ability_alert(s).apply(g);
+                mana::gain(g, s.side(), 1);
+                Ok(())
+            }),
+        )],
+        config: CardConfig::default(),
+    }
+}
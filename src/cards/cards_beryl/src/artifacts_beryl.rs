@@ -12,8 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use card_definition_data::ability_data::{Ability, ActivatedAbility};
-use card_definition_data::card_definition::CardDefinition;
+use card_definition_data::ability_data::{Ability, ActivatedAbility, Delegate};
+use card_definition_data::card_definition::{CardDefinition, Keyword};
 use card_definition_data::cards::CardDefinitionExt;
 use core_data::game_primitives::{
     CardSubtype, CardType, GameObjectId, Rarity, Resonance, RoomId, School, Side, INNER_ROOMS,
@@ -25,7 +25,7 @@ use game_data::card_name::{CardMetadata, CardName};
 use game_data::card_set_name::CardSetName;
 use game_data::card_state::{CardCounter, CardPosition};
 use game_data::custom_card_state::CustomCardState;
-use game_data::delegate_data::{CardInfoElementKind, CardStatusMarker, Scope};
+use game_data::delegate_data::{CardInfoElementKind, CardStatusMarker, GameDelegate, Scope};
 use game_data::game_actions::{ButtonPromptContext, CardTarget};
 use game_data::game_effect::GameEffect;
 use game_data::game_state::GameState;
@@ -533,7 +533,7 @@ pub fn shield_of_the_flames(meta: CardMetadata) -> CardDefinition {
                 .delegate(this::can_activate(|g, _, _, flag| {
                     flag.add_constraint(utils::is_true(|| {
                         Some(
-                            queries::resonance(g, raids::active_encounter_prompt(g)?)
+                            queries::resonance(g, raids::current_encounter(g)?.minion_id)
                                 .contains(Resonance::Infernal),
                         )
                     }))
@@ -581,7 +581,7 @@ pub fn foebane(meta: CardMetadata) -> CardDefinition {
                             Side::Riftcaller,
                             s,
                             PromptData::Card(event.data),
-                        );
+                        )?;
                     }
                     Ok(())
                 }),
@@ -659,7 +659,7 @@ pub fn whip_of_disjunction(meta: CardMetadata) -> CardDefinition {
         .delegate(this::can_activate(|g, _, _, flag| {
             flag.add_constraint(utils::is_true(|| {
                 Some(
-                    queries::resonance(g, raids::active_encounter_prompt(g)?)
+                    queries::resonance(g, raids::current_encounter(g)?.minion_id)
                         .contains(Resonance::Astral),
                 )
             }))
@@ -728,11 +728,11 @@ pub fn glimmersong(meta: CardMetadata) -> CardDefinition {
                     AddPowerCharges(1)
                 ],
                 in_play::on_raid_access_end(|g, s, event| {
-                    if history::accessed_cards_razed_this_turn(g)
-                        .all(|e| e.room_access_id() != event.room_access_id)
-                        && history::accessed_cards_scored_this_turn(g)
-                            .all(|e| e.room_access_id() != event.room_access_id)
-                    {
+                    let Some(room_access_id) = event.room_access_id else {
+                        return Ok(());
+                    };
+                    let result = history::room_access_result(g, room_access_id);
+                    if result.razed == 0 && result.scored == 0 {
                         g.card_mut(s.card_id()).add_counters(CardCounter::PowerCharges, 1);
                         apply_vfx(g, s);
                     }
@@ -773,7 +773,7 @@ pub fn spear_of_ultimatum(meta: CardMetadata) -> CardDefinition {
                         Side::Riftcaller,
                         s,
                         PromptData::AbilityActivation(*activation),
-                    );
+                    )?;
                     Ok(())
                 }))
                 .delegate(this::prompt(|g, s, source, _| {
@@ -1080,7 +1080,7 @@ pub fn summermorn(meta: CardMetadata) -> CardDefinition {
                 if defeated.weapon_id == Some(s.card_id()) {
                     custom_state::in_play_ability_once_per_turn(g, s, |g, s| {
                         visual_effects::show_alert(g, s);
-                        prompts::push(g, Side::Riftcaller, s);
+                        prompts::push(g, Side::Riftcaller, s)?;
                         Ok(())
                     })?;
                 }
@@ -1090,7 +1090,9 @@ pub fn summermorn(meta: CardMetadata) -> CardDefinition {
                 show_prompt::with_context_and_choices(
                     ButtonPromptContext::AddPowerCharges(s.upgrade(1, 2)),
                     g.all_permanents(Side::Riftcaller)
-                        .filter(|card| card.definition().subtypes.contains(&CardSubtype::Charge))
+                        .filter(|card| {
+                            card.definition().has_keyword(Keyword::Subtype(CardSubtype::Charge))
+                        })
                         .map(|card| {
                             PromptChoice::new()
                                 .effect(GameEffect::AddPowerCharges(card.id, s.upgrade(1, 2)))
@@ -1113,3 +1115,109 @@ pub fn summermorn(meta: CardMetadata) -> CardDefinition {
             .build(),
     }
 }
+
+pub fn waystone(meta: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::Waystone,
+        sets: vec![CardSetName::Beryl],
+        cost: costs::mana(meta.upgrade(2, 0)),
+        image: assets::riftcaller_card(meta, "waystone"),
+        card_type: CardType::Artifact,
+        subtypes: vec![],
+        side: Side::Riftcaller,
+        school: School::Law,
+        rarity: Rarity::Common,
+        abilities: vec![ActivatedAbility::new(
+            costs::mana_or_actions::<2, 1>(),
+            text!["Draw a card"],
+        )
+        .delegate(this::on_activated(|g, s, _| draw_cards::run(g, s.side(), 1, s.initiated_by())))
+        .build()],
+        config: CardConfig::default(),
+    }
+}
+
+pub fn echoing_mirror(meta: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::EchoingMirror,
+        sets: vec![CardSetName::Beryl],
+        cost: costs::mana(meta.upgrade(1, 0)),
+        image: assets::riftcaller_card(meta, "echoing_mirror"),
+        card_type: CardType::Artifact,
+        subtypes: vec![],
+        side: Side::Riftcaller,
+        school: School::Law,
+        rarity: Rarity::Uncommon,
+        abilities: vec![ActivatedAbility::new(
+            costs::actions(1),
+            text![
+                "Choose another artifact",
+                "This turn, this card gains its 'when you access a room' abilities"
+            ],
+        )
+        .delegate(this::on_activated(|g, s, _| {
+            prompts::push(g, s.side(), s)?;
+            Ok(())
+        }))
+        .delegate(this::prompt(|g, s, _, _| {
+            show_prompt::with_context_and_choices(
+                ButtonPromptContext::Card(s.card_id()),
+                g.all_permanents(Side::Riftcaller)
+                    .filter(|card| card.id != s.card_id() && card.definition().is_artifact())
+                    .flat_map(|card| {
+                        let definition = card.definition();
+                        definition
+                            .ability_ids(card.id)
+                            .filter(|ability_id| {
+                                let ability = definition.ability(ability_id.index);
+                                ability.can_be_copied()
+                                    && ability.delegates.iter().any(|d| {
+                                        matches!(
+                                            d,
+                                            Delegate::GameDelegate(GameDelegate::RaidAccessStart(
+                                                _
+                                            ))
+                                        )
+                                    })
+                            })
+                            .map(move |ability_id| {
+                                PromptChoice::new()
+                                    .effect(GameEffect::CopyAbilityForTurn(
+                                        s.ability_id(),
+                                        ability_id,
+                                    ))
+                                    .anchor_card(card.id)
+                            })
+                    })
+                    .collect(),
+            )
+        }))
+        .build()],
+        config: CardConfig::default(),
+    }
+}
+
+pub fn shatterflail(meta: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::Shatterflail,
+        sets: vec![CardSetName::Beryl],
+        cost: costs::mana(meta.upgrade(4, 2)),
+        image: assets::riftcaller_card(meta, "shatterflail"),
+        card_type: CardType::Artifact,
+        subtypes: vec![CardSubtype::Weapon],
+        side: Side::Riftcaller,
+        school: School::Beyond,
+        rarity: Rarity::Uncommon,
+        abilities: vec![abilities::splash::<2>(), abilities::encounter_boost()],
+        config: CardConfigBuilder::new()
+            .base_attack(3)
+            .attack_boost(AttackBoost::new().mana_cost(1).bonus(1))
+            .resonance(Resonance::Mortal)
+            .combat_projectile(
+                ProjectileData::new(Projectile::Projectiles1(2))
+                    .fire_sound(SoundEffect::WaterMagic("RPG3_WaterMagic_Projectiles01"))
+                    .impact_sound(SoundEffect::WaterMagic("RPG3_WaterMagic_Impact01")),
+            )
+            .build(),
+    }
+}
@@ -165,7 +165,7 @@ pub fn keepers_of_the_eye(meta: CardMetadata) -> CardDefinition {
                             )
                             .ability_alert(s)
                             .apply(g);
-                        prompts::push(g, Side::Covenant, s);
+                        prompts::push(g, Side::Covenant, s)?;
                         Ok(())
                     })?;
                 }
@@ -207,7 +207,7 @@ pub fn the_starseers(meta: CardMetadata) -> CardDefinition {
             Ability::new_with_delegate(
                 text_helpers::named_trigger(Dawn, text![text!["Choose a card type"],]),
                 in_play::at_dawn(|g, s, _| {
-                    prompts::push(g, Side::Covenant, s);
+                    prompts::push(g, Side::Covenant, s)?;
                     Ok(())
                 }),
             )
@@ -378,7 +378,7 @@ pub fn the_conjurers_circle(meta: CardMetadata) -> CardDefinition {
                     RealizeCards::SetVisibleToOwner,
                 )?;
 
-                prompts::push_with_data(g, Side::Covenant, s, PromptData::Cards(cards));
+                prompts::push_with_data(g, Side::Covenant, s, PromptData::Cards(cards))?;
                 Ok(())
             }))
             .delegate(this::prompt(|_, s, source, _| {
@@ -425,7 +425,7 @@ pub fn the_honorbound(meta: CardMetadata) -> CardDefinition {
                 && g.occupants_in_all_rooms().any(|card| flags::can_progress_card(g, card.id))
             {
                 visual_effects::show(g, s, s.card_id(), ShowAlert::Yes);
-                prompts::push(g, Side::Covenant, s);
+                prompts::push(g, Side::Covenant, s)?;
             }
             Ok(())
         }))
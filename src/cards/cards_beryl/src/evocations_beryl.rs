@@ -154,7 +154,7 @@ pub fn visitation(meta: CardMetadata) -> CardDefinition {
             }],
             in_play::on_will_deal_damage(|g, s, source| {
                 if source.side() == Side::Covenant {
-                    prompts::push(g, Side::Riftcaller, s);
+                    prompts::push(g, Side::Riftcaller, s)?;
                 }
                 Ok(())
             }),
@@ -249,13 +249,13 @@ pub fn planar_sanctuary(meta: CardMetadata) -> CardDefinition {
             Ability::new(text!["You may activate abilities after being cursed or damaged"])
                 .delegate(in_play::on_curse(|g, s, _| {
                     if g.card(s.card_id()).counters(CardCounter::PowerCharges) > 0 {
-                        prompts::push(g, Side::Riftcaller, s);
+                        prompts::push(g, Side::Riftcaller, s)?;
                     }
                     Ok(())
                 }))
                 .delegate(in_play::on_damage(|g, s, _| {
                     if g.card(s.card_id()).counters(CardCounter::PowerCharges) > 0 {
-                        prompts::push(g, Side::Riftcaller, s);
+                        prompts::push(g, Side::Riftcaller, s)?;
                     }
                     Ok(())
                 }))
@@ -318,7 +318,7 @@ pub fn knowledge_of_the_beyond(meta: CardMetadata) -> CardDefinition {
                     .partition(|id| g.card(*id).definition().is_permanent());
 
                 mutations::move_cards(g, &spells, CardPosition::DiscardPile(s.side()))?;
-                prompts::push_with_data(g, Side::Riftcaller, s, PromptData::Cards(permanents));
+                prompts::push_with_data(g, Side::Riftcaller, s, PromptData::Cards(permanents))?;
                 Ok(())
             }))
             .delegate(this::prompt(|_, s, source, _| {
@@ -359,7 +359,7 @@ pub fn splinter_of_twilight(meta: CardMetadata) -> CardDefinition {
                 ],
                 delegates::on_raid_access_start(requirements::in_hand, |g, s, event| {
                     if event.target == RoomId::Crypt {
-                        prompts::push(g, Side::Riftcaller, s);
+                        prompts::push(g, Side::Riftcaller, s)?;
                     }
                     Ok(())
                 }),
@@ -509,7 +509,7 @@ pub fn radiant_intervention(meta: CardMetadata) -> CardDefinition {
                 ]
             }],
             in_play::on_will_destroy_cards(|g, s, _| {
-                prompts::push(g, Side::Riftcaller, s);
+                prompts::push(g, Side::Riftcaller, s)?;
                 Ok(())
             }),
         )
@@ -632,7 +632,7 @@ pub fn potentiality_storm(meta: CardMetadata) -> CardDefinition {
                         >= s.upgrade(3, 2)
                 {
                     visual_effects::show_alert(g, s);
-                    prompts::push(g, Side::Riftcaller, s);
+                    prompts::push(g, Side::Riftcaller, s)?;
                 }
                 Ok(())
             }))
@@ -176,11 +176,11 @@ pub fn lawhold_cavalier(meta: CardMetadata) -> CardDefinition {
                 if permanents.len() == 2 {
                     g.covenant.prompt_selected_cards.push(permanents[0]);
                     g.covenant.prompt_selected_cards.push(permanents[1]);
-                    prompts::push_with_data(g, Side::Riftcaller, s, PromptData::Index(2));
+                    prompts::push_with_data(g, Side::Riftcaller, s, PromptData::Index(2))?;
                 } else if permanents.len() > 2 {
                     // Note that second option is shown first on prompt stack
-                    prompts::push_with_data(g, Side::Covenant, s, PromptData::Index(1));
-                    prompts::push_with_data(g, Side::Covenant, s, PromptData::Index(0));
+                    prompts::push_with_data(g, Side::Covenant, s, PromptData::Index(1))?;
+                    prompts::push_with_data(g, Side::Covenant, s, PromptData::Index(0))?;
                 }
 
                 Ok(())
@@ -351,7 +351,7 @@ pub fn aeon_swimmer(meta: CardMetadata) -> CardDefinition {
             }))
             .delegate(this::on_activated(|g, s, _| {
                 if g.riftcaller.actions > 0 {
-                    prompts::push(g, Side::Riftcaller, s.ability_id());
+                    prompts::push(g, Side::Riftcaller, s.ability_id())?;
                 } else {
                     end_raid::run(g, s.initiated_by(), RaidOutcome::Failure)?;
                 }
@@ -472,7 +472,7 @@ pub fn soldier_servitor(meta: CardMetadata) -> CardDefinition {
             ))
             .delegate(this::combat(|g, s, _| {
                 end_raid::run(g, s.initiated_by(), RaidOutcome::Failure)?;
-                prompts::push(g, Side::Covenant, s);
+                prompts::push(g, Side::Covenant, s)?;
                 Ok(())
             }))
             .delegate(this::prompt(|g, s, _, _| {
@@ -520,7 +520,7 @@ pub fn windmare(meta: CardMetadata) -> CardDefinition {
                 "to defeat this minion"
             ])
             .delegate(this::on_encountered(|g, s, _| {
-                prompts::push(g, Side::Riftcaller, s);
+                prompts::push(g, Side::Riftcaller, s)?;
                 Ok(())
             }))
             .delegate(this::prompt(|_, s, _, _| {
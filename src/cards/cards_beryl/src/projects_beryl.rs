@@ -142,7 +142,7 @@ pub fn sealed_necropolis(meta: CardMetadata) -> CardDefinition {
                 text!["Shuffle up to", 3, "cards from the crypt into the vault"],
             )
             .delegate(this::on_activated(|g, s, _| {
-                prompts::push(g, Side::Covenant, s.ability_id());
+                prompts::push(g, Side::Covenant, s.ability_id())?;
                 Ok(())
             }))
             .delegate(this::prompt(|g, s, _, _| {
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use card_definition_data::ability_data::{Ability, ActivatedAbility};
-use card_definition_data::card_definition::CardDefinition;
+use card_definition_data::card_definition::{CardDefinition, Keyword};
 use card_definition_data::cards::CardDefinitionExt;
 use card_helpers::{
     costs, delegates, history, in_play, raids, requirements, show_prompt, text, this,
@@ -126,7 +126,7 @@ pub fn strazihar_the_all_seeing(meta: CardMetadata) -> CardDefinition {
                             Side::Covenant,
                             s,
                             PromptData::Card(played.card_id),
-                        );
+                        )?;
 
                         Ok(())
                     })?;
@@ -485,12 +485,14 @@ pub fn usilyna_master_artificer(meta: CardMetadata) -> CardDefinition {
             if event.target == RoomId::Vault {
                 custom_state::identity_once_per_turn(g, s, |g, s| {
                     if g.all_permanents(Side::Riftcaller)
-                        .filter(|c| c.definition().subtypes.contains(&CardSubtype::Charge))
+                        .filter(|c| {
+                            c.definition().has_keyword(Keyword::Subtype(CardSubtype::Charge))
+                        })
                         .count()
                         > 0
                     {
                         visual_effects::show(g, s, s.card_id(), ShowAlert::Yes);
-                        prompts::push(g, Side::Riftcaller, s);
+                        prompts::push(g, Side::Riftcaller, s)?;
                     }
                     Ok(())
                 })?;
@@ -500,7 +502,7 @@ pub fn usilyna_master_artificer(meta: CardMetadata) -> CardDefinition {
         .delegate(this::prompt(|g, _, _, _| {
             show_prompt::with_choices(
                 g.all_permanents(Side::Riftcaller)
-                    .filter(|c| c.definition().subtypes.contains(&CardSubtype::Charge))
+                    .filter(|c| c.definition().has_keyword(Keyword::Subtype(CardSubtype::Charge)))
                     .map(|c| {
                         PromptChoice::new()
                             .effect(GameEffect::AddPowerCharges(c.id, 1))
@@ -551,7 +553,7 @@ pub fn sariandi_phase_walker(meta: CardMetadata) -> CardDefinition {
         .delegate(this::on_activated(|g, s, _| raids::initiate(g, s, RoomId::Crypt)))
         .delegate(delegates::on_raid_access_start(requirements::matching_raid, |g, s, _| {
             visual_effects::show(g, s, s.card_id(), ShowAlert::No);
-            prompts::push(g, Side::Riftcaller, s.ability_id());
+            prompts::push(g, Side::Riftcaller, s.ability_id())?;
             Ok(())
         }))
         .delegate(this::prompt(|_, s, _, _| {
@@ -66,8 +66,8 @@ pub fn equivalent_exchange(meta: CardMetadata) -> CardDefinition {
                     ],
                     this::on_played(|g, s, _| {
                         // Note that second option is shown first on prompt stack
-                        prompts::push_with_data(g, Side::Covenant, s, PromptData::Index(1));
-                        prompts::push_with_data(g, Side::Covenant, s, PromptData::Index(0));
+                        prompts::push_with_data(g, Side::Covenant, s, PromptData::Index(1))?;
+                        prompts::push_with_data(g, Side::Covenant, s, PromptData::Index(0))?;
                         Ok(())
                     }),
                 )
@@ -135,7 +135,7 @@ pub fn lightbond(meta: CardMetadata) -> CardDefinition {
                     text!["When the Riftcaller accesses this scheme, give them", 2, Curses],
                 ])
                 .delegate(this::on_played(|g, s, _| {
-                    prompts::push(g, Side::Covenant, s);
+                    prompts::push(g, Side::Covenant, s)?;
                     Ok(())
                 }))
                 .delegate(this::prompt(|g, s, _, _| {
@@ -208,7 +208,7 @@ pub fn foresee(meta: CardMetadata) -> CardDefinition {
                 5,
                 RealizeCards::SetVisibleToOwner,
             )?;
-            prompts::push_with_data(g, Side::Covenant, s, PromptData::Cards(cards));
+            prompts::push_with_data(g, Side::Covenant, s, PromptData::Cards(cards))?;
             Ok(())
         }))
         .delegate(this::prompt(|_, s, source, _| {
@@ -300,7 +300,7 @@ pub fn foretell_fate(meta: CardMetadata) -> CardDefinition {
             text!["Draw that many cards", meta.upgrade("", "plus one")]
         ])
         .delegate(this::on_played(|g, s, _| {
-            prompts::push(g, Side::Covenant, s);
+            prompts::push(g, Side::Covenant, s)?;
             Ok(())
         }))
         .delegate(this::prompt(|g, s, _, _| {
@@ -320,3 +320,27 @@ pub fn foretell_fate(meta: CardMetadata) -> CardDefinition {
         config: CardConfig::default(),
     }
 }
+
+pub fn interrogate(meta: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::Interrogate,
+        sets: vec![CardSetName::Beryl],
+        cost: costs::mana(meta.upgrade(2, 0)),
+        image: assets::covenant_card(meta, "interrogate"),
+        card_type: CardType::Ritual,
+        subtypes: vec![CardSubtype::Dictate],
+        side: Side::Covenant,
+        school: School::Law,
+        rarity: Rarity::Common,
+        abilities: vec![Ability::new_with_delegate(
+            text![
+                text!["The Riftcaller reveals their hand"],
+                text!["Choose a card for them to discard"]
+            ],
+            this::on_played(|g, s, _| {
+                mutations::opponent_reveals_and_you_choose_discard(g, s.side())
+            }),
+        )],
+        config: CardConfig::default(),
+    }
+}
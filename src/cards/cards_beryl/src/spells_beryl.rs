@@ -79,7 +79,7 @@ pub fn restoration(meta: CardMetadata) -> CardDefinition {
                                     .effect_color(design::YELLOW_900),
                             )
                             .apply(g);
-                        prompts::push(g, Side::Riftcaller, s);
+                        prompts::push(g, Side::Riftcaller, s)?;
                         Ok(())
                     }),
                 )
@@ -183,7 +183,7 @@ pub fn enduring_radiance(meta: CardMetadata) -> CardDefinition {
                 if s.is_upgraded() {
                     mutations::move_card(g, s.card_id(), CardPosition::Hand(s.side()))?;
                 } else {
-                    prompts::push(g, Side::Riftcaller, s);
+                    prompts::push(g, Side::Riftcaller, s)?;
                 }
 
                 Ok(())
@@ -234,7 +234,7 @@ pub fn sift_the_sands(meta: CardMetadata) -> CardDefinition {
                         .effect_color(design::YELLOW_900),
                 )
                 .apply(g);
-            prompts::push_with_data(g, Side::Riftcaller, s, PromptData::Cards(cards));
+            prompts::push_with_data(g, Side::Riftcaller, s, PromptData::Cards(cards))?;
             Ok(())
         }))
         .delegate(this::prompt(|_, s, source, _| {
@@ -374,7 +374,7 @@ pub fn keensight(meta: CardMetadata) -> CardDefinition {
             for occupant in occupants {
                 mutations::reveal_card(g, occupant)?;
             }
-            prompts::push_with_data(g, Side::Riftcaller, s, PromptData::Room(target));
+            prompts::push_with_data(g, Side::Riftcaller, s, PromptData::Room(target))?;
 
             Ok(())
         }))
@@ -474,7 +474,7 @@ pub fn chains_of_binding(meta: CardMetadata) -> CardDefinition {
                     Side::Riftcaller,
                     s,
                     PromptData::Room(played.target.room_id()?),
-                );
+                )?;
                 Ok(())
             }))
             .delegate(this::prompt(|g, s, source, _| {
@@ -578,7 +578,7 @@ pub fn delve_into_darkness(meta: CardMetadata) -> CardDefinition {
                                 Side::Riftcaller,
                                 s,
                                 PromptData::CardPlayId(play_id),
-                            );
+                            )?;
                         } else {
                             custom_access::end(g, InitiatedBy::Ability(s.ability_id()))?;
                         }
@@ -643,7 +643,12 @@ pub fn liminal_transposition(meta: CardMetadata) -> CardDefinition {
                         .ability_alert(s)
                         .apply(g);
 
-                    prompts::push_with_data(g, Side::Riftcaller, s, PromptData::Room(event.target));
+                    prompts::push_with_data(
+                        g,
+                        Side::Riftcaller,
+                        s,
+                        PromptData::Room(event.target),
+                    )?;
                     Ok(())
                 },
             ))
@@ -716,7 +721,7 @@ pub fn echoing_valor(meta: CardMetadata) -> CardDefinition {
             ))
             .delegate(this::at_dawn(|g, s, _| {
                 if g.card(s).position().in_discard_pile() {
-                    prompts::push(g, Side::Riftcaller, s);
+                    prompts::push(g, Side::Riftcaller, s)?;
                 }
                 Ok(())
             }))
@@ -784,3 +789,86 @@ pub fn condemn_to_eternity(meta: CardMetadata) -> CardDefinition {
             .build(),
     }
 }
+
+pub fn overcharge(meta: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::Overcharge,
+        sets: vec![CardSetName::Beryl],
+        cost: costs::mana(meta.upgrade(1, 0)),
+        image: assets::riftcaller_card(meta, "overcharge"),
+        card_type: CardType::Spell,
+        subtypes: vec![CardSubtype::Conjuration],
+        side: Side::Riftcaller,
+        school: School::Law,
+        rarity: Rarity::Uncommon,
+        abilities: vec![Ability::new_with_delegate(
+            text![
+                "The next time you would add",
+                PowerChargeSymbol,
+                "to a card, add twice as many instead"
+            ],
+            this::on_played(|g, s, played| {
+                g.card_mut(s)
+                    .custom_state
+                    .push(CustomCardState::DoubleNextPowerCharges { play_id: played.card_play_id });
+                Ok(())
+            }),
+        )],
+        config: CardConfig::default(),
+    }
+}
+
+pub fn twin_paths(meta: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TwinPaths,
+        sets: vec![CardSetName::Beryl],
+        cost: costs::mana(0),
+        image: assets::riftcaller_card(meta, "twin_paths"),
+        card_type: CardType::Spell,
+        subtypes: vec![CardSubtype::Conjuration],
+        side: Side::Riftcaller,
+        school: School::Law,
+        rarity: Rarity::Common,
+        abilities: vec![Ability::new_with_delegate(
+            text!["Choose one:", "Draw a card", "or", GainMana(meta.upgrade(2, 3))],
+            this::on_played(|g, s, _| {
+                prompts::push(g, Side::Riftcaller, s)?;
+                Ok(())
+            }),
+        )
+        .delegate(this::prompt(|_, s, _, _| {
+            show_prompt::modal(vec![
+                (
+                    PromptChoiceLabel::DrawCards(1),
+                    vec![GameEffect::DrawCards(s.side(), 1, s.initiated_by())],
+                ),
+                (
+                    PromptChoiceLabel::GainMana(s.upgrade(2, 3)),
+                    vec![GameEffect::GainMana(s.side(), s.upgrade(2, 3))],
+                ),
+            ])
+        }))],
+        config: CardConfig::default(),
+    }
+}
+
+pub fn forage(meta: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::Forage,
+        sets: vec![CardSetName::Beryl],
+        cost: costs::mana(1),
+        image: assets::riftcaller_card(meta, "forage"),
+        card_type: CardType::Spell,
+        subtypes: vec![CardSubtype::Conjuration],
+        side: Side::Riftcaller,
+        school: School::Law,
+        rarity: Rarity::Common,
+        abilities: vec![Ability::new_with_delegate(
+            text![text!["Draw", meta.upgrade(2, 3), "cards"], text!["Discard a card"]],
+            this::on_played(|g, s, _| {
+                mutations::draw_then_discard(g, s.side(), s.upgrade(2, 3), 1, s.initiated_by())
+            }),
+        )],
+        config: CardConfig::default(),
+    }
+}
@@ -14,24 +14,32 @@
 
 //! Test cards
 
-use card_definition_data::ability_data::{Ability, AbilityType};
-use card_definition_data::card_definition::CardDefinition;
+use card_definition_data::ability_data::{Ability, AbilityType, ActivatedAbility};
+use card_definition_data::card_definition::{CardDefinition, Keyword};
 use card_definition_data::cards::CardDefinitionExt;
 use card_helpers::costs::{actions, scheme};
 use card_helpers::text_helpers::named_trigger;
 use card_helpers::this::on_activated;
 use card_helpers::{abilities, combat_abilities, *};
 use core_data::game_primitives::{
-    CardSubtype, CardType, InitiatedBy, Rarity, Resonance, School, Side, Sprite,
+    CardSubtype, CardType, HealthValue, InitiatedBy, Rarity, Resonance, RoomId, RoomLocation,
+    School, Side, Sprite,
 };
 use game_data::card_configuration::{AttackBoost, CardConfig, CardConfigBuilder, SchemePoints};
 use game_data::card_name::{CardMetadata, CardName};
 use game_data::card_set_name::CardSetName;
-use game_data::card_state::{CardIdsExt, CardPosition};
+use game_data::card_state::{CardCounter, CardIdsExt, CardPosition};
 use game_data::delegate_data::{GameDelegate, QueryDelegate, RaidOutcome};
+use game_data::game_effect::GameEffect;
+use game_data::prompt_data::{
+    ButtonPrompt, ChooseMultiplePromptEffect, DistributePromptEffect, GamePrompt, PromptChoice,
+    PromptChoiceLabel,
+};
 use game_data::special_effects::{Projectile, ProjectileData, TimedEffect};
 use rules::mutations::{OnZeroStored, SummonMinion};
-use rules::{curses, damage, destroy, draw_cards, end_raid, mutations};
+use rules::{
+    curses, custom_state, damage, destroy, draw_cards, end_raid, mana, mutations, prompts,
+};
 
 pub fn test_ritual(_: CardMetadata) -> CardDefinition {
     CardDefinition {
@@ -838,3 +846,658 @@ pub fn test_charge_artifact(metadata: CardMetadata) -> CardDefinition {
         ..test_spell(metadata)
     }
 }
+
+pub fn test_spell_reveal_top_card_draw_if_weapon(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestSpellRevealTopCardDrawIfWeapon,
+        cost: cost(0),
+        card_type: CardType::Spell,
+        sets: vec![CardSetName::Test],
+        abilities: vec![Ability::new_with_delegate(
+            text!["Reveal the top card of your deck. If it is a weapon, draw it"],
+            this::on_played(|g, s, _| {
+                mutations::reveal_top_and_conditionally_draw(
+                    g,
+                    s.side(),
+                    s.initiated_by(),
+                    |g, card_id| {
+                        g.card(card_id)
+                            .definition()
+                            .has_keyword(Keyword::Subtype(CardSubtype::Weapon))
+                    },
+                )?;
+                Ok(())
+            }),
+        )],
+        ..test_spell(metadata)
+    }
+}
+
+pub fn test_minion_inner_room_only(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestMinionInnerRoomOnly,
+        config: CardConfigBuilder::new()
+            .health(test_constants::MINION_HEALTH)
+            .resonance(test_constants::TEST_RESONANCE)
+            .custom_targeting(requirements::inner_room())
+            .build(),
+        ..test_minion_end_raid(metadata)
+    }
+}
+
+pub fn test_project_minions_cost_less_in_defended_rooms(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestProjectMinionsCostLessInDefendedRooms,
+        cost: cost(test_constants::SUMMON_PROJECT_COST),
+        card_type: CardType::Project,
+        abilities: vec![Ability {
+            ability_type: AbilityType::Standard,
+            text: text![
+                "Minions cost",
+                ManaMinus(1),
+                "to summon in rooms with 2 or more other defenders"
+            ],
+            delegates: abilities::game(vec![delegates::mana_cost(
+                |g, s, card_id| {
+                    requirements::face_up_in_play(g, s, card_id)
+                        && g.card(*card_id).definition().card_type == CardType::Minion
+                        && match g.card(*card_id).position() {
+                            CardPosition::Room(_, room_id, RoomLocation::Defender) => {
+                                g.defenders_unordered(room_id).filter(|c| c.id != *card_id).count()
+                                    >= 2
+                            }
+                            _ => false,
+                        }
+                },
+                |_, _, _, cost| cost.map(|c| c.saturating_sub(1)),
+            )]),
+        }],
+        config: CardConfig::default(),
+        ..test_ritual(metadata)
+    }
+}
+
+pub fn test_project_pay_or_discard_on_access(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestProjectPayOrDiscardOnAccess,
+        cost: cost(test_constants::SUMMON_PROJECT_COST),
+        card_type: CardType::Project,
+        abilities: vec![abilities::pay_or_discard_on_access::<2>()],
+        config: CardConfig::default(),
+        ..test_ritual(metadata)
+    }
+}
+
+pub fn test_universal_weapon(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestUniversalWeapon,
+        subtypes: vec![CardSubtype::Weapon],
+        config: CardConfigBuilder::new().base_attack(5).resonances(Resonance::universal()).build(),
+        ..test_weapon_2_attack(metadata)
+    }
+}
+
+pub fn test_spell_distribute_power_charges(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestSpellDistributePowerCharges,
+        cost: cost(0),
+        card_type: CardType::Spell,
+        sets: vec![CardSetName::Test],
+        abilities: vec![Ability::new(text!["Distribute", PowerCharges(3), "among your artifacts"])
+            .delegate(this::on_played(|g, s, _| {
+                prompts::push(g, s.side(), s)?;
+                Ok(())
+            }))
+            .delegate(this::prompt(|g, s, _, _| {
+                show_prompt::distribute(
+                    s.ability_id(),
+                    DistributePromptEffect::AddPowerCharges,
+                    3,
+                    g.artifacts().map(|c| c.id).collect(),
+                )
+            }))],
+        ..test_spell(metadata)
+    }
+}
+
+pub fn test_ritual_summon_minion_from_discard(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestRitualSummonMinionFromDiscard,
+        cost: cost(0),
+        card_type: CardType::Ritual,
+        sets: vec![CardSetName::Test],
+        abilities: vec![Ability::new_with_delegate(
+            text!["Summon a minion from the crypt in target room, ignoring all costs"],
+            this::on_played(|g, s, played| {
+                let Some(minion) = g
+                    .discard_pile(Side::Covenant)
+                    .find(|c| c.definition().is_minion())
+                    .map(|c| c.id)
+                else {
+                    return Ok(());
+                };
+                mutations::summon_from_discard(
+                    g,
+                    minion,
+                    played.target.room_id()?,
+                    s.initiated_by(),
+                )
+            }),
+        )],
+        config: CardConfigBuilder::new().custom_targeting(requirements::any_room()).build(),
+        ..test_ritual(metadata)
+    }
+}
+
+pub fn test_spell_first_play_bonus(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestSpellFirstPlayBonus,
+        cost: cost(0),
+        abilities: vec![Ability::new_with_delegate(
+            text![
+                GainMana(1),
+                "The first time this card is played each game,",
+                GainMana(2),
+                "Return this card to your hand"
+            ],
+            this::on_played(|g, s, _| {
+                mana::gain(g, s.side(), 1);
+                custom_state::once_per_game(g, s, |g, s| {
+                    mana::gain(g, s.side(), 2);
+                    Ok(())
+                })?;
+                mutations::move_card(g, s.card_id(), CardPosition::Hand(s.side()))
+            }),
+        )],
+        ..test_spell(metadata)
+    }
+}
+
+pub fn test_ritual_look_at_opponent_top_card(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestRitualLookAtOpponentTopCard,
+        cost: cost(0),
+        abilities: vec![Ability::new_with_delegate(
+            text!["Look at the top card of the Riftcaller's deck"],
+            this::on_played(|g, s, _| {
+                mutations::reveal_opponent_top(g, s.side())?;
+                Ok(())
+            }),
+        )],
+        ..test_ritual(metadata)
+    }
+}
+
+pub fn test_ritual_two_prompt_triggers(metadata: CardMetadata) -> CardDefinition {
+    fn gain_mana_prompt() -> Ability {
+        Ability::new(text!["You may", GainMana(1)])
+            .delegate(this::on_played(|g, s, _| {
+                prompts::push(g, s.side(), s)?;
+                Ok(())
+            }))
+            .delegate(this::prompt(|_, s, _, _| {
+                show_prompt::with_choices(vec![
+                    PromptChoice::new().effect(GameEffect::GainMana(s.side(), 1)),
+                    PromptChoice::new().effect(GameEffect::Continue),
+                ])
+            }))
+    }
+
+    CardDefinition {
+        name: CardName::TestRitualTwoPromptTriggers,
+        cost: cost(0),
+        abilities: vec![gain_mana_prompt(), gain_mana_prompt()],
+        ..test_ritual(metadata)
+    }
+}
+
+pub fn test_ritual_destroy_up_to_two_artifacts(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestRitualDestroyUpToTwoArtifacts,
+        cost: cost(0),
+        abilities: vec![Ability::new(text!["Destroy up to two artifacts"])
+            .delegate(this::on_played(|g, s, _| {
+                prompts::push(g, s.side(), s)?;
+                Ok(())
+            }))
+            .delegate(this::prompt(|g, s, _, _| {
+                show_prompt::choose_multiple(
+                    s.ability_id(),
+                    ChooseMultiplePromptEffect::DestroyCards,
+                    g.artifacts().map(|c| c.id).collect(),
+                    2,
+                )
+            }))],
+        ..test_ritual(metadata)
+    }
+}
+
+pub fn test_project_limit_access_to_one(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestProjectLimitAccessToOne,
+        cost: cost(test_constants::SUMMON_PROJECT_COST),
+        card_type: CardType::Project,
+        abilities: vec![abilities::limit_access::<1>()],
+        config: CardConfig::default(),
+        ..test_ritual(metadata)
+    }
+}
+
+pub fn test_spell_return_card_from_discard(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestSpellReturnCardFromDiscard,
+        cost: cost(0),
+        abilities: vec![Ability::new(text!["Return a card from your discard pile to your hand"])
+            .delegate(this::on_played(|g, s, _| {
+                prompts::push(g, s.side(), s)?;
+                Ok(())
+            }))
+            .delegate(this::prompt(|g, s, _, _| {
+                show_prompt::choose_in_discard(
+                    g.discard_pile(s.side()).map(|c| c.id).filter(|id| *id != s.card_id()),
+                    |card_id| {
+                        PromptChoice::new()
+                            .effect(GameEffect::MoveCard(card_id, CardPosition::Hand(s.side())))
+                            .custom_label(PromptChoiceLabel::Return)
+                    },
+                )
+            }))],
+        ..test_spell(metadata)
+    }
+}
+
+pub fn test_weapon_once_per_raid(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestWeaponOncePerRaid,
+        abilities: vec![abilities::use_once_per_raid()],
+        config: CardConfigBuilder::new()
+            .base_attack(test_constants::MINION_HEALTH)
+            .resonance(test_constants::TEST_RESONANCE)
+            .build(),
+        ..test_weapon_2_attack(metadata)
+    }
+}
+
+pub fn test_minion_banish_until_end_of_turn(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestMinionBanishUntilEndOfTurn,
+        abilities: vec![Ability::new(named_trigger(
+            Combat,
+            text![text!["End the raid"], text!["Banish this minion until end of turn"]],
+        ))
+        .delegate(this::combat(|g, s, _| {
+            end_raid::run(g, InitiatedBy::Ability(s.ability_id()), RaidOutcome::Failure)?;
+            mutations::banish_until_end_of_turn(g, s.card_id())
+        }))],
+        ..test_minion_end_raid(metadata)
+    }
+}
+
+pub fn test_growth_artifact(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestGrowthArtifact,
+        cost: cost(test_constants::ARTIFACT_COST),
+        card_type: CardType::Artifact,
+        abilities: vec![
+            ActivatedAbility::new(actions(1), text!["Put a growth counter on this card"])
+                .delegate(this::on_activated(|g, s, _| {
+                    g.card_mut(s).add_counters(CardCounter::Growth, 1);
+                    Ok(())
+                }))
+                .build(),
+            ActivatedAbility::new(
+                costs::sacrifice(),
+                text!["Gain", Mana(1), "for each growth counter on this card"],
+            )
+            .delegate(this::on_activated(|g, s, _| {
+                let growth = g.card(s).last_known_counters(CardCounter::Growth);
+                mana::gain(g, s.side(), growth);
+                Ok(())
+            }))
+            .build(),
+        ],
+        config: CardConfig::default(),
+        ..test_spell(metadata)
+    }
+}
+
+pub fn test_spell_optional_pay_to_draw(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestSpellOptionalPayToDraw,
+        cost: cost(0),
+        abilities: vec![Ability::new(text!["You may pay", Mana(3), "to draw a card"]).delegate(
+            this::on_played(|g, s, _| {
+                prompts::push_immediate(
+                    g,
+                    s.side(),
+                    GamePrompt::ButtonPrompt(ButtonPrompt {
+                        context: None,
+                        choices: vec![
+                            PromptChoice::new()
+                                .effect(GameEffect::ManaCost(s.side(), 3, s.initiated_by()))
+                                .effect(GameEffect::DrawCards(s.side(), 1, s.initiated_by())),
+                            PromptChoice::new_continue(),
+                        ],
+                    }),
+                )?;
+                Ok(())
+            }),
+        )],
+        ..test_spell(metadata)
+    }
+}
+
+pub fn test_sacrifice_draw_two_artifact(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestSacrificeDrawTwoArtifact,
+        cost: cost(test_constants::ARTIFACT_COST),
+        card_type: CardType::Artifact,
+        abilities: vec![abilities::sacrifice_to_draw_cards::<2>()],
+        config: CardConfig::default(),
+        ..test_spell(metadata)
+    }
+}
+
+pub fn test_artifact_turn_mana_on_vault_access(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestArtifactTurnManaOnVaultAccess,
+        cost: cost(test_constants::ARTIFACT_COST),
+        card_type: CardType::Artifact,
+        abilities: vec![abilities::gain_turn_mana_on_vault_access::<2>()],
+        config: CardConfig::default(),
+        ..test_spell(metadata)
+    }
+}
+
+pub fn test_minion_damage_on_encounter(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestMinionDamageOnEncounter,
+        cost: cost(test_constants::MINION_COST),
+        abilities: vec![Ability::new_with_delegate(
+            text!["When a minion is encountered,", DealDamage(1)],
+            in_play::on_minion_encountered(|g, s, _| damage::deal(g, s, 1)),
+        )],
+        card_type: CardType::Minion,
+        config: CardConfigBuilder::new()
+            .health(test_constants::MINION_HEALTH)
+            .resonance(test_constants::TEST_RESONANCE)
+            .build(),
+        ..test_ritual(metadata)
+    }
+}
+
+pub fn test_spell_raid_vault_access_top_two(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestSpellRaidVaultAccessTopTwo,
+        cost: cost(test_constants::SPELL_COST),
+        card_type: CardType::Spell,
+        abilities: vec![Ability::new(text!["Raid the", Vault, ", accessing the top", 2, "cards"])
+            .delegate(this::on_played(|g, s, _| raids::initiate(g, s, RoomId::Vault)))
+            .delegate(raids::access_top_of_vault::<2>(requirements::matching_raid))],
+        ..test_spell(metadata)
+    }
+}
+
+pub fn test_minion_move_on_encounter(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestMinionMoveOnEncounter,
+        cost: cost(test_constants::MINION_COST),
+        abilities: vec![Ability::new_with_delegate(
+            named_trigger(Combat, text!["Move this minion to defend another room"]),
+            this::combat(|g, s, _| {
+                let to_room = match g.card(s.card_id()).position().defending_room() {
+                    Some(RoomId::RoomA) => RoomId::RoomB,
+                    _ => RoomId::RoomA,
+                };
+                mutations::move_minion(g, s.card_id(), to_room)
+            }),
+        )],
+        card_type: CardType::Minion,
+        config: CardConfigBuilder::new()
+            .health(test_constants::MINION_HEALTH)
+            .resonance(test_constants::TEST_RESONANCE)
+            .build(),
+        ..test_ritual(metadata)
+    }
+}
+
+pub fn test_project_end_raid_unless_weapon_revealed(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestProjectEndRaidUnlessWeaponRevealed,
+        cost: cost(test_constants::SUMMON_PROJECT_COST),
+        card_type: CardType::Project,
+        abilities: vec![abilities::end_raid_on_access_unless_weapon_revealed()],
+        config: CardConfig::default(),
+        ..test_ritual(metadata)
+    }
+}
+
+pub fn test_prismatic_minion(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestPrismaticMinion,
+        config: CardConfigBuilder::new()
+            .health(test_constants::MINION_HEALTH)
+            .resonance(Resonance::Astral)
+            .resonance(Resonance::Infernal)
+            .resonance(Resonance::Prismatic)
+            .build(),
+        ..test_minion_end_raid(metadata)
+    }
+}
+
+pub fn test_ritual_banish_from_opponent_discard(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestRitualBanishFromOpponentDiscard,
+        cost: cost(0),
+        abilities: vec![Ability::new(text!["Banish a card from the opponent's discard pile"])
+            .delegate(this::on_played(|g, s, _| {
+                prompts::push(g, s.side(), s)?;
+                Ok(())
+            }))
+            .delegate(this::prompt(|g, s, _, _| {
+                let opponent = s.side().opponent();
+                show_prompt::banish_from_discard(opponent, g.discard_pile(opponent).map(|c| c.id))
+            }))],
+        ..test_ritual(metadata)
+    }
+}
+
+pub fn test_spell_repeatedly_push_prompts(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestSpellRepeatedlyPushPrompts,
+        cost: cost(0),
+        abilities: vec![Ability::new(text!["Show prompts forever"])
+            .delegate(this::on_played(|g, s, _| loop {
+                prompts::push(g, s.side(), s)?;
+            }))
+            .delegate(this::prompt(|_, s, _, _| {
+                show_prompt::with_choices(vec![
+                    PromptChoice::new().effect(GameEffect::GainMana(s.side(), 1))
+                ])
+            }))],
+        ..test_spell(metadata)
+    }
+}
+
+pub fn test_spell_discard_to_reduce_cost(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestSpellDiscardToReduceCost,
+        cost: costs::discard_to_reduce::<2>(3),
+        abilities: vec![Ability::new(text![
+            "You may discard a card to reduce this card's cost by",
+            ManaMinus(2)
+        ])],
+        ..test_spell(metadata)
+    }
+}
+
+pub fn test_spell_dig_4_for_weapon(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestSpellDig4ForWeapon,
+        cost: cost(0),
+        abilities: vec![Ability::new_with_delegate(
+            text![
+                "Reveal the top 4 cards of your deck.",
+                "Draw the first weapon revealed and put the rest on the bottom of your deck"
+            ],
+            this::on_played(|g, s, _| {
+                mutations::dig_for(
+                    g,
+                    s.side(),
+                    s.initiated_by(),
+                    |g, card_id| {
+                        g.card(card_id)
+                            .definition()
+                            .has_keyword(Keyword::Subtype(CardSubtype::Weapon))
+                    },
+                    4,
+                )?;
+                Ok(())
+            }),
+        )],
+        ..test_spell(metadata)
+    }
+}
+
+pub fn test_ritual_draw_card(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestRitualDrawCard,
+        cost: cost(0),
+        abilities: vec![Ability::new_with_delegate(
+            text!["Draw a card"],
+            this::on_played(|g, s, _| draw_cards::run(g, s.side(), 1, s.initiated_by())),
+        )],
+        ..test_ritual(metadata)
+    }
+}
+
+pub fn test_minion_health_per_other_summoned_minion(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestMinionHealthPerOtherSummonedMinion,
+        abilities: vec![Ability::new_with_delegate(
+            text!["+1 health for each other summoned minion defending this room"],
+            this::health(|g, s, _, health| {
+                let Some(room_id) = g.card(s.card_id()).position().defending_room() else {
+                    return health;
+                };
+                health
+                    + g.defenders_unordered(room_id)
+                        .filter(|c| c.id != s.card_id() && c.is_face_up())
+                        .count() as HealthValue
+            }),
+        )],
+        ..test_minion_end_raid(metadata)
+    }
+}
+
+pub fn test_evocation_gain_mana_at_dawn(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestEvocationGainManaAtDawn,
+        abilities: vec![Ability::new_with_delegate(
+            named_trigger(Dawn, text![GainMana(1)]),
+            in_play::at_dawn(|g, s, _| {
+                mana::gain(g, s.side(), 1);
+                Ok(())
+            }),
+        )],
+        ..test_evocation(metadata)
+    }
+}
+
+pub fn test_evocation_first_raid_each_turn_free(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestEvocationFirstRaidEachTurnFree,
+        abilities: vec![Ability::new_with_delegate(
+            text!["Your first raid each turn costs no action points"],
+            in_play::on_raid_started(|g, s, _| {
+                let via_action = g
+                    .raid
+                    .as_ref()
+                    .is_some_and(|raid| raid.initiated_by == InitiatedBy::GameAction);
+                if via_action && history::rooms_raided_via_action_this_turn(g).count() == 1 {
+                    mutations::gain_action_points(g, s.side(), 1)?;
+                }
+                Ok(())
+            }),
+        )],
+        ..test_evocation(metadata)
+    }
+}
+
+pub fn test_scheme_score_points_at_dusk(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestSchemeScorePointsAtDusk,
+        abilities: vec![abilities::score_points_at_dusk::<5, 20>()],
+        ..test_scheme_310(metadata)
+    }
+}
+
+pub fn test_project_discard_to_gain_mana(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestProjectDiscardToGainMana,
+        cost: cost(test_constants::SUMMON_PROJECT_COST),
+        card_type: CardType::Project,
+        abilities: vec![ActivatedAbility::new(
+            costs::discard_cards_and_action::<2>(),
+            text![GainMana(5)],
+        )
+        .delegate(this::on_activated(|g, s, _| {
+            mana::gain(g, s.side(), 5);
+            Ok(())
+        }))
+        .build()],
+        config: CardConfig::default(),
+        ..test_ritual(metadata)
+    }
+}
+
+pub fn test_scheme_draw_card_on_score(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestSchemeDrawCardOnScore,
+        abilities: vec![Ability::new_with_delegate(
+            named_trigger(Score, text!["Draw a card"]),
+            this::on_scored_by_covenant(|g, s, _| {
+                draw_cards::run(g, s.side(), 1, s.initiated_by())
+            }),
+        )],
+        ..test_scheme_310(metadata)
+    }
+}
+
+pub fn test_project_count_minion_summons(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestProjectCountMinionSummons,
+        cost: cost(test_constants::SUMMON_PROJECT_COST),
+        card_type: CardType::Project,
+        abilities: vec![Ability::new_with_delegate(
+            text!["When a minion is summoned, add a growth counter to this card"],
+            in_play::on_minion_summoned(|g, s, _| {
+                g.card_mut(s).add_counters(CardCounter::Growth, 1);
+                Ok(())
+            }),
+        )],
+        config: CardConfig::default(),
+        ..test_ritual(metadata)
+    }
+}
+
+pub fn test_artifact_prompt_on_access(metadata: CardMetadata) -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestArtifactPromptOnAccess,
+        cost: cost(test_constants::ARTIFACT_COST),
+        card_type: CardType::Artifact,
+        abilities: vec![Ability::new(text!["When you access a room, you may", GainMana(1)])
+            .delegate(in_play::on_raid_access_start(|g, s, _| {
+                prompts::push(g, s.side(), s)?;
+                Ok(())
+            }))
+            .delegate(this::prompt(|_, s, _, _| {
+                show_prompt::with_choices(vec![
+                    PromptChoice::new().effect(GameEffect::GainMana(s.side(), 1)),
+                    PromptChoice::new_continue(),
+                ])
+            }))],
+        config: CardConfig::default(),
+        ..test_spell(metadata)
+    }
+}
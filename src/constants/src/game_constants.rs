@@ -39,3 +39,10 @@ pub const MAXIMUM_ALLIES_IN_PLAY: usize = 5;
 pub const COST_TO_REMOVE_CURSE: u32 = 2;
 
 pub const COST_TO_DISPEL_EVOCATION: u32 = 2;
+
+/// Maximum number of copies of a single card which a deck can contain.
+pub const MAXIMUM_COPIES_IN_DECK: u32 = 3;
+
+/// Maximum number of entries in a player's prompt stack. Adding a prompt beyond
+/// this depth is an error, which prevents buggy abilities from looping forever.
+pub const MAXIMUM_PROMPT_STACK_DEPTH: usize = 64;
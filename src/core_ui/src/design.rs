@@ -67,6 +67,7 @@ pub const OVERLAY_BORDER: FlexColor = BLACK;
 pub const COIN_COUNT_BORDER: FlexColor = GRAY_500;
 pub const DISABLED_BUTTON_TINT: FlexColor = BLACK_ALPHA_50;
 pub const PLAY_CARD_BROWSER_OUTLINE: FlexColor = YELLOW_500;
+pub const DROP_TARGET_HIGHLIGHT: FlexColor = YELLOW_500;
 
 /// Converts a [FlexColor] into a hex code representation.
 pub fn as_hex(input: impl Into<FlexColor>) -> String {
@@ -16,14 +16,15 @@ use element_names::ElementName;
 use protos::riftcaller::{node_type, ClientAction, DraggableNode, Node, NodeType};
 
 use crate::actions::InterfaceAction;
-use crate::flexbox;
 use crate::flexbox::HasNodeChildren;
 use crate::prelude::*;
+use crate::{actions, flexbox, interface_animations};
 
 #[derive(Default)]
 pub struct Draggable {
     render_node: Node,
     draggable: DraggableNode,
+    drop_targets: Vec<ElementName>,
     children: Vec<Node>,
     over_target_indicator: Option<Box<dyn Fn() -> Option<Node>>>,
     enabled: bool,
@@ -38,6 +39,7 @@ impl Draggable {
 
     pub fn drop_target(mut self, identifier: ElementName) -> Self {
         self.draggable.drop_target_identifiers = vec![identifier.into()];
+        self.drop_targets = vec![identifier];
         self
     }
 
@@ -84,6 +86,10 @@ impl Draggable {
 
     /// Action to invoke when a gesture has been confirmed as a drag, i.e. the
     /// element has been dragged through some fixed distance.
+    ///
+    /// When this element is enabled, drop targets are additionally highlighted
+    /// on drag and the highlight is removed when the drag ends, see
+    /// [Self::drop_target].
     pub fn on_drag_detected(mut self, action: Option<impl InterfaceAction + 'static>) -> Self {
         self.enabled = action.is_some();
         self.draggable.on_drag_detected =
@@ -114,6 +120,17 @@ impl Component for Draggable {
             None
         };
 
+        if self.enabled && !self.drop_targets.is_empty() {
+            self.draggable.drag_detected_update = Some(actions::command_list(
+                None,
+                vec![interface_animations::highlight_drop_targets(&self.drop_targets)],
+            ));
+            self.draggable.drag_ended_update = Some(actions::command_list(
+                None,
+                vec![interface_animations::clear_drop_target_highlights(&self.drop_targets)],
+            ));
+        }
+
         self.render_node.node_type = Some(Box::new(NodeType {
             node_type: Some(node_type::NodeType::DraggableNode(Box::new(self.draggable))),
         }));
@@ -39,3 +39,5 @@ pub const EYE_SLASH: &str = "\u{f070}";
 pub const CARET_UP: &str = "\u{f0d8}";
 pub const ANGLE_UP: &str = "\u{f106}";
 pub const LINK: &str = "\u{f0c1}";
+pub const SORT_NUMERIC_DOWN: &str = "\u{f162}";
+pub const LAYER_GROUP: &str = "\u{f5fd}";
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use element_names::{ElementNameSelector, TargetName};
+use element_names::{ElementName, ElementNameSelector, TargetName};
 use protos::riftcaller::animate_element_style::Property;
 use protos::riftcaller::game_command::Command;
 use protos::riftcaller::interface_update::Update;
@@ -22,6 +22,7 @@ use protos::riftcaller::{
     UpdateInterfaceStep,
 };
 
+use crate::design;
 use crate::prelude::*;
 
 /// Combines a list of [InterfaceAnimation]s into a new single merged animation.
@@ -45,6 +46,27 @@ pub fn set_displayed(element: impl ElementNameSelector, displayed: bool) -> Comm
     InterfaceAnimation::new().start(element, SetDisplayed { displayed }).into()
 }
 
+/// [Command] to outline each of the provided drop target elements, indicating
+/// that the element currently being dragged can be dropped onto them.
+pub fn highlight_drop_targets(targets: &[ElementName]) -> Command {
+    set_drop_targets_highlighted(targets, true)
+}
+
+/// [Command] to remove the outline added to drop target elements via
+/// [highlight_drop_targets].
+pub fn clear_drop_target_highlights(targets: &[ElementName]) -> Command {
+    set_drop_targets_highlighted(targets, false)
+}
+
+fn set_drop_targets_highlighted(targets: &[ElementName], highlighted: bool) -> Command {
+    targets
+        .iter()
+        .fold(InterfaceAnimation::new(), |animation, target| {
+            animation.start(*target, HighlightDropTarget { highlighted })
+        })
+        .into()
+}
+
 /// Builder to construct animated updates to user interface elements
 #[derive(Default)]
 pub struct InterfaceAnimation {
@@ -180,6 +202,29 @@ impl ElementUpdate for AnimateToElement {
     }
 }
 
+/// Immediately outlines an element to mark it as a valid drop target, or
+/// removes that outline.
+///
+/// Applied styles are merged with the element's original style by the
+/// client, so the outline is removed by applying an empty style, which
+/// restores any border the element originally had.
+pub struct HighlightDropTarget {
+    pub highlighted: bool,
+}
+
+impl ElementUpdate for HighlightDropTarget {
+    fn build(self) -> Update {
+        let style = if self.highlighted {
+            Style::new()
+                .border_color(Edge::All, design::DROP_TARGET_HIGHLIGHT)
+                .border_width(Edge::All, 4.px())
+        } else {
+            Style::new()
+        };
+        Update::ApplyStyle(*style.wrapped_style())
+    }
+}
+
 pub struct SetDisplayed {
     pub displayed: bool,
 }
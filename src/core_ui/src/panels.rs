@@ -14,6 +14,7 @@
 
 use std::collections::HashMap;
 
+use element_names::ElementNameSelector;
 use protos::riftcaller::client_action::Action;
 use protos::riftcaller::game_command::Command;
 use protos::riftcaller::toggle_panel_command::ToggleCommand;
@@ -24,6 +25,7 @@ use protos::riftcaller::{
 use user_action_data::UserAction;
 
 use crate::actions::{self, InterfaceAction};
+use crate::conditional::Conditional;
 
 /// Fluent builder to help open and close panels
 #[derive(Clone)]
@@ -123,3 +125,16 @@ impl InterfaceAction for Panels {
 pub fn update(panel: InterfacePanel) -> Command {
     Command::UpdatePanels(UpdatePanelsCommand { panels: vec![panel] })
 }
+
+/// Command to open the provided panel only if its root element, identified by
+/// `root`, does not already exist on the client.
+///
+/// Re-opening a panel which is already displayed resets its state, so flows
+/// which may render the same panel repeatedly should prefer this to
+/// [Panels::open].
+pub fn ensure_open(
+    address: impl Into<InterfacePanelAddress>,
+    root: impl ElementNameSelector,
+) -> Command {
+    Conditional::if_exists(root).or_else(Panels::open(address)).into()
+}
@@ -15,7 +15,9 @@
 use std::collections::HashMap;
 
 use anyhow::Result;
-use core_data::adventure_primitives::{AdventureOutcome, CardFilterId, Coins, TilePosition};
+use core_data::adventure_primitives::{
+    AdventureOutcome, CardFilterId, Coins, NarrativeEventId, TilePosition,
+};
 use core_data::game_primitives::{AdventureId, Side};
 use game_data::card_name::CardVariant;
 use game_data::card_set_name::CardSetName;
@@ -59,6 +61,13 @@ pub struct DraftData {
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct ShopData {
     pub choices: Vec<CardChoice>,
+    /// Filter used to select replacement cards when this shop restocks.
+    #[serde(default)]
+    pub filter: Option<CardFilterId>,
+    /// Number of tile visits elsewhere on the map since this shop last
+    /// restocked its sold-out inventory.
+    #[serde(default)]
+    pub restock_counter: u32,
 }
 
 /// Data for rendering an opponent character to initiate a battle with
@@ -75,6 +84,14 @@ pub struct BattleData {
 
     /// Coins earned for winning this battle
     pub reward: Coins,
+
+    /// If true, winning this battle wins the adventure.
+    #[serde(default)]
+    pub is_final_battle: bool,
+
+    /// Card added to the player's deck for winning this battle, if any.
+    #[serde(default)]
+    pub guaranteed_reward: Option<CardVariant>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -83,6 +100,8 @@ pub enum TileIcon {
     Shop,
     Battle,
     NarrativeEvent,
+    Hazard,
+    Coins,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -94,11 +113,41 @@ pub struct TileState {
     pub on_visited: Option<AdventureEffect>,
 
     pub icons: Vec<TileIcon>,
+
+    /// Inventory of the shop on this tile, if it has been visited. Shop tiles
+    /// can be visited repeatedly and retain their inventory between visits.
+    #[serde(default)]
+    pub shop: Option<ShopData>,
+
+    /// If specified, the effect on this tile is not removed when it is visited.
+    /// Instead, the tile can be visited again after this many visits to other
+    /// tiles on the map.
+    #[serde(default)]
+    pub cooldown_visits: Option<u32>,
+
+    /// Number of visits to other tiles remaining before this tile can be
+    /// visited again.
+    #[serde(default)]
+    pub cooldown_remaining: u32,
 }
 
 impl TileState {
     pub fn with_sprite(address: impl Into<String>) -> Self {
-        TileState { sprite: address.into(), road: None, on_visited: None, icons: vec![] }
+        TileState {
+            sprite: address.into(),
+            road: None,
+            on_visited: None,
+            icons: vec![],
+            shop: None,
+            cooldown_visits: None,
+            cooldown_remaining: 0,
+        }
+    }
+
+    /// Returns true if this tile has an entity which cannot currently be
+    /// visited because it was visited too recently.
+    pub fn is_on_cooldown(&self) -> bool {
+        self.cooldown_remaining > 0
     }
 }
 
@@ -148,6 +197,15 @@ impl AdventureConfiguration {
         }
     }
 
+    /// Randomly reorders the elements of `slice` in place.
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        if self.rng.is_some() {
+            slice.shuffle(self.rng.as_mut().expect("rng"))
+        } else {
+            slice.shuffle(&mut rand::thread_rng())
+        }
+    }
+
     pub fn gen_range<T, R>(&mut self, range: R) -> T
     where
         T: SampleUniform,
@@ -241,6 +299,39 @@ impl AdventureScreens {
     }
 }
 
+/// Shuffled pile of narrative events which have not yet been seen in the
+/// current adventure, used to select events for
+/// [AdventureEffect::RandomNarrativeEvent] tiles.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventDeck {
+    remaining: Vec<NarrativeEventId>,
+}
+
+impl EventDeck {
+    /// Removes and returns the next event from this deck.
+    ///
+    /// If the deck is empty, it is first refilled with all of the provided
+    /// `events` in a random order determined by `config`. Returns None if no
+    /// events are available.
+    pub fn draw(
+        &mut self,
+        config: &mut AdventureConfiguration,
+        events: impl Iterator<Item = NarrativeEventId>,
+    ) -> Option<NarrativeEventId> {
+        if self.remaining.is_empty() {
+            self.remaining = events.collect();
+            self.remaining.sort_by_key(|id| id.value);
+            config.shuffle(&mut self.remaining);
+        }
+        self.remaining.pop()
+    }
+
+    /// Number of events remaining before the deck is reshuffled.
+    pub fn remaining(&self) -> usize {
+        self.remaining.len()
+    }
+}
+
 /// Stores the primary state for a player during an ongoing adventure
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -257,8 +348,34 @@ pub struct AdventureState {
     pub world_map: WorldMap,
     /// Stack of interstitial screens the player can view during an adventure.
     pub screens: AdventureScreens,
+    /// Tile the player is currently visiting, if any.
+    #[serde(default)]
+    pub visiting_position: Option<TilePosition>,
+    /// Narrative events which can be drawn by event tiles.
+    #[serde(default)]
+    pub event_deck: EventDeck,
     /// Deck being used for this adventure
     pub deck: Deck,
     /// Customization options for this adventure
     pub config: AdventureConfiguration,
 }
+
+impl AdventureState {
+    /// Returns the minimum and maximum [TilePosition] coordinates among all
+    /// revealed tiles in the world map, used to frame the map camera.
+    ///
+    /// Returns the origin for both values if the world map is empty.
+    pub fn bounds(&self) -> (TilePosition, TilePosition) {
+        let mut positions = self.world_map.tiles.keys();
+        let Some(first) = positions.next() else {
+            return (TilePosition::new(0, 0), TilePosition::new(0, 0));
+        };
+
+        positions.fold((*first, *first), |(min, max), position| {
+            (
+                TilePosition::new(min.x.min(position.x), min.y.min(position.y)),
+                TilePosition::new(max.x.max(position.x), max.y.max(position.y)),
+            )
+        })
+    }
+}
@@ -72,8 +72,16 @@ pub enum AdventureEffect {
     Shop(CardFilterId),
     /// Open the narrative event with the given ID
     NarrativeEvent(NarrativeEventId),
+    /// Open a narrative event drawn from the adventure's event deck
+    RandomNarrativeEvent,
     /// Open a 'start battle' screen
     Battle,
+    /// Open a 'start battle' screen for the final battle of this adventure.
+    /// Winning this battle wins the adventure.
+    BossBattle,
+    /// Open a 'start battle' screen for a miniboss battle. Winning this battle
+    /// always adds one copy of the indicated card to the player's deck.
+    MiniBoss(CardName),
     /// Gain a quantity of coins
     GainCoins(Coins),
     /// Lose coins. This choice cannot be selected if insufficient coins are
@@ -81,6 +89,10 @@ pub enum AdventureEffect {
     LoseCoins(Coins),
     /// Lose all coins
     LoseAllCoins,
+    /// A hazard on a map tile which costs coins when visited. Unlike
+    /// [Self::LoseCoins], this is applied even if insufficient coins are
+    /// available, removing all remaining coins.
+    Hazard(Coins),
     /// Gain a quantity of arcanite
     GainArcanite(u32),
     /// The player may pick some number of cards in their deck matching
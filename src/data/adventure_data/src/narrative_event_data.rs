@@ -66,6 +66,9 @@ impl NarrativeEventChoice {
 /// Steps within the progress of resolving a narrative event.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash)]
 pub enum NarrativeEventStep {
+    /// Full-screen splash image and text shown before the introduction, for
+    /// events which have one configured.
+    Splash,
     /// Introductory text for this event.
     Introduction,
     /// View valid narrative choices for this event which have not yet been
@@ -84,12 +87,24 @@ pub struct NarrativeEventData {
     pub image: Sprite,
     /// Narrative description introducing this event.
     pub description: String,
+    /// Optional text to display on a full-screen splash image before showing
+    /// the introduction for this event.
+    pub splash: Option<String>,
     /// List of possible choices within this narrative event, indexed by
     /// [NarrativeChoiceId].
     pub choices: HashMap<NarrativeChoiceId, NarrativeEventChoice>,
 }
 
 impl NarrativeEventData {
+    /// Step to display when this event is first opened.
+    pub fn initial_step(&self) -> NarrativeEventStep {
+        if self.splash.is_some() {
+            NarrativeEventStep::Splash
+        } else {
+            NarrativeEventStep::Introduction
+        }
+    }
+
     pub fn enumerate_choices(
         &self,
     ) -> impl Iterator<Item = (NarrativeChoiceId, &NarrativeEventChoice)> {
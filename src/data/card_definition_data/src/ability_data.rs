@@ -62,6 +62,17 @@ impl Ability {
         self.delegates.push(Delegate::GameDelegate(delegate));
         self
     }
+
+    /// Returns true if this ability can be copied onto another card.
+    ///
+    /// Abilities which show prompts cannot be copied, since a copied prompt
+    /// would be resolved against the copying card's own abilities.
+    pub fn can_be_copied(&self) -> bool {
+        !self
+            .delegates
+            .iter()
+            .any(|d| matches!(d, Delegate::GameDelegate(GameDelegate::ShowPrompt(_))))
+    }
 }
 
 /// Builder helper for activated abilities
@@ -18,9 +18,28 @@ use core_data::game_primitives::{
 use game_data::card_configuration::{CardConfig, Cost};
 use game_data::card_name::{CardName, CardVariant};
 use game_data::card_set_name::CardSetName;
+use game_data::text::{TextElement, TextToken, TextTokenKind};
 
 use crate::ability_data::Ability;
 
+/// A keyword associated with a card, used for text search and by effects which
+/// refer to "cards with keyword X".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Keyword {
+    Subtype(CardSubtype),
+    Text(TextTokenKind),
+}
+
+impl Keyword {
+    /// Name of this keyword as displayed to players
+    pub fn name(&self) -> String {
+        match self {
+            Keyword::Subtype(subtype) => subtype.to_string(),
+            Keyword::Text(kind) => format!("{kind:?}"),
+        }
+    }
+}
+
 /// The fundamental object defining the behavior of a given card in Riftcaller
 ///
 /// This struct's top-level fields should be universal properties which need to
@@ -91,4 +110,66 @@ impl CardDefinition {
     pub fn is_astral(&self) -> bool {
         self.config.resonance.contains(Resonance::Astral)
     }
+
+    /// Returns the [Keyword]s for this card, derived from its subtypes and from
+    /// keyword tokens in its rules text. Each keyword appears at most once.
+    pub fn keywords(&self) -> Vec<Keyword> {
+        let mut result = self.subtypes.iter().map(|s| Keyword::Subtype(*s)).collect::<Vec<_>>();
+        for ability in &self.abilities {
+            add_text_keywords(&ability.text, &mut result);
+        }
+        result
+    }
+
+    /// Returns true if this card has the provided [Keyword], e.g. for effects
+    /// which refer to "your Charge weapons".
+    pub fn has_keyword(&self, keyword: Keyword) -> bool {
+        match keyword {
+            Keyword::Subtype(subtype) => self.subtypes.contains(&subtype),
+            Keyword::Text(_) => self.keywords().contains(&keyword),
+        }
+    }
+}
+
+fn add_text_keywords(text: &[TextElement], result: &mut Vec<Keyword>) {
+    for element in text {
+        match element {
+            TextElement::Children(children) => add_text_keywords(children, result),
+            TextElement::NamedTrigger(token, children) => {
+                add_token_keyword(*token, result);
+                add_text_keywords(children, result);
+            }
+            TextElement::Activated { cost, effect }
+            | TextElement::EncounterAbility { cost, effect } => {
+                add_text_keywords(cost, result);
+                add_text_keywords(effect, result);
+            }
+            TextElement::Token(token) => add_token_keyword(*token, result),
+            TextElement::CardName(_) | TextElement::Literal(_) | TextElement::Reminder(_) => {}
+        }
+    }
+}
+
+fn add_token_keyword(token: TextToken, result: &mut Vec<Keyword>) {
+    let kind = match token.kind() {
+        TextTokenKind::Curse | TextTokenKind::Curses | TextTokenKind::Cursed => {
+            TextTokenKind::Curse
+        }
+        TextTokenKind::Leyline | TextTokenKind::Leylines => TextTokenKind::Leyline,
+        TextTokenKind::Evade | TextTokenKind::Evaded | TextTokenKind::Evading => {
+            TextTokenKind::Evade
+        }
+        kind @ (TextTokenKind::Trap
+        | TextTokenKind::Wound
+        | TextTokenKind::Breach
+        | TextTokenKind::Banish
+        | TextTokenKind::Permanent
+        | TextTokenKind::Unsummon) => kind,
+        _ => return,
+    };
+
+    let keyword = Keyword::Text(kind);
+    if !result.contains(&keyword) {
+        result.push(keyword);
+    }
 }
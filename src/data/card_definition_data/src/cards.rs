@@ -16,7 +16,7 @@ use std::collections::HashMap;
 
 use core_data::game_primitives::AbilityId;
 use dashmap::DashSet;
-use game_data::card_name::{CardMetadata, CardVariant};
+use game_data::card_name::{CardMetadata, CardName, CardVariant};
 use game_data::card_state::CardState;
 use game_data::game_state::GameState;
 use once_cell::sync::Lazy;
@@ -58,6 +58,26 @@ pub fn get(variant: CardVariant) -> &'static CardDefinition {
         .unwrap_or_else(|| panic!("Card not found. Call initialize() or update cards?"))
 }
 
+/// Returns the names of all known cards which match a text search `query`,
+/// sorted by name.
+///
+/// A card matches if any word of its name or the name of any of its
+/// [crate::card_definition::Keyword]s begins with `query`, ignoring case.
+pub fn filter_cards(query: &str) -> Vec<CardName> {
+    let query = query.to_lowercase();
+    let mut result = all_cards()
+        .filter(|definition| !definition.config.metadata.is_upgraded)
+        .filter(|definition| {
+            let matches = |word: &str| word.to_lowercase().starts_with(&query);
+            definition.name.displayed_name().split(' ').any(matches)
+                || definition.keywords().iter().any(|keyword| matches(&keyword.name()))
+        })
+        .map(|definition| definition.name)
+        .collect::<Vec<_>>();
+    result.sort();
+    result
+}
+
 pub fn ability_definition(game: &GameState, ability_id: AbilityId) -> &'static Ability {
     game.card(ability_id.card_id).definition().ability(ability_id.index)
 }
@@ -49,6 +49,9 @@ pub type CopiesCount = u32;
 pub enum DeckId {
     /// The current deck being used in adventure mode
     Adventure,
+    /// A deck the player has built and saved, identified by a number which is
+    /// unique among that player's saved decks.
+    Saved(u64),
 }
 
 /// Identifies an ongoing game
@@ -676,6 +679,9 @@ impl InitiatedBy {
 
 /// The possible resonances of weapons and minions. Minions can only be
 /// damaged by weapons from the same resonance, or by Prismatic weapons.
+///
+/// Prismatic minions can only be damaged by weapons which share *every* other
+/// resonance of the minion, or by Prismatic weapons.
 #[derive(Hash, Debug, Display, EnumString, Serialize, Deserialize, EnumSetType)]
 pub enum Resonance {
     Mortal,
@@ -685,6 +691,27 @@ pub enum Resonance {
 }
 
 impl Resonance {
+    /// Resonance set which satisfies the resonance requirement of every minion,
+    /// including any resonance types added in the future.
+    ///
+    /// This is every resonance other than Prismatic, so a universal card is
+    /// not itself treated as a Prismatic card.
+    pub fn universal() -> EnumSet<Resonance> {
+        EnumSet::all() - Resonance::Prismatic
+    }
+
+    /// Returns true if a weapon with the `weapon` resonance set is able to
+    /// damage a minion with the `minion` resonance set.
+    pub fn can_damage(weapon: EnumSet<Resonance>, minion: EnumSet<Resonance>) -> bool {
+        if weapon.contains(Resonance::Prismatic) {
+            true
+        } else if minion.contains(Resonance::Prismatic) {
+            weapon.is_superset(minion - Resonance::Prismatic)
+        } else {
+            !weapon.is_disjoint(minion)
+        }
+    }
+
     pub fn basic_resonance_count(set: EnumSet<Resonance>) -> u32 {
         (if set.contains(Resonance::Mortal) { 1 } else { 0 })
             + (if set.contains(Resonance::Infernal) { 1 } else { 0 })
@@ -54,6 +54,11 @@ pub struct CustomCost<T> {
     /// Optionally, a description of this cost to include before the ':'
     /// character.
     pub description: Option<TextElement>,
+
+    /// Optionally, the largest amount by which paying this cost can currently
+    /// reduce the mana cost of the card being played. Used to check whether
+    /// the card's mana cost can be paid before this cost is paid.
+    pub mana_reduction: Option<fn(&GameState, T) -> ManaValue>,
 }
 
 impl<T> Debug for CustomCost<T> {
@@ -302,6 +307,13 @@ impl CardConfigBuilder {
         self
     }
 
+    /// Adds every [Resonance] in the provided set to this card, e.g.
+    /// [Resonance::universal].
+    pub fn resonances(mut self, resonances: EnumSet<Resonance>) -> Self {
+        self.config.resonance.insert_all(resonances);
+        self
+    }
+
     pub fn custom_targeting(mut self, targeting: TargetRequirement<CardId>) -> Self {
         self.config.custom_targeting = Some(targeting);
         self
@@ -222,6 +222,90 @@ pub enum CardName {
     TestAllyAccessAdditionalSanctumCard,
     TestAllyAccessAdditionalVaultCard,
     TestChargeArtifact,
+    /// Spell which reveals the top card of the Riftcaller's deck and draws it
+    /// if it is a weapon.
+    TestSpellRevealTopCardDrawIfWeapon,
+    /// Equivalent to `TestMinionEndRaid` which can only be played in inner
+    /// rooms
+    TestMinionInnerRoomOnly,
+    /// Project which reduces the cost of minions summoned in heavily-defended
+    /// rooms
+    TestProjectMinionsCostLessInDefendedRooms,
+    TestProjectPayOrDiscardOnAccess,
+    /// Weapon with 5 attack, no boost, and every resonance
+    TestUniversalWeapon,
+    /// Spell which distributes 3 power charges among your artifacts
+    TestSpellDistributePowerCharges,
+    /// Ritual which summons a minion from the crypt into the targeted room
+    TestRitualSummonMinionFromDiscard,
+    /// Spell which returns to hand and grants bonus mana only the first time it
+    /// is played
+    TestSpellFirstPlayBonus,
+    /// Ritual which looks at the top card of the Riftcaller's deck
+    TestRitualLookAtOpponentTopCard,
+    /// Ritual with two abilities which each show a prompt when played
+    TestRitualTwoPromptTriggers,
+    /// Ritual which destroys up to two artifacts
+    TestRitualDestroyUpToTwoArtifacts,
+    /// Project which limits the Riftcaller to scoring or razing one card when
+    /// accessing its room
+    TestProjectLimitAccessToOne,
+    /// Spell which returns a chosen card from your discard pile to your hand
+    TestSpellReturnCardFromDiscard,
+    /// Weapon which can only be used once per raid
+    TestWeaponOncePerRaid,
+    /// Minion which ends the raid and banishes itself until end of turn
+    TestMinionBanishUntilEndOfTurn,
+    /// Artifact which can gain growth counters and be sacrificed to gain mana
+    /// for each of them
+    TestGrowthArtifact,
+    /// Spell which immediately shows a prompt to optionally pay mana to draw a
+    /// card
+    TestSpellOptionalPayToDraw,
+    /// Artifact which can be sacrificed to draw 2 cards
+    TestSacrificeDrawTwoArtifact,
+    /// Artifact which grants mana for the current turn when the vault is
+    /// accessed
+    TestArtifactTurnManaOnVaultAccess,
+    /// Minion which deals 1 damage whenever a minion is encountered
+    TestMinionDamageOnEncounter,
+    /// Spell which raids the vault, accessing the top two cards
+    TestSpellRaidVaultAccessTopTwo,
+    /// Minion which moves to defend another room when not defeated
+    TestMinionMoveOnEncounter,
+    /// Project which ends the raid on access unless the Riftcaller reveals a
+    /// weapon
+    TestProjectEndRaidUnlessWeaponRevealed,
+    /// Minion with the 'astral', 'infernal', and 'prismatic' resonances,
+    /// MINION_HEALTH health, and an 'end raid' ability.
+    TestPrismaticMinion,
+    /// Ritual which banishes a chosen card from the opponent's discard pile
+    TestRitualBanishFromOpponentDiscard,
+    /// Spell which pushes prompts forever when played
+    TestSpellRepeatedlyPushPrompts,
+    /// Spell which may discard a card to reduce its cost by 2 mana
+    TestSpellDiscardToReduceCost,
+    /// Spell which draws the first weapon among the top 4 cards of your deck
+    TestSpellDig4ForWeapon,
+    /// Ritual which draws a card
+    TestRitualDrawCard,
+    /// Minion with +1 health for each other summoned minion in its room
+    TestMinionHealthPerOtherSummonedMinion,
+    /// Evocation which gains 1 mana at dawn
+    TestEvocationGainManaAtDawn,
+    /// Evocation which makes your first raid each turn cost no action points
+    TestEvocationFirstRaidEachTurnFree,
+    /// Scheme which scores 5 points at dusk while in a room, up to 20 total
+    TestSchemeScorePointsAtDusk,
+    /// Project which can discard 2 cards and spend an action to gain 5 mana
+    TestProjectDiscardToGainMana,
+    /// Scheme which draws a card for the Covenant when scored
+    TestSchemeDrawCardOnScore,
+    /// Project which adds a growth counter to itself whenever a minion is
+    /// summoned
+    TestProjectCountMinionSummons,
+    /// Artifact which shows a prompt to gain mana when you access a room
+    TestArtifactPromptOnAccess,
 
     // Proof of Concept
     GoldMine,
@@ -400,6 +484,14 @@ pub enum CardName {
     UsriaYinrelSpellseeker,
     PhasewarpPortal,
     DeliriumEngine,
+    Overcharge,
+    TwinPaths,
+    VigilantScout,
+    Forage,
+    Waystone,
+    Interrogate,
+    EchoingMirror,
+    Shatterflail,
 }
 
 impl CardName {
@@ -50,6 +50,10 @@ pub enum CardPosition {
     DeckUnknown(Side),
     /// A card which is known to at least one player to be on the top of a deck
     DeckTop(Side),
+    /// A card which has been placed on the bottom of a deck. These cards are
+    /// drawn only after all other cards in the deck, with lower sorting keys
+    /// being closer to the top.
+    DeckBottom(Side),
     Hand(Side),
     Room(CardPlayId, RoomId, RoomLocation),
     ArenaItem(CardPlayId, ItemLocation),
@@ -124,7 +128,12 @@ impl CardPosition {
 
     /// Returns true if this card is in a known or unknown deck position
     pub fn in_deck(&self) -> bool {
-        matches!(self.kind(), CardPositionKind::DeckUnknown | CardPositionKind::DeckTop)
+        matches!(
+            self.kind(),
+            CardPositionKind::DeckUnknown
+                | CardPositionKind::DeckTop
+                | CardPositionKind::DeckBottom
+        )
     }
 
     /// Returns true if this card is in an unknown position in the user's deck.
@@ -206,6 +215,8 @@ pub enum CardCounter {
     Progress,
     StoredMana,
     PowerCharges,
+    /// General-purpose counter for abilities which grow stronger over time
+    Growth,
 }
 
 /// Optional card state, properties which have a default.
@@ -217,6 +228,9 @@ pub struct CardData {
     stored_mana: ManaValue,
     /// Number of power charges on this card.
     power_charges: PowerChargeValue,
+    /// Number of growth counters on this card.
+    #[serde(default)]
+    growth: u32,
     /// Is this card face-up?
     is_face_up: bool,
     /// Is this card visible to the [CardId.side] user?
@@ -330,6 +344,7 @@ impl CardState {
             CardCounter::Progress => self.data.progress,
             CardCounter::StoredMana => self.data.stored_mana,
             CardCounter::PowerCharges => self.data.power_charges,
+            CardCounter::Growth => self.data.growth,
         }
     }
 
@@ -360,6 +375,7 @@ impl CardState {
         self.data.progress = 0;
         self.data.stored_mana = 0;
         self.data.power_charges = 0;
+        self.data.growth = 0;
     }
 
     pub fn side(&self) -> Side {
@@ -440,6 +456,7 @@ impl CardState {
             CardCounter::Progress => &mut self.data.progress,
             CardCounter::StoredMana => &mut self.data.stored_mana,
             CardCounter::PowerCharges => &mut self.data.power_charges,
+            CardCounter::Growth => &mut self.data.growth,
         }
     }
 }
@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use core_data::game_primitives::{CardId, CardPlayId, CardType, MinionEncounterId, RoomId};
+use core_data::game_primitives::{
+    AbilityId, AbilityIndex, CardId, CardPlayId, CardType, ManaValue, MinionEncounterId, RaidId,
+    RoomId,
+};
 use serde::{Deserialize, Serialize};
 
+use crate::card_state::CardPosition;
 use crate::game_state::TurnData;
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -40,14 +44,42 @@ pub enum CustomCardState {
     /// minion encounter with the provided [MinionEncounterId].
     ActiveForEncounter { encounter_id: MinionEncounterId },
 
+    /// This card (typically a weapon) has been used during the raid with the
+    /// provided [RaidId].
+    UsedForRaid { raid_id: RaidId },
+
     /// A Riftcaller's ability has triggered in the indicated turn.
     IdentityTriggeredForTurn { turn: TurnData },
 
     /// An ability which triggers once per turn while a card is in play has triggered
     InPlayAbilityTriggeredForTurn { turn: TurnData, play_id: CardPlayId },
 
+    /// An ability which can only trigger once per game has triggered.
+    AbilityTriggeredForGame { index: AbilityIndex },
+
     /// A card type selected for the duration of a given turn
     CardTypeForTurn { card_type: CardType, turn: TurnData },
+
+    /// The next time the owner of this card adds power charges to a card, twice
+    /// as many charges should be added instead.
+    DoubleNextPowerCharges { play_id: CardPlayId },
+
+    /// Records that the [CustomCardState::DoubleNextPowerCharges] effect for
+    /// the given [CardPlayId] has been applied.
+    PowerChargesDoubled { play_id: CardPlayId },
+
+    /// This card has been banished until the end of its controller's turn, and
+    /// should then be returned to play in the indicated [CardPosition].
+    BanishedUntilEndOfTurn { position: CardPosition },
+
+    /// This card has gained a copy of the `source` ability for the indicated
+    /// turn. The copy's delegates are invoked as if they belonged to this
+    /// card's ability with the provided [AbilityIndex].
+    CopiedAbilityForTurn { source: AbilityId, index: AbilityIndex, turn: TurnData },
+
+    /// The mana cost of this card is reduced by the indicated amount while it
+    /// is being played with the provided [CardPlayId].
+    ManaCostReduced { play_id: CardPlayId, amount: ManaValue },
 }
 
 /// Records custom state entries for a given card.
@@ -55,7 +87,8 @@ pub enum CustomCardState {
 /// This keeps track of miscellaneous state related to resolving a card's
 /// abilities, such as targets which have been selected for this card. It is
 /// designed as an "append-only" data structure, meaning that state entries are
-/// never removed.
+/// never removed except via [Self::retain] when they can no longer have any
+/// effect on the game.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct CustomCardStateList {
     list: Vec<CustomCardState>,
@@ -67,6 +100,22 @@ impl CustomCardStateList {
         self.list.push(state);
     }
 
+    /// Retains only the [CustomCardState] entries for which `predicate`
+    /// returns true, discarding all others.
+    pub fn retain(&mut self, predicate: impl FnMut(&CustomCardState) -> bool) {
+        self.list.retain(predicate);
+    }
+
+    /// Number of [CustomCardState] entries currently recorded.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns true if no [CustomCardState] entries are currently recorded.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
     /// Mark all of the provided cards as targets for the given [CardPlayId] via
     /// [CustomCardState::TargetCard].
     pub fn record_targets(&mut self, play_id: CardPlayId, targets: &[CardId]) {
@@ -166,6 +215,15 @@ impl CustomCardStateList {
         })
     }
 
+    /// Returns true if a [CustomCardState::UsedForRaid] entry has been recorded
+    /// for this [RaidId].
+    pub fn used_for_raid(&self, id: RaidId) -> bool {
+        self.list.iter().rev().any(|state| {
+            matches!(state,
+                CustomCardState::UsedForRaid { raid_id } if id == *raid_id)
+        })
+    }
+
     /// Returns true if a [CustomCardState::InPlayAbilityTriggeredForTurn] entry
     /// has been recorded for the provided turn and card play id.
     pub fn in_play_ability_triggered_for_turn(
@@ -189,6 +247,15 @@ impl CustomCardStateList {
         })
     }
 
+    /// Returns true if a [CustomCardState::AbilityTriggeredForGame] entry has
+    /// been recorded for the ability with the provided [AbilityIndex].
+    pub fn ability_triggered_for_game(&self, ability_index: AbilityIndex) -> bool {
+        self.list.iter().any(|state| {
+            matches!(state,
+                CustomCardState::AbilityTriggeredForGame { index } if ability_index == *index)
+        })
+    }
+
     /// Returns the chosen card type if a [CustomCardState::CardTypeForTurn]
     /// entry has been recorded for the provided turn.
     pub fn card_type_for_turn(&self, turn_data: TurnData) -> Option<CardType> {
@@ -203,4 +270,71 @@ impl CustomCardStateList {
             })
             .next()
     }
+
+    /// Returns the [CardPlayId] of a [CustomCardState::DoubleNextPowerCharges]
+    /// entry which has not yet been applied, if any.
+    pub fn pending_double_power_charges(&self) -> Option<CardPlayId> {
+        self.list.iter().find_map(|state| match state {
+            CustomCardState::DoubleNextPowerCharges { play_id }
+                if !self.power_charges_doubled(*play_id) =>
+            {
+                Some(*play_id)
+            }
+            _ => None,
+        })
+    }
+
+    /// Returns the total amount of all [CustomCardState::ManaCostReduced]
+    /// entries recorded for the provided [CardPlayId].
+    pub fn mana_cost_reduction(&self, id: CardPlayId) -> ManaValue {
+        self.list
+            .iter()
+            .filter_map(|state| match state {
+                CustomCardState::ManaCostReduced { play_id, amount } if *play_id == id => {
+                    Some(*amount)
+                }
+                _ => None,
+            })
+            .sum()
+    }
+
+    /// Returns the [CardPosition] recorded via a
+    /// [CustomCardState::BanishedUntilEndOfTurn] entry, if any.
+    pub fn banished_until_end_of_turn(&self) -> Option<CardPosition> {
+        self.list.iter().rev().find_map(|state| match state {
+            CustomCardState::BanishedUntilEndOfTurn { position } => Some(*position),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over the `(index, source)` pairs of all
+    /// [CustomCardState::CopiedAbilityForTurn] entries, regardless of turn.
+    pub fn copied_abilities(&self) -> impl Iterator<Item = (AbilityIndex, AbilityId)> + '_ {
+        self.list.iter().filter_map(|state| match state {
+            CustomCardState::CopiedAbilityForTurn { source, index, .. } => Some((*index, *source)),
+            _ => None,
+        })
+    }
+
+    /// Returns true if a [CustomCardState::CopiedAbilityForTurn] entry has been
+    /// recorded for the `source` ability via the ability with the provided
+    /// [AbilityIndex] in the provided turn.
+    pub fn ability_copied_for_turn(
+        &self,
+        ability_index: AbilityIndex,
+        ability_id: AbilityId,
+        turn_data: TurnData,
+    ) -> bool {
+        self.list.iter().rev().any(|state| {
+            matches!(state,
+                CustomCardState::CopiedAbilityForTurn { source, index, turn }
+                if *source == ability_id && *index == ability_index && *turn == turn_data)
+        })
+    }
+
+    fn power_charges_doubled(&self, id: CardPlayId) -> bool {
+        self.list.iter().any(|state| {
+            matches!(state, CustomCardState::PowerChargesDoubled { play_id } if id == *play_id)
+        })
+    }
 }
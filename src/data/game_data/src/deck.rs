@@ -17,9 +17,11 @@
 use std::collections::HashMap;
 use std::iter;
 
+use anyhow::Result;
 use core_data::game_primitives::{CopiesCount, School, Side};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
+use with_error::verify;
 
 use crate::card_name::CardVariant;
 
@@ -33,6 +35,10 @@ pub struct Deck {
     /// school is often called the 'primary' school for a deck and is e.g. used
     /// to determine the card backs shown for this player.
     pub schools: Vec<School>,
+    /// Maximum number of distinct non-neutral schools this deck may contain
+    /// cards from, if its format restricts deck schools.
+    #[serde(default)]
+    pub school_limit: Option<usize>,
     /// Identity cards for this deck. Currently you can only ever have one
     /// identity card, but who knows.
     pub identities: Vec<CardVariant>,
@@ -64,4 +70,41 @@ impl Deck {
     pub fn all_cards(&self) -> impl Iterator<Item = CardVariant> + '_ {
         self.identities.iter().chain(self.sigils.iter()).chain(self.cards.keys()).copied()
     }
+
+    /// Checks whether a card from the provided [School] can be added to this
+    /// deck without exceeding its [Self::school_limit].
+    ///
+    /// Neutral cards and cards from one of the deck's existing schools are
+    /// always valid.
+    pub fn validate(&self, school: School) -> Result<()> {
+        let Some(limit) = self.school_limit else {
+            return Ok(());
+        };
+        if school == School::Neutral || self.schools.contains(&school) {
+            return Ok(());
+        }
+        let count = self.schools.iter().filter(|s| **s != School::Neutral).count();
+        verify!(count < limit, "Deck is limited to {limit} schools, cannot add {school:?}");
+        Ok(())
+    }
+
+    /// Adds `quantity` copies of a card from the provided [School] to this
+    /// deck, selecting that school for the deck if it is restricted by
+    /// [Self::school_limit].
+    pub fn add_card(
+        &mut self,
+        variant: CardVariant,
+        school: School,
+        quantity: CopiesCount,
+    ) -> Result<()> {
+        self.validate(school)?;
+        if self.school_limit.is_some()
+            && !self.schools.contains(&school)
+            && school != School::Neutral
+        {
+            self.schools.push(school);
+        }
+        self.cards.entry(variant).and_modify(|i| *i += quantity).or_insert(quantity);
+        Ok(())
+    }
 }
@@ -719,6 +719,12 @@ impl fmt::Debug for GameDelegate {
 pub struct GameDelegateContext {
     pub delegate: GameDelegate,
     pub scope: Scope,
+    /// Ability this delegate was copied from, if it was added via an ability
+    /// copying effect instead of the scope card's own definition. Copied
+    /// delegates are only invoked while the corresponding
+    /// [crate::custom_card_state::CustomCardState::CopiedAbilityForTurn] entry
+    /// is active.
+    pub copied_from: Option<AbilityId>,
 }
 
 /// Stores delegates in a given game for faster lookup
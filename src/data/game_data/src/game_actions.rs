@@ -26,7 +26,7 @@ use core_data::game_primitives::{
 use enum_kinds::EnumKind;
 use serde::{Deserialize, Serialize};
 
-use crate::game_state::MulliganDecision;
+use crate::game_state::{HandSortOrder, MulliganDecision};
 use crate::prompt_data::{PromptAction, PromptContext};
 
 #[derive(Eq, PartialEq, Hash, Debug, Copy, Clone, Serialize, Deserialize)]
@@ -96,12 +96,19 @@ pub enum ButtonPromptContext {
     CardToGiveToOpponent,
     /// Prompt for a card to take from the opponent
     CardToTakeFromOpponent,
+    /// Prompt for a card in the opponent's hand to discard
+    CardToDiscardFromOpponent,
     /// Prompt to pay mana to prevent revealing a card
     PayToPreventRevealing(ManaValue),
     /// Prompt from the indicated card to pick a card type
     ChooseCardType(CardId),
     /// Prompt to add power charges to a card
     AddPowerCharges(PowerChargeValue),
+    /// Prompt to choose which player takes the first turn of the game
+    ChooseFirstTurn,
+    /// Prompt to discard a card in order to reduce the mana cost of the
+    /// [CardId] card by the provided amount.
+    DiscardToReduceCost(CardId, ManaValue),
 }
 
 impl ButtonPromptContext {
@@ -112,6 +119,7 @@ impl ButtonPromptContext {
             Self::SacrificeToPreventDamage(id, _) => Some(*id),
             Self::SacrificeToPreventCurses(id, _) => Some(*id),
             Self::ChooseCardType(id) => Some(*id),
+            Self::DiscardToReduceCost(id, _) => Some(*id),
             _ => None,
         }
     }
@@ -178,6 +186,18 @@ impl From<RoomId> for CardTarget {
     }
 }
 
+/// Describes the complete set of legal [CardTarget]s for playing a card or
+/// activating an ability in the current game state.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum CardTargeting {
+    /// No target is required. Contains true if this can currently be played.
+    NoTargeting { can_play: bool },
+    /// Card enters play in one of the provided rooms.
+    PlayInRoom { valid_rooms: Vec<RoomId> },
+    /// Card targets one of the provided rooms when played.
+    TargetRoom { valid_rooms: Vec<RoomId> },
+}
+
 #[derive(Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash)]
 pub struct RaidAction {
     /// Index position of the action to take in the current `RaidStep`.
@@ -189,6 +209,9 @@ pub struct RaidAction {
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash)]
 pub enum DisplayPreference {
     ShowArenaView(bool),
+    /// Sets the order in which the user's hand is displayed, or restores the
+    /// default order if `None`.
+    SortHand(Option<HandSortOrder>),
 }
 
 /// Possible actions a player can take to mutate a GameState
@@ -214,9 +237,12 @@ pub enum GameAction {
 
 impl GameAction {
     /// Returns true if this action should not cause state-based actions to run.
+    ///
+    /// Sorting the hand is not stateless because the sort order is stored in
+    /// the [crate::game_state::GameState].
     pub fn is_stateless_action(&self) -> bool {
         match self {
-            Self::SetDisplayPreference(..) => true,
+            Self::SetDisplayPreference(DisplayPreference::ShowArenaView(..)) => true,
             _ => false,
         }
     }
@@ -35,6 +35,8 @@ pub enum GameEffect {
     PlayChoiceEffect { owner: CardId, target: GameObjectId },
     /// Have the [Side] player draw some number of cards
     DrawCards(Side, u32, InitiatedBy),
+    /// Have the [Side] player gain some amount of mana
+    GainMana(Side, ManaValue),
     /// Sacrifice the indicated permanent, moving it to its owner's discard
     /// pile.
     SacrificeCard(CardId),
@@ -57,6 +59,8 @@ pub enum GameEffect {
     EndCustomAccess(AbilityId),
     /// Move a card to a new target position
     MoveCard(CardId, CardPosition),
+    /// Banish a card from the [Side] player's discard pile
+    BanishFromDiscard(Side, CardId),
     /// Prevent *up to* this amount of incoming damage if there is an active
     /// damage event.
     PreventDamage(DamageAmount),
@@ -95,6 +99,11 @@ pub enum GameEffect {
     RevealCard(CardId),
     /// Add power charge counters to a card
     AddPowerCharges(CardId, PowerChargeValue),
+    /// The card which owns the first ability gains a copy of the second ability
+    /// until the end of the current turn.
+    CopyAbilityForTurn(AbilityId, AbilityId),
+    /// Begin the game, with the [Side] player taking the first turn.
+    TakeFirstTurn(Side),
 }
 
 impl GameEffect {
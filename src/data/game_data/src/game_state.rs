@@ -16,10 +16,13 @@
 
 #![allow(clippy::use_self)] // Required to use EnumKind
 
+use std::collections::HashMap;
+
 use anyhow::Result;
 use core_data::game_primitives::{
-    ActionCount, CardId, CurseCount, GameId, HasCardId, ItemLocation, LeylineCount, ManaValue,
-    PointsValue, RaidId, RoomId, RoomLocation, School, Side, TurnNumber, WoundCount,
+    ActionCount, CardId, CurseCount, DeckId, GameId, HasCardId, ItemLocation, LeylineCount,
+    ManaValue, PointsValue, RaidId, RoomId, RoomLocation, School, Side, Sprite, TurnNumber,
+    WoundCount,
 };
 use rand_xoshiro::rand_core::SeedableRng;
 use rand_xoshiro::Xoshiro256StarStar;
@@ -28,12 +31,14 @@ use serde_with::serde_as;
 use with_error::{fail, WithError};
 
 use crate::animation_tracker::{AnimationState, AnimationStep, AnimationTracker, GameAnimation};
+use crate::card_name::CardName;
 use crate::card_state::{CardPosition, CardState};
 use crate::deck::Deck;
 use crate::delegate_data::GameDelegateMap;
 use crate::history_data::{GameHistory, HistoryCounters, HistoryEvent};
 use crate::player_name::PlayerId;
 use crate::prompt_data::PromptStack;
+use crate::public_game_state::PublicGameState;
 use crate::raid_data::RaidData;
 use crate::state_machine_data::StateMachines;
 use crate::tutorial_data::GameTutorialState;
@@ -47,6 +52,9 @@ pub struct ManaState {
     pub base_mana: ManaValue,
     /// Mana which can be used only during a specific raid.
     pub raid_mana: Option<(RaidId, ManaValue)>,
+    /// Mana which can be used only during a specific turn.
+    #[serde(default)]
+    pub turn_mana: Option<(TurnData, ManaValue)>,
 }
 
 /// Stores state for a player's curses. Please use the `curses` module instead
@@ -75,6 +83,26 @@ pub struct GamePlayerData {
     /// back & card frame assets get shown.
     pub schools: Vec<School>,
 
+    /// Alternate card art this player has selected, used in place of the
+    /// default image for cards they own.
+    #[serde(default)]
+    pub card_art_overrides: HashMap<CardName, Sprite>,
+
+    /// Card back this player has selected, used in place of the default
+    /// school-based card back for cards they own.
+    #[serde(default)]
+    pub card_back: Option<Sprite>,
+
+    /// Order in which this player has chosen to display the cards in their
+    /// hand. Cards are displayed in the order they were drawn if not set.
+    #[serde(default)]
+    pub hand_sort_order: Option<HandSortOrder>,
+
+    /// Deck this player started the game with, if it was one of their own
+    /// decks rather than a pre-built deck.
+    #[serde(default)]
+    pub deck_id: Option<DeckId>,
+
     pub prompts: PromptStack,
 
     /// Storage area for cards this player has selected. Sometimes we show
@@ -96,12 +124,25 @@ impl GamePlayerData {
             leylines: 0,
             bonus_points: 0,
             schools,
+            card_art_overrides: HashMap::default(),
+            card_back: None,
+            hand_sort_order: None,
+            deck_id: None,
             prompts: PromptStack::default(),
             prompt_selected_cards: vec![],
         }
     }
 }
 
+/// Possible orderings for the cards in a player's hand.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, Hash)]
+pub enum HandSortOrder {
+    /// Sort cards by ascending mana cost
+    ByCost,
+    /// Sort cards by card type
+    ByType,
+}
+
 /// Describes options for this game & the set of rules it is using.
 #[derive(Debug, Clone, Default, Copy, Serialize, Deserialize)]
 pub struct GameConfiguration {
@@ -114,14 +155,36 @@ pub struct GameConfiguration {
     /// Whether to overwrite the normal game behavior with the standard
     /// pre-scripted new player experience.
     pub scripted_tutorial: bool,
+    /// Number of cards to deal to the Covenant player's opening hand, if not
+    /// the default.
+    #[serde(default)]
+    pub covenant_opening_hand_size: Option<u32>,
+    /// Number of cards to deal to the Riftcaller player's opening hand, if not
+    /// the default.
+    #[serde(default)]
+    pub riftcaller_opening_hand_size: Option<u32>,
+    /// If specified, a player is given the choice of which player takes the
+    /// first turn of the game after mulligans are resolved. Otherwise the
+    /// Covenant player always goes first.
+    #[serde(default)]
+    pub first_turn_choice: Option<FirstTurnChoice>,
+}
+
+/// Identifies which player gets to choose who takes the first turn of a game.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, Hash)]
+pub enum FirstTurnChoice {
+    /// Pick a random player to make the choice
+    CoinFlip,
+    /// The indicated player makes the choice
+    Player(Side),
 }
 
 /// Mulligan decision a player made for their opening hand
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, Hash)]
 pub enum MulliganDecision {
-    /// The player has decided to keep their initial hand of 5 cards
+    /// The player has decided to keep their opening hand
     Keep,
-    /// The player has elected to draw a new hand of 5 cards
+    /// The player has elected to draw a new opening hand
     Mulligan,
 }
 
@@ -157,8 +220,10 @@ pub struct TurnData {
 
 impl TurnData {
     /// Returns the [TurnData] for the opponent's turn immediately prior to this
-    /// one. Returns None if this represents the Covenant's first turn of the
-    /// game.
+    /// one. Returns None if this represents the first turn of the game.
+    ///
+    /// The Riftcaller's turn 0 only exists when the Riftcaller takes the first
+    /// turn of the game, since the Covenant's first turn is always turn 1.
     pub fn previous(&self) -> Option<TurnData> {
         match self.side {
             Side::Covenant => {
@@ -168,7 +233,13 @@ impl TurnData {
                     Some(Self { side: Side::Riftcaller, turn_number: self.turn_number - 1 })
                 }
             }
-            Side::Riftcaller => Some(Self { side: Side::Covenant, turn_number: self.turn_number }),
+            Side::Riftcaller => {
+                if self.turn_number == 0 {
+                    None
+                } else {
+                    Some(Self { side: Side::Covenant, turn_number: self.turn_number })
+                }
+            }
         }
     }
 }
@@ -232,12 +303,20 @@ pub struct RoomState {
     pub last_raided: Option<TurnData>,
 }
 
+/// Current version of the serialized [GameState] format. This should be
+/// incremented, along with adding a corresponding migration step, whenever a
+/// change is made which prevents older games from being deserialized.
+pub const GAME_STATE_VERSION: u32 = 2;
+
 /// Stores the primary state for an ongoing game
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameState {
     /// Unique identifier for this game
     pub id: GameId,
+    /// Version of the serialization format this game was written with, see
+    /// [GAME_STATE_VERSION].
+    pub version: u32,
     /// General game state & configuration
     pub info: GameInfo,
     /// Collection of state machines for handling resolution of multi-step game
@@ -294,6 +373,7 @@ impl GameState {
         let turn = TurnData { side: Side::Covenant, turn_number: 0 };
         Self {
             id,
+            version: GAME_STATE_VERSION,
             info: GameInfo {
                 phase: GamePhase::ResolveMulligans(MulliganData::default()),
                 turn,
@@ -331,6 +411,7 @@ impl GameState {
             // logic.
             let clone = Self {
                 id: self.id,
+                version: self.version,
                 info: self.info.clone(),
                 state_machines: self.state_machines.clone(),
                 raid: self.raid.clone(),
@@ -355,6 +436,7 @@ impl GameState {
     pub fn clone_for_simulation(&self) -> Self {
         Self {
             id: self.id,
+            version: self.version,
             info: self.info.clone(),
             state_machines: self.state_machines.clone(),
             raid: self.raid.clone(),
@@ -619,6 +701,12 @@ impl GameState {
         self.covenant_cards.iter().chain(self.riftcaller_cards.iter())
     }
 
+    /// Returns a [PublicGameState] snapshot of this game containing only the
+    /// information available to the `viewer` player.
+    pub fn public_snapshot(&self, viewer: Side) -> PublicGameState {
+        PublicGameState::new(self, viewer)
+    }
+
     /// Returns the [RaidId] of the ongoing raid, if any.
     pub fn raid_id(&self) -> Option<RaidId> {
         self.raid.as_ref().map(|r| r.raid_id)
@@ -89,6 +89,8 @@ pub enum HistoryEvent {
     UseWeapon(RaidEvent<UsedWeapon>),
     /// A minion's combat ability has triggered
     MinionCombatAbility(RaidEvent<CardId>),
+    /// A card has been accessed during a raid access phase
+    AccessCard(AccessEvent<CardId>),
     /// A card's raze ability has been activated during a raid access phase
     RazeAccessedCard(AccessEvent<CardId>),
     /// A card has been scored during a raid access phase
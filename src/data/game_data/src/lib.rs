@@ -32,6 +32,7 @@ pub mod game_state;
 pub mod history_data;
 pub mod player_name;
 pub mod prompt_data;
+pub mod public_game_state;
 pub mod raid_data;
 pub mod random;
 pub mod special_effects;
@@ -86,6 +86,9 @@ pub enum PromptContext {
     /// limit, player must discard until they have the provided number of cards
     /// in hand.
     DiscardToHandSize(usize),
+    /// Prompt is being shown to discard the provided number of cards from
+    /// hand.
+    DiscardCards(usize),
     /// Play a chosen card
     PlayACard,
     /// Play a named card
@@ -201,6 +204,9 @@ pub enum PromptChoiceLabel {
     RaidVault,
     RaidSanctum,
     DefeatForCost(ManaValue),
+    Discard,
+    DrawCards(u32),
+    GainMana(ManaValue),
 }
 
 /// A specific card choice shown in a [ButtonPrompt].
@@ -291,6 +297,73 @@ pub struct RoomSelectorPrompt {
     pub can_skip: bool,
 }
 
+/// Mutation to apply to each target of a [DistributePrompt].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DistributePromptEffect {
+    AddPowerCharges,
+}
+
+/// Shows a prompt to split a fixed `total` of some resource among a set of
+/// target cards.
+///
+/// Each unit is allocated individually via
+/// [PromptAction::DistributeAllocate] and the prompt can only be submitted
+/// once the full total has been allocated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DistributePrompt {
+    pub initiated_by: AbilityId,
+    pub effect: DistributePromptEffect,
+    /// Total quantity which must be distributed.
+    pub total: u32,
+    /// Cards which may receive a share of the total.
+    pub targets: Vec<CardId>,
+    /// Cards which have been allocated a unit so far, one entry per unit.
+    pub allocated: Vec<CardId>,
+}
+
+impl DistributePrompt {
+    /// Quantity currently allocated to the `card_id` target.
+    pub fn allocated_to(&self, card_id: CardId) -> u32 {
+        self.allocated.iter().filter(|id| **id == card_id).count() as u32
+    }
+
+    /// Quantity which has not yet been allocated to any target.
+    pub fn remaining(&self) -> u32 {
+        self.total.saturating_sub(self.allocated.len() as u32)
+    }
+}
+
+/// Mutation to apply to each card selected via a [ChooseMultiplePrompt].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ChooseMultiplePromptEffect {
+    DestroyCards,
+}
+
+/// Shows a prompt to select up to `count` cards from among a set of target
+/// cards.
+///
+/// Each target is toggled individually via
+/// [PromptAction::ChooseMultipleToggle] and the effect is applied to every
+/// selected card when the prompt is submitted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChooseMultiplePrompt {
+    pub initiated_by: AbilityId,
+    pub effect: ChooseMultiplePromptEffect,
+    /// Maximum number of cards which may be selected.
+    pub count: usize,
+    /// Cards which may be selected.
+    pub targets: Vec<CardId>,
+    /// Cards which have been selected so far.
+    pub selected: Vec<CardId>,
+}
+
+impl ChooseMultiplePrompt {
+    /// Returns true if no further cards can be selected.
+    pub fn is_full(&self) -> bool {
+        self.selected.len() >= self.count
+    }
+}
+
 /// Possible types of prompt_ui which might be displayed to a user during the
 /// game.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -305,6 +378,12 @@ pub enum GamePrompt {
 
     /// Prompt to pick a room
     RoomSelector(RoomSelectorPrompt),
+
+    /// Prompt to distribute a quantity among several cards
+    Distribute(DistributePrompt),
+
+    /// Prompt to select several cards at once
+    ChooseMultiple(ChooseMultiplePrompt),
 }
 
 /// Possible actions in response to the [GamePrompt] currently being shown to a
@@ -321,4 +400,14 @@ pub enum PromptAction {
     RoomPromptSelect(RoomId),
     /// Do not select a room for a [RoomSelectorPrompt].
     SkipSelectingRoom,
+    /// Allocate one unit to the indicated card via a [DistributePrompt].
+    DistributeAllocate(CardId),
+    /// Clear all allocations made so far in the current [DistributePrompt].
+    DistributeReset,
+    /// Submit the allocations made in the current [DistributePrompt].
+    DistributeSubmit,
+    /// Select or deselect the indicated card via a [ChooseMultiplePrompt].
+    ChooseMultipleToggle(CardId),
+    /// Submit the selection made in the current [ChooseMultiplePrompt].
+    ChooseMultipleSubmit,
 }
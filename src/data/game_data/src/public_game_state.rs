@@ -0,0 +1,101 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serializable view of a game containing only information known to one
+//! player, suitable for sharing a board snapshot with spectators.
+
+use core_data::game_primitives::{
+    ActionCount, CardId, GameId, LeylineCount, ManaValue, PointsValue, Side, WoundCount,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::card_name::CardVariant;
+use crate::card_state::{CardPosition, CardState};
+use crate::game_state::{GamePlayerData, GameState, TurnData};
+
+/// Snapshot of a [GameState] as seen by the `viewer` player.
+///
+/// The identities of cards which are not visible to the viewer and the order
+/// of cards within each deck are omitted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublicGameState {
+    pub id: GameId,
+    /// Player from whose perspective this snapshot was taken.
+    pub viewer: Side,
+    pub turn: TurnData,
+    pub covenant: PublicPlayerState,
+    pub riftcaller: PublicPlayerState,
+    /// All cards in the game, Covenant cards followed by Riftcaller cards.
+    pub cards: Vec<PublicCardState>,
+}
+
+impl PublicGameState {
+    pub fn new(game: &GameState, viewer: Side) -> Self {
+        Self {
+            id: game.id,
+            viewer,
+            turn: game.info.turn,
+            covenant: PublicPlayerState::new(&game.covenant),
+            riftcaller: PublicPlayerState::new(&game.riftcaller),
+            cards: game.all_cards().map(|card| PublicCardState::new(card, viewer)).collect(),
+        }
+    }
+}
+
+/// Publicly-known resources of a player.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublicPlayerState {
+    pub mana: ManaValue,
+    pub actions: ActionCount,
+    pub wounds: WoundCount,
+    pub leylines: LeylineCount,
+    pub bonus_points: PointsValue,
+}
+
+impl PublicPlayerState {
+    fn new(player: &GamePlayerData) -> Self {
+        Self {
+            mana: player.mana_state.base_mana,
+            actions: player.actions,
+            wounds: player.wounds,
+            leylines: player.leylines,
+            bonus_points: player.bonus_points,
+        }
+    }
+}
+
+/// A card as seen by the viewer of a [PublicGameState].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublicCardState {
+    pub id: CardId,
+    pub position: CardPosition,
+    pub is_face_up: bool,
+    /// Identity of this card, or None if it is not visible to the viewer.
+    pub variant: Option<CardVariant>,
+    /// Sort order of this card within its position, or None if the card is in
+    /// a deck.
+    pub sorting_key: Option<u32>,
+}
+
+impl PublicCardState {
+    fn new(card: &CardState, viewer: Side) -> Self {
+        Self {
+            id: card.id,
+            position: card.position(),
+            is_face_up: card.is_face_up(),
+            variant: card.is_visible_to(viewer).then_some(card.variant),
+            sorting_key: (!card.position().in_deck()).then_some(card.sorting_key),
+        }
+    }
+}
@@ -96,6 +96,7 @@ pub enum RaidStep {
     RazeCardEvent(CardId),
     FinishAccess,
     FinishRaid,
+    FailRaid,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash)]
@@ -109,6 +110,9 @@ pub enum RaidLabel {
     RazeCard(CardId, RazeCardActionType),
     EndRaid,
     EndAccess,
+    /// Ends the raid when the Riftcaller has no legal way to continue past the
+    /// current minion encounter.
+    NoUsableWeapon,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -255,6 +259,9 @@ pub struct RaidData {
     /// A custom access raid plays out only the 'access' phase of a raid,
     /// accessing a specific set of cards.
     pub is_custom_access: bool,
+    /// Maximum number of additional accessed cards the Riftcaller may score
+    /// or raze during this raid, or None if access is not limited.
+    pub access_limit: Option<u32>,
 }
 
 impl RaidData {
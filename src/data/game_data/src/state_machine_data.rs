@@ -31,8 +31,8 @@ pub enum PlayCardStep {
     MoveToPlayedPosition,
     PayActionPoints,
     ApplyPlayCardBrowser,
-    PayManaCost,
     PayCustomCost,
+    PayManaCost,
     TurnFaceUp,
     MoveToTargetPosition,
     Finish,
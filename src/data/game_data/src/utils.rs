@@ -48,6 +48,7 @@ pub fn add_matching<T: PartialEq + Copy>(current: &mut Option<(T, u32)>, tag: T,
     if let Some((t, v)) = *current {
         if tag == t {
             *current = Some((tag, value + v));
+            return;
         }
     }
 
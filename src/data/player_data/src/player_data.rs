@@ -12,15 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::time::SystemTime;
+
 use adventure_data::adventure::{AdventureScreen, AdventureState, BattleData};
 use anyhow::Result;
-use core_data::game_primitives::{DeckId, GameId, Side};
+use core_data::game_primitives::{DeckId, GameId, School, Side, Sprite};
 use enum_kinds::EnumKind;
+use game_data::card_name::CardName;
 use game_data::deck::Deck;
 use game_data::player_name::PlayerId;
 use game_data::tutorial_data::TutorialData;
 use serde::{Deserialize, Serialize};
-use user_action_data::NewGameAction;
+use user_action_data::{GameOutcome, NewGameAction};
 use with_error::{fail, WithError};
 
 /// Represents the state of a game the player is participating in.
@@ -55,6 +59,69 @@ impl<'a> PlayerActivity<'a> {
     }
 }
 
+/// Maximum number of [MatchRecord]s kept in a player's match history. Older
+/// records are discarded first.
+pub const MAX_MATCH_HISTORY: usize = 100;
+
+/// Records the result of a game this player has finished playing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchRecord {
+    /// Game which was played
+    pub game_id: GameId,
+    /// Player this player was playing against
+    pub opponent: PlayerId,
+    /// Side this player was playing as
+    pub side: Side,
+    /// Whether this player won or lost the game
+    pub outcome: GameOutcome,
+    /// Deck this player used, if the game was started with one of their own
+    /// decks rather than a pre-built deck.
+    pub deck: Option<DeckId>,
+    /// Time at which the game ended
+    pub timestamp: SystemTime,
+}
+
+/// A deck a player has built and saved under a name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedDeck {
+    /// Identifies this deck via [DeckId::Saved].
+    pub id: u64,
+    /// Name the player has given this deck
+    pub name: String,
+    pub deck: Deck,
+}
+
+/// Brief description of one of a player's decks, used to display a list of
+/// decks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeckSummary {
+    pub name: String,
+    pub side: Side,
+    pub schools: Vec<School>,
+    /// Number of cards in this deck, not including identity or sigil cards.
+    pub card_count: usize,
+}
+
+impl DeckSummary {
+    pub fn new(name: impl Into<String>, deck: &Deck) -> Self {
+        Self {
+            name: name.into(),
+            side: deck.side,
+            schools: deck.schools.clone(),
+            card_count: deck.cards.values().sum::<u32>() as usize,
+        }
+    }
+}
+
+/// An adventure which is not currently selected by the player, stored so that
+/// it can be resumed later.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdventureSlot {
+    /// Identifies this slot for selection and deletion.
+    pub id: u64,
+    pub adventure: AdventureState,
+}
+
 /// Represents a player's stored data.
 ///
 /// For a player's state *within a given game* see `GamePlayerData`.
@@ -64,15 +131,48 @@ pub struct PlayerState {
     pub id: PlayerId,
     /// Identifies the game this player is currently participating in, if any.
     pub status: Option<PlayerStatus>,
-    /// State for an ongoing adventure, if any
+    /// State for the currently-selected adventure, if any
     pub adventure: Option<AdventureState>,
+    /// Slot ID for [Self::adventure].
+    #[serde(default)]
+    pub current_adventure_slot: u64,
+    /// Other adventures this player has started, which are not currently
+    /// selected.
+    #[serde(default)]
+    pub adventure_slots: Vec<AdventureSlot>,
     /// Data related to this player's tutorial progress
     pub tutorial: TutorialData,
+    /// Alternate card art this player has selected. Copied into each new game
+    /// this player participates in.
+    #[serde(default)]
+    pub card_art_overrides: HashMap<CardName, Sprite>,
+    /// Card back this player has selected, if any. Copied into each new game
+    /// this player participates in.
+    #[serde(default)]
+    pub card_back: Option<Sprite>,
+    /// Results of games this player has finished, in the order they were
+    /// completed.
+    #[serde(default)]
+    pub match_history: Vec<MatchRecord>,
+    /// Decks this player has saved, in the order they were created.
+    #[serde(default)]
+    pub saved_decks: Vec<SavedDeck>,
 }
 
 impl PlayerState {
     pub fn new(id: PlayerId) -> Self {
-        Self { id, status: None, adventure: None, tutorial: TutorialData::default() }
+        Self {
+            id,
+            status: None,
+            adventure: None,
+            current_adventure_slot: 0,
+            adventure_slots: vec![],
+            tutorial: TutorialData::default(),
+            card_art_overrides: HashMap::default(),
+            card_back: None,
+            match_history: vec![],
+            saved_decks: vec![],
+        }
     }
 
     /// Returns what this player is currently doing within the game.
@@ -108,10 +208,78 @@ impl PlayerState {
         self.adventure.as_mut().with_error(|| "Expected active adventure")
     }
 
+    /// Returns the slot ID and state of each of this player's adventures,
+    /// including the currently-selected adventure, ordered by slot ID.
+    pub fn all_adventures(&self) -> Vec<(u64, &AdventureState)> {
+        let mut result = self
+            .adventure
+            .iter()
+            .map(|adventure| (self.current_adventure_slot, adventure))
+            .chain(self.adventure_slots.iter().map(|slot| (slot.id, &slot.adventure)))
+            .collect::<Vec<_>>();
+        result.sort_by_key(|(id, _)| *id);
+        result
+    }
+
+    /// Stores the current adventure in a new slot and selects it, moving any
+    /// previously-selected adventure into [Self::adventure_slots].
+    ///
+    /// Returns the ID of the new slot.
+    pub fn create_adventure_slot(&mut self, adventure: AdventureState) -> u64 {
+        let id = self.all_adventures().iter().map(|(id, _)| id + 1).max().unwrap_or_default();
+        self.store_current_adventure();
+        self.current_adventure_slot = id;
+        self.adventure = Some(adventure);
+        id
+    }
+
+    /// Selects the adventure in the slot with the provided ID, moving the
+    /// previously-selected adventure into [Self::adventure_slots].
+    pub fn select_adventure_slot(&mut self, id: u64) -> Result<()> {
+        if self.adventure.is_some() && self.current_adventure_slot == id {
+            return Ok(());
+        }
+        let index = self
+            .adventure_slots
+            .iter()
+            .position(|slot| slot.id == id)
+            .with_error(|| format!("Adventure slot {id} not found"))?;
+        let slot = self.adventure_slots.remove(index);
+        self.store_current_adventure();
+        self.current_adventure_slot = id;
+        self.adventure = Some(slot.adventure);
+        Ok(())
+    }
+
+    /// Deletes the adventure in the slot with the provided ID. If this is the
+    /// currently-selected adventure, no adventure will be selected afterwards.
+    pub fn delete_adventure_slot(&mut self, id: u64) -> Result<()> {
+        if self.adventure.is_some() && self.current_adventure_slot == id {
+            self.adventure = None;
+            return Ok(());
+        }
+        let index = self
+            .adventure_slots
+            .iter()
+            .position(|slot| slot.id == id)
+            .with_error(|| format!("Adventure slot {id} not found"))?;
+        self.adventure_slots.remove(index);
+        Ok(())
+    }
+
+    /// Moves the currently-selected adventure, if any, into
+    /// [Self::adventure_slots].
+    fn store_current_adventure(&mut self) {
+        if let Some(adventure) = self.adventure.take() {
+            self.adventure_slots.push(AdventureSlot { id: self.current_adventure_slot, adventure });
+        }
+    }
+
     /// Retrieves one of a player's decks based on its [DeckId].
     pub fn deck(&self, deck_id: DeckId) -> Result<&Deck> {
         Ok(match deck_id {
             DeckId::Adventure => &self.adventure()?.deck,
+            DeckId::Saved(id) => &self.saved_deck(id)?.deck,
         })
     }
 
@@ -119,8 +287,53 @@ impl PlayerState {
     pub fn deck_mut(&mut self, deck_id: DeckId) -> Result<&mut Deck> {
         Ok(match deck_id {
             DeckId::Adventure => &mut self.adventure_mut()?.deck,
+            DeckId::Saved(id) => &mut self.saved_deck_mut(id)?.deck,
         })
     }
+
+    /// Retrieves one of a player's saved decks by its [SavedDeck::id].
+    pub fn saved_deck(&self, id: u64) -> Result<&SavedDeck> {
+        self.saved_decks.iter().find(|d| d.id == id).with_error(|| format!("Deck {id} not found"))
+    }
+
+    /// Mutable version of [Self::saved_deck]
+    pub fn saved_deck_mut(&mut self, id: u64) -> Result<&mut SavedDeck> {
+        self.saved_decks
+            .iter_mut()
+            .find(|d| d.id == id)
+            .with_error(|| format!("Deck {id} not found"))
+    }
+
+    /// Returns a [DeckSummary] for each of this player's decks, starting with
+    /// the current adventure deck (if any) followed by saved decks in creation
+    /// order.
+    pub fn decks(&self) -> Vec<(DeckId, DeckSummary)> {
+        self.adventure
+            .iter()
+            .map(|adventure| (DeckId::Adventure, DeckSummary::new("Adventure", &adventure.deck)))
+            .chain(
+                self.saved_decks.iter().map(|saved| {
+                    (DeckId::Saved(saved.id), DeckSummary::new(&saved.name, &saved.deck))
+                }),
+            )
+            .collect()
+    }
+
+    /// Adds a [MatchRecord] to this player's match history, discarding the
+    /// oldest records beyond [MAX_MATCH_HISTORY].
+    ///
+    /// Returns false without making changes if this game has already been
+    /// recorded.
+    pub fn record_match(&mut self, record: MatchRecord) -> bool {
+        if self.match_history.iter().any(|r| r.game_id == record.game_id) {
+            return false;
+        }
+
+        self.match_history.push(record);
+        let excess = self.match_history.len().saturating_sub(MAX_MATCH_HISTORY);
+        self.match_history.drain(..excess);
+        true
+    }
 }
 
 /// Returns the [GameId] an optional [PlayerState] is currently playing in, if
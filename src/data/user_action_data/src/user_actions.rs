@@ -24,6 +24,7 @@ use enum_iterator::Sequence;
 use game_data::card_name::{CardMetadata, CardVariant};
 use game_data::card_state::CardPosition;
 use game_data::game_actions::GameAction;
+use game_data::game_state::FirstTurnChoice;
 use game_data::player_name::{AIPlayer, PlayerId};
 use serde::{Deserialize, Serialize};
 use strum_macros::Display;
@@ -34,6 +35,18 @@ pub struct NewGameDebugOptions {
     pub deterministic: bool,
     /// Explicitly set the ID for this game
     pub override_game_id: Option<GameId>,
+    /// Number of cards to deal to the Covenant player's opening hand, if not
+    /// the default.
+    #[serde(default)]
+    pub covenant_opening_hand_size: Option<u32>,
+    /// Number of cards to deal to the Riftcaller player's opening hand, if not
+    /// the default.
+    #[serde(default)]
+    pub riftcaller_opening_hand_size: Option<u32>,
+    /// Whether a player should be given the choice of who takes the first
+    /// turn.
+    #[serde(default)]
+    pub first_turn_choice: Option<FirstTurnChoice>,
 }
 
 /// Canonical decklists which can be used in new games
@@ -148,6 +161,9 @@ pub enum DebugAction {
     ApplyScenario(DebugScenario),
     ApplyAdventureScenario(DebugAdventureScenario),
     DebugUndo,
+    /// Logs the abilities which are waiting to resolve on the current player's
+    /// prompt stack, in resolution order.
+    LogPendingAbilities,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash)]
@@ -171,6 +187,13 @@ pub enum UserAction {
     /// adventure summary screen. Typically happens *after* the 'abandon
     /// adventure' action transitions it to its summary state.
     LeaveAdventure,
+    /// Start a new adventure in a new save slot, keeping any existing
+    /// adventures in their slots.
+    NewAdventureSlot(Side),
+    /// Resume the adventure stored in the save slot with the given ID.
+    SelectAdventureSlot(u64),
+    /// Delete the adventure stored in the save slot with the given ID.
+    DeleteAdventureSlot(u64),
 
     /// Create a new game (match between two players)
     NewGame(NewGameAction),
@@ -181,6 +204,9 @@ pub enum UserAction {
     /// invoked from the game over screen, the 'resign' action is used to
     /// end the game itself.
     LeaveGame(GameOutcome),
+
+    /// Save a copy of one of the player's decks under a new name.
+    CopyDeck(DeckId),
 }
 
 impl From<GameAction> for UserAction {
@@ -202,9 +228,13 @@ impl fmt::Debug for UserAction {
             Self::NewAdventure(a) => f.debug_tuple("NewAdventure").field(a).finish(),
             Self::AdventureAction(a) => write!(f, "{a:?}"),
             Self::LeaveAdventure => write!(f, "LeaveAdventure"),
+            Self::NewAdventureSlot(a) => f.debug_tuple("NewAdventureSlot").field(a).finish(),
+            Self::SelectAdventureSlot(a) => f.debug_tuple("SelectAdventureSlot").field(a).finish(),
+            Self::DeleteAdventureSlot(a) => f.debug_tuple("DeleteAdventureSlot").field(a).finish(),
             Self::NewGame(a) => f.debug_tuple("NewGame").field(a).finish(),
             Self::GameAction(a) => write!(f, "{a:?}"),
             Self::LeaveGame(a) => f.debug_tuple("LeaveGame").field(a).finish(),
+            Self::CopyDeck(a) => f.debug_tuple("CopyDeck").field(a).finish(),
         }
     }
 }
@@ -22,6 +22,7 @@ use game_data::player_name::PlayerId;
 use player_data::PlayerState;
 
 pub mod firestore_database;
+pub mod game_migration;
 pub mod sled_database;
 
 #[async_trait]
@@ -19,9 +19,10 @@ use firestore::FirestoreDb;
 use game_data::game_state::GameState;
 use game_data::player_name::PlayerId;
 use player_data::PlayerState;
+use serde_json::Value;
 use with_error::{fail, WithError};
 
-use crate::Database;
+use crate::{game_migration, Database};
 
 pub struct FirestoreDatabase {
     db: FirestoreDb,
@@ -60,10 +61,12 @@ impl Database for FirestoreDatabase {
             .fluent()
             .select()
             .by_id_in("games")
-            .obj()
+            .obj::<Value>()
             .one(id.to_string())
             .await
-            .with_error(|| format!("Error fetching game {id}"))
+            .with_error(|| format!("Error fetching game {id}"))?
+            .map(game_migration::migrate)
+            .transpose()
     }
 
     async fn write_game(&self, game: &GameState) -> Result<()> {
@@ -0,0 +1,67 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Upgrades serialized games written by older versions of the game.
+
+use anyhow::Result;
+use game_data::game_state::{GameState, GAME_STATE_VERSION};
+use serde_json::Value;
+use with_error::{fail, verify, WithError};
+
+/// Version assumed for serialized games which predate the `version` field.
+const UNVERSIONED: u32 = 1;
+
+/// Deserializes a [GameState] from its raw JSON representation, first
+/// upgrading it to [GAME_STATE_VERSION] if it was written with an older
+/// version of the format.
+///
+/// Returns an error if the data was written with an unsupported version.
+pub fn migrate(mut raw: Value) -> Result<GameState> {
+    upgrade(&mut raw)?;
+    serde_json::from_value(raw).with_error(|| "Error deserializing game")
+}
+
+/// Upgrades a serialized game in place to [GAME_STATE_VERSION], including any
+/// game snapshot stored for the 'undo' action.
+fn upgrade(raw: &mut Value) -> Result<()> {
+    let object = raw.as_object_mut().with_error(|| "Expected game state object")?;
+    let mut version = match object.get("version") {
+        Some(value) => {
+            u32::try_from(value.as_u64().with_error(|| "Expected numeric game state version")?)?
+        }
+        None => UNVERSIONED,
+    };
+    verify!(
+        (UNVERSIONED..=GAME_STATE_VERSION).contains(&version),
+        "Unsupported game state version {version}, current version is {GAME_STATE_VERSION}"
+    );
+
+    while version < GAME_STATE_VERSION {
+        match version {
+            // Version 1 is identical to version 2 apart from the 'version'
+            // field itself, which is written below.
+            1 => {}
+            _ => fail!("No migration from game state version {version}"),
+        }
+        version += 1;
+    }
+
+    object.insert("version".to_string(), Value::from(version));
+    if let Some(undo) = object.get_mut("undo_tracker").and_then(|tracker| tracker.get_mut("undo")) {
+        if !undo.is_null() {
+            upgrade(undo)?;
+        }
+    }
+    Ok(())
+}
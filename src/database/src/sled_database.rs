@@ -22,7 +22,7 @@ use serde_json::{de, ser};
 use sled::{Db, Tree};
 use with_error::{fail, WithError};
 
-use crate::Database;
+use crate::{game_migration, Database};
 
 pub struct SledDatabase {
     db: Db,
@@ -69,8 +69,10 @@ impl Database for SledDatabase {
             .get(game_id_key(id))
             .with_error(|| format!("Error fetching game {id}"))?
             .map(|slice| {
-                de::from_slice::<GameState>(&slice)
-                    .with_error(|| format!("Error deserializing game {id}"))
+                game_migration::migrate(
+                    de::from_slice(&slice)
+                        .with_error(|| format!("Error deserializing game {id}"))?,
+                )
             })
             .transpose()
     }
@@ -0,0 +1,157 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for building decks in the deck editor
+
+use std::collections::HashMap;
+
+use card_definition_data::card_definition::CardDefinition;
+use card_definition_data::cards;
+use constants::game_constants;
+use core_data::game_primitives::{CardSubtype, CardType, CopiesCount, ManaValue, School, Side};
+use game_data::card_name::{CardName, CardVariant};
+use game_data::deck::Deck;
+
+/// A problem found with a deck by [validate_with_warnings].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeckIssue {
+    /// Card belongs to the opposing side.
+    WrongSide(CardVariant),
+    /// Identity, sigil, or game modifier card in the main deck.
+    NotDeckCard(CardVariant),
+    /// Riftcaller deck which contains no weapons and thus will be unable to
+    /// defeat most minions.
+    NoWeapons,
+}
+
+/// Result of validating a deck.
+///
+/// Errors make a deck illegal to play, while warnings only indicate that the
+/// deck is likely to perform poorly.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeckValidation {
+    pub errors: Vec<DeckIssue>,
+    pub warnings: Vec<DeckIssue>,
+}
+
+impl DeckValidation {
+    /// True if this deck has no blocking errors.
+    pub fn is_legal(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Mana costs at or above this value are grouped together when smoothing a
+/// deck's mana curve.
+const MAX_CURVE_BUCKET: ManaValue = 5;
+
+/// Suggests cards from `collection` to add to the `partial` deck in order to
+/// bring it up to `target_size` cards.
+///
+/// Only cards which match the deck's side and which are either neutral or
+/// belong to one of the deck's schools are considered. Each entry in
+/// `collection` represents a single owned copy and is suggested at most once.
+/// Cards already at [game_constants::MAXIMUM_COPIES_IN_DECK] copies are not
+/// suggested. Cards are chosen to fill the least-represented mana costs in the
+/// deck first, with ties broken by order in `collection`. Returns fewer cards
+/// than needed if `collection` runs out of eligible cards.
+pub fn suggest_additions(
+    partial: &Deck,
+    collection: &[CardName],
+    target_size: usize,
+) -> Vec<CardName> {
+    let mut curve = HashMap::<ManaValue, usize>::new();
+    let mut copies = HashMap::<CardName, CopiesCount>::new();
+    for variant in partial.card_variants() {
+        *curve.entry(curve_bucket(cards::get(variant))).or_default() += 1;
+        *copies.entry(variant.name).or_default() += 1;
+    }
+
+    let mut candidates = collection
+        .iter()
+        .map(|name| cards::get(CardVariant::standard(*name)))
+        .filter(|definition| can_add(partial, definition))
+        .collect::<Vec<_>>();
+    let current_size = partial.cards.values().sum::<u32>() as usize;
+    let mut result = vec![];
+
+    while current_size + result.len() < target_size {
+        candidates.retain(|definition| {
+            copies.get(&definition.name).copied().unwrap_or_default()
+                < game_constants::MAXIMUM_COPIES_IN_DECK
+        });
+        let Some((index, _)) = candidates.iter().enumerate().min_by_key(|(index, definition)| {
+            (curve.get(&curve_bucket(definition)).copied().unwrap_or_default(), *index)
+        }) else {
+            break;
+        };
+        let definition = candidates.remove(index);
+        *curve.entry(curve_bucket(definition)).or_default() += 1;
+        *copies.entry(definition.name).or_default() += 1;
+        result.push(definition.name);
+    }
+
+    result
+}
+
+/// Returns the list of errors which make `deck` illegal to play, or an empty
+/// vector if the deck is legal.
+pub fn validate(deck: &Deck) -> Vec<DeckIssue> {
+    validate_with_warnings(deck).errors
+}
+
+/// Checks `deck` for problems, returning both blocking errors and
+/// non-blocking warnings.
+pub fn validate_with_warnings(deck: &Deck) -> DeckValidation {
+    let mut result = DeckValidation::default();
+    let mut variants = deck.cards.keys().copied().collect::<Vec<_>>();
+    variants.sort();
+
+    for variant in deck.identities.iter().chain(deck.sigils.iter()).chain(variants.iter()) {
+        if cards::get(*variant).side != deck.side {
+            result.errors.push(DeckIssue::WrongSide(*variant));
+        }
+    }
+
+    for variant in &variants {
+        let card_type = cards::get(*variant).card_type;
+        if card_type.is_identity() || matches!(card_type, CardType::Sigil | CardType::GameModifier)
+        {
+            result.errors.push(DeckIssue::NotDeckCard(*variant));
+        }
+    }
+
+    if deck.side == Side::Riftcaller
+        && !variants
+            .iter()
+            .any(|variant| cards::get(*variant).subtypes.contains(&CardSubtype::Weapon))
+    {
+        result.warnings.push(DeckIssue::NoWeapons);
+    }
+
+    result
+}
+
+fn can_add(deck: &Deck, definition: &CardDefinition) -> bool {
+    definition.side == deck.side
+        && !definition.card_type.is_identity()
+        && !matches!(definition.card_type, CardType::Sigil | CardType::GameModifier)
+        && (definition.school == School::Neutral
+            || deck.schools.is_empty()
+            || deck.schools.contains(&definition.school))
+}
+
+fn curve_bucket(definition: &CardDefinition) -> ManaValue {
+    definition.cost.mana.unwrap_or_default().min(MAX_CURVE_BUCKET)
+}
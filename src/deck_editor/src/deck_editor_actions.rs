@@ -0,0 +1,64 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Handles user actions which modify a player's saved decks
+
+use anyhow::Result;
+use core_data::game_primitives::DeckId;
+use player_data::{PlayerState, SavedDeck};
+
+/// Saves a copy of the `deck_id` deck for this player, returning the
+/// [DeckId] of the new deck.
+///
+/// The copy is named after the original deck with a number appended, e.g.
+/// copying "Aggro" produces "Aggro 2" and copying "Aggro 2" produces
+/// "Aggro 3". The number is incremented until the name is not already used by
+/// one of the player's decks. If the number cannot be incremented further,
+/// the copy is numbered as a copy of the full original name instead.
+pub fn copy_deck(player: &mut PlayerState, deck_id: DeckId) -> Result<DeckId> {
+    let deck = player.deck(deck_id)?.clone();
+    let original = match deck_id {
+        DeckId::Adventure => "Adventure".to_string(),
+        DeckId::Saved(id) => player.saved_deck(id)?.name.clone(),
+    };
+    let (mut base, mut number) = split_name(&original);
+    let names = player.decks().into_iter().map(|(_, summary)| summary.name).collect::<Vec<_>>();
+    let name = loop {
+        let Some(next) = number.checked_add(1) else {
+            base = &original;
+            number = 1;
+            continue;
+        };
+        number = next;
+        let candidate = format!("{base} {number}");
+        if !names.contains(&candidate) {
+            break candidate;
+        }
+    };
+
+    let id = player.saved_decks.iter().map(|saved| saved.id + 1).max().unwrap_or_default();
+    player.saved_decks.push(SavedDeck { id, name, deck });
+    Ok(DeckId::Saved(id))
+}
+
+/// Splits a trailing number off of a deck name, treating names without one as
+/// copy number 1.
+fn split_name(name: &str) -> (&str, u32) {
+    if let Some((base, suffix)) = name.rsplit_once(' ') {
+        if let Ok(number) = suffix.parse() {
+            return (base, number);
+        }
+    }
+    (name, 1)
+}
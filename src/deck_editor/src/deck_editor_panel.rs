@@ -29,10 +29,12 @@ use core_ui::text::Text;
 use deck_card::deck_card_slot::DeckCardSlot;
 use deck_card::{CardHeight, DeckCard};
 use game_data::card_name::CardVariant;
+use game_data::deck::Deck;
 use panel_address::{Panel, PanelAddress};
 use player_data::PlayerState;
 use protos::riftcaller::{
-    FlexAlign, FlexDirection, FlexJustify, FlexPosition, ScrollBarVisibility, TouchScrollBehavior,
+    FlexAlign, FlexDirection, FlexJustify, FlexPosition, ScrollBarVisibility, SpriteAddress,
+    TouchScrollBehavior,
 };
 use screen_overlay::ScreenOverlay;
 
@@ -54,6 +56,17 @@ pub struct DeckEditorPanel<'a> {
 }
 
 impl<'a> DeckEditorPanel<'a> {
+    /// Cards to display from `deck` along with their quantities. Identities and
+    /// sigils do not display a quantity.
+    fn displayed_cards(deck: &Deck) -> Vec<(&CardVariant, Option<&u32>)> {
+        deck.identities
+            .iter()
+            .chain(deck.sigils.iter())
+            .map(|variant| (variant, None))
+            .chain(deck.cards.iter().map(|(variant, quantity)| (variant, Some(quantity))))
+            .collect()
+    }
+
     fn card_section(&self, mut cards: Vec<(&CardVariant, Option<&u32>)>) -> Column {
         cards.sort_by_key(|(variant, _)| {
             let definition = cards::get(**variant);
@@ -122,27 +135,38 @@ impl<'a> DeckEditorPanel<'a> {
         }
     }
 
+    /// Describes the schools selected for a deck with a school limit, e.g.
+    /// "Schools: Law, Primal (2/2)".
+    fn school_message(&self) -> Option<String> {
+        let deck = &self.player.adventure.as_ref()?.deck;
+        let limit = deck.school_limit?;
+        let schools = deck.schools.iter().map(|s| s.displayed_name()).collect::<Vec<_>>();
+        let names = if schools.is_empty() { "None".to_string() } else { schools.join(", ") };
+        Some(format!("Schools: {} ({}/{})", names, schools.len(), limit))
+    }
+
     fn title(&self) -> impl Component {
         let (title, message) = if let Some(effect) = &self.effect {
             match effect.action {
                 DeckCardAction::DuplicateTo3Copies => {
-                    ("Duplication", Some("Pick a card to have three copies of."))
+                    ("Duplication", Some("Pick a card to have three copies of.".to_string()))
                 }
                 DeckCardAction::TransmuteAllCopies => (
                     "Transmutation",
                     Some(
-                        "Pick a card to transform all copies into a random card of a higher rarity.",
+                        "Pick a card to transform all copies into a random card of a higher rarity."
+                            .to_string(),
                     ),
                 ),
                 DeckCardAction::UpgradeAllCopies => {
-                    ("Upgrade", Some("Pick a card to upgrade all copies."))
+                    ("Upgrade", Some("Pick a card to upgrade all copies.".to_string()))
                 }
                 DeckCardAction::RemoveOne => {
-                    ("Removal", Some("Pick a card to remove one copy from your deck."))
+                    ("Removal", Some("Pick a card to remove one copy from your deck.".to_string()))
                 }
             }
         } else {
-            ("Deck", None)
+            ("Deck", self.school_message())
         };
 
         Column::new("DeckEditorTitle")
@@ -154,7 +178,7 @@ impl<'a> DeckEditorPanel<'a> {
                     {
                         format!("{} Choices remaining: {}.", m, times)
                     } else {
-                        m.to_string()
+                        m
                     },
                 )
                 .font_size(FontSize::Body)
@@ -180,6 +204,19 @@ impl<'a> Panel for DeckEditorPanel<'a> {
         }
         .build()
     }
+
+    fn preload_sprites(&self) -> Vec<SpriteAddress> {
+        let Some(adventure) = self.player.adventure.as_ref() else {
+            return vec![];
+        };
+        let mut result = Self::displayed_cards(&adventure.deck)
+            .into_iter()
+            .map(|(variant, _)| adapters::sprite(&cards::get(*variant).image))
+            .collect::<Vec<_>>();
+        result.sort_by(|a, b| a.address.cmp(&b.address));
+        result.dedup();
+        result
+    }
 }
 
 impl<'a> Component for DeckEditorPanel<'a> {
@@ -204,20 +241,7 @@ impl<'a> Component for DeckEditorPanel<'a> {
                             .justify_content(FlexJustify::Center),
                     )
                     .child(self.title())
-                    .child(
-                        self.card_section(
-                            deck.identities
-                                .iter()
-                                .chain(deck.sigils.iter())
-                                .map(|variant| (variant, None))
-                                .chain(
-                                    deck.cards
-                                        .iter()
-                                        .map(|(variant, quantity)| (variant, Some(quantity))),
-                                )
-                                .collect(),
-                        ),
-                    ),
+                    .child(self.card_section(Self::displayed_cards(deck))),
             )
             .build()
     }
@@ -0,0 +1,77 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Panel listing all of a player's decks
+
+use core_data::game_primitives::DeckId;
+use core_ui::button::{Button, ButtonType};
+use core_ui::design::FontSize;
+use core_ui::panel_window::PanelWindow;
+use core_ui::prelude::*;
+use core_ui::text::Text;
+use panel_address::{Panel, PanelAddress, PlayerPanel};
+use player_data::{DeckSummary, PlayerState};
+use protos::riftcaller::{FlexAlign, FlexJustify};
+use user_action_data::UserAction;
+
+pub struct DeckListPanel {
+    decks: Vec<(DeckId, DeckSummary)>,
+}
+
+impl DeckListPanel {
+    pub fn new(player: &PlayerState) -> Self {
+        Self { decks: player.decks() }
+    }
+
+    fn deck_row(deck_id: DeckId, summary: &DeckSummary) -> impl Component {
+        Row::new("DeckRow")
+            .style(
+                Style::new()
+                    .align_items(FlexAlign::Center)
+                    .justify_content(FlexJustify::SpaceBetween)
+                    .margin(Edge::All, 16.px()),
+            )
+            .child(Text::new(summary.name.clone()).font_size(FontSize::Headline))
+            .child(Text::new(format!("{} cards", summary.card_count)).font_size(FontSize::Body))
+            .child(
+                Button::new("Duplicate")
+                    .action(UserAction::CopyDeck(deck_id))
+                    .button_type(ButtonType::Secondary),
+            )
+    }
+}
+
+impl Panel for DeckListPanel {
+    fn address(&self) -> PanelAddress {
+        PlayerPanel::DeckList.into()
+    }
+}
+
+impl Component for DeckListPanel {
+    fn build(self) -> Option<Node> {
+        PanelWindow::new(self.address(), 512.px(), 600.px())
+            .title("Decks")
+            .content(
+                Column::new("DeckList")
+                    .style(
+                        Style::new()
+                            .width(100.pct())
+                            .align_items(FlexAlign::Stretch)
+                            .justify_content(FlexJustify::FlexStart),
+                    )
+                    .children(self.decks.iter().map(|(id, summary)| Self::deck_row(*id, summary))),
+            )
+            .build()
+    }
+}
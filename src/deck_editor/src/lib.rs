@@ -14,4 +14,7 @@
 
 //! Renders the deck editor window
 
+pub mod deck;
+pub mod deck_editor_actions;
 pub mod deck_editor_panel;
+pub mod deck_list;
@@ -27,6 +27,7 @@ use user_action_data::NamedDeck;
 pub static EMPTY_COVENANT: Lazy<Deck> = Lazy::new(|| Deck {
     side: Side::Covenant,
     schools: vec![],
+    school_limit: None,
     identities: vec![],
     sigils: vec![],
     cards: HashMap::new(),
@@ -36,6 +37,7 @@ pub static EMPTY_COVENANT: Lazy<Deck> = Lazy::new(|| Deck {
 pub static COVENANT_TEST_SPELLS: Lazy<Deck> = Lazy::new(|| Deck {
     side: Side::Covenant,
     schools: vec![],
+    school_limit: None,
     identities: vec![],
     sigils: vec![],
     cards: hashmap! {CardVariant::standard(CardName::TestRitual) => 45},
@@ -45,6 +47,7 @@ pub static COVENANT_TEST_SPELLS: Lazy<Deck> = Lazy::new(|| Deck {
 pub static BASIC_COVENANT: Lazy<Deck> = Lazy::new(|| Deck {
     side: Side::Covenant,
     schools: vec![],
+    school_limit: None,
     identities: vec![],
     sigils: vec![],
     cards: hashmap! {
@@ -72,6 +75,7 @@ pub static TUTORIAL_COVENANT: Lazy<Deck> =
 pub static CANONICAL_COVENANT: Lazy<Deck> = Lazy::new(|| Deck {
     side: Side::Covenant,
     schools: vec![School::Law],
+    school_limit: None,
     identities: vec![],
     sigils: vec![],
     cards: hashmap! {
@@ -97,6 +101,7 @@ pub static CANONICAL_COVENANT: Lazy<Deck> = Lazy::new(|| Deck {
 pub static EMPTY_RIFTCALLER: Lazy<Deck> = Lazy::new(|| Deck {
     side: Side::Riftcaller,
     schools: vec![],
+    school_limit: None,
     identities: vec![],
     sigils: vec![],
     cards: HashMap::new(),
@@ -106,6 +111,7 @@ pub static EMPTY_RIFTCALLER: Lazy<Deck> = Lazy::new(|| Deck {
 pub static RIFTCALLER_TEST_SPELLS: Lazy<Deck> = Lazy::new(|| Deck {
     side: Side::Riftcaller,
     schools: vec![],
+    school_limit: None,
     identities: vec![],
     sigils: vec![],
     cards: hashmap! {CardVariant::standard(CardName::TestSpell) => 45},
@@ -115,6 +121,7 @@ pub static RIFTCALLER_TEST_SPELLS: Lazy<Deck> = Lazy::new(|| Deck {
 pub static BASIC_RIFTCALLER: Lazy<Deck> = Lazy::new(|| Deck {
     side: Side::Riftcaller,
     schools: vec![],
+    school_limit: None,
     identities: vec![],
     sigils: vec![],
     cards: hashmap! {
@@ -142,6 +149,7 @@ pub static TUTORIAL_RIFTCALLER: Lazy<Deck> =
 pub static CANONICAL_RIFTCALLER: Lazy<Deck> = Lazy::new(|| Deck {
     side: Side::Riftcaller,
     schools: vec![School::Primal],
+    school_limit: None,
     identities: vec![],
     sigils: vec![],
     cards: hashmap! {
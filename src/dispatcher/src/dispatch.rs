@@ -34,6 +34,14 @@ pub fn populate_delegate_map(game: &mut GameState) {
         let definition = cards::get(variant);
         add_card_to_delegate_map(&mut result, definition, card_id, variant.metadata);
     }
+    for card_id in game.all_card_ids() {
+        let scope_card = game.card(card_id);
+        for (index, source) in scope_card.custom_state.copied_abilities() {
+            let scope = Scope::new(AbilityId { card_id, index }, scope_card.variant.metadata);
+            let definition = cards::get(game.card(source.card_id).variant);
+            add_copied_ability_to_delegate_map(&mut result, definition, source, scope);
+        }
+    }
     game.delegate_map = result;
 }
 
@@ -49,10 +57,37 @@ pub fn add_card_to_delegate_map(
         let scope = Scope::new(ability_id, metadata);
         for delegate in &ability.delegates {
             if let Delegate::GameDelegate(d) = delegate {
-                map.lookup
-                    .entry(d.kind())
-                    .or_default()
-                    .push(GameDelegateContext { delegate: d.clone(), scope });
+                map.lookup.entry(d.kind()).or_default().push(GameDelegateContext {
+                    delegate: d.clone(),
+                    scope,
+                    copied_from: None,
+                });
+            }
+        }
+    }
+}
+
+/// Adds the delegates of the `source` ability, whose card has the provided
+/// [CardDefinition], to the delegate map under a different [Scope].
+///
+/// The added delegates are only invoked while the scope card has a matching
+/// `CustomCardState::CopiedAbilityForTurn` entry for the current turn. Has no
+/// effect if this ability has already been copied for this scope.
+pub fn add_copied_ability_to_delegate_map(
+    map: &mut GameDelegateMap,
+    definition: &CardDefinition,
+    source: AbilityId,
+    scope: Scope,
+) {
+    for delegate in &definition.ability(source.index).delegates {
+        if let Delegate::GameDelegate(d) = delegate {
+            let list = map.lookup.entry(d.kind()).or_default();
+            if !list.iter().any(|c| c.scope == scope && c.copied_from == Some(source)) {
+                list.push(GameDelegateContext {
+                    delegate: d.clone(),
+                    scope,
+                    copied_from: Some(source),
+                });
             }
         }
     }
@@ -88,7 +123,7 @@ pub fn invoke_event<D: Debug, E: EventData<D>>(game: &mut GameState, event: E) -
         let scope = delegate_context.scope;
         let functions = E::extract(&delegate_context.delegate).expect("Delegate not found!");
         let data = event.data();
-        if (functions.requirement)(game, scope, data) {
+        if is_active(game, delegate_context) && (functions.requirement)(game, scope, data) {
             (functions.mutation)(game, scope, data)?;
         }
     }
@@ -111,9 +146,21 @@ pub fn perform_query<D: Debug, V: Debug, Q: QueryData<D, V>>(
         let scope = delegate_context.scope;
         let functions = Q::extract(&delegate_context.delegate).expect("Delegate not found!");
         let data = query.data();
-        if (functions.requirement)(game, scope, data) {
+        if is_active(game, delegate_context) && (functions.requirement)(game, scope, data) {
             result = (functions.transformation)(game, scope, data, result);
         }
     }
     result
 }
+
+/// Returns false if this delegate was copied from another ability and the copy
+/// is not active for the current turn.
+fn is_active(game: &GameState, context: &GameDelegateContext) -> bool {
+    context.copied_from.is_none_or(|source| {
+        game.card(context.scope.card_id()).custom_state.ability_copied_for_turn(
+            context.scope.ability_id().index,
+            source,
+            game.info.turn,
+        )
+    })
+}
@@ -28,8 +28,8 @@ use protos::riftcaller::object_position::Position;
 use protos::riftcaller::play_effect_position::EffectPosition;
 use protos::riftcaller::{
     CreateTokenCardCommand, DelayCommand, DisplayGameMessageCommand, FireProjectileCommand,
-    FlexColor, GameMessageType, GameObjectMove, MoveGameObjectsCommand, MusicState,
-    PlayEffectCommand, PlayEffectPosition, PlaySoundCommand, RoomVisitType,
+    FlexColor, FocusGameObjectCommand, GameMessageType, GameObjectMove, MoveGameObjectsCommand,
+    MusicState, PlayEffectCommand, PlayEffectPosition, PlaySoundCommand, RoomVisitType,
     SetCardMovementEffectCommand, SetMusicCommand, TimeValue, TurnFaceDownArenaAnimationCommand,
     VisitRoomCommand,
 };
@@ -56,6 +56,7 @@ pub fn render(
             }
         }
         GameAnimation::AbilityTriggered(ability_id, effects) => {
+            focus(builder, ability_id.card_id);
             play_special_effects(builder, effects);
             show_ability(builder, snapshot, *ability_id);
         }
@@ -180,6 +181,12 @@ fn in_display_position(builder: &ResponseBuilder, card_id: CardId) -> bool {
     })
 }
 
+/// Requests that the client center its camera on the indicated game object.
+pub fn focus(builder: &mut ResponseBuilder, id: impl Into<GameObjectId>) {
+    let object = adapters::game_object_identifier(builder, id);
+    builder.push(Command::FocusGameObject(FocusGameObjectCommand { object: Some(object) }));
+}
+
 fn show_ability(builder: &mut ResponseBuilder, snapshot: &GameState, ability_id: AbilityId) {
     let mut card = card_sync::ability_card_view(builder, snapshot, ability_id, false, None);
     card.card_position = Some(positions::for_ability(snapshot, ability_id, positions::staging()));
@@ -213,7 +220,8 @@ fn combat_interaction(
     snapshot: &GameState,
     interaction: TargetedInteraction,
 ) {
-    let mut projectile = &ProjectileData::new(Projectile::Projectiles1(3));
+    let fallback = fallback_combat_projectile();
+    let mut projectile = &fallback;
     if let GameObjectId::CardId(card_id) = interaction.source {
         if let Some(data) = &snapshot.card(card_id).definition().config.combat_projectile {
             projectile = data;
@@ -223,6 +231,13 @@ fn combat_interaction(
     builder.push(fire_projectile(builder, interaction, projectile));
 }
 
+/// Projectile fired for combat interactions whose source card does not
+/// configure a `combat_projectile`, so that every attack shows some visual
+/// effect.
+pub fn fallback_combat_projectile() -> ProjectileData {
+    ProjectileData::new(Projectile::Projectiles1(3))
+}
+
 fn fire_projectile(
     builder: &ResponseBuilder,
     interaction: TargetedInteraction,
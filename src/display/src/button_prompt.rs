@@ -64,8 +64,8 @@ pub fn append_prompt_speech_bubble<'a>(
         .last_prompt_response
         .iter()
         .filter(|&(side, choice)| should_show_bubble(builder, *side, choice))
-        .map(|(side, choice)| TutorialEffect {
-            tutorial_effect_type: Some(tutorial_display::render_effect(
+        .map(|(side, choice)| {
+            tutorial_display::render_effect(
                 builder,
                 &TutorialDisplay::SpeechBubble(SpeechBubble {
                     text: effect_prompts::label(*side, choice),
@@ -73,7 +73,7 @@ pub fn append_prompt_speech_bubble<'a>(
                     delay: Milliseconds(0),
                     recurring: false,
                 }),
-            )),
+            )
         })
 }
 
@@ -139,6 +139,9 @@ fn prompt_context(game: &GameState, prompt_context: Option<&ButtonPromptContext>
         ButtonPromptContext::CardToTakeFromOpponent => {
             GameInstructions::new("Select card to take from opponent").build()
         }
+        ButtonPromptContext::CardToDiscardFromOpponent => {
+            GameInstructions::new("Select card for opponent to discard").build()
+        }
         ButtonPromptContext::PayToPreventRevealing(cost) => {
             GameInstructions::new(format!("Pay {}{} to prevent revealing?", cost, icons::MANA))
                 .build()
@@ -153,5 +156,17 @@ fn prompt_context(game: &GameState, prompt_context: Option<&ButtonPromptContext>
                 GameInstructions::new(format!("Add {}{}.", count, icons::POWER_CHARGE)).build()
             }
         }
+        ButtonPromptContext::ChooseFirstTurn => {
+            GameInstructions::new("Choose who takes the first turn".to_string()).build()
+        }
+        ButtonPromptContext::DiscardToReduceCost(card_id, amount) => {
+            GameInstructions::new(format!(
+                "Discard a card to reduce the cost of {} by {}{}?",
+                game.card(*card_id).variant.name.displayed_name(),
+                amount,
+                icons::MANA
+            ))
+            .build()
+        }
     }
 }
@@ -41,6 +41,8 @@ fn instructions(context: Option<PromptContext>) -> Option<String> {
         Some(PromptContext::DiscardToHandSize(amount)) => {
             Some(format!("You must discard until you have {amount} cards in hand."))
         }
+        Some(PromptContext::DiscardCards(1)) => Some("Discard a card.".to_string()),
+        Some(PromptContext::DiscardCards(amount)) => Some(format!("Discard {amount} cards.")),
         Some(PromptContext::MoveToTopOfVault) => {
             Some("Put a card from the crypt on top of the vault?".to_string())
         }
@@ -54,7 +56,7 @@ fn instructions(context: Option<PromptContext>) -> Option<String> {
 
 fn metatext(context: Option<PromptContext>) -> Option<String> {
     match context {
-        Some(PromptContext::DiscardToHandSize(..)) => {
+        Some(PromptContext::DiscardToHandSize(..)) | Some(PromptContext::DiscardCards(..)) => {
             Some("<i>(Drag cards down from your hand to your deck.)</i>".to_string())
         }
         Some(PromptContext::MoveToTopOfVault) => {
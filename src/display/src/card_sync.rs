@@ -16,9 +16,7 @@ use adapters::response_builder::ResponseBuilder;
 use card_definition_data::ability_data::AbilityType;
 use card_definition_data::card_view_context::CardViewContext;
 use card_definition_data::cards;
-use core_data::game_primitives::{
-    AbilityId, CardType, ItemLocation, RoomId, RoomLocation, School, Side,
-};
+use core_data::game_primitives::{AbilityId, CardType, ItemLocation, RoomLocation, School, Side};
 use core_ui::{design, icons};
 use dispatcher::dispatch;
 use enumset::EnumSet;
@@ -26,16 +24,15 @@ use game_data::card_configuration::TargetRequirement;
 use game_data::card_state::CardState;
 use game_data::continuous_visual_effect::ContinuousDisplayEffect;
 use game_data::delegate_data::ContinuousDisplayEffectQuery;
-use game_data::game_actions::CardTarget;
+use game_data::game_actions;
 use game_data::game_state::GameState;
 use game_data::prompt_data::GamePrompt;
 use game_data::special_effects::TimedEffect;
-use game_data::state_machine_data::PlayCardOptions;
 use protos::riftcaller::card_targeting::Targeting;
 use protos::riftcaller::{
     info_zoom_highlight, ArrowTargetRoom, CardEffects, CardIcons, CardPrefab, CardTargeting,
     CardTitle, CardView, EffectAddress, FlexColor, InfoZoomHighlight, NoTargeting, PlayInRoom,
-    RevealedCardView, RulesText, TargetingArrow,
+    RevealedCardView, RulesText, SpriteAddress, TargetingArrow,
 };
 use rules::{flags, prompts, queries};
 use rules_text::{card_icons, supplemental_info};
@@ -50,11 +47,11 @@ pub fn card_view(builder: &ResponseBuilder, context: &CardViewContext) -> CardVi
         card_position: context
             .query_or(None, |game, card| Some(positions::calculate(builder, game, card))),
         prefab: CardPrefab::Standard.into(),
-        card_back: Some(assets::card_back(
-            context.query_or(context.definition().school, |game, card| {
-                *game.player(card.side()).schools.first().unwrap_or(&School::Neutral)
+        card_back: Some(
+            context.query_or(assets::card_back(context.definition().school), |game, card| {
+                card_back(game, card.side())
             }),
-        )),
+        ),
         revealed_to_viewer: revealed,
         is_face_up: context.query_or(true, |_, card| card.is_face_up()),
         card_icons: Some(card_icons::build(context, revealed)),
@@ -187,28 +184,15 @@ fn revealed_card_view(
         card_frame: Some(assets::card_frame(definition.school, false)),
         title_background: Some(assets::title_background(resonance)),
         jewel: Some(assets::jewel(definition.rarity)),
-        image: Some(adapters::sprite(&definition.image)),
+        image: Some(card_image(context)),
         image_background: definition.config.image_background.as_ref().map(adapters::sprite),
         title: Some(CardTitle {
             text: definition.name.displayed_name(),
             text_color: Some(assets::title_color(resonance)),
         }),
         rules_text: Some(rules_text::build(context)),
-        targeting: context.query_or_none(|game, card| {
-            card_targeting(
-                definition.config.custom_targeting.as_ref(),
-                flags::enters_play_in_room(game, card.id),
-                |target| {
-                    flags::can_play_card(
-                        game,
-                        builder.user_side,
-                        card.id,
-                        target,
-                        PlayCardOptions::default(),
-                    )
-                },
-            )
-        }),
+        targeting: context
+            .query_or_none(|game, card| card_targeting(queries::valid_targets(game, card.id))),
         on_release_position: Some(positions::for_sorting_key(
             positions::RELEASE_SORTING_KEY,
             match definition.card_type {
@@ -234,6 +218,26 @@ fn revealed_card_view(
     })
 }
 
+/// Card back to display for cards owned by the `side` player, using their
+/// selected card back if one exists.
+pub fn card_back(game: &GameState, side: Side) -> SpriteAddress {
+    match &game.player(side).card_back {
+        Some(sprite) => adapters::sprite(sprite),
+        None => assets::card_back(*game.player(side).schools.first().unwrap_or(&School::Neutral)),
+    }
+}
+
+/// Image to display for a card, using the owning player's alternate art
+/// selection if one exists.
+fn card_image(context: &CardViewContext) -> SpriteAddress {
+    let definition = context.definition();
+    let image = context
+        .game()
+        .and_then(|game| game.player(definition.side).card_art_overrides.get(&definition.name))
+        .unwrap_or(&definition.image);
+    adapters::sprite(image)
+}
+
 fn info_zoom_highlight(card: &CardState) -> Option<InfoZoomHighlight> {
     if card.position().in_play() {
         let card_play_id = card.last_card_play_id?;
@@ -269,7 +273,7 @@ fn revealed_ability_card_view(
         card_frame: Some(assets::card_frame(definition.school, full_height)),
         title_background: Some(assets::ability_title_background()),
         jewel: Some(assets::jewel(definition.rarity)),
-        image: Some(adapters::sprite(&definition.image)),
+        image: Some(card_image(context)),
         image_background: definition.config.image_background.as_ref().map(adapters::sprite),
         title: Some(CardTitle {
             text: format!(
@@ -282,9 +286,9 @@ fn revealed_ability_card_view(
         }),
         rules_text: Some(RulesText { text: rules_text::ability_text(context, ability) }),
         targeting: context.query_or_none(|game, _| {
-            card_targeting(target_requirement, false, |target| {
+            card_targeting(queries::targets_for_requirement(target_requirement, false, |target| {
                 flags::can_take_activate_ability_action(game, ability_id.side(), ability_id, target)
-            })
+            }))
         }),
         on_release_position: context.query_or_none(|game, _| {
             positions::for_ability(game, ability_id, positions::staging())
@@ -296,32 +300,24 @@ fn revealed_ability_card_view(
     })
 }
 
-/// Builds a [CardTargeting] object for a card, given its target requirement and
-/// a can_play function.
-pub fn card_targeting<T>(
-    requirement: Option<&TargetRequirement<T>>,
-    play_in_room: bool,
-    can_play: impl Fn(CardTarget) -> bool,
-) -> CardTargeting {
+/// Builds a [CardTargeting] object for a card from the set of legal targets
+/// computed by the rules engine.
+pub fn card_targeting(targeting: game_actions::CardTargeting) -> CardTargeting {
     CardTargeting {
-        targeting: Some(match (requirement, play_in_room) {
-            (None, false) | (Some(TargetRequirement::None), _) => {
-                Targeting::NoTargeting(NoTargeting { can_play: can_play(CardTarget::None) })
+        targeting: Some(match targeting {
+            game_actions::CardTargeting::NoTargeting { can_play } => {
+                Targeting::NoTargeting(NoTargeting { can_play })
             }
-            (None, true) | (Some(TargetRequirement::TargetRoom(_)), _) => {
-                let valid = enum_iterator::all::<RoomId>()
-                    .filter(|room_id| can_play(CardTarget::Room(*room_id)))
-                    .map(adapters::room_identifier)
-                    .collect();
-
-                if play_in_room {
-                    Targeting::PlayInRoom(PlayInRoom { valid_rooms: valid })
-                } else {
-                    Targeting::ArrowTargetRoom(ArrowTargetRoom {
-                        valid_rooms: valid,
-                        arrow: TargetingArrow::Red.into(),
-                    })
-                }
+            game_actions::CardTargeting::PlayInRoom { valid_rooms } => {
+                Targeting::PlayInRoom(PlayInRoom {
+                    valid_rooms: valid_rooms.into_iter().map(adapters::room_identifier).collect(),
+                })
+            }
+            game_actions::CardTargeting::TargetRoom { valid_rooms } => {
+                Targeting::ArrowTargetRoom(ArrowTargetRoom {
+                    valid_rooms: valid_rooms.into_iter().map(adapters::room_identifier).collect(),
+                    arrow: TargetingArrow::Red.into(),
+                })
             }
         }),
     }
@@ -0,0 +1,65 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_data::game_primitives::CardId;
+use core_ui::prelude::*;
+use game_data::game_actions::GameAction;
+use game_data::prompt_data::{ChooseMultiplePrompt, ChooseMultiplePromptEffect, PromptAction};
+use prompt_ui::game_instructions::GameInstructions;
+use prompt_ui::prompt_container::PromptContainer;
+use prompt_ui::response_button::ResponseButton;
+use protos::riftcaller::InterfaceMainControls;
+
+pub fn controls(prompt: &ChooseMultiplePrompt) -> Option<InterfaceMainControls> {
+    let card_anchor_nodes = prompt
+        .targets
+        .iter()
+        .filter(|card_id| prompt.selected.contains(card_id) || !prompt.is_full())
+        .map(|card_id| {
+            ResponseButton::new(toggle_label(prompt, *card_id))
+                .primary(!prompt.selected.contains(card_id))
+                .anchor_to(*card_id)
+                .action(GameAction::PromptAction(PromptAction::ChooseMultipleToggle(*card_id)))
+                .render_to_card_anchor_node()
+        })
+        .collect();
+
+    Some(InterfaceMainControls {
+        node: PromptContainer::new()
+            .child(
+                ResponseButton::new("Submit")
+                    .action(GameAction::PromptAction(PromptAction::ChooseMultipleSubmit)),
+            )
+            .build(),
+        overlay: GameInstructions::new(instructions(prompt)).build(),
+        card_anchor_nodes,
+    })
+}
+
+fn toggle_label(prompt: &ChooseMultiplePrompt, card_id: CardId) -> &'static str {
+    if prompt.selected.contains(&card_id) {
+        "Deselect"
+    } else {
+        match prompt.effect {
+            ChooseMultiplePromptEffect::DestroyCards => "Destroy",
+        }
+    }
+}
+
+fn instructions(prompt: &ChooseMultiplePrompt) -> String {
+    let verb = match prompt.effect {
+        ChooseMultiplePromptEffect::DestroyCards => "destroy",
+    };
+    format!("Select up to {} cards to {verb} ({} selected).", prompt.count, prompt.selected.len())
+}
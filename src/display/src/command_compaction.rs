@@ -0,0 +1,46 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Post-processing passes which simplify the command list sent to the client.
+
+use protos::riftcaller::game_command::Command;
+use protos::riftcaller::MoveGameObjectsCommand;
+
+/// Merges runs of adjacent [MoveGameObjectsCommand]s with identical timing
+/// into a single command, so that the client animates them in parallel
+/// instead of one after another.
+///
+/// Commands are only merged if they share the same `delay` and
+/// `disable_animation` values and do not move any of the same game objects.
+pub fn merge_moves(commands: Vec<Command>) -> Vec<Command> {
+    let mut result: Vec<Command> = Vec::with_capacity(commands.len());
+    for command in commands {
+        if let (Some(Command::MoveGameObjects(previous)), Command::MoveGameObjects(next)) =
+            (result.last_mut(), &command)
+        {
+            if can_merge(previous, next) {
+                previous.moves.extend(next.moves.iter().cloned());
+                continue;
+            }
+        }
+        result.push(command);
+    }
+    result
+}
+
+fn can_merge(previous: &MoveGameObjectsCommand, next: &MoveGameObjectsCommand) -> bool {
+    previous.delay == next.delay
+        && previous.disable_animation == next.disable_animation
+        && !next.moves.iter().any(|m| previous.moves.iter().any(|p| p.id == m.id))
+}
@@ -21,7 +21,7 @@ use constants::game_constants;
 use core_data::game_primitives::{CardId, CardType, ManaValue, Rarity, School, Side, WoundCount};
 use core_ui::icons;
 use enumset::EnumSet;
-use game_data::card_configuration::TargetRequirement;
+use game_data::game_actions;
 use game_data::game_actions::CardTarget;
 use game_data::game_state::GameState;
 use game_data::prompt_data::{GamePrompt, RoomSelectorPrompt};
@@ -375,6 +375,7 @@ fn room_selector(
 }
 
 fn boolean_target(can_play: impl Fn(CardTarget) -> bool) -> CardTargeting {
-    let no_target: Option<&TargetRequirement<()>> = None;
-    card_sync::card_targeting(no_target, false, can_play)
+    card_sync::card_targeting(game_actions::CardTargeting::NoTargeting {
+        can_play: can_play(CardTarget::None),
+    })
 }
@@ -0,0 +1,87 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_data::game_primitives::CardId;
+use core_ui::icons;
+use core_ui::prelude::*;
+use game_data::game_actions::GameAction;
+use game_data::prompt_data::{DistributePrompt, DistributePromptEffect, PromptAction};
+use prompt_ui::game_instructions::GameInstructions;
+use prompt_ui::prompt_container::PromptContainer;
+use prompt_ui::response_button::ResponseButton;
+use protos::riftcaller::InterfaceMainControls;
+
+pub fn controls(prompt: &DistributePrompt) -> Option<InterfaceMainControls> {
+    let card_anchor_nodes = if prompt.remaining() > 0 {
+        prompt
+            .targets
+            .iter()
+            .map(|card_id| {
+                ResponseButton::new(allocate_label(prompt, *card_id))
+                    .anchor_to(*card_id)
+                    .action(GameAction::PromptAction(PromptAction::DistributeAllocate(*card_id)))
+                    .render_to_card_anchor_node()
+            })
+            .collect()
+    } else {
+        vec![]
+    };
+
+    Some(InterfaceMainControls {
+        node: buttons(prompt),
+        overlay: GameInstructions::new(instructions(prompt)).build(),
+        card_anchor_nodes,
+    })
+}
+
+fn icon(effect: DistributePromptEffect) -> &'static str {
+    match effect {
+        DistributePromptEffect::AddPowerCharges => icons::POWER_CHARGE,
+    }
+}
+
+fn allocate_label(prompt: &DistributePrompt, card_id: CardId) -> String {
+    match prompt.allocated_to(card_id) {
+        0 => format!("+1{}", icon(prompt.effect)),
+        n => format!("+1{} ({})", icon(prompt.effect), n),
+    }
+}
+
+fn instructions(prompt: &DistributePrompt) -> String {
+    if prompt.remaining() == 0 {
+        "Submit allocation?".to_string()
+    } else {
+        format!("Distribute {}{}.", prompt.remaining(), icon(prompt.effect))
+    }
+}
+
+fn buttons(prompt: &DistributePrompt) -> Option<Node> {
+    let mut container = PromptContainer::new();
+    if !prompt.allocated.is_empty() {
+        container = container.child(
+            ResponseButton::new("Reset")
+                .primary(false)
+                .action(GameAction::PromptAction(PromptAction::DistributeReset)),
+        );
+    }
+
+    if prompt.remaining() == 0 {
+        container = container.child(
+            ResponseButton::new("Submit")
+                .action(GameAction::PromptAction(PromptAction::DistributeSubmit)),
+        );
+    }
+
+    container.build()
+}
@@ -24,7 +24,10 @@ use protos::riftcaller::InterfaceMainControls;
 use raid_display::raid_prompt;
 use rules::flags;
 
-use crate::{button_prompt, card_selector, play_card_browser, room_selector_prompt};
+use crate::{
+    button_prompt, card_selector, choose_multiple_prompt, distribute_prompt, play_card_browser,
+    room_selector_prompt,
+};
 
 /// Returns a [InterfaceMainControls] to render the interface state for the
 /// provided `game`.
@@ -49,6 +52,8 @@ pub fn render(builder: &ResponseBuilder, game: &GameState) -> Option<InterfaceMa
                 },
             ),
             GamePrompt::RoomSelector(prompt) => room_selector_prompt::controls(prompt),
+            GamePrompt::Distribute(prompt) => distribute_prompt::controls(prompt),
+            GamePrompt::ChooseMultiple(prompt) => choose_multiple_prompt::controls(prompt),
         };
     } else if rules::prompts::current(game, side.opponent()).is_some() {
         // Wait for opponent to make a decision
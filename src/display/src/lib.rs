@@ -19,7 +19,10 @@ pub mod animations;
 pub mod button_prompt;
 pub mod card_selector;
 pub mod card_sync;
+pub mod choose_multiple_prompt;
+pub mod command_compaction;
 pub mod custom_card_views;
+pub mod distribute_prompt;
 pub mod game_over;
 pub mod interface;
 pub mod play_card_browser;
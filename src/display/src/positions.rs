@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use adapters;
 use adapters::response_builder::ResponseBuilder;
 use adapters::CustomCardIdentifier;
@@ -22,7 +24,7 @@ use core_data::game_primitives::{
 };
 use game_data::card_state::{CardPosition, CardState};
 use game_data::game_actions::{CardTarget, DisplayPreference};
-use game_data::game_state::{GamePhase, GameState, MulliganData};
+use game_data::game_state::{GamePhase, GameState, HandSortOrder, MulliganData};
 use game_data::prompt_data::{GamePrompt, SelectorPromptTarget};
 use game_data::raid_data::{RaidData, RaidDisplayState};
 use protos::riftcaller::object_position::Position;
@@ -212,12 +214,30 @@ pub fn calculate(builder: &ResponseBuilder, game: &GameState, card: &CardState)
     }
 
     ObjectPosition {
-        sorting_key: card.sorting_key,
+        sorting_key: builder.hand_sorting_keys.get(&card.id).copied().unwrap_or(card.sorting_key),
         position: adapt_position(builder, game, card.id, Some(card.position())),
         ..ObjectPosition::default()
     }
 }
 
+/// Returns sorting keys for the cards in the user's hand if they have selected
+/// a [HandSortOrder]. Cards which compare equal keep the order in which they
+/// were added to the hand.
+pub fn hand_sorting_keys(builder: &ResponseBuilder, game: &GameState) -> HashMap<CardId, u32> {
+    let Some(order) = game.player(builder.user_side).hand_sort_order else {
+        return HashMap::new();
+    };
+
+    let mut hand = game.hand(builder.user_side).collect::<Vec<_>>();
+    match order {
+        HandSortOrder::ByCost => {
+            hand.sort_by_key(|c| (queries::mana_cost(game, c.id), c.sorting_key))
+        }
+        HandSortOrder::ByType => hand.sort_by_key(|c| (c.definition().card_type, c.sorting_key)),
+    }
+    hand.iter().enumerate().map(|(index, c)| (c.id, index as u32)).collect()
+}
+
 fn adapt_position(
     builder: &ResponseBuilder,
     game: &GameState,
@@ -240,7 +260,7 @@ fn adapt_position(
         CardPosition::Played(card_play_id, side, target) => {
             played_position(builder, game, side, card_id, target, card_play_id)
         }
-        CardPosition::DeckUnknown(..) => None,
+        CardPosition::DeckUnknown(..) | CardPosition::DeckBottom(..) => None,
         CardPosition::GameModifier => Some(offscreen()),
         CardPosition::Sigil(side) => Some(display_shelf(builder, side)),
         CardPosition::Banished(Some(by_card))
@@ -437,6 +457,8 @@ fn prompt_position_override(
                 return Some(for_card(card, hand_storage()));
             }
         }
+        GamePrompt::Distribute(..) => {}
+        GamePrompt::ChooseMultiple(..) => {}
     }
 
     None
@@ -19,7 +19,7 @@ use game_data::game_actions::DisplayPreference;
 use game_data::game_state::GameState;
 use protos::riftcaller::game_command::Command;
 
-use crate::{animations, game_over, sync};
+use crate::{animations, command_compaction, game_over, sync};
 
 pub fn connect(game: &GameState, user_side: Side) -> Result<Vec<Command>> {
     let mut builder = ResponseBuilder::new(
@@ -50,5 +50,5 @@ pub fn render_updates(
     sync::run(&mut builder, game);
     game_over::check_game_over(&mut builder, game);
 
-    Ok(builder.commands)
+    Ok(command_compaction::merge_moves(builder.commands))
 }
@@ -15,14 +15,15 @@
 use adapters::response_builder::{ResponseBuilder, ResponseState};
 use core_data::game_primitives::Side;
 use game_data::game_actions::DisplayPreference;
-use game_data::game_state::{GamePhase, GameState};
+use game_data::game_state::{GamePhase, GameState, HandSortOrder};
 
 use crate::{positions, sync};
 
 /// Returns a [DisplayPreference] which can be requested in the current game
 /// state, via the "set display preference" button, if applicable.
 ///
-/// Example: A button to hide the raid browser and view the arena.
+/// Example: A button to hide the raid browser and view the arena. When no
+/// other preference applies, the button cycles through hand sort orders.
 pub fn button(
     game: &GameState,
     user_side: Side,
@@ -47,6 +48,10 @@ pub fn button(
     {
         Some(DisplayPreference::ShowArenaView(true))
     } else {
-        None
+        Some(DisplayPreference::SortHand(match game.player(user_side).hand_sort_order {
+            None => Some(HandSortOrder::ByCost),
+            Some(HandSortOrder::ByCost) => Some(HandSortOrder::ByType),
+            Some(HandSortOrder::ByType) => None,
+        }))
     }
 }
@@ -18,13 +18,14 @@ use adapters::response_builder::ResponseBuilder;
 use card_definition_data::card_view_context::CardViewContext;
 use card_definition_data::cards;
 use constants::game_constants;
-use core_data::game_primitives::{RoomId, School, Side};
+use core_data::game_primitives::{Milliseconds, RoomId, Side};
 use game_data::card_state::{CardPositionKind, CardState};
 use game_data::character_preset::CharacterPreset;
 use game_data::game_state::GameState;
 use protos::riftcaller::{
     ActionTrackerView, DeckView, GameView, ManaView, PlayerInfo, PlayerView, ScoreView,
 };
+use raid_display::raid_prompt;
 use rules::mana::ManaPurpose;
 use rules::{curses, flags, mana, queries};
 use {adapters, assets};
@@ -35,6 +36,7 @@ use crate::{
 };
 
 pub fn run(builder: &mut ResponseBuilder, game: &GameState) {
+    builder.hand_sorting_keys = positions::hand_sorting_keys(builder, game);
     let cards = game
         .all_cards()
         .filter(|c| !skip_sending_to_client(c))
@@ -83,6 +85,13 @@ pub fn run(builder: &mut ResponseBuilder, game: &GameState) {
             // Likewise hide tutorial updates while animating
             tutorial_display::render(builder, &game.info.tutorial_state)
                 .chain(button_prompt::append_prompt_speech_bubble(builder, game))
+                .chain(raid_prompt::has_no_usable_weapon(game, builder.user_side).then(|| {
+                    tutorial_display::toast(
+                        "You have no weapon which can defeat this minion",
+                        Milliseconds(0),
+                        Some(Milliseconds(4000)),
+                    )
+                }))
                 .collect()
         } else {
             vec![]
@@ -123,9 +132,7 @@ fn player_view(game: &GameState, side: Side) -> PlayerView {
             },
         }),
         deck_view: Some(DeckView {
-            card_back: Some(assets::card_back(
-                *game.player(side).schools.first().unwrap_or(&School::Neutral),
-            )),
+            card_back: Some(card_sync::card_back(game, side)),
             card_count: game.deck(side).count() as u32,
             can_take_draw_card_action: flags::can_take_draw_card_action(game, side),
         }),
@@ -136,5 +143,7 @@ fn player_view(game: &GameState, side: Side) -> PlayerView {
 pub fn skip_sending_to_client(card: &CardState) -> bool {
     let hidden = !(card.is_visible_to(Side::Riftcaller) || card.is_visible_to(Side::Covenant));
     let position = card.position().kind();
-    position == CardPositionKind::DeckUnknown || (position == CardPositionKind::DeckTop && hidden)
+    position == CardPositionKind::DeckUnknown
+        || position == CardPositionKind::DeckBottom
+        || (position == CardPositionKind::DeckTop && hidden)
 }
@@ -30,13 +30,11 @@ pub fn render<'a>(
     builder: &'a ResponseBuilder,
     state: &'a GameTutorialState,
 ) -> impl Iterator<Item = TutorialEffect> + 'a {
-    state.display.iter().map(|display| TutorialEffect {
-        tutorial_effect_type: Some(render_effect(builder, display)),
-    })
+    state.display.iter().map(|display| render_effect(builder, display))
 }
 
-pub fn render_effect(builder: &ResponseBuilder, display: &TutorialDisplay) -> TutorialEffectType {
-    match display {
+pub fn render_effect(builder: &ResponseBuilder, display: &TutorialDisplay) -> TutorialEffect {
+    let effect_type = match display {
         TutorialDisplay::Tooltip(tooltip) => TutorialEffectType::ArrowBubble(ShowArrowBubble {
             text: tooltip.text.clone(),
             color: Some(BackgroundColor::Tooltip.into()),
@@ -72,11 +70,24 @@ pub fn render_effect(builder: &ResponseBuilder, display: &TutorialDisplay) -> Tu
                 ..ShowArrowBubble::default()
             })
         }
-        TutorialDisplay::Toast(toast) => TutorialEffectType::ShowToast(ShowToast {
-            node: make_toast(&toast.text),
-            idle_timer: Some(adapters::time_value(toast.delay)),
-            hide_time: toast.hide_after.map(adapters::time_value),
-        }),
+        TutorialDisplay::Toast(t) => return toast(&t.text, t.delay, t.hide_after),
+    };
+    TutorialEffect { tutorial_effect_type: Some(effect_type) }
+}
+
+/// Builds a [TutorialEffect] which pops up a non-blocking toast message, for
+/// example to communicate operational information like "Opponent
+/// disconnected".
+///
+/// The toast is shown once the user has been idle for `idle` and is hidden
+/// after `hide_after`, or remains visible if no hide time is provided.
+pub fn toast(text: &str, idle: Milliseconds, hide_after: Option<Milliseconds>) -> TutorialEffect {
+    TutorialEffect {
+        tutorial_effect_type: Some(TutorialEffectType::ShowToast(ShowToast {
+            node: make_toast(text),
+            idle_timer: Some(adapters::time_value(idle)),
+            hide_time: hide_after.map(adapters::time_value),
+        })),
     }
 }
 
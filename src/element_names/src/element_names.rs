@@ -72,6 +72,8 @@ pub static MENU_BUTTON: ElementName = global("MenuButton");
 
 pub static FEEDBACK_BUTTON: ElementName = global("FeedbackButton");
 
+pub static COIN_COUNT: ElementName = global("CoinCount");
+
 pub static UNDO_BUTTON: ElementName = global("UndoButton");
 
 pub static REDO_BUTTON: ElementName = global("RedoButton");
@@ -111,3 +113,7 @@ pub fn draft_card(variant: CardVariant) -> ElementName {
 pub fn narrative_outcome_tooltip(index: NarrativeChoiceId) -> ElementName {
     ElementName { tag: "OutcomeTooltip", count: index.value as u64 }
 }
+
+pub fn adventure_screen(index: usize) -> ElementName {
+    ElementName { tag: "AdventureScreen", count: index as u64 }
+}
@@ -20,6 +20,7 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
 pub enum NarrativeEventEntryKind {
+    Splash,
     Introduction,
     Choice,
     Outcome,
@@ -48,6 +48,11 @@ pub fn narrative_event(id: NarrativeEventId) -> &'static NarrativeEventData {
         .unwrap_or_else(|| panic!("Narrative event {id:?} not found"))
 }
 
+/// Returns the [NarrativeEventId]s of all defined narrative events.
+pub fn narrative_event_ids() -> impl Iterator<Item = NarrativeEventId> {
+    GAME_TABLES.narrative_events.keys().copied()
+}
+
 static GAME_TABLES: Lazy<GameTables> =
     Lazy::new(|| initialize().expect("Error building GameTables"));
 
@@ -40,6 +40,8 @@ pub fn build(
                 image: Sprite { address: row.image_path.clone() },
                 description: find_entry(&details, NarrativeEventEntryKind::Introduction)?
                     .description,
+                splash: find_optional_entry(&details, NarrativeEventEntryKind::Splash)?
+                    .map(|row| row.description),
                 choices: all_choice_ids(&details)
                     .into_iter()
                     .map(|i| build_choice(&details, i))
@@ -110,6 +112,15 @@ fn find_entry(
     Ok(vec[0].clone())
 }
 
+fn find_optional_entry(
+    details: &[NarrativeEventDetailsRow],
+    kind: NarrativeEventEntryKind,
+) -> Result<Option<NarrativeEventDetailsRow>> {
+    let vec = details.iter().filter(|row| row.entry_kind == kind).collect::<Vec<_>>();
+    verify!(vec.len() <= 1, "Expected at most one row of kind {kind:?} but got {}", vec.len());
+    Ok(vec.first().map(|row| (*row).clone()))
+}
+
 fn find_choice_entry(
     details: &[NarrativeEventDetailsRow],
     kind: NarrativeEventEntryKind,
@@ -134,10 +145,14 @@ fn build_effect(
         AdventureEffectKind::Shop => {
             AdventureEffect::Shop(resolve_card_filter(row.card_filter_id)?)
         }
-        AdventureEffectKind::NarrativeEvent => {
+        AdventureEffectKind::NarrativeEvent | AdventureEffectKind::RandomNarrativeEvent => {
             fail!("Not supported")
         }
         AdventureEffectKind::Battle => AdventureEffect::Battle,
+        AdventureEffectKind::BossBattle => AdventureEffect::BossBattle,
+        AdventureEffectKind::MiniBoss => {
+            AdventureEffect::MiniBoss(resolve_card_name(row.card_name)?)
+        }
         AdventureEffectKind::GainCoins => {
             AdventureEffect::GainCoins(Coins(resolve_quantity(row.quantity)?))
         }
@@ -145,6 +160,9 @@ fn build_effect(
             AdventureEffect::LoseCoins(Coins(resolve_quantity(row.quantity)?))
         }
         AdventureEffectKind::LoseAllCoins => AdventureEffect::LoseAllCoins,
+        AdventureEffectKind::Hazard => {
+            AdventureEffect::Hazard(Coins(resolve_quantity(row.quantity)?))
+        }
         AdventureEffectKind::GainArcanite => {
             AdventureEffect::GainArcanite(resolve_quantity(row.quantity)?)
         }
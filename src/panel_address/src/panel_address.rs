@@ -21,7 +21,7 @@ use enum_kinds::EnumKind;
 use game_data::card_name::CardMetadata;
 use game_data::card_state::CardPosition;
 use player_data::PlayerActivityKind;
-use protos::riftcaller::{InterfacePanel, InterfacePanelAddress, Node};
+use protos::riftcaller::{InterfacePanel, InterfacePanelAddress, Node, SpriteAddress};
 use serde::{Deserialize, Serialize};
 use serde_json::ser;
 
@@ -38,16 +38,24 @@ pub trait Panel: Component {
         None
     }
 
+    /// Sprites which the client should preload before displaying this panel,
+    /// e.g. card art.
+    fn preload_sprites(&self) -> Vec<SpriteAddress> {
+        vec![]
+    }
+
     fn build_panel(self) -> Option<InterfacePanel>
     where
         Self: Sized,
     {
         let screen_overlay = self.screen_overlay();
+        let preload_sprites = self.preload_sprites();
         let address = self.address().into();
         self.build().map(|node| InterfacePanel {
             address: Some(address),
             node: Some(node),
             screen_overlay,
+            preload_sprites,
         })
     }
 }
@@ -94,10 +102,12 @@ impl From<StandardPanel> for InterfacePanelAddress {
 #[enum_kind(PlayerPanelKind)]
 pub enum PlayerPanel {
     DeckViewer,
+    DeckList,
     BattleVictory,
     BattleDefeat,
     AdventureScreen(usize),
     AdventureOver,
+    AdventureSlots,
 }
 
 impl From<PlayerPanel> for PanelAddress {
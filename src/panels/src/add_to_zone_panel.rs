@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use card_definition_data::cards;
 use core_ui::action_builder::ActionBuilder;
 use core_ui::actions;
 use core_ui::button::Button;
@@ -22,7 +23,7 @@ use core_ui::prelude::*;
 use core_ui::scroll_view::ScrollView;
 use core_ui::text::Text;
 use core_ui::text_field::TextField;
-use game_data::card_name::{CardMetadata, CardName, CardVariant};
+use game_data::card_name::{CardMetadata, CardVariant};
 use game_data::card_state::CardPosition;
 use panel_address::{Panel, PanelAddress, StandardPanel};
 use user_action_data::{DebugAction, UserAction};
@@ -44,12 +45,6 @@ impl AddToZonePanel {
     ) -> Self {
         Self { filter_string: filter_string.into(), position, metadata, turn_face_up }
     }
-
-    fn matches(&self, name: CardName) -> bool {
-        name.displayed_name()
-            .split(' ')
-            .any(|part| part.to_lowercase().starts_with(&self.filter_string.to_lowercase()))
-    }
 }
 
 impl Panel for AddToZonePanel {
@@ -64,9 +59,6 @@ impl Panel for AddToZonePanel {
 
 impl Component for AddToZonePanel {
     fn build(self) -> Option<Node> {
-        let mut names =
-            enum_iterator::all::<CardName>().filter(|n| self.matches(*n)).collect::<Vec<_>>();
-        names.sort();
         let mut content = ScrollView::new("Card List")
             .style(Style::new().margin(Edge::Vertical, 16.px()).flex_grow(1.0))
             .child(
@@ -90,19 +82,20 @@ impl Component for AddToZonePanel {
             );
 
         if self.filter_string.len() > 1 {
-            content = content.children(names.into_iter().map(|n| {
-                ListCell::new(n.displayed_name()).button(
-                    Button::new("Add").action(
-                        ActionBuilder::new()
-                            .action(UserAction::Debug(DebugAction::AddToZone {
-                                variant: CardVariant { name: n, metadata: self.metadata },
-                                position: self.position,
-                                turn_face_up: self.turn_face_up,
-                            }))
-                            .update(self.close()),
-                    ),
-                )
-            }));
+            content =
+                content.children(cards::filter_cards(&self.filter_string).into_iter().map(|n| {
+                    ListCell::new(n.displayed_name()).button(
+                        Button::new("Add").action(
+                            ActionBuilder::new()
+                                .action(UserAction::Debug(DebugAction::AddToZone {
+                                    variant: CardVariant { name: n, metadata: self.metadata },
+                                    position: self.position,
+                                    turn_face_up: self.turn_face_up,
+                                }))
+                                .update(self.close()),
+                        ),
+                    )
+                }));
         }
 
         PanelWindow::new(self.address(), 1200.px(), 900.px())
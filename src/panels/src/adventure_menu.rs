@@ -15,7 +15,7 @@
 use adventure_data::adventure_action::AdventureAction;
 use core_ui::panels::Panels;
 use core_ui::prelude::*;
-use panel_address::{Panel, PanelAddress, StandardPanel};
+use panel_address::{Panel, PanelAddress, PlayerPanel, StandardPanel};
 use user_action_data::UserAction;
 
 use crate::button_menu::ButtonMenu;
@@ -43,6 +43,10 @@ impl Component for AdventureMenu {
                 Panels::close(self.address())
                     .action(UserAction::AdventureAction(AdventureAction::AbandonAdventure)),
             )
+            .button(
+                "Adventures",
+                Panels::close(self.address()).and_open(PlayerPanel::AdventureSlots),
+            )
             .button("Settings", Panels::open(StandardPanel::Settings))
             .build()
     }
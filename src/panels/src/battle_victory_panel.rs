@@ -63,6 +63,10 @@ impl<'a> Component for BattleVictoryPanel<'a> {
                     ))
                     .font_size(FontSize::Headline),
                 )
+                .child(data.guaranteed_reward.map(|card| {
+                    Text::new(format!("Gained {}", card.displayed_name()))
+                        .font_size(FontSize::Headline)
+                }))
                 .child(
                     Button::new("Continue")
                         .action(
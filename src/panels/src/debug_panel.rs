@@ -103,6 +103,7 @@ impl DebugPanel {
             .child(debug_button("+Wound", DebugAction::AddWounds(1)))
             .child(debug_button("-Wound", DebugAction::RemoveWounds(1)))
             .child(debug_button("Flip View", DebugAction::FlipViewpoint))
+            .child(debug_button("Log Pending", DebugAction::LogPendingAbilities))
             .child(debug_button(format!("{} 1", icons::SAVE), DebugAction::SaveGameState(1)))
             .child(debug_button(format!("{} 1", icons::RESTORE), DebugAction::LoadGameState(1)))
             .child(debug_button(format!("{} 2", icons::SAVE), DebugAction::SaveGameState(2)))
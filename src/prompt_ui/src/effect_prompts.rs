@@ -52,6 +52,7 @@ fn effect_label(user_side: Side, effect: &GameEffect) -> String {
         GameEffect::AbortPlayingCard => "Cancel".to_string(),
         GameEffect::PlayChoiceEffect { .. } => String::new(),
         GameEffect::DrawCards(..) => "Draw".to_string(),
+        GameEffect::GainMana(_, amount) => format!("Gain {}{}", amount, icons::MANA),
         GameEffect::SacrificeCard(..) => "Sacrifice".to_string(),
         GameEffect::DestroyCard(..) => "Destroy".to_string(),
         GameEffect::ManaCost(side, amount, ..) => {
@@ -69,6 +70,7 @@ fn effect_label(user_side: Side, effect: &GameEffect) -> String {
         GameEffect::EndCustomAccess(..) => "End Access".to_string(),
         GameEffect::TakeDamageCost(_, amount) => format!("Take {amount}"),
         GameEffect::MoveCard(..) => "Move".to_string(),
+        GameEffect::BanishFromDiscard(..) => "Banish".to_string(),
         GameEffect::PreventDamage(..) => "Prevent".to_string(),
         GameEffect::PreventCurses(..) => "Prevent".to_string(),
         GameEffect::PreventDestroyingCard(..) => "Prevent".to_string(),
@@ -90,6 +92,10 @@ fn effect_label(user_side: Side, effect: &GameEffect) -> String {
                 format!("Add {}{}", count, icons::POWER_CHARGE)
             }
         }
+        GameEffect::CopyAbilityForTurn(..) => "Copy".to_string(),
+        GameEffect::TakeFirstTurn(side) => {
+            if *side == user_side { "Go First" } else { "Go Second" }.to_string()
+        }
     }
 }
 
@@ -112,6 +118,10 @@ fn custom_label(label: PromptChoiceLabel) -> String {
         PromptChoiceLabel::DefeatForCost(cost) => {
             format!("{}{}: Defeat", cost, icons::MANA)
         }
+        PromptChoiceLabel::Discard => "Discard".to_string(),
+        PromptChoiceLabel::DrawCards(1) => "Draw a Card".to_string(),
+        PromptChoiceLabel::DrawCards(count) => format!("Draw {count} Cards"),
+        PromptChoiceLabel::GainMana(amount) => format!("Gain {}{}", amount, icons::MANA),
     }
 }
 
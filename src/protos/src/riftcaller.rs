@@ -405,6 +405,14 @@ pub struct DraggableNode {
     /// element has been dragged through some fixed distance.
     #[prost(message, optional, tag = "8")]
     pub on_drag_detected: ::core::option::Option<ClientAction>,
+    /// Commands to apply immediately when a drag is detected, in addition to
+    /// invoking 'on_drag_detected'.
+    #[prost(message, optional, tag = "9")]
+    pub drag_detected_update: ::core::option::Option<CommandList>,
+    /// Commands to apply immediately when a drag ends, whether or not the node
+    /// was dropped over a target.
+    #[prost(message, optional, tag = "10")]
+    pub drag_ended_update: ::core::option::Option<CommandList>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1519,6 +1527,10 @@ pub struct InterfacePanel {
     /// RenderScreenOverlayCommand.
     #[prost(message, optional, tag = "3")]
     pub screen_overlay: ::core::option::Option<Node>,
+    /// Sprites displayed by this panel which the client should load ahead of
+    /// time to avoid images appearing late.
+    #[prost(message, repeated, tag = "4")]
+    pub preload_sprites: ::prost::alloc::vec::Vec<SpriteAddress>,
 }
 /// Requests that a specific corner of a Node be anchored to a specific
 /// corner of a card.
@@ -1956,6 +1968,14 @@ pub struct WorldMapTile {
 pub struct UpdateWorldMapCommand {
     #[prost(message, repeated, tag = "1")]
     pub tiles: ::prost::alloc::vec::Vec<WorldMapTile>,
+    /// Minimum tile position among all revealed tiles, used to frame the
+    /// camera.
+    #[prost(message, optional, tag = "2")]
+    pub min_bounds: ::core::option::Option<MapPosition>,
+    /// Maximum tile position among all revealed tiles, used to frame the
+    /// camera.
+    #[prost(message, optional, tag = "3")]
+    pub max_bounds: ::core::option::Option<MapPosition>,
 }
 /// Displays a UI element on top of all other elements. This is typically used
 /// to render chrome, e.g. buttons related to global navigation.
@@ -2213,12 +2233,21 @@ pub struct ClearEffectsCommand {
     #[prost(message, optional, tag = "1")]
     pub owner: ::core::option::Option<GameObjectIdentifier>,
 }
+/// Requests that the client center its camera on a given game object, e.g. to
+/// draw attention to a card whose ability has just fired.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FocusGameObjectCommand {
+    /// Object to center the camera on
+    #[prost(message, optional, tag = "1")]
+    pub object: ::core::option::Option<GameObjectIdentifier>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GameCommand {
     #[prost(
         oneof = "game_command::Command",
-        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25"
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26"
     )]
     pub command: ::core::option::Option<game_command::Command>,
 }
@@ -2277,6 +2306,8 @@ pub mod game_command {
         TurnFaceDownArenaAnimation(super::TurnFaceDownArenaAnimationCommand),
         #[prost(message, tag = "25")]
         ClearPersistentEffects(super::ClearEffectsCommand),
+        #[prost(message, tag = "26")]
+        FocusGameObject(super::FocusGameObjectCommand),
     }
 }
 /// Metadata to include with logging for this client, e.g. for crash
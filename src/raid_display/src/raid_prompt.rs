@@ -23,9 +23,10 @@ use game_data::card_configuration::{Cost, CustomBoostCost, CustomWeaponCost};
 use game_data::game_actions::{GameAction, RaidAction, RazeCardActionType};
 use game_data::game_state::GameState;
 use game_data::raid_data::{
-    RaidChoice, RaidData, RaidLabel, RaidPrompt, RaidState, RaidStep, WeaponInteraction,
+    RaidChoice, RaidData, RaidLabel, RaidPrompt, RaidState, RaidStatus, RaidStep, WeaponInteraction,
 };
 use game_data::tutorial_data::TutorialTrigger;
+use prompt_ui::game_instructions::GameInstructions;
 use prompt_ui::prompt_container::PromptContainer;
 use prompt_ui::response_button::ResponseButton;
 use protos::riftcaller::{
@@ -52,7 +53,7 @@ pub fn build(game: &GameState, raid: &RaidData, side: Side) -> Option<InterfaceM
 
         Some(InterfaceMainControls {
             node: PromptContainer::new().children(main_controls).build(),
-            overlay: None,
+            overlay: access_limit_instructions(raid, prompt),
             card_anchor_nodes,
         })
     } else if raid.target == RoomId::Sanctum && side == Side::Covenant && !raid.accessed.is_empty()
@@ -78,6 +79,14 @@ pub fn legal_actions(game: &GameState, side: Side) -> Vec<GameAction> {
     })
 }
 
+/// Returns true if the `side` player is currently being offered to end the
+/// raid because they have no usable weapon against the encountered minion.
+pub fn has_no_usable_weapon(game: &GameState, side: Side) -> bool {
+    current_prompt(game, side).is_some_and(|prompt| {
+        prompt.choices.iter().any(|choice| choice.label == RaidLabel::NoUsableWeapon)
+    })
+}
+
 /// Returns the current raid prompt for the `side` user, if any.
 fn current_prompt(game: &GameState, side: Side) -> Option<&RaidPrompt> {
     if let Some(RaidState::Prompt(prompt)) = game.raid.as_ref().map(|r| &r.state) {
@@ -89,6 +98,18 @@ fn current_prompt(game: &GameState, side: Side) -> Option<&RaidPrompt> {
     None
 }
 
+fn access_limit_instructions(raid: &RaidData, prompt: &RaidPrompt) -> Option<Node> {
+    if prompt.status != RaidStatus::Access {
+        return None;
+    }
+
+    match raid.access_limit? {
+        0 => GameInstructions::new("No further cards may be scored or razed.").build(),
+        1 => GameInstructions::new("You may score or raze 1 more card.").build(),
+        n => GameInstructions::new(format!("You may score or raze {n} more cards.")).build(),
+    }
+}
+
 fn covenant_sanctum_access_info(raid: &RaidData) -> Option<InterfaceMainControls> {
     Some(InterfaceMainControls {
         node: None,
@@ -169,6 +190,7 @@ fn render_button(game: &GameState, index: usize, choice: &RaidChoice) -> Respons
         RaidLabel::RazeCard(card_id, action) => raze_button(game, card_id, action),
         RaidLabel::EndRaid => ResponseButton::new("End Raid").primary(false).shift_down(true),
         RaidLabel::EndAccess => ResponseButton::new("End Access").primary(false).shift_down(true),
+        RaidLabel::NoUsableWeapon => ResponseButton::new("End Raid").primary(false),
     };
 
     button.action(GameAction::RaidAction(RaidAction { index }))
@@ -38,8 +38,13 @@ pub fn standard_panels() -> Vec<StandardPanel> {
 
 /// Enumerates all player panel addresses
 pub fn player_panels(player: &PlayerState) -> Vec<PlayerPanel> {
-    let mut panels =
-        vec![PlayerPanel::AdventureOver, PlayerPanel::BattleVictory, PlayerPanel::BattleDefeat];
+    let mut panels = vec![
+        PlayerPanel::AdventureOver,
+        PlayerPanel::BattleVictory,
+        PlayerPanel::BattleDefeat,
+        PlayerPanel::DeckList,
+        PlayerPanel::AdventureSlots,
+    ];
     if let Some(adventure) = &player.adventure {
         for i in 0..adventure.screens.count() {
             panels.push(PlayerPanel::AdventureScreen(i));
@@ -17,8 +17,10 @@
 
 use adventure_display::adventure_over_panel::AdventureOverPanel;
 use adventure_display::adventure_panels;
+use adventure_display::adventure_slots_panel::AdventureSlotsPanel;
 use anyhow::Result;
 use deck_editor::deck_editor_panel::DeckEditorPanel;
+use deck_editor::deck_list::DeckListPanel;
 use panel_address::{Panel, PlayerPanel, StandardPanel};
 use panels::about_panel::AboutPanel;
 use panels::add_to_zone_panel::AddToZonePanel;
@@ -78,9 +80,11 @@ pub fn render_player_panel(
             filter: None,
         }
         .build_panel(),
+        PlayerPanel::DeckList => DeckListPanel::new(player).build_panel(),
         PlayerPanel::BattleVictory => BattleVictoryPanel::new(player).build_panel(),
         PlayerPanel::BattleDefeat => BattleDefeatPanel {}.build_panel(),
         PlayerPanel::AdventureScreen(index) => adventure_panels::tile_entity_panel(player, index)?,
-        PlayerPanel::AdventureOver => AdventureOverPanel::new().build_panel(),
+        PlayerPanel::AdventureOver => AdventureOverPanel::new(player).build_panel(),
+        PlayerPanel::AdventureSlots => AdventureSlotsPanel::new(player).build_panel(),
     })
 }
@@ -160,13 +160,7 @@ pub fn can_encounter_target(game: &GameState, weapon: CardId, minion: CardId) ->
     let weapon_resonance = queries::resonance(game, weapon);
     let minion_resonance = queries::resonance(game, minion);
 
-    let can_encounter = weapon_resonance.contains(Resonance::Prismatic)
-        || (weapon_resonance.contains(Resonance::Mortal)
-            && minion_resonance.contains(Resonance::Mortal))
-        || (weapon_resonance.contains(Resonance::Infernal)
-            && minion_resonance.contains(Resonance::Infernal))
-        || (weapon_resonance.contains(Resonance::Astral)
-            && minion_resonance.contains(Resonance::Astral));
+    let can_encounter = Resonance::can_damage(weapon_resonance, minion_resonance);
 
     dispatch::perform_query(
         game,
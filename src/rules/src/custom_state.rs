@@ -49,3 +49,18 @@ pub fn identity_once_per_turn(
     }
     Ok(())
 }
+
+/// Invokes a function associated with a card ability only the first time it is
+/// called during a game, e.g. the first time a card is played.
+pub fn once_per_game(
+    game: &mut GameState,
+    scope: Scope,
+    function: impl FnOnce(&mut GameState, Scope) -> Result<()>,
+) -> Result<()> {
+    let index = scope.ability_id().index;
+    if !game.card(scope).custom_state.ability_triggered_for_game(index) {
+        game.card_mut(scope).custom_state.push(CustomCardState::AbilityTriggeredForGame { index });
+        function(game, scope)?;
+    }
+    Ok(())
+}
@@ -33,6 +33,9 @@ use crate::{mutations, state_machine};
 ///    are returned.
 ///  - If `side == Riftcaller`, all remaining cards are returned.
 ///
+/// These rules apply identically to the basic 'draw card' game action and to
+/// draws forced by card abilities.
+///
 /// Cards are marked as revealed to the `side` player.
 pub fn run(game: &mut GameState, side: Side, quantity: u32, source: InitiatedBy) -> Result<()> {
     state_machine::initiate(
@@ -23,7 +23,7 @@ use game_data::history_data::HistoryEvent;
 use game_data::state_machine_data::{EndRaidData, EndRaidStep};
 
 use crate::state_machine::StateMachine;
-use crate::{flags, state_machine};
+use crate::{flags, mutations, state_machine};
 
 /// Ends the current raid.
 ///
@@ -126,6 +126,7 @@ impl StateMachine for EndRaidData {
             }
             EndRaidStep::EndRaid => {
                 game.raid = None;
+                mutations::clear_expired_custom_state(game);
                 Some(EndRaidStep::Finish)
             }
             EndRaidStep::Finish => None,
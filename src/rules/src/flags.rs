@@ -33,7 +33,7 @@ use game_data::delegate_data::{
     CanWinGameViaPointsQuery,
 };
 use game_data::flag_data::{AbilityFlag, Flag};
-use game_data::game_actions::CardTarget;
+use game_data::game_actions::{CardTarget, CardTargetKind};
 use game_data::game_state::{GamePhase, GameState, TurnState};
 use game_data::prompt_data::{
     CardSelectorPrompt, CardSelectorPromptValidation, GamePrompt, PlayCardBrowser,
@@ -54,6 +54,10 @@ pub fn current_priority(game: &GameState) -> Option<Side> {
                 Some(Side::Covenant)
             } else if can_make_mulligan_decision(game, Side::Riftcaller) {
                 Some(Side::Riftcaller)
+            } else if !prompts::is_empty(game, Side::Covenant) {
+                Some(Side::Covenant)
+            } else if !prompts::is_empty(game, Side::Riftcaller) {
+                Some(Side::Riftcaller)
             } else {
                 None
             }
@@ -109,13 +113,19 @@ pub fn can_make_mulligan_decision(game: &GameState, side: Side) -> bool {
 /// See [can_play_card] for a function which checks all factor related to
 /// playing a card.
 pub fn can_pay_card_cost(game: &GameState, card_id: CardId, options: PlayCardOptions) -> bool {
+    let custom_cost = &game.card(card_id).definition().cost.custom_cost;
+    let reduction = custom_cost
+        .as_ref()
+        .and_then(|custom_cost| custom_cost.mana_reduction)
+        .map_or(0, |mana_reduction| mana_reduction(game, card_id));
     let mut can_pay = if options.ignore_mana_cost {
         true
     } else {
-        matches!(queries::mana_cost(game, card_id), Some(cost)
-                             if cost <= mana::get(game, card_id.side, ManaPurpose::PayForCard(card_id)))
+        let available = mana::get(game, card_id.side, ManaPurpose::PayForCard(card_id));
+        queries::mana_cost(game, card_id)
+            .is_some_and(|cost| cost.saturating_sub(reduction) <= available)
     };
-    if let Some(custom_cost) = &game.card(card_id).definition().cost.custom_cost {
+    if let Some(custom_cost) = custom_cost {
         can_pay &= (custom_cost.can_pay)(game, card_id);
     }
 
@@ -154,6 +164,68 @@ pub fn can_play_card(
     can_play
 }
 
+/// Explanation for whether a card can currently be played, see
+/// [play_card_diagnosis].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayCardDiagnosis {
+    /// The card can be played with at least one valid target.
+    CanPlay,
+    /// It is not currently the owning player's turn, or their turn has ended.
+    NotYourTurn,
+    /// Cards cannot be played while a raid is in progress.
+    DuringRaid,
+    /// A player must respond to a prompt before the card can be played.
+    PendingPrompt,
+    /// The card is not in its owner's hand.
+    NotInHand,
+    /// The owning player does not have enough action points.
+    NoActionPoints,
+    /// The owning player cannot pay the card's cost.
+    InsufficientMana,
+    /// The card must target a room, but no room is a valid target.
+    NoValidRoom,
+    /// The card cannot be played due to a card ability or other rule.
+    Prevented,
+}
+
+/// Returns a [PlayCardDiagnosis] describing why the `card_id` card cannot
+/// currently be played by its owner, or [PlayCardDiagnosis::CanPlay] if it can
+/// be played with some target via [can_play_card].
+pub fn play_card_diagnosis(game: &GameState, card_id: CardId) -> PlayCardDiagnosis {
+    let side = card_id.side;
+    let options = PlayCardOptions::default();
+    let rooms = || enum_iterator::all::<RoomId>();
+    let playable = match queries::card_target_kind(game, card_id) {
+        CardTargetKind::None => can_play_card(game, side, card_id, CardTarget::None, options),
+        CardTargetKind::Room => rooms()
+            .any(|room_id| can_play_card(game, side, card_id, CardTarget::Room(room_id), options)),
+    };
+
+    if playable {
+        PlayCardDiagnosis::CanPlay
+    } else if game.info.turn.side != side || game.info.turn_state == TurnState::Ended {
+        PlayCardDiagnosis::NotYourTurn
+    } else if game.raid.is_some() {
+        PlayCardDiagnosis::DuringRaid
+    } else if !can_take_game_actions(game, side) {
+        PlayCardDiagnosis::PendingPrompt
+    } else if game.card(card_id).position() != CardPosition::Hand(side) {
+        PlayCardDiagnosis::NotInHand
+    } else if game.player(side).actions == 0
+        || queries::action_cost(game, card_id) > game.player(side).actions
+    {
+        PlayCardDiagnosis::NoActionPoints
+    } else if enters_play_face_up(game, card_id) && !can_pay_card_cost(game, card_id, options) {
+        PlayCardDiagnosis::InsufficientMana
+    } else if queries::card_target_kind(game, card_id) == CardTargetKind::Room
+        && !rooms().any(|room_id| is_valid_target(game, card_id, CardTarget::Room(room_id)))
+    {
+        PlayCardDiagnosis::NoValidRoom
+    } else {
+        PlayCardDiagnosis::Prevented
+    }
+}
+
 /// Checks whether a card can be played from a [PlayCardBrowser].
 ///
 /// Cards in the browser are assumed to bypass normal positional checks for
@@ -54,6 +54,7 @@ pub fn handle(game: &mut GameState, effect: GameEffect) -> Result<()> {
         GameEffect::DrawCards(side, count, initiated_by) => {
             draw_cards::run(game, side, count, initiated_by)?;
         }
+        GameEffect::GainMana(side, amount) => mana::gain(game, side, amount),
         GameEffect::SacrificeCard(card_id) => mutations::sacrifice_card(game, card_id)?,
         GameEffect::DestroyCard(card_id, initiated_by) => {
             destroy::run(game, vec![card_id], initiated_by)?
@@ -79,6 +80,9 @@ pub fn handle(game: &mut GameState, effect: GameEffect) -> Result<()> {
         GameEffect::MoveCard(card_id, target_position) => {
             mutations::move_card(game, card_id, target_position)?
         }
+        GameEffect::BanishFromDiscard(side, card_id) => {
+            mutations::banish_from_discard(game, side, card_id)?
+        }
         GameEffect::PreventDamage(amount) => damage::prevent(game, amount),
         GameEffect::PreventCurses(quantity) => curses::prevent_curses(game, quantity),
         GameEffect::PreventDestroyingCard(id) => destroy::prevent(game, id),
@@ -89,7 +93,7 @@ pub fn handle(game: &mut GameState, effect: GameEffect) -> Result<()> {
             game.player_mut(side).prompt_selected_cards.clear();
         }
         GameEffect::PushPromptWithIndex(side, ability_id, index) => {
-            prompts::push_with_data(game, side, ability_id, PromptData::Index(index));
+            prompts::push_with_data(game, side, ability_id, PromptData::Index(index))?;
         }
         GameEffect::SwapWithSelected(side, card_id) => {
             let source_position = game.card(card_id).position();
@@ -141,6 +145,10 @@ pub fn handle(game: &mut GameState, effect: GameEffect) -> Result<()> {
         GameEffect::AddPowerCharges(card_id, count) => {
             game.card_mut(card_id).add_counters(CardCounter::PowerCharges, count);
         }
+        GameEffect::CopyAbilityForTurn(ability_id, source) => {
+            mutations::copy_ability_for_turn(game, ability_id, source)?
+        }
+        GameEffect::TakeFirstTurn(side) => mutations::start_game(game, side)?,
     }
 
     Ok(())
@@ -59,6 +59,13 @@ pub fn get(game: &GameState, side: Side, purpose: ManaPurpose) -> ManaValue {
         _ => {}
     }
 
+    match &game.player(side).mana_state.turn_mana {
+        Some((turn, turn_mana)) if *turn == game.info.turn => {
+            result += turn_mana;
+        }
+        _ => {}
+    }
+
     match purpose {
         ManaPurpose::BaseMana => base_mana,
         ManaPurpose::BonusForDisplay => result - base_mana,
@@ -70,7 +77,7 @@ pub fn get(game: &GameState, side: Side, purpose: ManaPurpose) -> ManaValue {
 ///
 /// An effort is made to spend "more specific" mana first, i.e. mana which can
 /// only be used for a certain type of action is preferred, then raid-specific
-/// mana, then general mana.
+/// mana, then turn-specific mana, then general mana.
 ///
 /// Returns an error if insufficient mana is available.
 pub fn spend(
@@ -93,6 +100,13 @@ pub fn spend(
         }
     }
 
+    let current_turn = game.info.turn;
+    if let Some((turn, mana)) = &mut game.player_mut(side).mana_state.turn_mana {
+        if *turn == current_turn {
+            to_spend = try_spend(mana, to_spend);
+        }
+    }
+
     game.player_mut(side).mana_state.base_mana -= to_spend;
 
     match initiated_by {
@@ -144,6 +158,14 @@ pub fn add_raid_specific_mana(
     utils::add_matching(&mut game.player_mut(side).mana_state.raid_mana, raid_id, amount);
 }
 
+/// Adds mana for the `side` player which can only be used during the current
+/// turn.
+pub fn add_turn_specific_mana(game: &mut GameState, side: Side, amount: ManaValue) {
+    let turn = game.info.turn;
+    debug!(?amount, ?side, ?turn, "Adding turn-specific mana");
+    utils::add_matching(&mut game.player_mut(side).mana_state.turn_mana, turn, amount);
+}
+
 fn try_spend(source: &mut ManaValue, amount: ManaValue) -> ManaValue {
     if *source >= amount {
         *source -= amount;
@@ -26,8 +26,8 @@ use card_definition_data::cards;
 use card_definition_data::cards::CardDefinitionExt;
 use constants::game_constants;
 use core_data::game_primitives::{
-    ActionCount, CardId, InitiatedBy, ManaValue, PointsValue, PowerChargeValue, ProgressValue,
-    RoomId, Side, TurnNumber,
+    AbilityId, ActionCount, CardId, CardPlayId, CardType, InitiatedBy, ManaValue, PointsValue,
+    PowerChargeValue, ProgressValue, RoomId, RoomLocation, Side, TurnNumber,
 };
 use dispatcher::dispatch;
 use game_data::animation_tracker::GameAnimation;
@@ -35,23 +35,30 @@ use game_data::card_name::CardVariant;
 use game_data::card_state::{CardCounter, CardIdsExt, CardState};
 #[allow(unused)] // Used in rustdocs
 use game_data::card_state::{CardData, CardPosition, CardPositionKind};
+use game_data::custom_card_state::CustomCardState;
 use game_data::delegate_data::{
     ActionPointsLostDuringRaidEvent, CardRevealedEvent, CardSacrificedEvent,
     CovenantScoreCardEvent, DawnEvent, DiscardCardEvent, DiscardedCard, DiscardedFrom,
     DrawCardEvent, DuskEvent, EnterArenaEvent, EnterHandEvent, LeaveArenaEvent,
-    MoveToDiscardPileEvent, ScoreCard, ScoreCardEvent, StoredManaTakenEvent, SummonMinionEvent,
-    SummonProjectEvent,
+    MoveToDiscardPileEvent, Scope, ScoreCard, ScoreCardEvent, StoredManaTakenEvent,
+    SummonMinionEvent, SummonProjectEvent,
 };
 use game_data::flag_data::AbilityFlag;
-use game_data::game_state::{GamePhase, GameState, TurnData, TurnState};
+use game_data::game_actions::ButtonPromptContext;
+use game_data::game_effect::GameEffect;
+use game_data::game_state::{FirstTurnChoice, GamePhase, GameState, TurnData, TurnState};
+use game_data::prompt_data::{
+    ButtonPrompt, CardSelectorPrompt, CardSelectorPromptValidation, GamePrompt, PromptChoice,
+    PromptChoiceLabel, PromptContext, SelectorPromptTarget,
+};
 use game_data::raid_data::RaidJumpRequest;
 use game_data::random;
 use tracing::{debug, instrument};
-use with_error::{fail, verify};
+use with_error::{fail, verify, WithError};
 
 use crate::mana::ManaPurpose;
 use crate::visual_effects::VisualEffects;
-use crate::{draw_cards, flags, mana, queries};
+use crate::{draw_cards, flags, mana, prompts, queries};
 
 /// Change a card to the 'face up' state and makes the card revealed to both
 /// players.
@@ -197,6 +204,110 @@ pub fn banish_card(game: &mut GameState, card_id: CardId) -> Result<()> {
     move_card(game, card_id, CardPosition::Banished(None))
 }
 
+/// Banishes a card from the `side` player's discard pile. Returns an error if
+/// the card is not currently in that discard pile.
+pub fn banish_from_discard(game: &mut GameState, side: Side, card_id: CardId) -> Result<()> {
+    verify!(
+        game.card(card_id).position() == CardPosition::DiscardPile(side),
+        "Card is not in discard pile"
+    );
+    banish_card(game, card_id)
+}
+
+/// Causes the card which owns `ability_id` to gain a copy of the `source`
+/// ability until the end of the current turn.
+///
+/// The copy resolves through the standard delegate dispatch with the scope of
+/// `ability_id`, so references to "this card" in the copied ability apply to
+/// the copying card. Returns an error if the `source` ability cannot be copied,
+/// see [card_definition_data::ability_data::Ability::can_be_copied].
+pub fn copy_ability_for_turn(
+    game: &mut GameState,
+    ability_id: AbilityId,
+    source: AbilityId,
+) -> Result<()> {
+    verify!(
+        cards::get(game.card(source.card_id).variant).ability(source.index).can_be_copied(),
+        "Ability {source:?} cannot be copied"
+    );
+    let turn = game.info.turn;
+    let card = game.card_mut(ability_id.card_id);
+    card.custom_state.push(CustomCardState::CopiedAbilityForTurn {
+        source,
+        index: ability_id.index,
+        turn,
+    });
+    let scope = Scope::new(ability_id, card.variant.metadata);
+    let definition = cards::get(game.card(source.card_id).variant);
+    dispatch::add_copied_ability_to_delegate_map(&mut game.delegate_map, definition, source, scope);
+    Ok(())
+}
+
+/// Banishes a card in play, returning it to its current position at the end
+/// of its controller's turn.
+///
+/// The card re-enters play with a new [CardPlayId], so state associated with
+/// its previous instance in play no longer applies to it.
+pub fn banish_until_end_of_turn(game: &mut GameState, card_id: CardId) -> Result<()> {
+    let position = game.card(card_id).position();
+    verify!(position.in_play(), "Card is not in play");
+    game.card_mut(card_id).custom_state.push(CustomCardState::BanishedUntilEndOfTurn { position });
+    banish_card(game, card_id)
+}
+
+/// Moves a minion which is currently defending a room to instead defend
+/// `to_room`. The minion remains in play, keeping its [CardPlayId] and
+/// counters.
+pub fn move_minion(game: &mut GameState, card_id: CardId, to_room: RoomId) -> Result<()> {
+    let CardPosition::Room(card_play_id, _, RoomLocation::Defender) = game.card(card_id).position()
+    else {
+        fail!("Card {card_id:?} is not defending a room");
+    };
+    move_card(game, card_id, CardPosition::Room(card_play_id, to_room, RoomLocation::Defender))
+}
+
+/// Returns cards the `side` player banished via [banish_until_end_of_turn] to
+/// their previous positions in play.
+///
+/// Face-up minions and projects are treated as having been summoned again,
+/// firing their summon events.
+fn return_banished_cards(game: &mut GameState, side: Side) -> Result<()> {
+    let banished = game
+        .cards(side)
+        .iter()
+        .filter(|card| card.position().is_banished())
+        .filter_map(|card| card.custom_state.banished_until_end_of_turn().map(|p| (card.id, p)))
+        .collect::<Vec<_>>();
+
+    for (card_id, position) in banished {
+        game.card_mut(card_id)
+            .custom_state
+            .retain(|state| !matches!(state, CustomCardState::BanishedUntilEndOfTurn { .. }));
+        let card_play_id = CardPlayId(game.info.next_event_id());
+        let new_position = match position {
+            CardPosition::Room(_, room_id, location) => {
+                CardPosition::Room(card_play_id, room_id, location)
+            }
+            CardPosition::ArenaItem(_, location) => CardPosition::ArenaItem(card_play_id, location),
+            _ => position,
+        };
+        move_card(game, card_id, new_position)?;
+
+        if game.card(card_id).is_face_up() {
+            let definition = game.card(card_id).definition();
+            if definition.is_minion() {
+                dispatch::invoke_event(game, SummonMinionEvent(&card_id))?;
+                game.add_animation(|| GameAnimation::SummonMinion(card_id));
+            } else if definition.card_type == CardType::Project {
+                dispatch::invoke_event(game, SummonProjectEvent(&card_id))?;
+                game.add_animation(|| GameAnimation::SummonProject(card_id));
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// Shuffles the provided `cards` into the `side` player's deck, clearing their
 /// revealed state for both players.
 pub fn shuffle_into_deck(game: &mut GameState, side: Side, cards: &[CardId]) -> Result<()> {
@@ -209,8 +320,11 @@ pub fn shuffle_into_deck(game: &mut GameState, side: Side, cards: &[CardId]) ->
 /// Shuffles the `side` player's deck, moving all cards into the `DeckUnknown`
 /// card position.
 pub fn shuffle_deck(game: &mut GameState, side: Side) -> Result<()> {
-    let cards =
-        game.cards_in_position(side, CardPosition::DeckTop(side)).map(|c| c.id).collect::<Vec<_>>();
+    let cards = game
+        .cards_in_position(side, CardPosition::DeckTop(side))
+        .chain(game.cards_in_position(side, CardPosition::DeckBottom(side)))
+        .map(|c| c.id)
+        .collect::<Vec<_>>();
     for card_id in &cards {
         turn_face_down(game, *card_id);
         set_visible_to(game, *card_id, Side::Covenant, false);
@@ -328,11 +442,26 @@ pub fn take_stored_mana(
 }
 
 /// Adds `count` power charges to the `card_id` card.
+///
+/// If a card owned by the same player has a pending
+/// [CustomCardState::DoubleNextPowerCharges] effect, twice as many charges are
+/// added instead and that effect is consumed.
 pub fn add_power_charges(
     game: &mut GameState,
     card_id: CardId,
     count: PowerChargeValue,
 ) -> Result<()> {
+    let mut count = count;
+    let pending = game.cards(card_id.side).iter().find_map(|card| {
+        card.custom_state.pending_double_power_charges().map(|play_id| (card.id, play_id))
+    });
+    if let Some((source_id, play_id)) = pending {
+        game.card_mut(source_id)
+            .custom_state
+            .push(CustomCardState::PowerChargesDoubled { play_id });
+        count *= 2;
+    }
+
     game.card_mut(card_id).add_counters(CardCounter::PowerCharges, count);
     Ok(())
 }
@@ -358,40 +487,70 @@ pub fn spend_power_charges(
 #[instrument(skip(game))]
 pub fn deal_opening_hands(game: &mut GameState) -> Result<()> {
     debug!("Dealing opening hands");
-    draw_cards::run(
-        game,
-        Side::Covenant,
-        game_constants::STARTING_HAND_SIZE,
-        InitiatedBy::GameAction,
-    )?;
-    draw_cards::run(
-        game,
-        Side::Riftcaller,
-        game_constants::STARTING_HAND_SIZE,
-        InitiatedBy::GameAction,
-    )?;
+    for side in [Side::Covenant, Side::Riftcaller] {
+        let count = queries::opening_hand_size(game, side);
+        draw_cards::run(game, side, count, InitiatedBy::GameAction)?;
+    }
     Ok(())
 }
 
 /// Invoked after a mulligan decision is received in order to check if the game
 /// should be started.
 ///
-/// Handles assigning initial mana & action points to players.
+/// If the game is configured with a [FirstTurnChoice], prompts the choosing
+/// player to pick who takes the first turn instead of starting the game
+/// immediately.
 #[instrument(skip(game))]
 pub fn check_start_game(game: &mut GameState) -> Result<()> {
     match &game.info.phase {
         GamePhase::ResolveMulligans(mulligans)
             if mulligans.covenant.is_some() && mulligans.riftcaller.is_some() =>
         {
-            mana::set_initial(game, Side::Covenant, game_constants::STARTING_MANA);
-            mana::set_initial(game, Side::Riftcaller, game_constants::STARTING_MANA);
-            start_turn(game, Side::Covenant, 1)?;
+            match game.info.config.first_turn_choice {
+                None => start_game(game, Side::Covenant)?,
+                Some(choice) => {
+                    let chooser = match choice {
+                        FirstTurnChoice::CoinFlip => {
+                            random::choose(game, [Side::Covenant, Side::Riftcaller].into_iter())
+                                .with_error(|| "Expected side")?
+                        }
+                        FirstTurnChoice::Player(side) => side,
+                    };
+                    prompts::push_immediate(
+                        game,
+                        chooser,
+                        GamePrompt::ButtonPrompt(ButtonPrompt {
+                            context: Some(ButtonPromptContext::ChooseFirstTurn),
+                            choices: vec![
+                                PromptChoice::new().effect(GameEffect::TakeFirstTurn(chooser)),
+                                PromptChoice::new()
+                                    .effect(GameEffect::TakeFirstTurn(chooser.opponent())),
+                            ],
+                        }),
+                    )?;
+                }
+            }
         }
         _ => {}
     }
     Ok(())
 }
 
+/// Starts the game with the `first` player taking the first turn.
+///
+/// Handles assigning initial mana & action points to players. If the
+/// Riftcaller goes first, their opening turn is numbered 0 so that the
+/// Covenant's first turn is still turn 1.
+pub fn start_game(game: &mut GameState, first: Side) -> Result<()> {
+    verify!(matches!(game.info.phase, GamePhase::ResolveMulligans(_)), "Game has already started");
+    mana::set_initial(game, Side::Covenant, game_constants::STARTING_MANA);
+    mana::set_initial(game, Side::Riftcaller, game_constants::STARTING_MANA);
+    match first {
+        Side::Covenant => start_turn(game, Side::Covenant, 1),
+        Side::Riftcaller => start_turn(game, Side::Riftcaller, 0),
+    }
+}
+
 /// Options for [realize_top_of_deck]
 #[derive(Eq, PartialEq)]
 pub enum RealizeCards {
@@ -404,6 +563,8 @@ pub enum RealizeCards {
 /// of the deck).
 ///
 /// Selects randomly unless cards are already known to be in this position.
+/// Cards in the [CardPosition::DeckBottom] position are only selected once
+/// all other cards in the deck have been selected.
 /// If insufficient cards are present in the deck, returns all available
 /// cards. Cards are moved to the DeckTop position via [move_card],
 /// meaning that subsequent calls to this function will see the same results.
@@ -425,8 +586,12 @@ pub fn realize_top_of_deck(
         let remaining = count - cards.len();
         let mut shuffled =
             random::cards_in_position(game, side, CardPosition::DeckUnknown(side), remaining);
-        shuffled.append(&mut cards);
-        shuffled
+        let mut bottom = game.card_list_for_position(side, CardPosition::DeckBottom(side));
+        bottom.truncate(remaining - shuffled.len());
+        bottom.reverse();
+        bottom.append(&mut shuffled);
+        bottom.append(&mut cards);
+        bottom
     };
 
     for card_id in &result {
@@ -439,6 +604,164 @@ pub fn realize_top_of_deck(
     Ok(result)
 }
 
+/// Reveals the top card of the `side` player's deck via [reveal_card]. If
+/// `predicate` returns true for the revealed card, it is drawn. Otherwise, it
+/// is left on top of the deck, revealed to both players.
+///
+/// Returns the revealed card, or `None` if the deck is empty.
+pub fn reveal_top_and_conditionally_draw(
+    game: &mut GameState,
+    side: Side,
+    source: InitiatedBy,
+    predicate: impl FnOnce(&GameState, CardId) -> bool,
+) -> Result<Option<CardId>> {
+    let Some(card_id) =
+        realize_top_of_deck(game, side, 1, RealizeCards::SetVisibleToOwner)?.first().copied()
+    else {
+        return Ok(None);
+    };
+
+    reveal_card(game, card_id)?;
+    if predicate(game, card_id) {
+        draw_cards::run(game, side, 1, source)?;
+    }
+
+    Ok(Some(card_id))
+}
+
+/// Reveals the top `depth` cards of the `side` player's deck via
+/// [reveal_card]. The first revealed card for which `predicate` returns true
+/// is drawn, and all other revealed cards are placed on the bottom of the deck
+/// in their original order, hidden from the opponent.
+///
+/// Returns the drawn card, or `None` if no revealed card matched.
+pub fn dig_for(
+    game: &mut GameState,
+    side: Side,
+    source: InitiatedBy,
+    predicate: impl Fn(&GameState, CardId) -> bool,
+    depth: u32,
+) -> Result<Option<CardId>> {
+    let mut revealed = realize_top_of_deck(game, side, depth, RealizeCards::SetVisibleToOwner)?;
+    revealed.reverse();
+    for card_id in &revealed {
+        reveal_card(game, *card_id)?;
+    }
+
+    let found = revealed.iter().copied().find(|card_id| predicate(game, *card_id));
+    for card_id in revealed.iter().filter(|card_id| Some(**card_id) != found) {
+        move_card(game, *card_id, CardPosition::DeckBottom(side))?;
+        set_visible_to(game, *card_id, side.opponent(), false);
+    }
+
+    if found.is_some() {
+        draw_cards::run(game, side, 1, source)?;
+    }
+
+    Ok(found)
+}
+
+/// Makes the top card of the opponent's deck visible to the `side` player.
+///
+/// Unlike [reveal_card], the card is *not* revealed to its owner and no reveal
+/// event fires. Returns the card, or `None` if the opponent's deck is empty.
+pub fn reveal_opponent_top(game: &mut GameState, side: Side) -> Result<Option<CardId>> {
+    let Some(card_id) =
+        realize_top_of_deck(game, side.opponent(), 1, RealizeCards::NotVisibleToOwner)?
+            .first()
+            .copied()
+    else {
+        return Ok(None);
+    };
+
+    set_visible_to(game, card_id, side, true);
+    Ok(Some(card_id))
+}
+
+/// Draws `draw` cards for the `side` player and then prompts them to discard
+/// `discard` cards from their hand.
+///
+/// The discard prompt is built from the player's hand after the draw has been
+/// initiated and requires exactly `discard` cards to be selected, or the
+/// entire hand if fewer cards are available.
+pub fn draw_then_discard(
+    game: &mut GameState,
+    side: Side,
+    draw: u32,
+    discard: usize,
+    source: InitiatedBy,
+) -> Result<()> {
+    draw_cards::run(game, side, draw, source)?;
+    let hand = game.hand(side).card_ids();
+    let count = cmp::min(discard, hand.len());
+    if count > 0 {
+        prompts::push_immediate(
+            game,
+            side,
+            GamePrompt::CardSelector(CardSelectorPrompt {
+                initiated_by: source,
+                context: Some(PromptContext::DiscardCards(count)),
+                unchosen_subjects: hand,
+                chosen_subjects: vec![],
+                target: SelectorPromptTarget::DiscardPile,
+                validation: Some(CardSelectorPromptValidation::ExactlyCount(count)),
+                can_reorder: false,
+            }),
+        )?;
+    }
+    Ok(())
+}
+
+/// Reveals each card in the hand of the opponent of the `side` player via
+/// [reveal_card] and then prompts the `side` player to choose one of those
+/// cards to discard.
+///
+/// Does nothing if the opponent's hand is empty.
+pub fn opponent_reveals_and_you_choose_discard(game: &mut GameState, side: Side) -> Result<()> {
+    let opponent = side.opponent();
+    let hand = game.hand(opponent).card_ids();
+    if hand.is_empty() {
+        return Ok(());
+    }
+
+    for card_id in &hand {
+        reveal_card(game, *card_id)?;
+    }
+
+    prompts::push_immediate(
+        game,
+        side,
+        GamePrompt::ButtonPrompt(ButtonPrompt {
+            context: Some(ButtonPromptContext::CardToDiscardFromOpponent),
+            choices: hand
+                .into_iter()
+                .map(|card_id| {
+                    PromptChoice::new()
+                        .effect(GameEffect::MoveCard(card_id, CardPosition::DiscardPile(opponent)))
+                        .anchor_card(card_id)
+                        .custom_label(PromptChoiceLabel::Discard)
+                })
+                .collect(),
+        }),
+    )?;
+    Ok(())
+}
+
+/// Reveals each card in the hand of the `side` player via [reveal_card].
+///
+/// Returns true if `predicate` returns true for any of the revealed cards.
+pub fn reveal_hand(
+    game: &mut GameState,
+    side: Side,
+    predicate: impl Fn(&GameState, CardId) -> bool,
+) -> Result<bool> {
+    let hand = game.hand(side).card_ids();
+    for card_id in &hand {
+        reveal_card(game, *card_id)?;
+    }
+    Ok(hand.into_iter().any(|card_id| predicate(game, card_id)))
+}
+
 /// Increases the progress level of all `can_progress_card` Covenant cards
 /// occupying room by the provided `amount`. If a Scheme card's progress level
 /// reaches its `progress_requirement`, that card is immediately scored and
@@ -560,9 +883,14 @@ pub fn summon_project_ignoring_costs(game: &mut GameState, card_id: CardId) -> R
 
 /// Starts the turn for the `next_side` player.
 pub fn start_turn(game: &mut GameState, next_side: Side, turn_number: TurnNumber) -> Result<()> {
+    if game.info.phase == GamePhase::Play {
+        return_banished_cards(game, game.info.turn.side)?;
+    }
+
     game.info.phase = GamePhase::Play;
     game.info.turn = TurnData { side: next_side, turn_number };
     game.info.turn_state = TurnState::Active;
+    clear_expired_custom_state(game);
 
     debug!(?next_side, "Starting player turn");
     game.add_animation(|| GameAnimation::StartTurn(next_side));
@@ -638,6 +966,30 @@ pub fn summon_minion(
     Ok(())
 }
 
+/// Moves a minion card from the Covenant's discard pile into the `room_id`
+/// room as a defender and summons it, ignoring its costs.
+///
+/// Returns an error if the indicated card is not a minion in the discard pile.
+pub fn summon_from_discard(
+    game: &mut GameState,
+    card_id: CardId,
+    room_id: RoomId,
+    initiated_by: InitiatedBy,
+) -> Result<()> {
+    verify!(
+        game.card(card_id).position() == CardPosition::DiscardPile(Side::Covenant),
+        "Card is not in the discard pile"
+    );
+    verify!(game.card(card_id).definition().is_minion(), "Card is not a minion");
+
+    let card_play_id = CardPlayId(game.info.next_event_id());
+    move_card(game, card_id, CardPosition::Room(card_play_id, room_id, RoomLocation::Defender))?;
+    if game.card(card_id).is_face_up() {
+        turn_face_down(game, card_id);
+    }
+    summon_minion(game, card_id, initiated_by, SummonMinion::IgnoreCosts)
+}
+
 /// Turn a minion card in play face down, if able.
 pub fn unsummon_minion(game: &mut GameState, card_id: CardId) -> Result<()> {
     turn_face_down(game, card_id);
@@ -675,6 +1027,38 @@ pub fn apply_raid_jump(game: &mut GameState, request: RaidJumpRequest) {
     }
 }
 
+/// Removes [CustomCardState] entries which can no longer affect the game, in
+/// order to bound the growth of card state over the course of a long game.
+///
+/// Encounter-scoped and raid-scoped entries are removed unless they refer to
+/// the current minion encounter or raid respectively. Turn-scoped entries are
+/// removed unless they refer to the current turn or the turn immediately prior
+/// to it, since some effects last for a full turn cycle.
+pub fn clear_expired_custom_state(game: &mut GameState) {
+    let current_encounter = game.raid.as_ref().and_then(|raid| raid.minion_encounter_id);
+    let current_raid = game.raid_id();
+    let current_turn = game.info.turn;
+    let previous_turn = current_turn.previous();
+    let is_live = |turn: &TurnData| *turn == current_turn || Some(*turn) == previous_turn;
+
+    for side in [Side::Covenant, Side::Riftcaller] {
+        for card in game.cards_mut(side) {
+            card.custom_state.retain(|state| match state {
+                CustomCardState::ActiveForEncounter { encounter_id } => {
+                    Some(*encounter_id) == current_encounter
+                }
+                CustomCardState::UsedForRaid { raid_id } => Some(*raid_id) == current_raid,
+                CustomCardState::TargetCardForTurn { turn, .. }
+                | CustomCardState::IdentityTriggeredForTurn { turn }
+                | CustomCardState::InPlayAbilityTriggeredForTurn { turn, .. }
+                | CustomCardState::CardTypeForTurn { turn, .. }
+                | CustomCardState::CopiedAbilityForTurn { turn, .. } => is_live(turn),
+                _ => true,
+            });
+        }
+    }
+}
+
 /// Creates an entirely new card from outside the game face-up in the indicated
 /// `position`.
 pub fn create_and_add_card(
@@ -199,7 +199,7 @@ fn check_limits(game: &mut GameState, play_card: PlayCardData) -> Result<Option<
     };
 
     if let Some(p) = prompt {
-        prompts::push_immediate(game, play_card.card_id.side, p);
+        prompts::push_immediate(game, play_card.card_id.side, p)?;
     }
 
     Ok(Some(PlayCardStep::ClearPreviousState))
@@ -254,7 +254,7 @@ fn apply_play_card_browser(
     play_card: PlayCardData,
 ) -> Result<Option<PlayCardStep>> {
     invoke_play_card_browser(game, play_card.card_id.side, Some(play_card.card_id))?;
-    Ok(Some(PlayCardStep::PayManaCost))
+    Ok(Some(PlayCardStep::PayCustomCost))
 }
 
 /// Handles resolution of a [GamePrompt] with a `PlayCardBrowser`. Fires the
@@ -289,10 +289,22 @@ pub fn invoke_play_card_browser(
     Ok(())
 }
 
+/// Custom costs are paid before mana costs, since they may modify the mana cost
+/// of the card being played.
+fn pay_custom_cost(game: &mut GameState, play_card: PlayCardData) -> Result<Option<PlayCardStep>> {
+    if flags::enters_play_face_up(game, play_card.card_id) {
+        let definition = game.card(play_card.card_id).definition();
+        if let Some(custom_cost) = &definition.cost.custom_cost {
+            (custom_cost.pay)(game, play_card.card_id)?;
+        }
+    }
+    Ok(Some(PlayCardStep::PayManaCost))
+}
+
 fn pay_mana_cost(game: &mut GameState, play_card: PlayCardData) -> Result<Option<PlayCardStep>> {
     if flags::enters_play_face_up(game, play_card.card_id) {
         if play_card.options.ignore_mana_cost {
-            Ok(Some(PlayCardStep::PayCustomCost))
+            Ok(Some(PlayCardStep::TurnFaceUp))
         } else {
             let amount = queries::mana_cost(game, play_card.card_id)
                 .with_error(|| "Card has no mana cost")?;
@@ -304,21 +316,13 @@ fn pay_mana_cost(game: &mut GameState, play_card: PlayCardData) -> Result<Option
                 amount,
             )?;
 
-            Ok(Some(PlayCardStep::PayCustomCost))
+            Ok(Some(PlayCardStep::TurnFaceUp))
         }
     } else {
         Ok(Some(PlayCardStep::MoveToTargetPosition))
     }
 }
 
-fn pay_custom_cost(game: &mut GameState, play_card: PlayCardData) -> Result<Option<PlayCardStep>> {
-    let definition = game.card(play_card.card_id).definition();
-    if let Some(custom_cost) = &definition.cost.custom_cost {
-        (custom_cost.pay)(game, play_card.card_id)?;
-    }
-    Ok(Some(PlayCardStep::TurnFaceUp))
-}
-
 fn turn_face_up(game: &mut GameState, play_card: PlayCardData) -> Result<Option<PlayCardStep>> {
     mutations::turn_face_up(game, play_card.card_id);
     game.add_animation(|| GameAnimation::PlayCard(play_card.card_id.side, play_card.card_id));
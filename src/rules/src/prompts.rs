@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use core_data::game_primitives::{HasAbilityId, Side};
+use anyhow::Result;
+use constants::game_constants;
+use core_data::game_primitives::{AbilityId, HasAbilityId, Side};
 use dispatcher::dispatch;
 use game_data::delegate_data::ShowPromptQuery;
 use game_data::game_effect::GameEffect;
@@ -21,6 +23,7 @@ use game_data::prompt_data::{
     AbilityPromptSource, GamePrompt, PromptChoice, PromptData, PromptEntry,
 };
 use game_data::special_effects::Projectile;
+use with_error::verify;
 
 use crate::mana;
 use crate::mana::ManaPurpose;
@@ -43,6 +46,21 @@ pub fn current_mut(game: &mut GameState, side: Side) -> Option<&mut GamePrompt>
     game.player_mut(side).prompts.stack.last_mut().map(|e| &mut e.prompt)
 }
 
+/// Returns the abilities which have queued prompts on the [Side] player's
+/// prompt stack, in the order in which they will be resolved.
+///
+/// The first entry is the currently-visible prompt. Prompts which were added
+/// via [push_immediate] have no source ability and are not included.
+pub fn pending_abilities(game: &GameState, side: Side) -> Vec<AbilityId> {
+    game.player(side)
+        .prompts
+        .stack
+        .iter()
+        .rev()
+        .filter_map(|entry| entry.source.as_ref().map(|s| s.ability_id))
+        .collect()
+}
+
 /// Registers an ability as wanting to show a prompt.
 ///
 /// Abilities can request to show a prompt, and the specific value of the prompt
@@ -61,7 +79,10 @@ pub fn current_mut(game: &mut GameState, side: Side) -> Option<&mut GamePrompt>
 /// will not be shown if the player is unable to pay the associated cost.
 /// Similarly a button prompt consisting only of a "continue" button will not be
 /// shown.
-pub fn push(game: &mut GameState, side: Side, ability_id: impl HasAbilityId) {
+///
+/// Returns an error if the prompt stack already contains
+/// [game_constants::MAXIMUM_PROMPT_STACK_DEPTH] entries.
+pub fn push(game: &mut GameState, side: Side, ability_id: impl HasAbilityId) -> Result<()> {
     push_with_data(game, side, ability_id, PromptData::None)
 }
 
@@ -73,22 +94,30 @@ pub fn push_with_data(
     side: Side,
     ability_id: impl HasAbilityId,
     data: PromptData,
-) {
+) -> Result<()> {
     let source = AbilityPromptSource { ability_id: ability_id.ability_id(), data };
     if let Some(prompt) = run_prompt_query(game, &source) {
+        check_depth(game, side)?;
         add_card_movement_animations(game, &prompt);
         game.player_mut(side).prompts.stack.push(PromptEntry { prompt, source: Some(source) });
     }
+    Ok(())
 }
 
 /// Add a [GamePrompt] to this player's prompt stack immediately.
 ///
 /// This bypasses the query mechanism described above, meaning that the added
 /// prompt cannot adapt to changes in game state between the time when it is
-/// added to the stack and the time when it is shown.
-pub fn push_immediate(game: &mut GameState, side: Side, prompt: GamePrompt) {
-    add_card_movement_animations(game, &prompt);
-    game.player_mut(side).prompts.stack.push(PromptEntry { prompt, source: None });
+/// added to the stack and the time when it is shown. The same cleanups as in
+/// [push] are applied when the prompt is added, so e.g. a button prompt whose
+/// only legal choice is "continue" is skipped entirely.
+pub fn push_immediate(game: &mut GameState, side: Side, prompt: GamePrompt) -> Result<()> {
+    if let Some(prompt) = remove_empty(game, prompt) {
+        check_depth(game, side)?;
+        add_card_movement_animations(game, &prompt);
+        game.player_mut(side).prompts.stack.push(PromptEntry { prompt, source: None });
+    }
+    Ok(())
 }
 
 /// Remove and return the topmost entry in the [Side] player's prompt stack, if
@@ -121,6 +150,16 @@ pub fn pop(game: &mut GameState, side: Side) -> Option<GamePrompt> {
     previous.map(|p| p.prompt)
 }
 
+/// Returns an error if the [Side] player's prompt stack is already at its
+/// maximum depth.
+fn check_depth(game: &GameState, side: Side) -> Result<()> {
+    verify!(
+        game.player(side).prompts.stack.len() < game_constants::MAXIMUM_PROMPT_STACK_DEPTH,
+        "Prompt stack for {side:?} exceeded maximum depth"
+    );
+    Ok(())
+}
+
 fn run_prompt_query(game: &GameState, source: &AbilityPromptSource) -> Option<GamePrompt> {
     dispatch::perform_query(game, ShowPromptQuery(source), None).and_then(|p| remove_empty(game, p))
 }
@@ -163,6 +202,20 @@ fn remove_empty(game: &GameState, prompt: GamePrompt) -> Option<GamePrompt> {
                 Some(GamePrompt::RoomSelector(room_selector))
             }
         }
+        GamePrompt::Distribute(distribute) => {
+            if distribute.targets.is_empty() || distribute.total == 0 {
+                None
+            } else {
+                Some(GamePrompt::Distribute(distribute))
+            }
+        }
+        GamePrompt::ChooseMultiple(choose) => {
+            if choose.targets.is_empty() || choose.count == 0 {
+                None
+            } else {
+                Some(GamePrompt::ChooseMultiple(choose))
+            }
+        }
     }
 }
 
@@ -27,25 +27,46 @@ use core_data::game_primitives::{
 use dispatcher::dispatch;
 use enumset::EnumSet;
 use game_data::card_configuration::{AttackBoost, CardStats, Cost, TargetRequirement};
-use game_data::card_state::{CardPosition, CardState};
+use game_data::card_state::{CardCounter, CardPosition, CardState};
+#[allow(unused)] // Used in rustdocs
+use game_data::custom_card_state::CustomCardState;
 use game_data::delegate_data::{
-    AbilityManaCostQuery, ActionCostQuery, BaseAttackQuery, BreachValueQuery, HealthValueQuery,
-    ManaCostQuery, MaximumHandSizeQuery, PointsValueQuery, RazeCostQuery, ResonanceQuery,
-    SanctumAccessCountQuery, ScoreAccessedCardCostQuery, ShieldCardInfo, ShieldValueQuery,
-    StartOfTurnActionsQuery, VaultAccessCountQuery,
+    AbilityManaCostQuery, ActionCostQuery, BaseAttackQuery, BreachValueQuery, CanUseWeaponQuery,
+    CardEncounter, HealthValueQuery, ManaCostQuery, MaximumHandSizeQuery, PointsValueQuery,
+    RazeCostQuery, ResonanceQuery, SanctumAccessCountQuery, ScoreAccessedCardCostQuery,
+    ShieldCardInfo, ShieldValueQuery, StartOfTurnActionsQuery, VaultAccessCountQuery,
 };
-use game_data::game_actions::{CardTarget, CardTargetKind};
+use game_data::flag_data::Flag;
+use game_data::game_actions::{CardTarget, CardTargetKind, CardTargeting};
 use game_data::game_state::GameState;
 use game_data::prompt_data::GamePrompt;
 use game_data::raid_data::{RaidData, RaidState, RaidStatus, RaidStep};
+use game_data::state_machine_data::PlayCardOptions;
 
-use crate::prompts;
+use crate::{combat, flags, prompts};
 
 /// Obtain the [CardStats] for a given card
 pub fn stats(game: &GameState, card_id: CardId) -> &CardStats {
     &cards::get(game.card(card_id).variant).config.stats
 }
 
+/// Returns true if the Covenant player could currently score the `card_id`
+/// scheme.
+///
+/// The scheme must be an occupant of a room which has met its progress
+/// requirement, and no `CanCovenantScoreScheme` effect may be preventing it
+/// from being scored.
+pub fn can_score_scheme(game: &GameState, card_id: CardId) -> bool {
+    let card = game.card(card_id);
+    let Some(scheme_points) = card.definition().config.stats.scheme_points else {
+        return false;
+    };
+
+    card.position().is_occupant()
+        && card.counters(CardCounter::Progress) >= scheme_points.progress_requirement
+        && flags::can_covenant_score_scheme(game, card_id).value()
+}
+
 /// Queries the number of points the `card_id` card is worth in a score area.
 ///
 /// Returns 0 if this card has no associated scheme points value.
@@ -71,12 +92,17 @@ pub fn score(game: &GameState, side: Side) -> PointsValue {
 /// - For minions and projects, this is the summon cost.
 /// - For other cards this is the casting cost.
 /// - Schemes do not have a mana cost
+///
+/// Includes any reduction recorded via [CustomCardState::ManaCostReduced] for
+/// the current play of this card.
 pub fn mana_cost(game: &GameState, card_id: CardId) -> Option<ManaValue> {
-    dispatch::perform_query(
-        game,
-        ManaCostQuery(&card_id),
-        cards::get(game.card(card_id).variant).cost.mana,
-    )
+    let card = game.card(card_id);
+    let mut cost = cards::get(card.variant).cost.mana;
+    if let CardPosition::Played(play_id, ..) = card.position() {
+        let reduction = card.custom_state.mana_cost_reduction(play_id);
+        cost = cost.map(|mana| mana.saturating_sub(reduction));
+    }
+    dispatch::perform_query(game, ManaCostQuery(&card_id), cost)
 }
 
 /// Returns the mana cost for a given ability, if any. Includes the cost of the
@@ -158,6 +184,35 @@ pub fn breach(game: &GameState, card_id: CardId) -> BreachValue {
     )
 }
 
+/// Returns the total mana the owner of `weapon_id` would need to spend to
+/// defeat `minion_id` with it, including boost activations and shield costs
+/// after breach. Returns 0 if the weapon can already defeat the minion.
+///
+/// Returns None if this weapon cannot be used against this minion at all, e.g.
+/// because their resonances do not match. Does not check whether the owner
+/// currently has enough mana available.
+pub fn weapon_cost_to_defeat(
+    game: &GameState,
+    weapon_id: CardId,
+    minion_id: CardId,
+) -> Option<ManaValue> {
+    if !combat::can_encounter_target(game, weapon_id, minion_id) {
+        return None;
+    }
+
+    let can_use: bool = dispatch::perform_query(
+        game,
+        CanUseWeaponQuery(&CardEncounter::new(weapon_id, minion_id)),
+        Flag::new(true),
+    )
+    .into();
+    if !can_use {
+        return None;
+    }
+
+    combat::cost_to_defeat_target(game, weapon_id, minion_id).map(|cost| cost.mana_cost)
+}
+
 /// Returns the cost for the Riftcaller to score an accessed card, if any
 pub fn score_accessed_card_cost(game: &GameState, card_id: CardId) -> Cost<CardId> {
     dispatch::perform_query(game, ScoreAccessedCardCostQuery(&card_id), Cost::zero())
@@ -184,6 +239,15 @@ pub fn start_of_turn_action_count(game: &GameState, side: Side) -> ActionCount {
     dispatch::perform_query(game, StartOfTurnActionsQuery(&side), default)
 }
 
+/// Look up the number of cards to deal to a player's opening hand
+pub fn opening_hand_size(game: &GameState, side: Side) -> u32 {
+    match side {
+        Side::Covenant => game.info.config.covenant_opening_hand_size,
+        Side::Riftcaller => game.info.config.riftcaller_opening_hand_size,
+    }
+    .unwrap_or(game_constants::STARTING_HAND_SIZE)
+}
+
 /// Look up the number of cards the Riftcaller player can access from the Vault
 /// during the current raid
 pub fn vault_access_count(game: &GameState) -> Result<u32> {
@@ -199,7 +263,8 @@ pub fn sanctum_access_count(game: &GameState) -> Result<u32> {
 }
 
 /// Queries the Resonance set for a card (weapon or minion). Minions can only be
-/// damaged by weapons from the same resonance, or by Prismatic weapons.
+/// damaged by weapons from the same resonance, or by Prismatic weapons. See
+/// [Resonance::can_damage] for the rules for Prismatic minions.
 pub fn resonance(game: &GameState, card_id: CardId) -> EnumSet<Resonance> {
     let resonance = game.card(card_id).definition().config.resonance;
     dispatch::perform_query(game, ResonanceQuery(&card_id), resonance)
@@ -221,6 +286,45 @@ pub fn card_target_kind(game: &GameState, card_id: CardId) -> CardTargetKind {
     }
 }
 
+/// Returns the complete set of legal targets for the owner of the `card_id`
+/// card to play it, resolving any custom targeting requirement into concrete
+/// rooms.
+pub fn valid_targets(game: &GameState, card_id: CardId) -> CardTargeting {
+    targets_for_requirement(
+        game.card(card_id).definition().config.custom_targeting.as_ref(),
+        flags::enters_play_in_room(game, card_id),
+        |target| {
+            flags::can_play_card(game, card_id.side, card_id, target, PlayCardOptions::default())
+        },
+    )
+}
+
+/// Builds a [CardTargeting] for an optional [TargetRequirement] by testing each
+/// possible [CardTarget] against the provided `can_play` function.
+///
+/// If `play_in_room` is true, targets are rooms the card will enter play in.
+pub fn targets_for_requirement<T>(
+    requirement: Option<&TargetRequirement<T>>,
+    play_in_room: bool,
+    can_play: impl Fn(CardTarget) -> bool,
+) -> CardTargeting {
+    match (requirement, play_in_room) {
+        (None, false) | (Some(TargetRequirement::None), _) => {
+            CardTargeting::NoTargeting { can_play: can_play(CardTarget::None) }
+        }
+        (None, true) | (Some(TargetRequirement::TargetRoom(_)), _) => {
+            let valid_rooms = enum_iterator::all::<RoomId>()
+                .filter(|room_id| can_play(CardTarget::Room(*room_id)))
+                .collect();
+            if play_in_room {
+                CardTargeting::PlayInRoom { valid_rooms }
+            } else {
+                CardTargeting::TargetRoom { valid_rooms }
+            }
+        }
+    }
+}
+
 /// Returns the highest mana cost card among those in the provided
 /// `card_iterator` (breaking ties based on sorting key), or None if there is no
 /// such card.
@@ -300,7 +404,8 @@ pub fn raid_status(raid: &RaidData) -> RaidStatus {
             | RaidStep::PopulateEncounterPrompt(..)
             | RaidStep::UseWeapon(..)
             | RaidStep::MinionDefeated(..)
-            | RaidStep::FireMinionCombatAbility(..) => RaidStatus::Encounter,
+            | RaidStep::FireMinionCombatAbility(..)
+            | RaidStep::FailRaid => RaidStatus::Encounter,
 
             RaidStep::PopulateApproachPrompt => RaidStatus::ApproachRoom,
 
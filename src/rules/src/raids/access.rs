@@ -59,6 +59,24 @@ pub fn select_accessed_cards(game: &mut GameState, info: RaidInfo) -> Result<Vec
     Ok(accessed)
 }
 
+/// Limits the Riftcaller to scoring or razing at most `limit` more accessed
+/// cards during the current raid. If a limit is already in place, the lower
+/// of the two limits applies.
+pub fn limit_access(game: &mut GameState, limit: u32) -> Result<()> {
+    let raid = game.raid_mut()?;
+    raid.access_limit = Some(raid.access_limit.map_or(limit, |current| current.min(limit)));
+    Ok(())
+}
+
+/// Records that the Riftcaller has scored or razed an accessed card, reducing
+/// the remaining access limit for the current raid, if any.
+pub fn consume_access_limit(game: &mut GameState) -> Result<()> {
+    if let Some(limit) = &mut game.raid_mut()?.access_limit {
+        *limit = limit.saturating_sub(1);
+    }
+    Ok(())
+}
+
 /// Returns a [RaidChoice] for the Riftcaller to access the provided
 /// `card_id`, if any action can be taken.
 pub fn access_action_for_card(
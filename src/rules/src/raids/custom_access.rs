@@ -20,8 +20,8 @@ use game_data::game_state::GameState;
 use game_data::raid_data::{RaidData, RaidState, RaidStep};
 use with_error::verify;
 
-use crate::flags;
 use crate::raids::raid_state;
+use crate::{flags, mutations};
 
 /// Initiates a "Custom Access" raid.
 ///
@@ -36,6 +36,7 @@ use crate::raids::raid_state;
 ///  - RiftcallerScoreCardEvent
 ///  - ScoreCardEvent
 ///  - RazeCardEvent
+///  - HistoryEvent::AccessCard
 ///  - HistoryEvent::ScoreAccessedCard
 ///  - HistoryEvent::RazeAccessedCard
 ///
@@ -75,6 +76,7 @@ pub fn initiate(
         jump_request: None,
         is_card_access_prevented: false,
         is_custom_access: true,
+        access_limit: None,
     });
 
     raid_state::run(game, None)
@@ -84,5 +86,6 @@ pub fn initiate(
 pub fn end(game: &mut GameState, initiated_by: InitiatedBy) -> Result<()> {
     dispatch::invoke_event(game, CustomAccessEndEvent(&initiated_by))?;
     game.raid = None;
+    mutations::clear_expired_custom_state(game);
     Ok(())
 }
@@ -94,6 +94,7 @@ pub fn initiate_with_callback(
         jump_request: None,
         is_card_access_prevented: options.is_card_access_prevented,
         is_custom_access: false,
+        access_limit: None,
     };
 
     let info = raid.info();
@@ -270,6 +271,7 @@ fn evaluate_raid_step(game: &mut GameState, info: RaidInfo, step: RaidStep) -> R
         RaidStep::RazeCardEvent(card_id) => raze_card_event(game, card_id, info),
         RaidStep::FinishAccess => finish_access(game, info),
         RaidStep::FinishRaid => finish_raid(game),
+        RaidStep::FailRaid => fail_raid(game),
     };
 
     // Write history events after each state machine step so they are visible
@@ -337,23 +339,25 @@ fn encounter_minion(game: &mut GameState, minion_id: CardId) -> Result<RaidState
 }
 
 fn populate_encounter_prompt(game: &mut GameState, minion_id: CardId) -> Result<RaidState> {
-    RaidState::prompt(
-        RaidStatus::Encounter,
-        RaidStep::PopulateEncounterPrompt(minion_id),
-        game.artifacts()
-            .filter(|weapon| combat::can_defeat_target(game, weapon.id, minion_id))
-            .map(|weapon| {
-                let interaction = WeaponInteraction::new(weapon.id, minion_id);
-                RaidChoice::new(RaidLabel::UseWeapon(interaction), RaidStep::UseWeapon(interaction))
-            })
-            .chain(flags::can_take_use_no_weapon_action(game, minion_id).then(|| {
-                RaidChoice::new(
-                    RaidLabel::DoNotUseWeapon,
-                    RaidStep::FireMinionCombatAbility(minion_id),
-                )
-            }))
-            .collect(),
-    )
+    let mut choices = game
+        .artifacts()
+        .filter(|weapon| combat::can_defeat_target(game, weapon.id, minion_id))
+        .map(|weapon| {
+            let interaction = WeaponInteraction::new(weapon.id, minion_id);
+            RaidChoice::new(RaidLabel::UseWeapon(interaction), RaidStep::UseWeapon(interaction))
+        })
+        .chain(flags::can_take_use_no_weapon_action(game, minion_id).then(|| {
+            RaidChoice::new(RaidLabel::DoNotUseWeapon, RaidStep::FireMinionCombatAbility(minion_id))
+        }))
+        .collect::<Vec<_>>();
+
+    if choices.is_empty() {
+        // Offer to end the raid instead of leaving the Riftcaller without any
+        // way to continue.
+        choices.push(RaidChoice::new(RaidLabel::NoUsableWeapon, RaidStep::FailRaid));
+    }
+
+    RaidState::prompt(RaidStatus::Encounter, RaidStep::PopulateEncounterPrompt(minion_id), choices)
 }
 
 fn use_weapon(
@@ -493,6 +497,7 @@ fn reveal_accessed_cards(game: &mut GameState, info: RaidInfo) -> Result<RaidSta
 fn access_cards(game: &mut GameState, info: RaidInfo) -> Result<RaidState> {
     let accessed = game.raid()?.accessed.clone();
     for card_id in &accessed {
+        game.add_history_event(HistoryEvent::AccessCard(info.access_event(*card_id)));
         dispatch::invoke_event(game, CardAccessEvent(&info.access_event(*card_id)))?;
     }
 
@@ -510,12 +515,14 @@ fn will_populate_access_prompt(
 
 fn populate_access_prompt(game: &mut GameState, info: RaidInfo) -> Result<RaidState> {
     let can_end = flags::can_take_end_raid_access_phase_action(game, info.raid_id);
+    let can_interact = game.raid()?.access_limit != Some(0);
     RaidState::prompt(
         RaidStatus::Access,
         RaidStep::PopulateAccessPrompt,
         game.raid()?
             .accessed
             .iter()
+            .filter(|_| can_interact)
             .filter_map(|card_id| access::access_action_for_card(game, info, *card_id))
             .chain(can_end.then_some(RaidChoice::new(
                 if info.is_custom_access { RaidLabel::EndAccess } else { RaidLabel::EndRaid },
@@ -532,6 +539,7 @@ fn start_scoring_card(
 ) -> Result<RaidState> {
     game.add_history_event(HistoryEvent::ScoreAccessedCard(info.access_event(scored.id)));
     game.current_history_counters(Side::Riftcaller).schemes_scored += 1;
+    access::consume_access_limit(game)?;
     RaidState::step(RaidStep::PayScoringCosts(scored))
 }
 
@@ -587,6 +595,7 @@ fn move_to_scored_position(game: &mut GameState, scored: ScoredCard) -> Result<R
 
 fn start_razing_card(game: &mut GameState, card_id: CardId, cost: u32) -> Result<RaidState> {
     game.raid_mut()?.accessed.retain(|c| *c != card_id);
+    access::consume_access_limit(game)?;
     RaidState::step(RaidStep::RazeCard(card_id, cost))
 }
 
@@ -627,3 +636,8 @@ fn finish_raid(game: &mut GameState) -> Result<RaidState> {
     end_raid::run(game, InitiatedBy::GameAction, RaidOutcome::Success)?;
     RaidState::step(RaidStep::FinishRaid)
 }
+
+fn fail_raid(game: &mut GameState) -> Result<RaidState> {
+    end_raid::run(game, InitiatedBy::GameAction, RaidOutcome::Failure)?;
+    RaidState::step(RaidStep::FailRaid)
+}
@@ -62,6 +62,13 @@ pub fn build(context: &CardViewContext, revealed: bool) -> CardIcons {
         _ => {}
     }
 
+    match context.card() {
+        Some(card) if card.counters(CardCounter::Growth) > 0 => {
+            icons.arena_icon = Some(status_quantity_icon(card.counters(CardCounter::Growth)))
+        }
+        _ => {}
+    }
+
     if revealed {
         icons.top_left_icon = if let Some(mana_cost) =
             context.query_id_or(definition.cost.mana, queries::mana_cost)
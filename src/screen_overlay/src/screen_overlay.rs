@@ -26,7 +26,7 @@ use core_ui::prelude::*;
 use core_ui::style::Corner;
 use core_ui::text::Text;
 use game_data::game_actions::{DisplayPreference, GameAction};
-use game_data::game_state::GameState;
+use game_data::game_state::{GameState, HandSortOrder};
 use panel_address::{PlayerPanel, StandardPanel};
 use player_data::{PlayerActivityKind, PlayerState, PlayerStatus};
 use protos::riftcaller::client_debug_command::DebugCommand;
@@ -128,7 +128,7 @@ impl<'a, 'b> Component for ScreenOverlay<'a, 'b> {
                     }))
                     .child(self.show_coin_count.then(|| {
                         self.player.adventure.as_ref().map(|adventure| {
-                            Row::new("CoinCount")
+                            Row::new(element_names::COIN_COUNT)
                                 .style(
                                     Style::new()
                                         .margin(Edge::Horizontal, 12.px())
@@ -185,6 +185,10 @@ fn set_display_preference_button(display_preference: DisplayPreference) -> impl
     let icon = match display_preference {
         DisplayPreference::ShowArenaView(true) => icons::EYE_SLASH,
         DisplayPreference::ShowArenaView(false) => icons::EYE,
+        // The button selects the next sort order, but displays the current one
+        DisplayPreference::SortHand(Some(HandSortOrder::ByCost)) => icons::BARS,
+        DisplayPreference::SortHand(Some(HandSortOrder::ByType)) => icons::SORT_NUMERIC_DOWN,
+        DisplayPreference::SortHand(None) => icons::LAYER_GROUP,
     };
 
     IconButton::new(icon)
@@ -22,7 +22,8 @@ use player_data::PlayerState;
 use tracing::info;
 use with_error::WithError;
 
-use crate::requests::{self, SceneName};
+use crate::requests;
+use crate::scenes::{self, LoadMode, SceneName};
 use crate::server_data::{ClientData, GameResponse, RequestData};
 
 pub async fn connect(
@@ -31,7 +32,7 @@ pub async fn connect(
     adventure: &AdventureState,
 ) -> Result<GameResponse> {
     info!(?player.id, ?adventure.id, "Connected to adventure");
-    let mut commands = vec![requests::load_scene(SceneName::World)];
+    let mut commands = vec![scenes::load_scene(SceneName::World, LoadMode::SkipIfCurrent)];
     commands.append(&mut adventure_display::render(adventure)?);
     let client_data = ClientData { adventure_id: Some(adventure.id), game_id: None };
     let mut result = GameResponse::new(client_data).commands(commands);
@@ -52,7 +53,7 @@ pub async fn handle_new_adventure(
     })
     .await?;
 
-    result.insert_command(0, requests::load_scene(SceneName::World));
+    result.insert_command(0, scenes::load_scene(SceneName::World, LoadMode::SkipIfCurrent));
     Ok(result)
 }
 
@@ -74,7 +75,61 @@ pub async fn handle_leave_adventure(
     requests::with_player(database, data, |player| {
         player.adventure = None;
         Ok(GameResponse::new(ClientData::with_adventure_id(data, None))
-            .command(requests::load_scene(SceneName::Main)))
+            .command(scenes::load_scene(SceneName::Main, LoadMode::SkipIfCurrent)))
+    })
+    .await
+}
+
+pub async fn handle_new_adventure_slot(
+    database: &impl Database,
+    data: &RequestData,
+    side: Side,
+) -> Result<GameResponse> {
+    let mut result = requests::with_player(database, data, |player| {
+        let adventure = mock_adventure::create(AdventureConfiguration::new(player.id, side));
+        let id = adventure.id;
+        let slot = player.create_adventure_slot(adventure);
+        info!(?slot, ?id, "Created adventure slot");
+        Ok(GameResponse::new(ClientData::with_adventure_id(data, Some(id))))
+    })
+    .await?;
+
+    result.insert_command(0, scenes::load_scene(SceneName::World, LoadMode::SkipIfCurrent));
+    Ok(result)
+}
+
+pub async fn handle_select_adventure_slot(
+    database: &impl Database,
+    data: &RequestData,
+    slot: u64,
+) -> Result<GameResponse> {
+    let mut result = requests::with_player(database, data, |player| {
+        player.select_adventure_slot(slot)?;
+        let adventure = player.adventure()?;
+        Ok(GameResponse::new(ClientData::with_adventure_id(data, Some(adventure.id)))
+            .commands(adventure_display::render(adventure)?))
+    })
+    .await?;
+
+    result.insert_command(0, scenes::load_scene(SceneName::World, LoadMode::SkipIfCurrent));
+    Ok(result)
+}
+
+pub async fn handle_delete_adventure_slot(
+    database: &impl Database,
+    data: &RequestData,
+    slot: u64,
+) -> Result<GameResponse> {
+    requests::with_player(database, data, |player| {
+        let deleting_current = player.adventure.is_some() && player.current_adventure_slot == slot;
+        player.delete_adventure_slot(slot)?;
+        let id = player.adventure.as_ref().map(|adventure| adventure.id);
+        let mut response = GameResponse::new(ClientData::with_adventure_id(data, id));
+        if deleting_current {
+            response =
+                response.command(scenes::load_scene(SceneName::Main, LoadMode::SkipIfCurrent));
+        }
+        Ok(response)
     })
     .await
 }
@@ -88,8 +143,11 @@ pub async fn update_adventure(
         let adventure_state =
             player.adventure.as_mut().with_error(|| "Expected active adventure")?;
         let id = adventure_state.id;
+        let previous_coins = adventure_state.coins;
         function(adventure_state)?;
-        let commands = adventure_display::render(adventure_state)?;
+        let mut commands = adventure_display::render(adventure_state)?;
+        commands
+            .extend(adventure_display::coin_count_update(previous_coins, adventure_state.coins));
         Ok(GameResponse::new(ClientData::with_adventure_id(data, Some(id))).commands(commands))
     })
     .await
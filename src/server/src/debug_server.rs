@@ -40,10 +40,10 @@ use panel_address::Panel;
 use player_data::PlayerStatus;
 use protos::riftcaller::client_debug_command::DebugCommand;
 use protos::riftcaller::game_command::Command;
-use protos::riftcaller::{ClientAction, ClientDebugCommand, LoadSceneCommand, SceneLoadMode};
+use protos::riftcaller::{ClientAction, ClientDebugCommand, LogMessage, LogMessageLevel};
 use rules::mana::ManaPurpose;
 use rules::mutations::{RealizeCards, SummonMinion};
-use rules::{curses, draw_cards, mana, mutations, wounds};
+use rules::{curses, draw_cards, mana, mutations, prompts, wounds};
 use serde_json::{de, ser};
 use sled::Db;
 use ulid::Ulid;
@@ -53,9 +53,9 @@ use user_action_data::{
 };
 use with_error::WithError;
 
-use crate::requests::SceneName;
+use crate::scenes::{LoadMode, SceneName};
 use crate::server_data::{ClientData, GameResponse, RequestData};
-use crate::{adventure_server, requests};
+use crate::{adventure_server, requests, scenes};
 
 static DEBUG_DB: Lazy<Db> = Lazy::new(|| sled::open("debug_db").expect("Error opening debug_db"));
 
@@ -310,6 +310,38 @@ pub async fn handle_debug_action(
             })
             .await
         }
+        DebugAction::LogPendingAbilities => {
+            let game = requests::fetch_game(database, data.game_id).await?;
+            let user_side = game.player_side(data.player_id)?;
+            Ok(GameResponse::new(ClientData::with_game_id(data, Some(game.id))).commands(vec![
+                Command::Debug(ClientDebugCommand {
+                    debug_command: Some(DebugCommand::LogMessage(LogMessage {
+                        text: pending_abilities_message(&game, user_side),
+                        level: LogMessageLevel::Standard.into(),
+                    })),
+                }),
+            ]))
+        }
+    }
+}
+
+/// Describes the abilities on the `side` player's prompt stack, in the order
+/// in which they will resolve.
+fn pending_abilities_message(game: &GameState, side: Side) -> String {
+    let abilities = prompts::pending_abilities(game, side)
+        .into_iter()
+        .map(|ability_id| {
+            format!(
+                "{} (ability {})",
+                game.card(ability_id.card_id).variant.name.displayed_name(),
+                ability_id.index.0
+            )
+        })
+        .collect::<Vec<_>>();
+    if abilities.is_empty() {
+        format!("No pending abilities for {side:?}")
+    } else {
+        format!("Pending abilities for {side:?}: {}", abilities.join(", "))
     }
 }
 
@@ -334,6 +366,7 @@ fn create_debug_game(data: &RequestData, side: Side) -> Result<GameResponse> {
                         debug_options: Some(NewGameDebugOptions {
                             deterministic: false,
                             override_game_id: Some(id),
+                            ..NewGameDebugOptions::default()
                         }),
                         tutorial: false,
                     })
@@ -345,11 +378,7 @@ fn create_debug_game(data: &RequestData, side: Side) -> Result<GameResponse> {
 }
 
 fn reload_scene(data: &RequestData, game: &GameState) -> Result<GameResponse> {
-    let command = Command::LoadScene(LoadSceneCommand {
-        scene_name: "Game".to_string(),
-        mode: SceneLoadMode::Single as i32,
-        skip_if_current: false,
-    });
+    let command = scenes::load_scene(SceneName::Game, LoadMode::Force);
     let user_side = game.player_side(data.player_id)?;
     let opponent_id = game.player(user_side.opponent()).id;
     Ok(GameResponse::new(ClientData::with_game_id(data, Some(game.id)))
@@ -358,11 +387,7 @@ fn reload_scene(data: &RequestData, game: &GameState) -> Result<GameResponse> {
 }
 
 fn reload_world_scene(data: &RequestData) -> GameResponse {
-    let command = Command::LoadScene(LoadSceneCommand {
-        scene_name: "World".to_string(),
-        mode: SceneLoadMode::Single as i32,
-        skip_if_current: false,
-    });
+    let command = scenes::load_scene(SceneName::World, LoadMode::Force);
     GameResponse::new(ClientData::propagate(data)).command(command)
 }
 
@@ -502,6 +527,6 @@ async fn apply_adventure_scenario(
     })
     .await?;
 
-    result.insert_command(0, requests::force_load_scene(SceneName::World));
+    result.insert_command(0, scenes::load_scene(SceneName::World, LoadMode::Force));
     Ok(result)
 }
@@ -0,0 +1,35 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use core_data::game_primitives::DeckId;
+use database::Database;
+use deck_editor::deck_editor_actions;
+use tracing::info;
+
+use crate::requests;
+use crate::server_data::{ClientData, GameResponse, RequestData};
+
+pub async fn handle_copy_deck(
+    database: &impl Database,
+    data: &RequestData,
+    deck_id: DeckId,
+) -> Result<GameResponse> {
+    requests::with_player(database, data, |player| {
+        let new_id = deck_editor_actions::copy_deck(player, deck_id)?;
+        info!(?deck_id, ?new_id, "Copied deck");
+        Ok(GameResponse::new(ClientData::propagate(data)))
+    })
+    .await
+}
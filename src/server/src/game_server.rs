@@ -12,16 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::SystemTime;
+
 use adapters::ServerCardId;
 use adventure_data::adventure::AdventureScreen;
 use anyhow::Result;
+use core_data::adventure_primitives::AdventureOutcome;
 use core_data::game_primitives::{GameId, Side};
 use database::Database;
 use display::{render, set_display_preference};
 use game_data::game_actions::{self, DisplayPreference, GameAction};
-use game_data::game_state::GameState;
+use game_data::game_state::{GamePhase, GameState};
+use game_data::player_name::PlayerId;
 use game_data::prompt_data::PromptAction;
-use player_data::PlayerState;
+use player_data::{MatchRecord, PlayerState};
 use protos::riftcaller::{
     DrawCardAction, GainManaAction, InitiateRaidAction, MoveCardAction, PlayCardAction,
     ProgressRoomAction, SpendActionPointAction,
@@ -32,9 +36,9 @@ use user_action_data::GameOutcome;
 use with_error::{fail, WithError};
 
 use crate::ai_agent_response::IncrementalUpdates;
-use crate::requests::SceneName;
+use crate::scenes::{LoadMode, SceneName};
 use crate::server_data::{ClientData, GameResponse, RequestData};
-use crate::{ai_agent_response, requests};
+use crate::{ai_agent_response, requests, scenes};
 
 pub async fn connect(
     database: &impl Database,
@@ -44,7 +48,7 @@ pub async fn connect(
     let game = requests::fetch_game(database, Some(game_id)).await?;
     info!(?player.id, ?game.id, "Connected to game");
     let side = game.player_side(player.id)?;
-    let mut commands = vec![requests::load_scene(SceneName::Game)];
+    let mut commands = vec![scenes::load_scene(SceneName::Game, LoadMode::SkipIfCurrent)];
     commands.append(&mut render::connect(&game, side)?);
     let client_data = ClientData {
         adventure_id: player.adventure.as_ref().map(|a| a.id),
@@ -66,6 +70,15 @@ pub async fn handle_leave_game(
     data: &RequestData,
     outcome: GameOutcome,
 ) -> Result<GameResponse> {
+    let game = match data.game_id {
+        Some(game_id) => database.fetch_game(game_id).await?,
+        None => None,
+    };
+
+    if let Some(game) = &game {
+        record_match_history(database, game).await?;
+    }
+
     requests::with_player(database, data, |player| {
         player.status = None;
         let scene = if let Some(adventure) = player.adventure.as_mut() {
@@ -74,6 +87,14 @@ pub async fn handle_leave_game(
             };
 
             adventure.coins += battle.reward;
+            if outcome == GameOutcome::Victory {
+                if battle.is_final_battle {
+                    adventure.outcome = Some(AdventureOutcome::Victory);
+                }
+                if let Some(card) = battle.guaranteed_reward {
+                    *adventure.deck.cards.entry(card).or_insert(0) += 1;
+                }
+            }
             adventure.screens.pop();
 
             match outcome {
@@ -85,11 +106,50 @@ pub async fn handle_leave_game(
         };
 
         Ok(GameResponse::new(ClientData::with_game_id(data, None))
-            .command(requests::load_scene(scene)))
+            .command(scenes::load_scene(scene, LoadMode::SkipIfCurrent)))
     })
     .await
 }
 
+/// Adds a [MatchRecord] to the match history of each human player in `game`
+/// if the game has ended and has not already been recorded.
+async fn record_match_history(database: &impl Database, game: &GameState) -> Result<()> {
+    for side in enum_iterator::all::<Side>() {
+        let Some(record) = match_record(game, side) else {
+            return Ok(());
+        };
+        let id = game.player(side).id;
+        if !matches!(id, PlayerId::Database(_)) {
+            continue;
+        }
+        let Some(mut player) = database.fetch_player(id).await? else {
+            continue;
+        };
+        if player.record_match(record) {
+            database.write_player(&player).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Builds a [MatchRecord] for the `side` player from the result of a
+/// completed game.
+///
+/// Returns None if the game has not ended.
+fn match_record(game: &GameState, side: Side) -> Option<MatchRecord> {
+    let GamePhase::GameOver { winner } = game.info.phase else {
+        return None;
+    };
+    Some(MatchRecord {
+        game_id: game.id,
+        opponent: game.player(side.opponent()).id,
+        side,
+        outcome: if winner == side { GameOutcome::Victory } else { GameOutcome::Defeat },
+        deck: game.player(side).deck_id,
+        timestamp: SystemTime::now(),
+    })
+}
+
 pub async fn handle_game_action(
     database: &impl Database,
     data: &RequestData,
@@ -97,10 +157,14 @@ pub async fn handle_game_action(
 ) -> Result<GameResponse> {
     let mut game = requests::fetch_game(database, data.game_id).await?;
     let user_side = game.player_side(data.player_id)?;
+    let was_game_over = matches!(game.info.phase, GamePhase::GameOver { .. });
     apply_game_action(&mut game, user_side, action)?;
 
     let ran_agent =
         ai_agent_response::maybe_run_ai(data, &mut game, IncrementalUpdates::Send).await?;
+    if !was_game_over {
+        record_match_history(database, &game).await?;
+    }
 
     let result = if ran_agent {
         debug!("AI connected, sending empty response");
@@ -19,12 +19,13 @@ use panel_address::StandardPanel;
 use player_data::PlayerState;
 use tracing::info;
 
-use crate::requests::{self, SceneName};
+use crate::requests;
+use crate::scenes::{self, LoadMode, SceneName};
 use crate::server_data::{ClientData, GameResponse};
 
 pub async fn connect(_: &impl Database, player: &PlayerState) -> Result<GameResponse> {
     info!(?player.id, "Connected");
-    let mut commands = vec![requests::load_scene(SceneName::Main)];
+    let mut commands = vec![scenes::load_scene(SceneName::Main, LoadMode::SkipIfCurrent)];
     commands.push(Panels::open(StandardPanel::MainMenu).into());
     let client_data = ClientData { adventure_id: None, game_id: None };
     let mut result = GameResponse::new(client_data).commands(commands);
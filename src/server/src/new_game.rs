@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use anyhow::Result;
-use core_data::game_primitives::Side;
+use core_data::game_primitives::{DeckId, Side};
 use database::Database;
 use dispatcher::dispatch;
 use game_data::deck::Deck;
@@ -27,9 +27,9 @@ use user_action_data::{NewGameAction, NewGameDeck};
 use with_error::fail;
 
 use crate::ai_agent_response::IncrementalUpdates;
-use crate::requests::SceneName;
+use crate::scenes::{LoadMode, SceneName};
 use crate::server_data::{ClientData, GameResponse, OpponentData, RequestData};
-use crate::{ai_agent_response, requests};
+use crate::{ai_agent_response, requests, scenes};
 
 /// Creates a new game and assigns the player to their requested side & deck.
 pub async fn create(
@@ -82,10 +82,24 @@ pub async fn create(
         GameConfiguration {
             deterministic: debug_options.deterministic,
             scripted_tutorial: action.tutorial,
+            covenant_opening_hand_size: debug_options.covenant_opening_hand_size,
+            riftcaller_opening_hand_size: debug_options.riftcaller_opening_hand_size,
+            first_turn_choice: debug_options.first_turn_choice,
             ..GameConfiguration::default()
         },
     );
 
+    game.player_mut(user_side).card_art_overrides = player.card_art_overrides.clone();
+    game.player_mut(user_side).card_back = player.card_back.clone();
+    game.player_mut(user_side).deck_id = deck_id(action.deck);
+    if let OpponentData::HumanPlayer(o) = &opponent {
+        game.player_mut(opponent_side).card_art_overrides = o.card_art_overrides.clone();
+        game.player_mut(opponent_side).card_back = o.card_back.clone();
+        if let Some(PlayerStatus::RequestedGame(requested)) = o.status {
+            game.player_mut(opponent_side).deck_id = deck_id(requested.deck);
+        }
+    }
+
     dispatch::populate_delegate_map(&mut game);
     mutations::deal_opening_hands(&mut game)?;
 
@@ -100,8 +114,11 @@ pub async fn create(
     ai_agent_response::maybe_run_ai(data, &mut game, IncrementalUpdates::Skip).await?;
 
     let result = Ok(GameResponse::new(ClientData::with_game_id(data, Some(game_id)))
-        .command(requests::force_load_scene(SceneName::Game))
-        .opponent_response(opponent_id, vec![requests::force_load_scene(SceneName::Game)]));
+        .command(scenes::load_scene(SceneName::Game, LoadMode::Force))
+        .opponent_response(
+            opponent_id,
+            vec![scenes::load_scene(SceneName::Game, LoadMode::Force)],
+        ));
 
     database.write_game(&game).await?;
     database.write_player(&player).await?;
@@ -139,6 +156,13 @@ fn requested_deck(opponent: &OpponentData, side: Side) -> Result<Option<Deck>> {
     })
 }
 
+fn deck_id(deck: NewGameDeck) -> Option<DeckId> {
+    match deck {
+        NewGameDeck::DeckId(id) => Some(id),
+        NewGameDeck::NamedDeck(_) => None,
+    }
+}
+
 fn find_deck(player: &PlayerState, deck: NewGameDeck) -> Result<Deck> {
     Ok(match deck {
         NewGameDeck::DeckId(id) => player.deck(id)?.clone(),
@@ -12,8 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fmt::{self, Display};
-
 use anyhow::Result;
 use core_data::game_primitives::GameId;
 use core_ui::prelude::*;
@@ -27,9 +25,7 @@ use game_data::player_name::PlayerId;
 use panel_address::PanelAddress;
 use player_data::PlayerState;
 use protos::riftcaller::game_command::Command;
-use protos::riftcaller::{
-    LoadSceneCommand, RenderScreenOverlayCommand, SceneLoadMode, UpdatePanelsCommand,
-};
+use protos::riftcaller::{RenderScreenOverlayCommand, UpdatePanelsCommand};
 use routing::all_panels;
 use screen_overlay::ScreenOverlay;
 use with_error::WithError;
@@ -96,39 +92,6 @@ pub async fn fetch_game(database: &impl Database, game_id: Option<GameId>) -> Re
     Ok(game)
 }
 
-pub enum SceneName {
-    Game,
-    World,
-    Main,
-}
-
-impl Display for SceneName {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            SceneName::Game => write!(f, "Game"),
-            SceneName::World => write!(f, "World"),
-            SceneName::Main => write!(f, "Main"),
-        }
-    }
-}
-
-/// Requests to switch to a new scene if it's not currently being displayed
-pub fn load_scene(name: SceneName) -> Command {
-    Command::LoadScene(LoadSceneCommand {
-        scene_name: name.to_string(),
-        mode: SceneLoadMode::Single.into(),
-        skip_if_current: true,
-    })
-}
-
-pub fn force_load_scene(name: SceneName) -> Command {
-    Command::LoadScene(LoadSceneCommand {
-        scene_name: name.to_string(),
-        mode: SceneLoadMode::Single.into(),
-        skip_if_current: false,
-    })
-}
-
 pub async fn add_standard_ui(
     response: &mut GameResponse,
     player: &PlayerState,
@@ -0,0 +1,55 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Names of client scenes and helpers for switching between them.
+
+use std::fmt::{self, Display};
+
+use protos::riftcaller::game_command::Command;
+use protos::riftcaller::{LoadSceneCommand, SceneLoadMode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SceneName {
+    Game,
+    World,
+    Main,
+}
+
+impl Display for SceneName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SceneName::Game => write!(f, "Game"),
+            SceneName::World => write!(f, "World"),
+            SceneName::Main => write!(f, "Main"),
+        }
+    }
+}
+
+/// Controls whether a scene which is already being displayed is reloaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadMode {
+    /// Do nothing if the scene is already being displayed.
+    SkipIfCurrent,
+    /// Always reload the scene.
+    Force,
+}
+
+/// Requests to switch to a new scene.
+pub fn load_scene(name: SceneName, mode: LoadMode) -> Command {
+    Command::LoadScene(LoadSceneCommand {
+        scene_name: name.to_string(),
+        mode: SceneLoadMode::Single.into(),
+        skip_if_current: mode == LoadMode::SkipIfCurrent,
+    })
+}
@@ -40,11 +40,13 @@ use crate::server_data::{ClientData, GameResponse, RequestData};
 pub mod adventure_server;
 pub mod ai_agent_response;
 pub mod debug_server;
+pub mod deck_editor_server;
 pub mod game_server;
 pub mod keyboard_shortcuts;
 pub mod main_menu_server;
 pub mod new_game;
 pub mod requests;
+pub mod scenes;
 pub mod server_data;
 
 /// Stores active channels for each user.
@@ -231,6 +233,19 @@ async fn handle_standard_action(
         UserAction::LeaveAdventure => {
             adventure_server::handle_leave_adventure(database, data).instrument(span).await
         }
+        UserAction::NewAdventureSlot(side) => {
+            adventure_server::handle_new_adventure_slot(database, data, side).instrument(span).await
+        }
+        UserAction::SelectAdventureSlot(id) => {
+            adventure_server::handle_select_adventure_slot(database, data, id)
+                .instrument(span)
+                .await
+        }
+        UserAction::DeleteAdventureSlot(id) => {
+            adventure_server::handle_delete_adventure_slot(database, data, id)
+                .instrument(span)
+                .await
+        }
         UserAction::NewGame(a) => new_game::create(database, data, &a).instrument(span).await,
         UserAction::GameAction(a) => {
             game_server::handle_game_action(database, data, &a).instrument(span).await
@@ -238,6 +253,9 @@ async fn handle_standard_action(
         UserAction::LeaveGame(o) => {
             game_server::handle_leave_game(database, data, o).instrument(span).await
         }
+        UserAction::CopyDeck(deck_id) => {
+            deck_editor_server::handle_copy_deck(database, data, deck_id).instrument(span).await
+        }
     }
 }
 
@@ -14,7 +14,10 @@
 
 use std::collections::HashMap;
 
+use anyhow::Result;
 use protos::riftcaller::client_action::Action;
+use protos::riftcaller::conditional_query::Query;
+use protos::riftcaller::element_selector::Selector;
 use protos::riftcaller::game_command::Command;
 use protos::riftcaller::node_background::BackgroundAddress;
 use protos::riftcaller::studio_display::Display;
@@ -23,6 +26,7 @@ use protos::riftcaller::{
     node_type, CardAnchorNode, CardView, ClientAction, DraggableNode, EventHandlers,
     FetchPanelAction, InterfacePanel, InterfacePanelAddress, Node, NodeType, StudioDisplay,
 };
+use with_error::WithError;
 
 /// Simulated user interface state
 #[derive(Debug, Clone, Default)]
@@ -75,6 +79,23 @@ impl ClientInterface {
         }
     }
 
+    /// Returns the addresses of sprites the topmost currently-open panel
+    /// requested be preloaded.
+    pub fn top_panel_preload_sprites(&self) -> Vec<&str> {
+        self.open_panels
+            .last()
+            .and_then(|address| self.panels.get(address))
+            .map_or(vec![], |panel| {
+                panel.preload_sprites.iter().map(|sprite| sprite.address.as_str()).collect()
+            })
+    }
+
+    /// Returns the contents of the panel with the given address, if it has
+    /// been received from the server.
+    pub fn panel(&self, address: impl Into<InterfacePanelAddress>) -> Option<&Node> {
+        self.panels.get(&address.into())?.node.as_ref()
+    }
+
     pub fn screen_overlay(&self) -> &Node {
         self.screen_overlay_option().expect("Screen overlay not found")
     }
@@ -105,6 +126,25 @@ impl ClientInterface {
         result
     }
 
+    /// Evaluates a [Query] for a conditional command against the current
+    /// interface state.
+    ///
+    /// The simulated client never has a drag in progress and does not create
+    /// synthetic target elements, so queries for those elements are false.
+    pub fn evaluate_query(&self, query: &Query) -> Result<bool> {
+        let Query::ElementExists(selector) = query;
+        Ok(match selector.selector.as_ref().with_error(|| "ElementSelector missing selector")? {
+            Selector::ElementName(name) => {
+                let panels = self
+                    .open_panels
+                    .iter()
+                    .filter_map(|address| self.panels.get(address)?.node.as_ref());
+                self.controls().into_iter().chain(panels).any(|node| has_element(node, name))
+            }
+            Selector::DragIndicator(()) | Selector::TargetElement(_) => false,
+        })
+    }
+
     pub fn panel_count(&self) -> usize {
         self.open_panels.len()
     }
@@ -184,6 +224,12 @@ pub fn find_element_name(node: &Node, name: impl Into<String>) -> Option<&Node>
     }
 }
 
+/// Returns true if `node` or any of its children has exactly the element name
+/// `name`.
+fn has_element(node: &Node, name: &str) -> bool {
+    node.name == name || node.children.iter().any(|child| has_element(child, name))
+}
+
 /// Panics if no child element of `node` contains the substring `name` in its
 /// element name.
 pub fn assert_has_element_name(node: &Node, name: impl Into<String>) {
@@ -0,0 +1,146 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Checks that server responses are internally consistent.
+
+use std::collections::HashSet;
+
+use anyhow::Result;
+use protos::riftcaller::game_command::Command;
+use protos::riftcaller::game_object_identifier::Id;
+use protos::riftcaller::play_effect_position::EffectPosition;
+use protos::riftcaller::toggle_panel_command::ToggleCommand;
+use protos::riftcaller::{CommandList, GameObjectIdentifier, InterfacePanelAddress};
+use with_error::{fail, verify, WithError};
+
+/// Validates the referential integrity of a [CommandList].
+///
+/// Checks that every command is populated, that panel commands include their
+/// addresses, and that if the list contains a game view update, every card
+/// referenced by an animation command is a card included in that update or
+/// created via a token card command.
+pub fn validate_commands(list: &CommandList) -> Result<()> {
+    let mut known_cards = HashSet::new();
+    let mut has_game_view = false;
+    for command in commands(list)? {
+        match command {
+            Command::UpdateGameView(update) => {
+                has_game_view = true;
+                let game = update.game.as_ref().with_error(|| "UpdateGameView missing game")?;
+                for card in &game.cards {
+                    known_cards.insert(card.card_id.with_error(|| "CardView missing card_id")?);
+                }
+            }
+            Command::CreateTokenCard(create) => {
+                let card = create.card.as_ref().with_error(|| "CreateTokenCard missing card")?;
+                known_cards.insert(card.card_id.with_error(|| "CreateTokenCard missing card_id")?);
+            }
+            _ => {}
+        }
+    }
+
+    for command in commands(list)? {
+        let mut referenced = vec![];
+        match command {
+            Command::UpdatePanels(update) => {
+                for panel in &update.panels {
+                    verify!(panel.address.is_some(), "UpdatePanels contains panel without address");
+                }
+            }
+            Command::TogglePanel(toggle) => {
+                let toggle_command = toggle
+                    .toggle_command
+                    .as_ref()
+                    .with_error(|| "TogglePanel missing toggle_command")?;
+                validate_toggle(toggle_command)?;
+            }
+            Command::FireProjectile(projectile) => {
+                referenced.push(object(&projectile.source_id, "FireProjectile source_id")?);
+                referenced.push(object(&projectile.target_id, "FireProjectile target_id")?);
+            }
+            Command::PlayEffect(effect) => {
+                let position = effect
+                    .position
+                    .as_ref()
+                    .and_then(|p| p.effect_position.as_ref())
+                    .with_error(|| "PlayEffect missing position")?;
+                let EffectPosition::GameObject(id) = position;
+                referenced.push(("PlayEffect position", id));
+            }
+            Command::MoveGameObjects(move_objects) => {
+                for object_move in &move_objects.moves {
+                    verify!(object_move.position.is_some(), "GameObjectMove missing position");
+                    referenced.push(object(&object_move.id, "GameObjectMove id")?);
+                }
+            }
+            _ => {}
+        }
+
+        if has_game_view {
+            for (description, id) in referenced {
+                if let Some(Id::CardId(card_id)) = &id.id {
+                    verify!(
+                        known_cards.contains(card_id),
+                        "{description} references unknown card {card_id:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns all commands in this list, including commands nested within
+/// conditional branches.
+fn commands(list: &CommandList) -> Result<Vec<&Command>> {
+    let mut result = vec![];
+    for command in &list.commands {
+        let c = command.command.as_ref().with_error(|| "GameCommand missing command")?;
+        result.push(c);
+        if let Command::Conditional(conditional) = c {
+            for branch in [&conditional.if_true, &conditional.if_false].into_iter().flatten() {
+                result.extend(commands(branch)?);
+            }
+        }
+    }
+    Ok(result)
+}
+
+fn validate_toggle(toggle: &ToggleCommand) -> Result<()> {
+    match toggle {
+        ToggleCommand::Transition(transition) => {
+            verify!(transition.open.is_some(), "Panel transition missing 'open' address");
+        }
+        ToggleCommand::OpenBottomSheetAddress(address)
+        | ToggleCommand::PushBottomSheetAddress(address)
+        | ToggleCommand::PopToBottomSheetAddress(address) => validate_address(address)?,
+        ToggleCommand::CloseBottomSheet(()) => {}
+    }
+    Ok(())
+}
+
+fn validate_address(address: &InterfacePanelAddress) -> Result<()> {
+    if address.serialized.is_empty() {
+        fail!("Bottom sheet command has empty panel address");
+    }
+    Ok(())
+}
+
+fn object<'a>(
+    id: &'a Option<GameObjectIdentifier>,
+    description: &'static str,
+) -> Result<(&'static str, &'a GameObjectIdentifier)> {
+    Ok((description, id.as_ref().with_error(|| format!("{description} is missing"))?))
+}
@@ -293,6 +293,7 @@ impl Summarize for Command {
             Self::SetKeyboardShortcuts(_) => {}
             Self::TurnFaceDownArenaAnimation(_) => {}
             Self::ClearPersistentEffects(_) => {}
+            Self::FocusGameObject(_) => {}
         }
     }
 }
@@ -670,6 +671,8 @@ impl Summarize for CreateTokenCardCommand {
 impl Summarize for UpdateWorldMapCommand {
     fn summarize(self, summary: &mut Summary) {
         summary.children("tiles", self.tiles);
+        summary.child("min_bounds", self.min_bounds);
+        summary.child("max_bounds", self.max_bounds);
     }
 }
 
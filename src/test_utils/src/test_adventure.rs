@@ -15,10 +15,10 @@
 use std::collections::{HashMap, HashSet};
 
 use adventure_data::adventure::{
-    AdventureConfiguration, AdventureScreens, AdventureState, WorldMap,
+    AdventureConfiguration, AdventureScreens, AdventureState, EventDeck, WorldMap,
 };
 use core_data::adventure_primitives::{Coins, TilePosition};
-use core_data::game_primitives::{AdventureId, Side};
+use core_data::game_primitives::{AdventureId, School, Side};
 use game_data::card_name::{CardName, CardVariant};
 use game_data::card_set_name::CardSetName;
 use game_data::deck::Deck;
@@ -36,6 +36,8 @@ pub struct TestAdventure {
     deck: HashMap<CardVariant, u32>,
     collection: HashMap<CardVariant, u32>,
     set: CardSetName,
+    schools: Vec<School>,
+    school_limit: Option<usize>,
 }
 
 impl TestAdventure {
@@ -47,6 +49,8 @@ impl TestAdventure {
             deck: HashMap::new(),
             collection: HashMap::new(),
             set: CardSetName::Beryl,
+            schools: vec![],
+            school_limit: None,
         }
     }
 
@@ -70,6 +74,16 @@ impl TestAdventure {
         self
     }
 
+    pub fn school(mut self, school: School) -> Self {
+        self.schools.push(school);
+        self
+    }
+
+    pub fn school_limit(mut self, limit: usize) -> Self {
+        self.school_limit = Some(limit);
+        self
+    }
+
     pub fn card_set(mut self, set: CardSetName) -> Self {
         self.set = set;
         self
@@ -86,7 +100,8 @@ impl TestAdventure {
         revealed_regions.insert(1);
         let deck = Deck {
             side: self.side,
-            schools: vec![],
+            schools: self.schools,
+            school_limit: self.school_limit,
             identities: vec![],
             sigils: vec![],
             cards: self.deck,
@@ -105,6 +120,8 @@ impl TestAdventure {
             outcome: None,
             world_map: WorldMap { tiles: HashMap::new() },
             screens: AdventureScreens::default(),
+            visiting_position: self.visiting_position,
+            event_deck: EventDeck::default(),
             deck,
             config,
         }
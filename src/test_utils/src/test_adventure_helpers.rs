@@ -14,9 +14,11 @@
 
 use std::sync::atomic::{AtomicI32, Ordering};
 
-use adventure_data::adventure::TileState;
+use adventure_data::adventure::{CardChoice, TileState};
+use adventure_data::adventure_action::AdventureAction;
 use adventure_data::adventure_effect_data::AdventureEffect;
 use core_data::adventure_primitives::TilePosition;
+use user_action_data::UserAction;
 
 use crate::test_session::TestSession;
 use crate::TestSessionHelpers;
@@ -29,6 +31,12 @@ pub trait TestAdventureHelpers {
     fn insert_tile_at_position(&mut self, effect: AdventureEffect, position: TilePosition);
 
     fn visit_tile(&mut self, position: TilePosition);
+
+    /// Performs an [AdventureAction] as the user player.
+    fn perform_adventure_action(&mut self, action: AdventureAction);
+
+    /// Returns the cards offered by the shop at the given tile position.
+    fn shop_choices(&self, position: TilePosition) -> Vec<CardChoice>;
 }
 
 impl TestAdventureHelpers for TestSession {
@@ -41,12 +49,7 @@ impl TestAdventureHelpers for TestSession {
     fn insert_tile_at_position(&mut self, effect: AdventureEffect, position: TilePosition) {
         self.overwrite_adventure_tile(
             position,
-            TileState {
-                sprite: "/sprite.png".to_string(),
-                road: None,
-                on_visited: Some(effect),
-                icons: vec![],
-            },
+            TileState { on_visited: Some(effect), ..TileState::with_sprite("/sprite.png") },
         );
     }
 
@@ -55,4 +58,14 @@ impl TestAdventureHelpers for TestSession {
         let action = tile.tile.on_visit.as_ref().expect("No visit action found");
         self.perform(action.action.as_ref().expect("action").clone(), self.user_id())
     }
+
+    fn perform_adventure_action(&mut self, action: AdventureAction) {
+        self.perform_user_action(UserAction::AdventureAction(action));
+    }
+
+    fn shop_choices(&self, position: TilePosition) -> Vec<CardChoice> {
+        self.query_adventure(|state| {
+            state.world_map.tiles[&position].shop.as_ref().expect("shop").choices.clone()
+        })
+    }
 }
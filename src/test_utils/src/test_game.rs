@@ -147,6 +147,7 @@ impl TestGame {
         let covenant_deck = Deck {
             side: Side::Covenant,
             schools: vec![],
+            school_limit: None,
             identities: chapters.into_iter().map(CardVariant::standard).collect(),
             sigils: vec![],
             cards: hashmap! {CardVariant::standard(CardName::TestRitual) => self.deck_sizes},
@@ -154,6 +155,7 @@ impl TestGame {
         let riftcaller_deck = Deck {
             side: Side::Riftcaller,
             schools: vec![],
+            school_limit: None,
             identities: riftcallers.into_iter().map(CardVariant::standard).collect(),
             sigils: vec![],
             cards: hashmap! {CardVariant::standard(CardName::TestSpell) => self.deck_sizes},
@@ -214,6 +216,7 @@ impl TestRaid {
             jump_request: None,
             is_card_access_prevented: false,
             is_custom_access: false,
+            access_limit: None,
         })
     }
 }
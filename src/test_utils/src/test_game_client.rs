@@ -116,6 +116,18 @@ impl TestGameClient {
         if let Command::LoadScene(s) = command {
             self.current_scene = Some(s.scene_name.clone());
         }
+
+        if let Command::Conditional(conditional) = command {
+            let query = conditional.query.as_ref().and_then(|q| q.query.as_ref()).expect("query");
+            let branch = if self.interface.evaluate_query(query).expect("Error evaluating query") {
+                &conditional.if_true
+            } else {
+                &conditional.if_false
+            };
+            if let Some(list) = branch {
+                self.handle_command_list(list.clone());
+            }
+        }
     }
 }
 
@@ -258,6 +270,7 @@ pub struct ClientPlayer {
     actions: Option<ActionCount>,
     score: Option<PointsValue>,
     can_take_action: Option<bool>,
+    card_back: Option<String>,
 }
 
 impl ClientPlayer {
@@ -269,6 +282,7 @@ impl ClientPlayer {
             actions: None,
             score: None,
             can_take_action: None,
+            card_back: None,
         }
     }
 
@@ -292,6 +306,10 @@ impl ClientPlayer {
         self.can_take_action.expect("can_take_action")
     }
 
+    pub fn card_back(&self) -> &str {
+        self.card_back.as_deref().expect("card_back")
+    }
+
     fn update(&mut self, command: Command) {
         if let Command::UpdateGameView(update) = command {
             self.update_with_player(if self.name == PlayerName::User {
@@ -309,6 +327,9 @@ impl ClientPlayer {
             self.actions = Some(p.action_tracker.clone().expect("actions").available_action_count);
             self.score = Some(p.score.clone().expect("score").score);
             self.can_take_action = Some(p.can_take_action);
+            if let Some(card_back) = p.deck_view.and_then(|d| d.card_back) {
+                self.card_back = Some(card_back.address);
+            }
         }
     }
 }
@@ -570,6 +591,7 @@ impl ClientCards {
 pub struct ClientCard {
     id: Option<CardIdentifier>,
     title: Option<String>,
+    image: Option<String>,
     rules_text: Option<String>,
     position: Option<ObjectPosition>,
     revealed_to_me: Option<bool>,
@@ -605,6 +627,12 @@ impl ClientCard {
         self.title.clone()
     }
 
+    /// Returns the address of the image for this card. Panics if no image is
+    /// available.
+    pub fn image(&self) -> String {
+        self.image.clone().expect("No card image found")
+    }
+
     pub fn rules_text(&self) -> String {
         self.rules_text.clone().expect("No rules text found")
     }
@@ -711,6 +739,10 @@ impl ClientCard {
             self.title = Some(title);
         }
 
+        if let Some(image) = &revealed.image {
+            self.image = Some(image.address.clone());
+        }
+
         self.rules_text = revealed.rules_text.as_ref().map(|r| r.text.clone())
     }
 }
@@ -52,6 +52,8 @@ pub enum Button {
     StartBattle,
     ReturnToDeck,
     ReturnToHand,
+    Banish,
+    Copy,
     SelectForMultipart,
     SwapCard,
     ChooseOnPlay,
@@ -71,6 +73,8 @@ pub enum Button {
     AccessVault,
     AccessSanctum,
     DefeatMinion,
+    DrawCard,
+    GainMana,
 }
 
 pub trait TestInterfaceHelpers {
@@ -285,6 +289,8 @@ fn resolve_button(button: Button) -> String {
         Button::StartBattle => "Start",
         Button::ReturnToDeck => "Return",
         Button::ReturnToHand => "Return",
+        Button::Banish => "Banish",
+        Button::Copy => "Copy",
         Button::SelectForMultipart => "Select",
         Button::SwapCard => "Swap",
         Button::ChooseOnPlay => "Choose",
@@ -304,6 +310,8 @@ fn resolve_button(button: Button) -> String {
         Button::AccessVault => "Access Vault",
         Button::AccessSanctum => "Access Sanctum",
         Button::DefeatMinion => "Defeat",
+        Button::DrawCard => "Draw",
+        Button::GainMana => "Gain",
     }
     .to_string()
 }
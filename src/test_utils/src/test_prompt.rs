@@ -0,0 +1,46 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_data::game_primitives::Side;
+use game_data::game_effect::GameEffect;
+use game_data::game_state::GameState;
+use game_data::prompt_data::GamePrompt;
+use prompt_ui::effect_prompts;
+use rules::prompts;
+
+/// Structured representation of one option in a button prompt, used to make
+/// assertions about the choices presented to a player.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestPromptChoice {
+    /// Button label displayed for this choice
+    pub label: String,
+    /// Effects of selecting this choice
+    pub effects: Vec<GameEffect>,
+}
+
+/// Returns the choices of the button prompt currently being shown to the
+/// `side` player, or an empty vector if no button prompt is active.
+pub fn choices(game: &GameState, side: Side) -> Vec<TestPromptChoice> {
+    let Some(GamePrompt::ButtonPrompt(prompt)) = prompts::current(game, side) else {
+        return vec![];
+    };
+    prompt
+        .choices
+        .iter()
+        .map(|choice| TestPromptChoice {
+            label: effect_prompts::label(side, choice),
+            effects: choice.effects.clone(),
+        })
+        .collect()
+}
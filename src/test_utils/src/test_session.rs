@@ -16,16 +16,16 @@
 //! [TestSession].
 
 use actions::legal_actions;
-use adventure_data::adventure::TileState;
+use adventure_data::adventure::{AdventureState, TileState};
 use anyhow::Result;
 use core_data::adventure_primitives::{Coins, TilePosition};
 use core_data::game_primitives::{GameId, Side};
 use game_data::card_name::{CardName, CardVariant};
 use game_data::card_state::CardPosition;
 use game_data::game_actions::GameAction;
-#[allow(unused_imports)] // Used in docs
 use game_data::game_state::GameState;
 use game_data::player_name::PlayerId;
+use player_data::PlayerState;
 use protos::riftcaller::client_action::Action;
 use protos::riftcaller::{CardIdentifier, ClientAction, ClientMetadata, CommandList, GameRequest};
 use rules::mutations;
@@ -36,7 +36,7 @@ use {adapters, tokio};
 
 use crate::fake_database::FakeDatabase;
 use crate::test_game_client::{self, TestGameClient};
-use crate::{fake_database, TestSessionHelpers};
+use crate::{command_validator, fake_database, TestSessionHelpers};
 
 /// A helper for interacting with a database and server calls during testing.
 ///
@@ -95,6 +95,41 @@ impl TestSession {
     #[tokio::main]
     pub async fn connect(&mut self, user_id: PlayerId) -> Result<CommandList> {
         let result = server::handle_connect(&self.database, user_id).await?.build();
+        self.handle_connect_response(user_id, result)
+    }
+
+    /// Simulates a client connecting to the server via the native plugin.
+    ///
+    /// Asynchronous updates for this client, such as AI moves, are queued on
+    /// the server and must be retrieved by calling [Self::poll].
+    #[tokio::main]
+    pub async fn connect_for_polling(&mut self, user_id: PlayerId) -> Result<CommandList> {
+        let result = server::plugin_connect(&self.database, user_id).await?.build();
+        self.handle_connect_response(user_id, result)
+    }
+
+    /// Requests queued updates for the user client, which must previously
+    /// have connected via [Self::connect_for_polling]. Applies the updates to
+    /// the client and returns them, or returns None if no updates are
+    /// available.
+    pub fn poll(&mut self) -> Result<Option<CommandList>> {
+        let Some(list) = server::plugin_poll(self.client.id)? else {
+            return Ok(None);
+        };
+
+        for command in &list.commands {
+            let c = command.command.as_ref().with_error(|| "command")?;
+            self.client.handle_command(c);
+        }
+
+        Ok(Some(list))
+    }
+
+    fn handle_connect_response(
+        &mut self,
+        user_id: PlayerId,
+        result: GameResponseOutput,
+    ) -> Result<CommandList> {
         let to_update = match () {
             _ if user_id == self.client.id => &mut self.client,
             _ if user_id == self.opponent.id => &mut self.opponent,
@@ -157,6 +192,11 @@ impl TestSession {
             self.metadata = m;
         }
 
+        command_validator::validate_commands(&response.user_response)?;
+        if let Some((_, list)) = &response.opponent_response {
+            command_validator::validate_commands(list)?;
+        }
+
         let (opponent_id, local, remote) = self.opponent_local_remote(player_id);
 
         for command in &response.user_response.commands {
@@ -258,6 +298,15 @@ impl TestSession {
             .collect()
     }
 
+    /// Invokes `query` with the current server-side [GameState].
+    ///
+    /// Intended for testing rules queries which have no direct
+    /// client-facing representation. Prefer asserting on client state where
+    /// possible.
+    pub fn query_game<T>(&self, query: impl FnOnce(&GameState) -> T) -> T {
+        query(self.database.game.lock().unwrap().as_ref().expect("game"))
+    }
+
     #[tokio::main]
     pub async fn run_agent_loop(&mut self) {
         let (game_id, user_id) = (self.game_id(), self.user_id());
@@ -266,6 +315,25 @@ impl TestSession {
             .expect("Error running agent loop");
     }
 
+    /// Runs a query against the current player's adventure state. Panics if
+    /// there is no active adventure.
+    pub fn query_adventure<T>(&self, query: impl FnOnce(&AdventureState) -> T) -> T {
+        let db = self.database.players.lock().unwrap();
+        query(db[&self.user_id()].adventure.as_ref().expect("No active adventure"))
+    }
+
+    /// Runs a query against the current player's stored [PlayerState].
+    pub fn query_player<T>(&self, query: impl FnOnce(&PlayerState) -> T) -> T {
+        let db = self.database.players.lock().unwrap();
+        query(&db[&self.user_id()])
+    }
+
+    /// Runs a query against the opponent player's stored [PlayerState].
+    pub fn query_opponent_player<T>(&self, query: impl FnOnce(&PlayerState) -> T) -> T {
+        let db = self.database.players.lock().unwrap();
+        query(&db[&self.opponent.id])
+    }
+
     /// Returns the number of Coins the current player has in their active
     /// adventure
     pub fn current_coins(&self) -> Coins {
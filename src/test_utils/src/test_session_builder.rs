@@ -12,13 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Mutex;
 
-use core_data::game_primitives::GameId;
+use core_data::game_primitives::{GameId, Sprite};
+use game_data::card_name::CardName;
+use game_data::deck::Deck;
 use game_data::player_name::PlayerId;
 use game_data::tutorial_data::TutorialData;
 use maplit::hashmap;
-use player_data::{PlayerState, PlayerStatus};
+use player_data::{PlayerState, PlayerStatus, SavedDeck};
 
 use crate::fake_database::FakeDatabase;
 use crate::test_adventure::TestAdventure;
@@ -33,12 +36,25 @@ pub struct TestSessionBuilder {
     game_id: GameId,
     user_id: PlayerId,
     opponent_id: PlayerId,
+    card_art_overrides: HashMap<CardName, Sprite>,
+    card_back: Option<Sprite>,
+    saved_decks: Vec<SavedDeck>,
 }
 
 impl TestSessionBuilder {
     pub fn new() -> Self {
         let (game_id, user_id, opponent_id) = test_helpers::generate_ids();
-        Self { game: None, adventure: None, do_not_connect: false, game_id, user_id, opponent_id }
+        Self {
+            game: None,
+            adventure: None,
+            do_not_connect: false,
+            game_id,
+            user_id,
+            opponent_id,
+            card_art_overrides: HashMap::default(),
+            card_back: None,
+            saved_decks: vec![],
+        }
     }
 
     pub fn game(mut self, game: TestGame) -> Self {
@@ -71,6 +87,25 @@ impl TestSessionBuilder {
         self
     }
 
+    /// Selects alternate art for a card in the user's player data.
+    pub fn card_art_override(mut self, card_name: CardName, sprite: Sprite) -> Self {
+        self.card_art_overrides.insert(card_name, sprite);
+        self
+    }
+
+    /// Selects a card back in the user's player data.
+    pub fn card_back(mut self, sprite: Sprite) -> Self {
+        self.card_back = Some(sprite);
+        self
+    }
+
+    /// Adds a saved deck with the given name to the user's player data.
+    pub fn saved_deck(mut self, name: impl Into<String>, deck: Deck) -> Self {
+        let id = self.saved_decks.len() as u64;
+        self.saved_decks.push(SavedDeck { id, name: name.into(), deck });
+        self
+    }
+
     pub fn build(self) -> TestSession {
         cards_all::initialize();
 
@@ -90,13 +125,25 @@ impl TestSessionBuilder {
                         id: self.user_id,
                         status: Some(PlayerStatus::Playing(self.game_id, user_side)),
                         adventure,
-                        tutorial: TutorialData::new().skip_all(true)
+                        current_adventure_slot: 0,
+                        adventure_slots: vec![],
+                        tutorial: TutorialData::new().skip_all(true),
+                        card_art_overrides: self.card_art_overrides.clone(),
+                        card_back: self.card_back.clone(),
+                        match_history: vec![],
+                        saved_decks: self.saved_decks.clone(),
                     },
                     self.opponent_id => PlayerState {
                         id: self.opponent_id,
                         status: Some(PlayerStatus::Playing(self.game_id, user_side.opponent())),
                         adventure: None,
-                        tutorial: TutorialData::default()
+                        current_adventure_slot: 0,
+                        adventure_slots: vec![],
+                        tutorial: TutorialData::default(),
+                        card_art_overrides: HashMap::default(),
+                        card_back: None,
+                        match_history: vec![],
+                        saved_decks: vec![],
                     }
                 }),
             };
@@ -111,13 +158,25 @@ impl TestSessionBuilder {
                         id: self.user_id,
                         status: None,
                         adventure,
-                        tutorial: TutorialData::new().skip_all(true)
+                        current_adventure_slot: 0,
+                        adventure_slots: vec![],
+                        tutorial: TutorialData::new().skip_all(true),
+                        card_art_overrides: self.card_art_overrides.clone(),
+                        card_back: self.card_back.clone(),
+                        match_history: vec![],
+                        saved_decks: self.saved_decks.clone(),
                     },
                     self.opponent_id => PlayerState {
                         id: self.opponent_id,
                         status: None,
                         adventure: None,
-                        tutorial: TutorialData::default()
+                        current_adventure_slot: 0,
+                        adventure_slots: vec![],
+                        tutorial: TutorialData::default(),
+                        card_art_overrides: HashMap::default(),
+                        card_back: None,
+                        match_history: vec![],
+                        saved_decks: vec![],
                     }
                 }),
             };
@@ -16,6 +16,7 @@ use adapters::CustomCardIdentifier;
 use anyhow::Result;
 use card_definition_data::cards;
 use core_data::game_primitives::{CardType, Resonance, RoomId, Side};
+use core_ui::actions::InterfaceAction;
 use game_data::card_name::{CardName, CardVariant};
 use game_data::player_name::PlayerId;
 use protos::riftcaller::client_action::Action;
@@ -24,10 +25,12 @@ use protos::riftcaller::{
     InitiateRaidAction, MoveCardAction, PlayCardAction, ProgressRoomAction, SpendActionPointAction,
 };
 use server::server_data::GameResponseOutput;
+use user_action_data::UserAction;
 
 use crate::test_game_client::{ClientPlayer, TestGameClient};
+use crate::test_prompt::TestPromptChoice;
 use crate::test_session::TestSession;
-use crate::{test_game_client, Button, CardNamesExt, TestInterfaceHelpers};
+use crate::{test_game_client, test_prompt, Button, CardNamesExt, TestInterfaceHelpers};
 
 pub trait TestSessionHelpers {
     fn user_id(&self) -> PlayerId;
@@ -52,6 +55,9 @@ pub trait TestSessionHelpers {
     /// return the action result.
     fn perform(&mut self, action: Action, user_id: PlayerId);
 
+    /// Performs a [UserAction] as the user player, panicking on error.
+    fn perform_user_action(&mut self, action: UserAction);
+
     /// Helper to perform the standard draw card action
     fn draw_card(&mut self);
 
@@ -200,6 +206,15 @@ pub trait TestSessionHelpers {
     /// next player's turn (clicking the "Start Turn" button if it appears).
     fn pass_turn(&mut self, side: Side);
 
+    /// Advances the game by `n` full turns, invoking [Self::pass_turn] for the
+    /// current player and then for their opponent `n` times. Dawn and dusk
+    /// triggers fire as normal.
+    fn pass_turns(&mut self, n: u32);
+
+    /// Returns the choices of the button prompt currently shown to the `side`
+    /// player.
+    fn prompt_choices(&self, side: Side) -> Vec<TestPromptChoice>;
+
     /// Returns true if the last-received Game Message was 'Dawn'.
     fn dawn(&self) -> bool;
 
@@ -282,6 +297,10 @@ impl TestSessionHelpers for TestSession {
         self.perform_action(action, user_id).expect("Request failed");
     }
 
+    fn perform_user_action(&mut self, action: UserAction) {
+        self.perform(action.as_client_action(), self.user_id());
+    }
+
     fn draw_card(&mut self) {
         self.draw_card_with_result().expect("Error performing draw card action");
     }
@@ -550,6 +569,18 @@ impl TestSessionHelpers for TestSession {
         }
     }
 
+    fn pass_turns(&mut self, n: u32) {
+        for _ in 0..n {
+            let side = self.query_game(|game| game.info.turn.side);
+            self.pass_turn(side);
+            self.pass_turn(side.opponent());
+        }
+    }
+
+    fn prompt_choices(&self, side: Side) -> Vec<TestPromptChoice> {
+        self.query_game(|game| test_prompt::choices(game, side))
+    }
+
     fn dawn(&self) -> bool {
         assert_eq!(self.client.data.last_message(), self.opponent.data.last_message());
         self.client.data.last_message() == GameMessageType::Dawn
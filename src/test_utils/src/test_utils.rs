@@ -22,6 +22,7 @@ pub use test_session_helpers::TestSessionHelpers;
 
 pub mod client_interface;
 pub mod client_positions;
+pub mod command_validator;
 pub mod fake_database;
 pub mod summarize;
 pub mod test_adventure;
@@ -30,6 +31,7 @@ pub mod test_game;
 pub mod test_game_client;
 pub mod test_helpers;
 pub mod test_interface_helpers;
+pub mod test_prompt;
 pub mod test_session;
 pub mod test_session_builder;
 pub mod test_session_helpers;
@@ -21,11 +21,16 @@ use protos::riftcaller::WorldMapTile;
 #[derive(Default, Clone)]
 pub struct TestWorldMap {
     tiles: HashMap<(i32, i32), TestMapTile>,
+    bounds: Option<(TilePosition, TilePosition)>,
 }
 
 impl TestWorldMap {
     pub fn update(&mut self, command: Command) {
         if let Command::UpdateWorldMap(map) = command {
+            if let (Some(min), Some(max)) = (&map.min_bounds, &map.max_bounds) {
+                self.bounds =
+                    Some((TilePosition::new(min.x, min.y), TilePosition::new(max.x, max.y)));
+            }
             for tile in map.tiles {
                 let clone = tile.clone();
                 let position = tile.position.expect("tile position").clone();
@@ -38,6 +43,11 @@ impl TestWorldMap {
         self.tiles.get(&(position.x, position.y)).expect("Tile not found")
     }
 
+    /// Minimum and maximum tile positions from the most recent map update.
+    pub fn bounds(&self) -> (TilePosition, TilePosition) {
+        self.bounds.expect("Map bounds not found")
+    }
+
     pub fn tile_count(&self) -> usize {
         self.tiles.len()
     }
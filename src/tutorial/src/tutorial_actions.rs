@@ -20,8 +20,8 @@ use game_data::card_state::CardPosition;
 use game_data::game_actions::{GameAction, GameStateAction, RaidAction};
 use game_data::game_state::{GameState, MulliganDecision};
 use game_data::tutorial_data::{
-    TutorialDisplay, TutorialGameStateTrigger, TutorialOpponentAction, TutorialStep,
-    TutorialTrigger,
+    TutorialDisplay, TutorialGameStateTrigger, TutorialOpponentAction, TutorialSequence,
+    TutorialStep, TutorialTrigger,
 };
 use raid_display::raid_prompt;
 use rules::mutations;
@@ -54,13 +54,27 @@ pub fn handle_game_action(game: &mut GameState, action: Option<&GameAction>) ->
 /// pre-determined.
 pub fn handle_sequence_game_action(
     game: &mut GameState,
+    user_action: Option<&GameAction>,
+) -> Result<()> {
+    run_sequence(game, &crate::SEQUENCE, user_action)
+}
+
+/// Advances through the steps of the provided [TutorialSequence], starting
+/// from the current tutorial step index.
+///
+/// Steps are applied in order until one is reached whose trigger condition is
+/// not yet satisfied by `user_action` or by the current game state. The index
+/// of that step is stored so that later calls resume from it.
+pub fn run_sequence(
+    game: &mut GameState,
+    sequence: &TutorialSequence,
     mut user_action: Option<&GameAction>,
 ) -> Result<()> {
     let _span = debug_span!("handle_sequence_game_action").entered();
     let mut i = game.info.tutorial_state.index;
 
-    while i < crate::SEQUENCE.steps.len() {
-        let action = &crate::SEQUENCE.steps[i];
+    while i < sequence.steps.len() {
+        let action = &sequence.steps[i];
         let _span = debug_span!("handle_tutorial_action", ?action).entered();
         debug!(?action, "Handling tutorial action");
 
@@ -108,7 +122,7 @@ pub fn handle_sequence_game_action(
     }
 
     game.info.tutorial_state.index = i;
-    if i < crate::SEQUENCE.steps.len() {
+    if i < sequence.steps.len() {
         debug!("Tutorial at step {}", i);
     } else {
         info!("Pre-scripted tutorial sequence completed");
@@ -180,7 +180,12 @@ fn create_canonical_game() -> Result<GameState> {
         decklists::CANONICAL_COVENANT.clone(),
         PlayerId::AI(AIPlayer::NoAction),
         decklists::CANONICAL_RIFTCALLER.clone(),
-        GameConfiguration { deterministic: true, simulation: true, scripted_tutorial: false },
+        GameConfiguration {
+            deterministic: true,
+            simulation: true,
+            scripted_tutorial: false,
+            ..GameConfiguration::default()
+        },
     );
 
     dispatch::populate_delegate_map(&mut game);
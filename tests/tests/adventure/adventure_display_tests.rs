@@ -0,0 +1,86 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use adventure_data::adventure_effect_data::AdventureEffect;
+use core_data::adventure_primitives::TilePosition;
+use core_data::game_primitives::Side;
+use protos::riftcaller::game_command::Command;
+use protos::riftcaller::toggle_panel_command::ToggleCommand;
+use test_utils::test_adventure::TestAdventure;
+use test_utils::*;
+
+#[test]
+fn open_screen_is_conditional() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller).build();
+    let battle = adventure.insert_tile(AdventureEffect::Battle);
+    let tile = adventure.client.map.tile(battle);
+    let action = tile.tile.on_visit.as_ref().expect("visit").action.clone().expect("action");
+    let response =
+        adventure.perform_action(action, adventure.user_id()).expect("Error visiting tile");
+
+    let conditional = response
+        .user_response
+        .commands
+        .iter()
+        .find_map(|c| match c.command.as_ref() {
+            Some(Command::Conditional(conditional)) => Some(conditional),
+            _ => None,
+        })
+        .expect("Expected conditional command");
+    assert!(conditional.if_true.as_ref().expect("if_true").commands.is_empty());
+    let if_false = &conditional.if_false.as_ref().expect("if_false").commands;
+    assert!(matches!(
+        if_false[0].command.as_ref(),
+        Some(Command::TogglePanel(toggle))
+            if matches!(&toggle.toggle_command, Some(ToggleCommand::Transition(t)) if t.open.is_some())
+    ));
+    assert_eq!(adventure.client.interface.panel_count(), 1);
+}
+
+#[test]
+fn minimap_contains_revealed_tiles() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller).build();
+    let battle = adventure.insert_tile(AdventureEffect::Battle);
+    let minimap = adventure.query_adventure(adventure_display::minimap);
+
+    assert_eq!(minimap.len(), adventure.query_adventure(|a| a.world_map.tiles.len()));
+    for tile in &minimap {
+        assert!(adventure.query_adventure(|a| a.world_map.tiles.contains_key(&tile.position)));
+    }
+    let entities = minimap.iter().filter(|t| t.has_entity).map(|t| t.position).collect::<Vec<_>>();
+    assert!(entities.contains(&battle));
+    assert_eq!(
+        entities.len(),
+        adventure.query_adventure(|a| {
+            a.world_map.tiles.values().filter(|t| t.on_visited.is_some()).count()
+        })
+    );
+}
+
+#[test]
+fn bounds_grow_when_tiles_revealed() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller).build();
+    adventure.insert_tile_at_position(AdventureEffect::Battle, TilePosition::new(0, 0));
+    let (min, max) = adventure.query_adventure(|a| a.bounds());
+    assert_eq!(adventure.client.map.bounds(), (min, max));
+
+    let position = TilePosition::new(max.x + 3, min.y - 2);
+    adventure.insert_tile_at_position(AdventureEffect::Battle, position);
+    let (new_min, new_max) = adventure.query_adventure(|a| a.bounds());
+    assert_eq!(new_max.x, position.x);
+    assert_eq!(new_min.y, position.y);
+    assert_eq!(new_min.x, min.x);
+    assert_eq!(new_max.y, max.y);
+    assert_eq!(adventure.client.map.bounds(), (new_min, new_max));
+}
@@ -0,0 +1,79 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use adventure_data::adventure_effect_data::AdventureEffect;
+use adventure_generator::STARTING_COINS;
+use core_data::adventure_primitives::Coins;
+use core_data::game_primitives::Side;
+use panel_address::PlayerPanel;
+use server::scenes::SceneName;
+use test_utils::client_interface::HasText;
+use test_utils::test_adventure::TestAdventure;
+use test_utils::*;
+use user_action_data::UserAction;
+
+#[test]
+fn switch_between_adventure_slots() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller).coins(Coins(100)).build();
+    let hazard = adventure.insert_tile(AdventureEffect::Hazard(Coins(25)));
+    adventure.visit_tile(hazard);
+    assert_eq!(Coins(75), adventure.current_coins());
+
+    adventure.perform_user_action(UserAction::NewAdventureSlot(Side::Covenant));
+    assert_eq!(1, adventure.query_player(|player| player.current_adventure_slot));
+    assert_eq!(STARTING_COINS, adventure.current_coins());
+    assert_eq!(Side::Covenant, adventure.query_adventure(|state| state.side));
+    let generated_tiles = adventure.query_adventure(|state| state.world_map.tiles.len());
+
+    adventure.perform_user_action(UserAction::SelectAdventureSlot(0));
+    adventure.connect(adventure.user_id()).expect("Connection failed");
+    assert_eq!(Coins(75), adventure.current_coins());
+    assert_eq!(Side::Riftcaller, adventure.query_adventure(|state| state.side));
+    assert_eq!(1, adventure.query_adventure(|state| state.world_map.tiles.len()));
+
+    adventure.perform_user_action(UserAction::SelectAdventureSlot(1));
+    adventure.connect(adventure.user_id()).expect("Connection failed");
+    assert_eq!(STARTING_COINS, adventure.current_coins());
+    assert_eq!(generated_tiles, adventure.query_adventure(|state| state.world_map.tiles.len()));
+
+    let panel = adventure.client.interface.panel(PlayerPanel::AdventureSlots).expect("Slots");
+    assert!(panel.has_text("75 coins"));
+    assert!(panel.has_text(format!("{} coins", STARTING_COINS.0)));
+}
+
+#[test]
+fn delete_adventure_slot() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller).build();
+    adventure.perform_user_action(UserAction::NewAdventureSlot(Side::Covenant));
+    adventure.perform_user_action(UserAction::DeleteAdventureSlot(0));
+    assert_eq!(
+        vec![1],
+        adventure.query_player(|player| player
+            .all_adventures()
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect::<Vec<_>>())
+    );
+    assert_eq!(Side::Covenant, adventure.query_adventure(|state| state.side));
+}
+
+#[test]
+fn delete_current_adventure_slot_loads_main_scene() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller).build();
+    adventure.connect(adventure.user_id()).expect("Connection failed");
+    assert_eq!(SceneName::World.to_string(), adventure.client.current_scene());
+    adventure.perform_user_action(UserAction::DeleteAdventureSlot(0));
+    assert!(adventure.query_player(|player| player.adventure.is_none()));
+    assert_eq!(SceneName::Main.to_string(), adventure.client.current_scene());
+}
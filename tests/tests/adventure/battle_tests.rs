@@ -14,8 +14,11 @@
 
 use adventure_data::adventure_effect_data::AdventureEffect;
 use core_data::game_primitives::Side;
+use core_ui::actions::InterfaceAction;
+use game_data::card_name::{CardName, CardVariant};
 use test_utils::test_adventure::TestAdventure;
 use test_utils::*;
+use user_action_data::{GameOutcome, UserAction};
 
 #[test]
 fn test_open_battle_screen() {
@@ -36,3 +39,57 @@ fn test_start_battle() {
     adventure.click(Button::StartBattle);
     assert_eq!("Game", adventure.client.current_scene());
 }
+
+#[test]
+fn test_win_boss_battle() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller).build();
+
+    let boss = adventure.insert_tile(AdventureEffect::BossBattle);
+    adventure.visit_tile(boss);
+    adventure.click(Button::StartBattle);
+    adventure.perform(
+        UserAction::LeaveGame(GameOutcome::Victory).as_client_action(),
+        adventure.user_id(),
+    );
+    assert_eq!("World", adventure.client.current_scene());
+    adventure.connect(adventure.user_id()).expect("Connection failed");
+    assert!(adventure.has_text("Victory"));
+}
+
+#[test]
+fn test_win_regular_battle() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller).build();
+
+    let battle = adventure.insert_tile(AdventureEffect::Battle);
+    adventure.visit_tile(battle);
+    adventure.click(Button::StartBattle);
+    adventure.perform(
+        UserAction::LeaveGame(GameOutcome::Victory).as_client_action(),
+        adventure.user_id(),
+    );
+    adventure.connect(adventure.user_id()).expect("Connection failed");
+    assert!(!adventure.has_text("Victory"));
+}
+
+#[test]
+fn test_win_mini_boss_battle() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller).build();
+
+    let mini_boss = adventure.insert_tile(AdventureEffect::MiniBoss(CardName::TestRitual));
+    adventure.visit_tile(mini_boss);
+    assert!(adventure.has_text("Guaranteed Card"));
+    let deck_size = adventure.query_adventure(|state| state.deck.cards.values().sum::<u32>());
+    adventure.click(Button::StartBattle);
+    adventure.perform(
+        UserAction::LeaveGame(GameOutcome::Victory).as_client_action(),
+        adventure.user_id(),
+    );
+
+    adventure.query_adventure(|state| {
+        assert_eq!(deck_size + 1, state.deck.cards.values().sum::<u32>());
+        assert_eq!(Some(&1), state.deck.cards.get(&CardVariant::standard(CardName::TestRitual)));
+        let tile = state.world_map.tile(mini_boss).expect("Tile");
+        assert!(tile.on_visited.is_none());
+    });
+    assert!(adventure.client.map.tile(mini_boss).tile.on_visit.is_none());
+}
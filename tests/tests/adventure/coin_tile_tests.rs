@@ -0,0 +1,59 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use adventure_data::adventure_effect_data::AdventureEffect;
+use core_data::adventure_primitives::Coins;
+use core_data::game_primitives::Side;
+use protos::riftcaller::element_selector::Selector;
+use protos::riftcaller::game_command::Command;
+use test_utils::client_interface::HasText;
+use test_utils::test_adventure::TestAdventure;
+use test_utils::*;
+
+#[test]
+fn collect_coins() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller).coins(Coins(100)).build();
+    let tile = adventure.insert_tile(AdventureEffect::GainCoins(Coins(50)));
+    adventure.visit_tile(tile);
+    assert_eq!(Coins(150), adventure.query_adventure(|state| state.coins));
+    assert!(adventure.query_adventure(|state| {
+        state.world_map.tiles[&tile].on_visited.is_none() && state.screens.is_empty()
+    }));
+    assert!(adventure.client.map.tile(tile).tile.on_visit.is_none());
+}
+
+#[test]
+fn collect_coins_animates_coin_count() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller).coins(Coins(100)).build();
+    let tile = adventure.insert_tile(AdventureEffect::GainCoins(Coins(50)));
+    let visit = adventure.client.map.tile(tile).tile.on_visit.as_ref().expect("visit");
+    let action = visit.action.clone().expect("action");
+    let response =
+        adventure.perform_action(action, adventure.user_id()).expect("Error visiting tile");
+
+    let update = response
+        .user_response
+        .commands
+        .iter()
+        .find_map(|c| match c.command.as_ref() {
+            Some(Command::UpdateInterface(update)) => Some(update),
+            _ => None,
+        })
+        .expect("Expected coin count update");
+    assert!(update.steps.iter().all(|step| matches!(
+        step.element.as_ref().and_then(|e| e.selector.as_ref()),
+        Some(Selector::ElementName(name)) if name == "CoinCount0"
+    )));
+    assert!(adventure.client.interface.screen_overlay().has_text("150"));
+}
@@ -0,0 +1,91 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use adventure_data::adventure::CardChoice;
+use adventure_data::adventure_action::AdventureAction;
+use adventure_data::adventure_effect_data::AdventureEffect;
+use card_definition_data::cards;
+use core_data::adventure_primitives::CardFilterId;
+use core_data::game_primitives::{School, Side};
+use core_ui::actions::InterfaceAction;
+use test_utils::test_adventure::TestAdventure;
+use test_utils::test_session::TestSession;
+use test_utils::*;
+use user_action_data::UserAction;
+
+const FILTER: CardFilterId = CardFilterId { value: 2 };
+
+#[test]
+fn cannot_add_card_outside_restricted_schools() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller).school_limit(1).build();
+    let choices = visit_shop_with_two_schools(&mut adventure);
+    let (first, first_school) = find_school(&choices, |_| true);
+    adventure.perform_adventure_action(AdventureAction::BuyCard(first));
+    let (other, _) = find_school(&choices, |school| school != first_school);
+
+    let result = buy_card(&mut adventure, other);
+    assert!(result.is_err());
+    assert!(adventure.query_adventure(|state| !state.deck.cards.contains_key(&choices[other].card)));
+    assert_eq!(vec![first_school], adventure.query_adventure(|state| state.deck.schools.clone()));
+}
+
+#[test]
+fn deck_editor_shows_selected_schools() {
+    let mut adventure =
+        TestAdventure::new(Side::Riftcaller).school(School::Law).school_limit(2).build();
+    adventure.click(Button::ShowDeck);
+    assert!(adventure.has_text("Schools: Law (1/2)"));
+}
+
+/// Visits new shop tiles until one offers cards from at least two non-neutral
+/// schools, returning that shop's choices.
+fn visit_shop_with_two_schools(adventure: &mut TestSession) -> Vec<CardChoice> {
+    loop {
+        let shop = adventure.insert_tile(AdventureEffect::Shop(FILTER));
+        adventure.visit_tile(shop);
+        let choices = adventure.shop_choices(shop);
+        let schools = choices
+            .iter()
+            .map(|choice| cards::get(choice.card).school)
+            .filter(|school| *school != School::Neutral)
+            .collect::<HashSet<_>>();
+        if schools.len() >= 2 {
+            return choices;
+        }
+        adventure.perform_adventure_action(AdventureAction::EndVisit);
+    }
+}
+
+/// Returns the index and school of the first non-neutral shop choice whose
+/// school matches `predicate`.
+fn find_school(choices: &[CardChoice], predicate: impl Fn(School) -> bool) -> (usize, School) {
+    choices
+        .iter()
+        .enumerate()
+        .map(|(i, choice)| (i, cards::get(choice.card).school))
+        .find(|(_, school)| *school != School::Neutral && predicate(*school))
+        .expect("No matching shop choice")
+}
+
+fn buy_card(adventure: &mut TestSession, index: usize) -> anyhow::Result<()> {
+    let user_id = adventure.user_id();
+    adventure
+        .perform_action(
+            UserAction::AdventureAction(AdventureAction::BuyCard(index)).as_client_action(),
+            user_id,
+        )
+        .map(|_| ())
+}
@@ -0,0 +1,29 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_data::game_primitives::Side;
+use core_ui::actions::InterfaceAction;
+use server::scenes::SceneName;
+use test_utils::test_adventure::TestAdventure;
+use test_utils::*;
+use user_action_data::UserAction;
+
+#[test]
+fn leave_adventure_loads_main_scene() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller).build();
+    adventure.connect(adventure.user_id()).expect("Connection failed");
+    assert_eq!(SceneName::World.to_string(), adventure.client.current_scene());
+    adventure.perform(UserAction::LeaveAdventure.as_client_action(), adventure.user_id());
+    assert_eq!(SceneName::Main.to_string(), adventure.client.current_scene());
+}
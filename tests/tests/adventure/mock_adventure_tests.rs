@@ -0,0 +1,52 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use adventure_data::adventure::{AdventureConfiguration, AdventureState, TileIcon};
+use adventure_data::adventure_effect_data::AdventureEffect;
+use adventure_generator::mock_adventure;
+use core_data::game_primitives::Side;
+use game_data::player_name::PlayerId;
+
+#[test]
+fn tiles_show_effect_icons() {
+    let state =
+        mock_adventure::create(AdventureConfiguration::new(PlayerId::generate(), Side::Riftcaller));
+    assert_tiles_show_icon(
+        &state,
+        |effect| matches!(effect, AdventureEffect::Hazard(_)),
+        |icon| matches!(icon, TileIcon::Hazard),
+    );
+    assert_tiles_show_icon(
+        &state,
+        |effect| matches!(effect, AdventureEffect::GainCoins(_)),
+        |icon| matches!(icon, TileIcon::Coins),
+    );
+}
+
+/// Asserts that the map contains at least one tile with an effect matching
+/// `has_effect` and that all such tiles show a single icon matching
+/// `has_icon`.
+fn assert_tiles_show_icon(
+    state: &AdventureState,
+    has_effect: impl Fn(&AdventureEffect) -> bool,
+    has_icon: impl Fn(&TileIcon) -> bool,
+) {
+    let tiles = state
+        .world_map
+        .tiles
+        .values()
+        .filter(|tile| tile.on_visited.as_ref().is_some_and(&has_effect))
+        .collect::<Vec<_>>();
+    assert!(!tiles.is_empty());
+    assert!(tiles.iter().all(|tile| matches!(tile.icons.as_slice(), [icon] if has_icon(icon))));
+}
@@ -12,7 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod adventure_display_tests;
+mod adventure_slot_tests;
+mod battle_tests;
+mod coin_tile_tests;
+mod deck_school_tests;
+mod hazard_tests;
+mod leave_adventure_tests;
+mod mock_adventure_tests;
+mod narrative_event_tests;
+mod shop_restock_tests;
+mod tile_cooldown_tests;
+mod upgrade_card_tests;
+
 // Temporarily disabled
-// mod battle_tests;
 // mod draft_tests;
 // mod shop_tests;
@@ -0,0 +1,136 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use adventure_data::adventure::AdventureScreen;
+use adventure_data::adventure_action::{AdventureAction, NarrativeEffectIndex};
+use adventure_data::adventure_effect_data::AdventureEffect;
+use adventure_data::narrative_event_data::NarrativeEventStep;
+use core_data::adventure_primitives::{Coins, NarrativeChoiceId, NarrativeEventId};
+use core_data::game_primitives::Side;
+use core_ui::actions::InterfaceAction;
+use protos::riftcaller::node_background::BackgroundAddress;
+use protos::riftcaller::SpriteAddress;
+use test_utils::client_interface::HasText;
+use test_utils::test_adventure::TestAdventure;
+use test_utils::test_session::TestSession;
+use test_utils::*;
+use user_action_data::UserAction;
+
+#[test]
+fn visit_random_event_tile() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller).coins(Coins(100)).build();
+    let tile = adventure.insert_tile(AdventureEffect::RandomNarrativeEvent);
+    adventure.visit_tile(tile);
+    assert_eq!(Some(NarrativeEventId::new(2)), current_event(&adventure));
+
+    let choice = NarrativeChoiceId::new(1);
+    adventure.perform_adventure_action(AdventureAction::SetNarrativeStep(
+        NarrativeEventStep::ViewChoices,
+    ));
+    adventure.perform_adventure_action(AdventureAction::SetNarrativeStep(
+        NarrativeEventStep::SelectChoice(choice),
+    ));
+    assert_eq!(Coins(50), adventure.query_adventure(|state| state.coins));
+
+    adventure.perform_adventure_action(AdventureAction::ApplyNarrativeEffect(
+        choice,
+        NarrativeEffectIndex::Reward(0),
+    ));
+    assert!(adventure.query_adventure(|state| matches!(
+        state.screens.current(),
+        Some(AdventureScreen::Shop(_))
+    )));
+}
+
+#[test]
+fn cannot_select_unaffordable_choice() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller).coins(Coins(30)).build();
+    let tile = adventure.insert_tile(AdventureEffect::RandomNarrativeEvent);
+    adventure.visit_tile(tile);
+    assert_eq!(Some(NarrativeEventId::new(2)), current_event(&adventure));
+    adventure.perform_adventure_action(AdventureAction::SetNarrativeStep(
+        NarrativeEventStep::ViewChoices,
+    ));
+
+    let panel = adventure.client.interface.top_panel();
+    let unaffordable = panel.find_handlers("Name your price").expect("Choice handlers");
+    assert!(unaffordable.on_click.is_none());
+    let affordable = panel.find_handlers("find my own way").expect("Choice handlers");
+    assert!(affordable.on_click.is_some());
+
+    let result = adventure.perform_action(
+        UserAction::AdventureAction(AdventureAction::SetNarrativeStep(
+            NarrativeEventStep::SelectChoice(NarrativeChoiceId::new(1)),
+        ))
+        .as_client_action(),
+        adventure.user_id(),
+    );
+    assert!(result.is_err());
+    assert_eq!(Coins(30), adventure.query_adventure(|state| state.coins));
+}
+
+#[test]
+fn event_deck_reshuffles_when_empty() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller).build();
+    let mut drawn = vec![];
+    for _ in 0..2 {
+        let tile = adventure.insert_tile(AdventureEffect::RandomNarrativeEvent);
+        adventure.visit_tile(tile);
+        drawn.extend(current_event(&adventure));
+        adventure.perform_adventure_action(AdventureAction::EndNarrativeEvent);
+    }
+    assert_eq!(vec![NarrativeEventId::new(2), NarrativeEventId::new(1)], drawn);
+    assert_eq!(0, adventure.query_adventure(|state| state.event_deck.remaining()));
+
+    let tile = adventure.insert_tile(AdventureEffect::RandomNarrativeEvent);
+    adventure.visit_tile(tile);
+    assert_eq!(1, adventure.query_adventure(|state| state.event_deck.remaining()));
+}
+
+#[test]
+fn event_splash_opens_full_screen_image() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller).build();
+    let tile = adventure.insert_tile(AdventureEffect::NarrativeEvent(NarrativeEventId::new(1)));
+    adventure.visit_tile(tile);
+    assert_eq!(Some(NarrativeEventStep::Splash), current_step(&adventure));
+
+    let data = game_tables::narrative_event(NarrativeEventId::new(1));
+    let panel = adventure.client.interface.top_panel();
+    assert_eq!(
+        Some(&BackgroundAddress::Sprite(SpriteAddress { address: data.image.address.clone() })),
+        panel
+            .style
+            .as_ref()
+            .and_then(|style| style.background_image.as_ref())
+            .and_then(|background| background.background_address.as_ref())
+    );
+    assert!(panel.has_text(data.splash.clone().expect("Splash text")));
+
+    adventure.click_on(adventure.user_id(), "Continue");
+    assert_eq!(Some(NarrativeEventStep::Introduction), current_step(&adventure));
+}
+
+fn current_step(adventure: &TestSession) -> Option<NarrativeEventStep> {
+    adventure.query_adventure(|state| match state.screens.current() {
+        Some(AdventureScreen::NarrativeEvent(narrative)) => Some(narrative.step),
+        _ => None,
+    })
+}
+
+fn current_event(adventure: &TestSession) -> Option<NarrativeEventId> {
+    adventure.query_adventure(|state| match state.screens.current() {
+        Some(AdventureScreen::NarrativeEvent(narrative)) => Some(narrative.id),
+        _ => None,
+    })
+}
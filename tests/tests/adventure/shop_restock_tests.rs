@@ -0,0 +1,47 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use adventure_data::adventure_action::AdventureAction;
+use adventure_data::adventure_effect_data::AdventureEffect;
+use core_data::adventure_primitives::CardFilterId;
+use core_data::game_primitives::Side;
+use test_utils::test_adventure::TestAdventure;
+use test_utils::*;
+
+const FILTER: CardFilterId = CardFilterId { value: 2 };
+
+#[test]
+fn shop_restocks_sold_cards() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller).build();
+    let shop = adventure.insert_tile(AdventureEffect::Shop(FILTER));
+    adventure.visit_tile(shop);
+    let initial = adventure.shop_choices(shop);
+    for i in 0..initial.len() {
+        adventure.perform_adventure_action(AdventureAction::BuyCard(i));
+    }
+    adventure.perform_adventure_action(AdventureAction::EndVisit);
+    assert!(adventure.shop_choices(shop).iter().all(|choice| choice.sold));
+
+    for _ in 0..adventure_generator::SHOP_RESTOCK_VISITS {
+        let other = adventure.insert_tile(AdventureEffect::Shop(FILTER));
+        adventure.visit_tile(other);
+        adventure.perform_adventure_action(AdventureAction::EndVisit);
+    }
+
+    let restocked = adventure.shop_choices(shop);
+    assert!(restocked.iter().all(|choice| !choice.sold));
+    assert!(restocked.iter().all(|choice| initial.iter().all(|c| c.card != choice.card)));
+    adventure.visit_tile(shop);
+    assert!(adventure.query_adventure(|state| state.screens.current().is_some()));
+}
@@ -0,0 +1,61 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::Ordering;
+
+use adventure_data::adventure::TileState;
+use adventure_data::adventure_action::AdventureAction;
+use adventure_data::adventure_effect_data::AdventureEffect;
+use core_data::adventure_primitives::{Coins, TilePosition};
+use core_data::game_primitives::Side;
+use core_ui::actions::InterfaceAction;
+use test_utils::test_adventure::TestAdventure;
+use test_utils::test_adventure_helpers::NEXT_X_POSITION;
+use test_utils::*;
+use user_action_data::UserAction;
+
+#[test]
+fn tile_on_cooldown_cannot_be_revisited() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller).coins(Coins(100)).build();
+    let tile = TilePosition::new(NEXT_X_POSITION.fetch_add(1, Ordering::SeqCst), 1);
+    adventure.overwrite_adventure_tile(
+        tile,
+        TileState {
+            on_visited: Some(AdventureEffect::GainCoins(Coins(50))),
+            cooldown_visits: Some(2),
+            ..TileState::with_sprite("/sprite.png")
+        },
+    );
+
+    adventure.visit_tile(tile);
+    assert_eq!(Coins(150), adventure.query_adventure(|state| state.coins));
+    assert!(adventure.client.map.tile(tile).tile.on_visit.is_none());
+    let user_id = adventure.user_id();
+    let result = adventure.perform_action(
+        UserAction::AdventureAction(AdventureAction::VisitTileEntity(tile)).as_client_action(),
+        user_id,
+    );
+    assert!(result.is_err());
+
+    let other = adventure.insert_tile(AdventureEffect::GainCoins(Coins(10)));
+    adventure.visit_tile(other);
+    assert!(adventure.client.map.tile(tile).tile.on_visit.is_none());
+
+    let other = adventure.insert_tile(AdventureEffect::GainCoins(Coins(10)));
+    adventure.visit_tile(other);
+    assert!(adventure.client.map.tile(tile).tile.on_visit.is_some());
+
+    adventure.visit_tile(tile);
+    assert_eq!(Coins(220), adventure.query_adventure(|state| state.coins));
+}
@@ -0,0 +1,100 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use adventure_data::adventure_action::AdventureAction;
+use adventure_data::adventure_effect_data::{AdventureEffect, DeckCardAction, DeckCardEffect};
+use card_definition_data::cards;
+use core_data::adventure_primitives::{CardFilterId, Coins};
+use core_data::game_primitives::Side;
+use core_ui::actions::InterfaceAction;
+use game_data::card_name::{CardName, CardVariant};
+use test_utils::test_adventure::TestAdventure;
+use test_utils::test_session::TestSession;
+use test_utils::*;
+use user_action_data::UserAction;
+
+const STANDARD_CARD: CardFilterId = CardFilterId { value: 1 };
+
+fn upgrade_effect() -> AdventureEffect {
+    AdventureEffect::PickCardForEffect(
+        STANDARD_CARD,
+        DeckCardEffect::new(DeckCardAction::UpgradeAllCopies).cost(Coins(50)),
+    )
+}
+
+#[test]
+fn purchase_card_upgrade() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller)
+        .coins(Coins(100))
+        .deck_card(CardName::Glimmersong, 2)
+        .build();
+    let tile = adventure.insert_tile(upgrade_effect());
+    adventure.visit_tile(tile);
+    apply(&mut adventure, CardVariant::standard(CardName::Glimmersong)).expect("Upgrade");
+
+    let upgraded = CardVariant::upgraded(CardName::Glimmersong);
+    assert_eq!(Coins(50), adventure.current_coins());
+    assert_eq!(
+        Some(2),
+        adventure.query_adventure(|state| state.deck.cards.get(&upgraded).copied())
+    );
+    assert!(adventure.query_adventure(|state| !state
+        .deck
+        .cards
+        .contains_key(&CardVariant::standard(CardName::Glimmersong))));
+    assert_eq!(Some(1), cards::get(upgraded).config.stats.base_attack);
+    assert_eq!(
+        Some(0),
+        cards::get(CardVariant::standard(CardName::Glimmersong)).config.stats.base_attack
+    );
+}
+
+#[test]
+fn cannot_upgrade_without_coins() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller)
+        .coins(Coins(25))
+        .deck_card(CardName::Glimmersong, 1)
+        .build();
+    let tile = adventure.insert_tile(upgrade_effect());
+    adventure.visit_tile(tile);
+    test_helpers::assert_error(apply(&mut adventure, CardVariant::standard(CardName::Glimmersong)));
+    assert_eq!(Coins(25), adventure.current_coins());
+}
+
+#[test]
+fn preload_deck_card_art() {
+    let mut adventure = TestAdventure::new(Side::Riftcaller)
+        .coins(Coins(100))
+        .deck_card(CardName::Glimmersong, 2)
+        .deck_card(CardName::SimpleAxe, 1)
+        .build();
+    let tile = adventure.insert_tile(upgrade_effect());
+    adventure.visit_tile(tile);
+    let preload = adventure.client.interface.top_panel_preload_sprites();
+    for name in [CardName::Glimmersong, CardName::SimpleAxe] {
+        let image = &cards::get(CardVariant::standard(name)).image;
+        assert!(preload.contains(&image.address.as_str()));
+    }
+}
+
+fn apply(adventure: &mut TestSession, card: CardVariant) -> anyhow::Result<()> {
+    let user_id = adventure.user_id();
+    adventure
+        .perform_action(
+            UserAction::AdventureAction(AdventureAction::ApplyDeckCardEffect(card))
+                .as_client_action(),
+            user_id,
+        )
+        .map(|_| ())
+}
@@ -0,0 +1,30 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ai_core::state_evaluator::StateEvaluator;
+use ai_game_integration::evaluators;
+use ai_game_integration::state_node::RiftcallerState;
+use core_data::game_primitives::{PointsValue, Side};
+use test_utils::test_game::{TestGame, TestSide};
+
+fn evaluate_with_points(points: PointsValue) -> i32 {
+    let g = TestGame::new(TestSide::new(Side::Riftcaller).bonus_points(points)).build();
+    let state = g.query_game(|game| RiftcallerState(game.clone_for_simulation()));
+    evaluators::heuristic_evaluator().evaluate(&state, Side::Riftcaller).expect("evaluate")
+}
+
+#[test]
+pub fn higher_score_evaluates_higher() {
+    assert!(evaluate_with_points(2) > evaluate_with_points(1));
+}
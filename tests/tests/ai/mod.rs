@@ -12,5 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod evaluator_tests;
 mod monte_carlo_tests;
 mod tree_search_tests;
@@ -14,6 +14,8 @@
 
 use core_data::game_primitives::{RoomId, Side};
 use game_data::card_name::CardName;
+use protos::riftcaller::game_command::Command;
+use protos::riftcaller::game_object_identifier::Id;
 use protos::riftcaller::object_position::Position;
 use test_utils::test_game::{TestGame, TestSide};
 use test_utils::*;
@@ -305,3 +307,48 @@ fn phalanx_guardian_play_after_damage() {
     g.click(Button::EndRaid);
     assert_eq!(g.client.cards.hand().len(), 3);
 }
+
+#[test]
+fn vigilant_scout() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .face_down_defender(RoomId::Vault, CardName::TestMinionEndRaid)
+                .face_down_defender(RoomId::Sanctum, CardName::TestMinionEndRaid),
+        )
+        .build();
+    g.create_and_play(CardName::VigilantScout);
+    let mana = g.me().mana();
+    g.initiate_raid(RoomId::Vault);
+    g.opponent_click(Button::Summon);
+    assert_eq!(g.me().mana(), mana + 1);
+    g.click(Button::NoWeapon);
+    g.pass_turn(Side::Riftcaller);
+    g.pass_turn(Side::Covenant);
+    g.initiate_raid(RoomId::Sanctum);
+    g.opponent_click(Button::Summon);
+    assert_eq!(g.me().mana(), mana + 2);
+}
+
+#[test]
+fn vigilant_scout_focuses_camera() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .face_down_defender(RoomId::Vault, CardName::TestMinionEndRaid),
+        )
+        .build();
+    let id = g.create_and_play(CardName::VigilantScout);
+    g.initiate_raid(RoomId::Vault);
+    let response = g.opponent_click(Button::Summon);
+    let focused = response
+        .user_response
+        .commands
+        .iter()
+        .filter_map(|c| match c.command.as_ref() {
+            Some(Command::FocusGameObject(focus)) => focus.object.as_ref()?.id,
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(focused, vec![Id::CardId(id)]);
+}
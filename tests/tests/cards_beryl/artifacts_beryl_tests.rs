@@ -13,7 +13,11 @@
 // limitations under the License.
 
 use core_data::game_primitives::{Resonance, RoomId, Side};
+use core_ui::icons;
 use game_data::card_name::{CardName, CardVariant};
+use game_data::game_actions::CardTargeting;
+use game_data::game_effect::GameEffect;
+use rules::queries;
 use test_utils::client_interface::HasText;
 use test_utils::test_game::{TestGame, TestSide};
 use test_utils::test_helpers::WeaponStats;
@@ -402,6 +406,28 @@ fn foebane() {
     g.click(Button::EndRaid);
 }
 
+#[test]
+fn foebane_prompt_choices() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .face_up_defender(RoomId::Vault, CardName::TestMinionShield1Infernal),
+        )
+        .build();
+    g.create_and_play_with_target(CardName::Foebane, RoomId::Vault);
+    g.click(Button::ChooseOnPlay);
+    g.initiate_raid(RoomId::Vault);
+    let choices = g.prompt_choices(Side::Riftcaller);
+    assert_eq!(choices.len(), 2);
+    assert_eq!(choices[0].label, format!("Pay 1{}, Evade", icons::MANA));
+    assert!(matches!(
+        choices[0].effects[..],
+        [GameEffect::ManaCost(Side::Riftcaller, 1, _), GameEffect::EvadeCurrentEncounter]
+    ));
+    assert_eq!(choices[1].label, "Continue");
+    assert_eq!(choices[1].effects, vec![GameEffect::Continue]);
+}
+
 #[test]
 fn foebane_do_not_use() {
     let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
@@ -441,6 +467,23 @@ fn foebane_cannot_play_without_target() {
     assert!(g.play_card_with_result(id, g.user_id(), Some(RoomId::Vault)).is_err());
 }
 
+#[test]
+fn foebane_valid_targets() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .face_up_defender(RoomId::Vault, CardName::TestMinionShield1Infernal)
+                .face_down_defender(RoomId::RoomA, CardName::TestInfernalMinion)
+                .room_occupant(RoomId::RoomB, CardName::TestScheme3_10),
+        )
+        .build();
+    let id = g.add_to_hand(CardName::Foebane);
+    assert_eq!(
+        g.query_game(|game| queries::valid_targets(game, test_helpers::server_card_id(id))),
+        CardTargeting::TargetRoom { valid_rooms: vec![RoomId::Vault, RoomId::RoomA] }
+    );
+}
+
 #[test]
 fn whip_of_disjunction() {
     let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
@@ -457,6 +500,27 @@ fn whip_of_disjunction() {
     g.click(Button::EndRaid);
 }
 
+#[test]
+fn whip_of_disjunction_custom_state_does_not_grow() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .face_up_defender(RoomId::Vault, CardName::TestAstralMinion),
+        )
+        .build();
+    let id = g.create_and_play(CardName::WhipOfDisjunction);
+    let card_id = test_helpers::server_card_id(id);
+    for _ in 0..5 {
+        g.initiate_raid(RoomId::Vault);
+        g.activate_ability(id, 0);
+        g.click(Button::NoWeapon);
+        g.click(Button::EndRaid);
+        assert!(g.query_game(|game| game.card(card_id).custom_state.is_empty()));
+        g.pass_turn(Side::Riftcaller);
+        g.pass_turn(Side::Covenant);
+    }
+}
+
 #[test]
 fn whip_of_disjunction_cannot_activate_mortal() {
     let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
@@ -525,6 +589,24 @@ fn glimmersong_does_not_trigger_on_score() {
     );
 }
 
+#[test]
+fn glimmersong_triggers_on_ending_access_without_scoring() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .opponent(
+            TestSide::new(Side::Covenant).room_occupant(RoomId::RoomA, CardName::TestScheme3_10),
+        )
+        .build();
+    g.create_and_play(CardName::Glimmersong);
+    g.initiate_raid(RoomId::RoomA);
+    assert!(g.has(Button::Score));
+    g.click(Button::EndRaid);
+    assert!(!g.client.data.raid_active());
+    assert_eq!(
+        "1".to_string(),
+        g.client.cards.artifacts().find_card(CardName::Glimmersong).attack_icon()
+    );
+}
+
 #[test]
 fn glimmersong_does_not_trigger_on_raze() {
     let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
@@ -725,6 +807,19 @@ fn amaras_decree_destroyed() {
     assert_eq!(g.me().score(), 10);
 }
 
+#[test]
+fn amaras_decree_can_score_scheme() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant)).build();
+    g.pass_turn(Side::Covenant);
+    g.create_and_play(CardName::AmarasDecree);
+    g.pass_turn(Side::Riftcaller);
+    let scheme_id = test_helpers::server_card_id(g.create_and_play(CardName::TestScheme1_10));
+    g.progress_room(test_constants::ROOM_ID);
+    assert!(!g.query_game(|game| queries::can_score_scheme(game, scheme_id)));
+    g.pass_turn(Side::Covenant);
+    assert!(g.query_game(|game| queries::can_score_scheme(game, scheme_id)));
+}
+
 #[test]
 fn lawbringer() {
     let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
@@ -898,3 +993,109 @@ fn summermorn_upgraded() {
     g.click_card_button(g.user_id(), charge_id, Button::AddPowerCharges);
     assert!(g.client.cards.get(charge_id).arena_icon().contains("2"));
 }
+
+#[test]
+fn waystone_pay_mana() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).actions(1).build();
+    let id = g.create_and_play(CardName::Waystone);
+    let hand = g.client.cards.hand().len();
+    assert_eq!(g.me().actions(), 0);
+    g.activate_ability(id, 0);
+    assert_eq!(g.me().mana(), test_constants::STARTING_MANA - 4);
+    assert_eq!(g.client.cards.hand().len(), hand + 1);
+}
+
+#[test]
+fn waystone_pay_action() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller).mana(3)).build();
+    let id = g.create_and_play(CardName::Waystone);
+    let (actions, hand) = (g.me().actions(), g.client.cards.hand().len());
+    g.activate_ability(id, 0);
+    assert_eq!(g.me().mana(), 1);
+    assert_eq!(g.me().actions(), actions - 1);
+    assert_eq!(g.client.cards.hand().len(), hand + 1);
+}
+
+#[test]
+fn waystone_choose_cost() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    let id = g.create_and_play(CardName::Waystone);
+    let (actions, hand) = (g.me().actions(), g.client.cards.hand().len());
+    g.activate_ability(id, 0);
+    assert!(g.client.interface.controls().has_text(format!("Pay 2{}", icons::MANA)));
+    g.click_on(g.user_id(), format!("Pay {}", icons::ACTION));
+    assert_eq!(g.me().mana(), test_constants::STARTING_MANA - 2);
+    assert_eq!(g.me().actions(), actions - 1);
+    assert_eq!(g.client.cards.hand().len(), hand + 1);
+}
+
+#[test]
+fn echoing_mirror() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).actions(5).build();
+    let spear = g.create_and_play(CardName::SpearOfConquest);
+    let mirror = g.create_and_play(CardName::EchoingMirror);
+    g.activate_ability(mirror, 0);
+    g.click_card_button(g.user_id(), spear, Button::Copy);
+    assert!(g.client.cards.get(mirror).arena_icon_option().is_none());
+
+    g.initiate_raid(RoomId::Crypt);
+    g.click(Button::EndRaid);
+    assert!(g.client.cards.get(spear).arena_icon().contains('1'));
+    assert!(g.client.cards.get(mirror).arena_icon().contains('1'));
+
+    g.pass_turn(Side::Riftcaller);
+    g.pass_turn(Side::Covenant);
+    g.initiate_raid(RoomId::Crypt);
+    g.click(Button::EndRaid);
+    assert!(g.client.cards.get(spear).arena_icon().contains('2'));
+    assert!(g.client.cards.get(mirror).arena_icon().contains('1'));
+}
+
+#[test]
+fn shatterflail() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .face_up_defender(RoomId::Sanctum, CardName::TestMortalMinion)
+                .face_up_defender(RoomId::Sanctum, CardName::TestMortalMinion)
+                .face_up_defender(RoomId::Sanctum, CardName::TestMortalMinion),
+        )
+        .build();
+    let defenders =
+        g.client.cards.room_defenders(RoomId::Sanctum).iter().map(|c| c.id()).collect::<Vec<_>>();
+    assert_eq!(defenders.len(), 3);
+    g.create_and_play(CardName::Shatterflail);
+    g.initiate_raid(RoomId::Sanctum);
+    g.click_card_name(CardName::Shatterflail);
+
+    let mut health =
+        defenders.iter().map(|id| g.client.cards.get(*id).health_icon()).collect::<Vec<_>>();
+    health.sort();
+    assert_eq!(health, vec!["3", "3", "5"]);
+}
+
+#[test]
+fn echoing_mirror_cannot_copy_prompt() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).actions(5).build();
+    let artifact = g.create_and_play(CardName::TestArtifactPromptOnAccess);
+    let mirror = g.create_and_play(CardName::EchoingMirror);
+    g.activate_ability(mirror, 0);
+    assert!(!g.client.interface.card_anchors().iter().any(|a| a.card_id == Some(artifact)));
+}
+
+#[test]
+fn echoing_mirror_copy_expires() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).actions(5).build();
+    let spear = g.create_and_play(CardName::SpearOfConquest);
+    let mirror = g.create_and_play(CardName::EchoingMirror);
+    g.activate_ability(mirror, 0);
+    g.click_card_button(g.user_id(), spear, Button::Copy);
+    let card_id = test_helpers::server_card_id(mirror);
+    for _ in 0..2 {
+        g.pass_turn(Side::Riftcaller);
+        g.pass_turn(Side::Covenant);
+    }
+    g.initiate_raid(RoomId::Crypt);
+    g.click(Button::EndRaid);
+    assert!(g.query_game(|game| game.card(card_id).custom_state.is_empty()));
+}
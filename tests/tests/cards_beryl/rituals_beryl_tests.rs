@@ -286,3 +286,30 @@ fn foretell_fate_5_cards() {
     g.click(Button::SubmitCardSelector);
     assert_eq!(g.client.cards.hand().len(), 6);
 }
+
+#[test]
+fn interrogate() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant))
+        .opponent(
+            TestSide::new(Side::Riftcaller)
+                .in_hand(CardName::TestSpell)
+                .in_hand(CardName::TestWeapon2Attack),
+        )
+        .build();
+    let hand = g.client.cards.opponent_hand().iter().map(|c| c.id()).collect::<Vec<_>>();
+    assert!(hand.iter().all(|id| !g.client.cards.get(*id).revealed_to_me()));
+    g.create_and_play(CardName::Interrogate);
+    assert!(hand.iter().all(|id| g.client.cards.get(*id).revealed_to_me()));
+
+    let weapon = *hand
+        .iter()
+        .find(|id| g.client.cards.get(**id).title() == CardName::TestWeapon2Attack.displayed_name())
+        .expect("Weapon not found");
+    g.click_card_button(g.user_id(), weapon, Button::Discard);
+    assert_eq!(
+        g.client.cards.opponent_discard_pile().find_card(CardName::TestWeapon2Attack).id(),
+        weapon
+    );
+    assert_eq!(g.opponent.cards.hand().len(), hand.len() - 1);
+    assert!(g.opponent.cards.hand().iter().all(|c| c.id() != weapon));
+}
@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use core_data::game_primitives::{RoomId, Side};
+use core_ui::icons;
 use game_data::card_name::{CardName, CardVariant};
 use protos::riftcaller::object_position::Position;
+use test_utils::client_interface::HasText;
 use test_utils::test_game::{TestGame, TestSide};
 use test_utils::test_session::TestSession;
 use test_utils::*;
@@ -599,3 +601,92 @@ fn echoing_valor_skip() {
     g.play_card(id, g.user_id(), Some(RoomId::Sanctum));
     g.click(Button::EndRaid);
 }
+
+#[test]
+fn overcharge() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).actions(5).build();
+    g.create_and_play(CardName::SpearOfConquest);
+    g.create_and_play(CardName::Overcharge);
+
+    g.initiate_raid(RoomId::Crypt);
+    g.click(Button::EndRaid);
+    assert!(g
+        .client
+        .cards
+        .artifacts()
+        .find_card(CardName::SpearOfConquest)
+        .arena_icon()
+        .contains('2'));
+
+    g.initiate_raid(RoomId::Crypt);
+    g.click(Button::EndRaid);
+    assert!(g
+        .client
+        .cards
+        .artifacts()
+        .find_card(CardName::SpearOfConquest)
+        .arena_icon()
+        .contains('3'));
+}
+
+#[test]
+fn twin_paths_draw() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    g.create_and_play(CardName::TwinPaths);
+    assert_eq!(g.client.cards.hand().len(), 0);
+    g.click(Button::DrawCard);
+    assert_eq!(g.client.cards.hand().len(), 1);
+    assert_eq!(g.me().mana(), test_constants::STARTING_MANA);
+}
+
+#[test]
+fn twin_paths_gain_mana() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    g.create_and_play(CardName::TwinPaths);
+    g.click(Button::GainMana);
+    assert_eq!(g.client.cards.hand().len(), 0);
+    assert_eq!(g.me().mana(), test_constants::STARTING_MANA + 2);
+}
+
+#[test]
+fn forage() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    g.create_and_play(CardName::Forage);
+    let drawn = g.client.cards.browser().iter().map(|c| c.id()).collect::<Vec<_>>();
+    assert_eq!(drawn.len(), 2);
+    assert!(g.click_with_result(Button::SubmitCardSelector).is_err());
+    g.move_selector_card(drawn[0]);
+    g.click(Button::SubmitCardSelector);
+    assert_eq!(g.client.cards.hand().len(), 1);
+    assert_eq!(g.client.cards.discard_pile().len(), 2);
+}
+
+#[test]
+fn forage_must_discard_exactly_one() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    g.create_and_play(CardName::Forage);
+    let drawn = g.client.cards.browser().iter().map(|c| c.id()).collect::<Vec<_>>();
+    g.move_selector_card(drawn[0]);
+    g.move_selector_card(drawn[1]);
+    assert!(g.click_with_result(Button::SubmitCardSelector).is_err());
+}
+
+#[test]
+fn forage_upgraded() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    g.create_and_play_upgraded(CardName::Forage);
+    let drawn = g.client.cards.browser().iter().map(|c| c.id()).collect::<Vec<_>>();
+    assert_eq!(drawn.len(), 3);
+    g.move_selector_card(drawn[0]);
+    g.click(Button::SubmitCardSelector);
+    assert_eq!(g.client.cards.hand().len(), 2);
+}
+
+#[test]
+fn twin_paths_mode_labels() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    g.create_and_play(CardName::TwinPaths);
+    let nodes = g.client.interface.all_active_nodes();
+    assert!(nodes.has_text("Draw a Card"));
+    assert!(nodes.has_text(format!("Gain 2{}", icons::MANA)));
+}
@@ -0,0 +1,59 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_data::game_primitives::Side;
+use game_data::card_name::CardName;
+use game_data::card_state::CardCounter;
+use test_utils::test_game::{TestGame, TestSide};
+use test_utils::*;
+
+#[test]
+fn once_per_game_bonus() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    let card_id = g.create_and_play(CardName::TestSpellFirstPlayBonus);
+    assert_eq!(g.me().mana(), test_constants::STARTING_MANA + 3);
+    g.pass_turn(Side::Riftcaller);
+    g.pass_turn(Side::Covenant);
+    g.play_card(card_id, g.user_id(), None);
+    assert_eq!(g.me().mana(), test_constants::STARTING_MANA + 4);
+}
+
+#[test]
+fn growth_counters() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).actions(4).build();
+    let id = g.create_and_play(CardName::TestGrowthArtifact);
+    g.activate_ability(id, 0);
+    g.activate_ability(id, 0);
+    assert_eq!(g.client.cards.get(id).arena_icon(), "2");
+    let card_id = test_helpers::server_card_id(id);
+    assert_eq!(2, g.query_game(|game| game.card(card_id).counters(CardCounter::Growth)));
+
+    let mana = g.me().mana();
+    g.activate_ability(id, 1);
+    assert_eq!(mana + 2, g.me().mana());
+    assert!(g.client.cards.discard_pile().contains_card(CardName::TestGrowthArtifact));
+    assert_eq!(0, g.query_game(|game| game.card(card_id).counters(CardCounter::Growth)));
+    assert_eq!(2, g.query_game(|game| game.card(card_id).last_known_counters(CardCounter::Growth)));
+}
+
+#[test]
+fn pass_turns_fires_dawn_triggers() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    g.create_and_play(CardName::TestEvocationGainManaAtDawn);
+    let mana = test_constants::STARTING_MANA - test_constants::EVOCATION_COST;
+    assert_eq!(g.me().mana(), mana);
+    g.pass_turns(3);
+    assert!(g.dawn());
+    assert_eq!(g.me().mana(), mana + 3);
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use core_data::game_primitives::{RoomId, Side};
+use core_ui::actions::InterfaceAction;
 use game_data::card_name::CardName;
 use game_data::game_actions;
 use game_data::game_actions::GameAction;
@@ -20,13 +21,17 @@ use insta::assert_snapshot;
 use protos::riftcaller::client_action::Action;
 use protos::riftcaller::object_position::Position;
 use protos::riftcaller::{
-    card_target, CardTarget, DrawCardAction, GainManaAction, GameMessageType,
-    ObjectPositionDiscardPile, PlayCardAction, PlayerName, ProgressRoomAction,
+    card_target, CardIdentifier, CardTarget, DrawCardAction, GainManaAction, GameMessageType,
+    ObjectPositionDiscardPile, PlayCardAction, PlayerName, ProgressRoomAction, RoomIdentifier,
 };
+use rules::flags;
+use rules::flags::PlayCardDiagnosis;
 use test_utils::summarize::Summary;
 use test_utils::test_game::{TestGame, TestRaid, TestSide};
+use test_utils::test_session::TestSession;
 use test_utils::test_session_builder::TestSessionBuilder;
 use test_utils::*;
+use user_action_data::DebugAction;
 
 #[test]
 fn connect() {
@@ -264,6 +269,19 @@ fn minion_limit_cancel_playing() {
     assert!(g.draw_card_with_result().is_ok());
 }
 
+#[test]
+fn inner_room_only_minion() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant)).build();
+    let id = g.add_to_hand(CardName::TestMinionInnerRoomOnly);
+    assert_eq!(
+        vec![RoomIdentifier::Vault, RoomIdentifier::Sanctum, RoomIdentifier::Crypt],
+        g.client.cards.get(id).valid_rooms()
+    );
+    assert!(g.play_card_with_result(id, g.user_id(), Some(RoomId::RoomA)).is_err());
+    g.play_card(id, g.user_id(), Some(RoomId::Vault));
+    assert_eq!(g.client.cards.room_defenders(RoomId::Vault).len(), 1);
+}
+
 #[test]
 fn weapon_limit() {
     let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).actions(6).build();
@@ -944,3 +962,103 @@ fn riftcaller_legal_actions() {
         ],
     );
 }
+
+#[test]
+fn undo_play_card() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    let mana = g.me().mana();
+    let actions = g.me().actions();
+    let spell_id = g.add_to_hand(CardName::TestSpell);
+    g.play_card(spell_id, g.user_id(), None);
+    assert_eq!(g.client.cards.discard_pile().len(), 1);
+
+    g.perform(DebugAction::DebugUndo.as_client_action(), g.user_id());
+    assert_eq!(g.me().mana(), mana);
+    assert_eq!(g.me().actions(), actions);
+    test_helpers::assert_cards_match(g.client.cards.hand(), vec![CardName::TestSpell]);
+    assert!(g.client.cards.discard_pile().is_empty());
+}
+
+#[test]
+fn legal_actions_in_main_phase() {
+    let g = TestGame::new(TestSide::new(Side::Riftcaller).in_hand(CardName::TestSpell))
+        .opponent(
+            TestSide::new(Side::Covenant).room_occupant(RoomId::RoomA, CardName::TestScheme3_10),
+        )
+        .build();
+    let spell_id = g.query_game(|game| game.hand(Side::Riftcaller).next().expect("card").id);
+    assert_eq!(
+        g.legal_actions(Side::Riftcaller),
+        vec![
+            GameAction::InitiateRaid(RoomId::Vault),
+            GameAction::InitiateRaid(RoomId::Sanctum),
+            GameAction::InitiateRaid(RoomId::Crypt),
+            GameAction::InitiateRaid(RoomId::RoomA),
+            GameAction::PlayCard(spell_id, game_actions::CardTarget::None),
+            GameAction::DrawCard,
+            GameAction::GainMana,
+        ]
+    );
+}
+
+#[test]
+fn play_card_diagnosis_can_play() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    let id = g.add_to_hand(CardName::TestSpell);
+    assert_eq!(play_card_diagnosis(&g, id), PlayCardDiagnosis::CanPlay);
+}
+
+#[test]
+fn play_card_diagnosis_not_your_turn() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).current_turn(Side::Covenant).build();
+    let id = g.add_to_hand(CardName::TestSpell);
+    assert_eq!(play_card_diagnosis(&g, id), PlayCardDiagnosis::NotYourTurn);
+}
+
+#[test]
+fn play_card_diagnosis_during_raid() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .room_occupant(RoomId::RoomA, CardName::TestScheme3_10)
+                .face_up_defender(RoomId::RoomA, CardName::TestInfernalMinion),
+        )
+        .build();
+    let id = g.add_to_hand(CardName::TestSpell);
+    g.initiate_raid(RoomId::RoomA);
+    assert_eq!(play_card_diagnosis(&g, id), PlayCardDiagnosis::DuringRaid);
+}
+
+#[test]
+fn play_card_diagnosis_pending_prompt() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller).mana(3)).build();
+    let id = g.add_to_hand(CardName::TestSpell);
+    g.create_and_play(CardName::TestSpellOptionalPayToDraw);
+    assert_eq!(play_card_diagnosis(&g, id), PlayCardDiagnosis::PendingPrompt);
+}
+
+#[test]
+fn play_card_diagnosis_no_action_points() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).actions(0).build();
+    let id = g.add_to_hand(CardName::TestSpell);
+    assert_eq!(play_card_diagnosis(&g, id), PlayCardDiagnosis::NoActionPoints);
+}
+
+#[test]
+fn play_card_diagnosis_insufficient_mana() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller).mana(0)).build();
+    let id = g.add_to_hand(CardName::TestSpell);
+    assert_eq!(play_card_diagnosis(&g, id), PlayCardDiagnosis::InsufficientMana);
+}
+
+#[test]
+fn play_card_diagnosis_no_valid_room() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant)).build();
+    let id = g.add_to_hand(CardName::DusksAscension);
+    assert_eq!(play_card_diagnosis(&g, id), PlayCardDiagnosis::NoValidRoom);
+}
+
+fn play_card_diagnosis(g: &TestSession, card_id: CardIdentifier) -> PlayCardDiagnosis {
+    let card_id = test_helpers::server_card_id(card_id);
+    g.query_game(|game| flags::play_card_diagnosis(game, card_id))
+}
@@ -0,0 +1,45 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_definition_data::card_definition::Keyword;
+use card_definition_data::cards;
+use core_data::game_primitives::CardSubtype;
+use game_data::card_name::{CardName, CardVariant};
+use game_data::text::TextTokenKind;
+
+#[test]
+fn search_by_subtype_keyword() {
+    cards_all::initialize();
+    let results = cards::filter_cards("Charge");
+    assert!(results.contains(&CardName::SpearOfConquest));
+    assert!(results.contains(&CardName::BladeOfReckoning));
+    assert_eq!(cards::filter_cards("charge"), results);
+}
+
+#[test]
+fn keywords_include_subtypes() {
+    cards_all::initialize();
+    let spear = cards::get(CardVariant::standard(CardName::SpearOfConquest));
+    assert!(spear.has_keyword(Keyword::Subtype(CardSubtype::Charge)));
+    assert!(spear.has_keyword(Keyword::Subtype(CardSubtype::Weapon)));
+    assert!(!spear.has_keyword(Keyword::Subtype(CardSubtype::Runic)));
+}
+
+#[test]
+fn keywords_include_rules_text_tokens() {
+    cards_all::initialize();
+    let shield = cards::get(CardVariant::standard(CardName::ShieldOfTheFlames));
+    assert!(shield.has_keyword(Keyword::Text(TextTokenKind::Evade)));
+    assert!(cards::filter_cards("Evade").contains(&CardName::ShieldOfTheFlames));
+}
@@ -0,0 +1,62 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use display::command_compaction;
+use protos::riftcaller::game_command::Command;
+use protos::riftcaller::game_object_identifier::Id;
+use protos::riftcaller::{
+    GameObjectIdentifier, GameObjectMove, MoveGameObjectsCommand, PlayerName, TimeValue,
+};
+
+#[test]
+fn adjacent_moves_with_same_delay_merge() {
+    let commands = command_compaction::merge_moves(vec![
+        move_command(Id::Deck(PlayerName::User.into()), 500),
+        move_command(Id::DiscardPile(PlayerName::User.into()), 500),
+    ]);
+    assert_eq!(1, commands.len());
+    let Command::MoveGameObjects(merged) = &commands[0] else {
+        panic!("Expected MoveGameObjects command");
+    };
+    assert_eq!(2, merged.moves.len());
+}
+
+#[test]
+fn adjacent_moves_with_different_delay_do_not_merge() {
+    let commands = command_compaction::merge_moves(vec![
+        move_command(Id::Deck(PlayerName::User.into()), 500),
+        move_command(Id::DiscardPile(PlayerName::User.into()), 1000),
+    ]);
+    assert_eq!(2, commands.len());
+}
+
+#[test]
+fn moves_of_same_object_do_not_merge() {
+    let commands = command_compaction::merge_moves(vec![
+        move_command(Id::Deck(PlayerName::User.into()), 500),
+        move_command(Id::Deck(PlayerName::User.into()), 500),
+    ]);
+    assert_eq!(2, commands.len());
+}
+
+fn move_command(id: Id, delay: u32) -> Command {
+    Command::MoveGameObjects(MoveGameObjectsCommand {
+        moves: vec![GameObjectMove {
+            id: Some(GameObjectIdentifier { id: Some(id) }),
+            position: None,
+        }],
+        disable_animation: false,
+        delay: Some(TimeValue { milliseconds: delay }),
+    })
+}
@@ -0,0 +1,80 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use protos::riftcaller::game_command::Command;
+use protos::riftcaller::game_object_identifier::Id;
+use protos::riftcaller::{
+    CardIdentifier, CardView, CommandList, FireProjectileCommand, GameCommand,
+    GameObjectIdentifier, GameView, PlayerName, UpdateGameViewCommand,
+};
+use test_utils::command_validator;
+
+#[test]
+fn valid_projectile() {
+    let list = command_list(vec![
+        game_view(vec![card(1)]),
+        projectile(Id::CardId(card(1)), Id::Character(PlayerName::User.into())),
+    ]);
+    assert!(command_validator::validate_commands(&list).is_ok());
+}
+
+#[test]
+fn projectile_references_unknown_card() {
+    let list = command_list(vec![
+        game_view(vec![card(1)]),
+        projectile(Id::CardId(card(2)), Id::Character(PlayerName::User.into())),
+    ]);
+    let error = command_validator::validate_commands(&list).expect_err("Expected error");
+    assert!(error.to_string().contains("FireProjectile source_id references unknown card"));
+}
+
+#[test]
+fn missing_command() {
+    let list =
+        CommandList { commands: vec![GameCommand { command: None }], ..CommandList::default() };
+    let error = command_validator::validate_commands(&list).expect_err("Expected error");
+    assert!(error.to_string().contains("GameCommand missing command"));
+}
+
+fn command_list(commands: Vec<Command>) -> CommandList {
+    CommandList {
+        commands: commands.into_iter().map(|c| GameCommand { command: Some(c) }).collect(),
+        ..CommandList::default()
+    }
+}
+
+fn card(index: u32) -> CardIdentifier {
+    CardIdentifier { index, ..CardIdentifier::default() }
+}
+
+fn game_view(cards: Vec<CardIdentifier>) -> Command {
+    Command::UpdateGameView(UpdateGameViewCommand {
+        game: Some(GameView {
+            cards: cards
+                .into_iter()
+                .map(|card_id| CardView { card_id: Some(card_id), ..CardView::default() })
+                .collect(),
+            ..GameView::default()
+        }),
+        animate: false,
+    })
+}
+
+fn projectile(source: Id, target: Id) -> Command {
+    Command::FireProjectile(FireProjectileCommand {
+        source_id: Some(GameObjectIdentifier { id: Some(source) }),
+        target_id: Some(GameObjectIdentifier { id: Some(target) }),
+        ..FireProjectileCommand::default()
+    })
+}
@@ -0,0 +1,143 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_data::game_primitives::{RoomId, Side};
+use game_data::card_name::CardName;
+use test_utils::test_game::{TestGame, TestSide};
+use test_utils::*;
+
+#[test]
+fn minion_discount_in_defended_room() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .face_up_room_occupant(
+                    RoomId::RoomB,
+                    CardName::TestProjectMinionsCostLessInDefendedRooms,
+                )
+                .room_occupant(RoomId::RoomA, CardName::TestScheme3_10)
+                .face_up_defender(RoomId::RoomA, CardName::TestMinionEndRaid)
+                .face_up_defender(RoomId::RoomA, CardName::TestMinionEndRaid)
+                .face_down_defender(RoomId::RoomA, CardName::TestMinionEndRaid),
+        )
+        .build();
+    g.initiate_raid(RoomId::RoomA);
+    g.opponent_click(Button::Summon);
+    assert_eq!(
+        g.opponent.this_player.mana(),
+        test_constants::STARTING_MANA - (test_constants::MINION_COST - 1)
+    );
+}
+
+#[test]
+fn no_minion_discount_in_lightly_defended_room() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .face_up_room_occupant(
+                    RoomId::RoomB,
+                    CardName::TestProjectMinionsCostLessInDefendedRooms,
+                )
+                .room_occupant(RoomId::RoomA, CardName::TestScheme3_10)
+                .face_up_defender(RoomId::RoomA, CardName::TestMinionEndRaid)
+                .face_down_defender(RoomId::RoomA, CardName::TestMinionEndRaid),
+        )
+        .build();
+    g.initiate_raid(RoomId::RoomA);
+    g.opponent_click(Button::Summon);
+    assert_eq!(
+        g.opponent.this_player.mana(),
+        test_constants::STARTING_MANA - test_constants::MINION_COST
+    );
+}
+
+#[test]
+fn discard_to_reduce_cost() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    let discarded = g.add_to_hand(CardName::TestSpell);
+    let id = g.add_to_hand(CardName::TestSpellDiscardToReduceCost);
+    let mana = g.me().mana();
+    g.play_card(id, g.user_id(), None);
+    g.click_card_button(g.user_id(), discarded, Button::Discard);
+    assert_eq!(mana - 1, g.me().mana());
+    assert!(g.client.cards.discard_pile().iter().any(|c| c.id() == discarded));
+    assert!(g.client.cards.discard_pile().iter().any(|c| c.id() == id));
+}
+
+#[test]
+fn discard_to_reduce_cost_decline() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    let kept = g.add_to_hand(CardName::TestSpell);
+    let id = g.add_to_hand(CardName::TestSpellDiscardToReduceCost);
+    let mana = g.me().mana();
+    g.play_card(id, g.user_id(), None);
+    g.click(Button::NoPromptAction);
+    assert_eq!(mana - 3, g.me().mana());
+    assert!(g.client.cards.hand().iter().any(|c| c.id() == kept));
+    assert!(g.client.cards.discard_pile().iter().any(|c| c.id() == id));
+}
+
+#[test]
+fn discard_to_reduce_cost_with_only_reduced_mana() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller).mana(1)).build();
+    let discarded = g.add_to_hand(CardName::TestSpell);
+    let id = g.add_to_hand(CardName::TestSpellDiscardToReduceCost);
+    assert!(g.client.cards.get(id).can_play());
+    g.play_card(id, g.user_id(), None);
+    assert!(!g.has(Button::NoPromptAction));
+    g.click_card_button(g.user_id(), discarded, Button::Discard);
+    assert_eq!(0, g.me().mana());
+    assert!(g.client.cards.discard_pile().iter().any(|c| c.id() == discarded));
+    assert!(g.client.cards.discard_pile().iter().any(|c| c.id() == id));
+}
+
+#[test]
+fn sacrifice_to_draw_cards() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).actions(3).build();
+    let id = g.create_and_play(CardName::TestSacrificeDrawTwoArtifact);
+    let hand_size = g.client.cards.hand().real_cards().len();
+    g.activate_ability(id, 0);
+    assert!(g.client.cards.discard_pile().contains_card(CardName::TestSacrificeDrawTwoArtifact));
+    assert_eq!(hand_size + 2, g.client.cards.hand().real_cards().len());
+    assert!(g.activate_ability_with_result(id, 0).is_err());
+}
+
+#[test]
+fn discard_cards_cost() {
+    let mut g = TestGame::new(
+        TestSide::new(Side::Covenant)
+            .hand_size(3)
+            .face_up_room_occupant(RoomId::RoomA, CardName::TestProjectDiscardToGainMana),
+    )
+    .build();
+    let id = g.client.cards.room_occupants(RoomId::RoomA)[0].id();
+    let hand_size = g.client.cards.hand().len();
+    g.activate_ability(id, 0);
+    assert_eq!(g.client.cards.hand().len(), hand_size - 2);
+    assert_eq!(g.client.cards.discard_pile().len(), 2);
+    assert_eq!(g.me().mana(), test_constants::STARTING_MANA + 5);
+}
+
+#[test]
+fn discard_cards_cost_insufficient_hand_size() {
+    let mut g = TestGame::new(
+        TestSide::new(Side::Covenant)
+            .face_up_room_occupant(RoomId::RoomA, CardName::TestProjectDiscardToGainMana),
+    )
+    .build();
+    let id = g.client.cards.room_occupants(RoomId::RoomA)[0].id();
+    assert_eq!(g.client.cards.hand().len(), 1);
+    test_helpers::assert_error(g.activate_ability_with_result(id, 0));
+    assert_eq!(g.client.cards.hand().len(), 1);
+}
@@ -12,10 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use core_data::game_primitives::Side;
+use core_data::game_primitives::{DeckId, Side, Sprite};
 use core_ui::actions::InterfaceAction;
+use game_data::card_name::CardName;
 use game_data::game_actions::{GameAction, GameStateAction};
-use game_data::game_state::MulliganDecision;
+use game_data::game_state::{FirstTurnChoice, MulliganDecision, TurnData};
 use insta::assert_snapshot;
 use test_utils::client_interface::HasText;
 use test_utils::summarize::Summary;
@@ -72,6 +73,47 @@ fn connect_to_new_game() {
     assert_snapshot!(Summary::run(&response));
 }
 
+#[test]
+fn custom_opening_hand_sizes() {
+    let (game_id, covenant_id, riftcaller_id) = test_helpers::generate_ids();
+    let mut session = TestSessionBuilder::new()
+        .game_id(game_id)
+        .user_id(covenant_id)
+        .opponent_id(riftcaller_id)
+        .do_not_connect(true)
+        .build();
+    let debug_options = NewGameDebugOptions {
+        deterministic: true,
+        covenant_opening_hand_size: Some(3),
+        riftcaller_opening_hand_size: Some(7),
+        ..NewGameDebugOptions::default()
+    };
+    session.perform(
+        UserAction::NewGame(NewGameAction {
+            deck: RIFTCALLER_DECK,
+            opponent: session.user_id(),
+            debug_options: Some(debug_options),
+            tutorial: false,
+        })
+        .as_client_action(),
+        session.opponent_id(),
+    );
+    session.perform(
+        UserAction::NewGame(NewGameAction {
+            deck: COVENANT_DECK,
+            opponent: session.opponent_id(),
+            debug_options: Some(debug_options),
+            tutorial: false,
+        })
+        .as_client_action(),
+        session.user_id(),
+    );
+    session.connect(covenant_id).unwrap();
+
+    assert_eq!(3, session.client.cards.revealed_cards_browser().len());
+    assert_eq!(7, session.client.cards.opponent_hand().len());
+}
+
 #[test]
 fn mulligan_legal_actions() {
     let (game_id, covenant_id, riftcaller_id) = test_helpers::generate_ids();
@@ -194,15 +236,101 @@ fn both_keep_opening_hands() {
     assert!(session.dusk());
 }
 
+#[test]
+fn choose_to_go_second() {
+    let (game_id, covenant_id, riftcaller_id) = test_helpers::generate_ids();
+    let mut session = TestSessionBuilder::new()
+        .game_id(game_id)
+        .user_id(covenant_id)
+        .opponent_id(riftcaller_id)
+        .do_not_connect(true)
+        .build();
+    initiate_game_with_options(
+        &mut session,
+        NewGameDebugOptions {
+            deterministic: true,
+            first_turn_choice: Some(FirstTurnChoice::Player(Side::Covenant)),
+            ..NewGameDebugOptions::default()
+        },
+    );
+
+    session.click_on(covenant_id, "Keep");
+    session.click_on(riftcaller_id, "Keep");
+    assert!(session.client.interface.controls().has_text("Go First"));
+    assert!(session.client.interface.controls().has_text("Go Second"));
+    assert!(session.legal_actions_result(Side::Riftcaller).is_err());
+
+    session.click_on(covenant_id, "Go Second");
+    assert!(session.dawn());
+    assert_eq!(None, session.query_game(|game| game.info.turn.previous()));
+    assert_eq!(0, session.client.this_player.actions());
+    assert_eq!(4, session.opponent.this_player.actions());
+    assert_eq!(5, session.client.cards.hand().len());
+
+    session.pass_turn(Side::Riftcaller);
+    assert!(session.dusk());
+    assert_eq!(
+        Some(TurnData { side: Side::Riftcaller, turn_number: 0 }),
+        session.query_game(|game| game.info.turn.previous())
+    );
+    assert_eq!(3, session.client.this_player.actions());
+    assert_eq!(6, session.client.cards.hand().len());
+}
+
+#[test]
+fn game_over_records_saved_deck() {
+    let (game_id, covenant_id, riftcaller_id) = test_helpers::generate_ids();
+    let mut session = TestSessionBuilder::new()
+        .game_id(game_id)
+        .user_id(covenant_id)
+        .opponent_id(riftcaller_id)
+        .saved_deck("Saved", decklists::canonical_deck(Side::Covenant))
+        .do_not_connect(true)
+        .build();
+    session.perform(
+        UserAction::NewGame(NewGameAction {
+            deck: RIFTCALLER_DECK,
+            opponent: session.user_id(),
+            debug_options: None,
+            tutorial: false,
+        })
+        .as_client_action(),
+        session.opponent_id(),
+    );
+    session.perform(
+        UserAction::NewGame(NewGameAction {
+            deck: NewGameDeck::DeckId(DeckId::Saved(0)),
+            opponent: session.opponent_id(),
+            debug_options: None,
+            tutorial: false,
+        })
+        .as_client_action(),
+        session.user_id(),
+    );
+    session.connect(session.user_id()).unwrap();
+
+    session.perform(UserAction::GameAction(GameAction::Resign).as_client_action(), covenant_id);
+    let history = session.query_player(|player| player.match_history.clone());
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].deck, Some(DeckId::Saved(0)));
+    let opponent_history = session.query_opponent_player(|player| player.match_history.clone());
+    assert_eq!(opponent_history.len(), 1);
+    assert_eq!(opponent_history[0].deck, None);
+}
+
 fn initiate_game(session: &mut TestSession) {
+    initiate_game_with_options(
+        session,
+        NewGameDebugOptions { deterministic: true, ..NewGameDebugOptions::default() },
+    );
+}
+
+fn initiate_game_with_options(session: &mut TestSession, debug_options: NewGameDebugOptions) {
     session.perform(
         UserAction::NewGame(NewGameAction {
             deck: RIFTCALLER_DECK,
             opponent: session.user_id(),
-            debug_options: Some(NewGameDebugOptions {
-                deterministic: true,
-                ..NewGameDebugOptions::default()
-            }),
+            debug_options: Some(debug_options),
             tutorial: false,
         })
         .as_client_action(),
@@ -212,10 +340,7 @@ fn initiate_game(session: &mut TestSession) {
         UserAction::NewGame(NewGameAction {
             deck: COVENANT_DECK,
             opponent: session.opponent_id(),
-            debug_options: Some(NewGameDebugOptions {
-                deterministic: true,
-                ..NewGameDebugOptions::default()
-            }),
+            debug_options: Some(debug_options),
             tutorial: false,
         })
         .as_client_action(),
@@ -225,3 +350,51 @@ fn initiate_game(session: &mut TestSession) {
     session.connect(session.user_id()).unwrap();
     session.connect(session.opponent_id()).unwrap();
 }
+
+#[test]
+fn card_art_override() {
+    let (game_id, covenant_id, riftcaller_id) = test_helpers::generate_ids();
+    let mut session = TestSessionBuilder::new()
+        .game_id(game_id)
+        .user_id(covenant_id)
+        .opponent_id(riftcaller_id)
+        .card_art_override(CardName::TestRitual, Sprite::new("Alternate/TestRitual.png"))
+        .do_not_connect(true)
+        .build();
+    initiate_game(&mut session);
+    let cards = session.client.cards.revealed_cards_browser();
+    assert_eq!(5, cards.len());
+    assert!(cards.iter().all(|c| c.image() == "Alternate/TestRitual.png"));
+}
+
+#[test]
+fn default_card_art() {
+    let (game_id, covenant_id, riftcaller_id) = test_helpers::generate_ids();
+    let mut session = TestSessionBuilder::new()
+        .game_id(game_id)
+        .user_id(covenant_id)
+        .opponent_id(riftcaller_id)
+        .do_not_connect(true)
+        .build();
+    initiate_game(&mut session);
+    let cards = session.client.cards.revealed_cards_browser();
+    assert_eq!(5, cards.len());
+    assert!(cards.iter().all(|c| c.image() == "Enixion/Fantasy Art Pack 2/Resized/3.png"));
+}
+
+#[test]
+fn custom_card_back() {
+    let (game_id, covenant_id, riftcaller_id) = test_helpers::generate_ids();
+    let mut session = TestSessionBuilder::new()
+        .game_id(game_id)
+        .user_id(covenant_id)
+        .opponent_id(riftcaller_id)
+        .card_back(Sprite::new("Alternate/CardBack.png"))
+        .do_not_connect(true)
+        .build();
+    initiate_game(&mut session);
+    assert_eq!(session.client.this_player.card_back(), "Alternate/CardBack.png");
+    assert_ne!(session.client.other_player.card_back(), "Alternate/CardBack.png");
+    assert_eq!(session.opponent.other_player.card_back(), "Alternate/CardBack.png");
+    assert_ne!(session.opponent.this_player.card_back(), "Alternate/CardBack.png");
+}
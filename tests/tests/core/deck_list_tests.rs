@@ -0,0 +1,104 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_data::game_primitives::{DeckId, Side};
+use core_ui::actions::InterfaceAction;
+use panel_address::PlayerPanel;
+use player_data::DeckSummary;
+use protos::riftcaller::client_action::Action;
+use protos::riftcaller::FetchPanelAction;
+use test_utils::client_interface::HasText;
+use test_utils::test_session_builder::TestSessionBuilder;
+use test_utils::*;
+use user_action_data::UserAction;
+
+#[test]
+fn list_saved_decks() {
+    let riftcaller = decklists::canonical_deck(Side::Riftcaller);
+    let covenant = decklists::canonical_deck(Side::Covenant);
+    let mut g = TestSessionBuilder::new()
+        .saved_deck("Alpha", riftcaller.clone())
+        .saved_deck("Beta", covenant.clone())
+        .build();
+
+    let decks = g.query_player(|player| player.decks());
+    assert_eq!(
+        decks,
+        vec![
+            (DeckId::Saved(0), DeckSummary::new("Alpha", &riftcaller)),
+            (DeckId::Saved(1), DeckSummary::new("Beta", &covenant)),
+        ]
+    );
+    assert_eq!(decks[0].1.side, Side::Riftcaller);
+    assert_eq!(decks[0].1.card_count, riftcaller.card_variants().len());
+    assert_eq!(decks[1].1.side, Side::Covenant);
+    assert_eq!(decks[1].1.card_count, covenant.card_variants().len());
+
+    g.perform(
+        Action::FetchPanel(FetchPanelAction { panel_address: Some(PlayerPanel::DeckList.into()) }),
+        g.user_id(),
+    );
+    let panel = g.client.interface.panel(PlayerPanel::DeckList).expect("Deck list panel");
+    assert!(panel.has_text("Alpha"));
+    assert!(panel.has_text("Beta"));
+    assert!(panel.has_text(format!("{} cards", riftcaller.card_variants().len())));
+}
+
+#[test]
+fn copy_deck() {
+    let riftcaller = decklists::canonical_deck(Side::Riftcaller);
+    let covenant = decklists::canonical_deck(Side::Covenant);
+    let mut g = TestSessionBuilder::new()
+        .saved_deck("Alpha", riftcaller.clone())
+        .saved_deck("Beta", covenant)
+        .build();
+    assert!(g
+        .client
+        .interface
+        .panel(PlayerPanel::DeckList)
+        .expect("Deck list panel")
+        .has_text("Duplicate"));
+
+    g.perform(UserAction::CopyDeck(DeckId::Saved(0)).as_client_action(), g.user_id());
+    let decks = g.query_player(|player| player.decks());
+    assert_eq!(decks.len(), 3);
+    let (copy_id, summary) = decks[2].clone();
+    assert_ne!(copy_id, DeckId::Saved(0));
+    assert_eq!(summary, DeckSummary::new("Alpha 2", &riftcaller));
+    assert_eq!(
+        g.query_player(|player| player.deck(copy_id).expect("Deck").cards.clone()),
+        riftcaller.cards
+    );
+    assert!(g.client.interface.panel(PlayerPanel::DeckList).expect("Panel").has_text("Alpha 2"));
+
+    g.perform(UserAction::CopyDeck(copy_id).as_client_action(), g.user_id());
+    g.perform(UserAction::CopyDeck(DeckId::Saved(0)).as_client_action(), g.user_id());
+    let names = g.query_player(|player| {
+        player.decks().into_iter().map(|(_, summary)| summary.name).collect::<Vec<_>>()
+    });
+    assert_eq!(names, vec!["Alpha", "Beta", "Alpha 2", "Alpha 3", "Alpha 4"]);
+}
+
+#[test]
+fn copy_deck_with_maximum_number() {
+    let name = format!("Alpha {}", u32::MAX);
+    let mut g = TestSessionBuilder::new()
+        .saved_deck(name.clone(), decklists::canonical_deck(Side::Riftcaller))
+        .build();
+    g.perform(UserAction::CopyDeck(DeckId::Saved(0)).as_client_action(), g.user_id());
+    let names = g.query_player(|player| {
+        player.decks().into_iter().map(|(_, summary)| summary.name).collect::<Vec<_>>()
+    });
+    assert_eq!(names, vec![name.clone(), format!("{name} 2")]);
+}
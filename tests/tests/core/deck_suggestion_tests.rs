@@ -0,0 +1,93 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_definition_data::cards;
+use core_data::game_primitives::{CardSubtype, Side};
+use deck_editor::deck;
+use deck_editor::deck::DeckIssue;
+use game_data::card_name::{CardName, CardVariant};
+use user_action_data::NamedDeck;
+
+#[test]
+fn suggest_additions_completes_deck() {
+    cards_all::initialize();
+    let complete = decklists::canonical_deck(Side::Riftcaller);
+    let target_size = complete.card_variants().len();
+    let mut partial = complete.clone();
+    let mut variants = partial.cards.keys().copied().collect::<Vec<_>>();
+    variants.sort();
+    for variant in variants.iter().skip(variants.len() / 2) {
+        partial.cards.remove(variant);
+    }
+    let partial_size = partial.card_variants().len();
+
+    let collection = cards::all_cards().map(|definition| definition.name).collect::<Vec<_>>();
+    let suggestions = deck::suggest_additions(&partial, &collection, target_size);
+
+    assert_eq!(partial_size + suggestions.len(), target_size);
+    assert!(suggestions
+        .iter()
+        .all(|name| cards::get(CardVariant::standard(*name)).side == Side::Riftcaller));
+}
+
+#[test]
+fn suggest_additions_limited_by_collection() {
+    cards_all::initialize();
+    let partial = decklists::named_deck(NamedDeck::EmptyRiftcaller);
+    let collection = vec![CardName::ArcaneRecovery, CardName::Conspire];
+    let suggestions = deck::suggest_additions(&partial, &collection, 10);
+    assert_eq!(suggestions, vec![CardName::ArcaneRecovery]);
+}
+
+#[test]
+fn canonical_decks_are_valid() {
+    cards_all::initialize();
+    for side in [Side::Riftcaller, Side::Covenant] {
+        let validation = deck::validate_with_warnings(&decklists::canonical_deck(side));
+        assert!(validation.is_legal());
+        assert!(validation.warnings.is_empty());
+    }
+}
+
+#[test]
+fn deck_without_weapons_is_legal_but_warned() {
+    cards_all::initialize();
+    let mut riftcaller = decklists::canonical_deck(Side::Riftcaller);
+    riftcaller
+        .cards
+        .retain(|variant, _| !cards::get(*variant).subtypes.contains(&CardSubtype::Weapon));
+    let validation = deck::validate_with_warnings(&riftcaller);
+    assert!(validation.is_legal());
+    assert!(deck::validate(&riftcaller).is_empty());
+    assert_eq!(validation.warnings, vec![DeckIssue::NoWeapons]);
+}
+
+#[test]
+fn deck_with_opposing_card_is_illegal() {
+    cards_all::initialize();
+    let mut riftcaller = decklists::canonical_deck(Side::Riftcaller);
+    let variant = CardVariant::standard(CardName::Conspire);
+    riftcaller.cards.insert(variant, 1);
+    assert_eq!(deck::validate(&riftcaller), vec![DeckIssue::WrongSide(variant)]);
+}
+
+#[test]
+fn suggest_additions_respects_copy_limit() {
+    cards_all::initialize();
+    let mut partial = decklists::named_deck(NamedDeck::EmptyRiftcaller);
+    partial.cards.insert(CardVariant::standard(CardName::ArcaneRecovery), 2);
+    let collection = vec![CardName::ArcaneRecovery; 3];
+    let suggestions = deck::suggest_additions(&partial, &collection, 10);
+    assert_eq!(suggestions, vec![CardName::ArcaneRecovery]);
+}
@@ -0,0 +1,95 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_data::game_primitives::Side;
+use game_data::card_name::CardName;
+use game_data::card_state::CardPosition;
+use test_utils::test_game::{TestGame, TestSide};
+use test_utils::*;
+
+#[test]
+fn reveal_top_card_draw_if_weapon() {
+    let mut g =
+        TestGame::new(TestSide::new(Side::Riftcaller).deck_top(CardName::TestMortalWeapon)).build();
+    g.create_and_play(CardName::TestSpellRevealTopCardDrawIfWeapon);
+    test_helpers::assert_cards_match(g.client.cards.hand(), vec![CardName::TestMortalWeapon]);
+    assert!(g.client.cards.deck_top().is_empty());
+}
+
+#[test]
+fn reveal_top_card_no_match() {
+    let mut g =
+        TestGame::new(TestSide::new(Side::Riftcaller).deck_top(CardName::TestSpell)).build();
+    g.create_and_play(CardName::TestSpellRevealTopCardDrawIfWeapon);
+    assert!(g.client.cards.hand().is_empty());
+    test_helpers::assert_cards_match(g.client.cards.deck_top(), vec![CardName::TestSpell]);
+    assert!(g.opponent.cards.opponent_deck_top()[0].revealed_to_me());
+}
+
+#[test]
+fn look_at_opponent_top_card() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant))
+        .opponent(TestSide::new(Side::Riftcaller).deck_top(CardName::TestSpell))
+        .build();
+    assert!(g.client.cards.opponent_deck_top().iter().all(|c| !c.revealed_to_me()));
+    g.create_and_play(CardName::TestRitualLookAtOpponentTopCard);
+    let top = g.client.cards.opponent_deck_top();
+    assert_eq!(top.len(), 1);
+    assert!(top[0].revealed_to_me());
+    assert_eq!(top[0].title(), "Test Spell");
+    assert!(!g.opponent.cards.get(top[0].id()).revealed_to_me());
+}
+
+#[test]
+fn dig_for_weapon() {
+    let mut g = TestGame::new(
+        TestSide::new(Side::Riftcaller)
+            .deck_top(CardName::TestMortalWeapon)
+            .deck_top(CardName::TestSpell)
+            .deck_top(CardName::Test0CostSpell),
+    )
+    .build();
+    g.create_and_play(CardName::TestSpellDig4ForWeapon);
+    test_helpers::assert_cards_match(g.client.cards.hand(), vec![CardName::TestMortalWeapon]);
+    assert!(g.client.cards.deck_top().is_empty());
+    let bottom = g.query_game(|game| {
+        game.card_list_for_position(Side::Riftcaller, CardPosition::DeckBottom(Side::Riftcaller))
+            .into_iter()
+            .map(|card_id| game.card(card_id).variant.name)
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(3, bottom.len());
+    assert_eq!(&[CardName::Test0CostSpell, CardName::TestSpell], &bottom[0..2]);
+}
+
+#[test]
+fn dig_for_weapon_no_match() {
+    let mut g = TestGame::new(
+        TestSide::new(Side::Riftcaller)
+            .deck_top(CardName::TestSpell)
+            .deck_top(CardName::Test0CostSpell),
+    )
+    .build();
+    g.create_and_play(CardName::TestSpellDig4ForWeapon);
+    assert!(g.client.cards.hand().is_empty());
+    assert!(g.client.cards.deck_top().is_empty());
+    let bottom = g.query_game(|game| {
+        game.card_list_for_position(Side::Riftcaller, CardPosition::DeckBottom(Side::Riftcaller))
+            .into_iter()
+            .map(|card_id| game.card(card_id).variant.name)
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(4, bottom.len());
+    assert_eq!(&[CardName::Test0CostSpell, CardName::TestSpell], &bottom[0..2]);
+}
@@ -0,0 +1,105 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_data::game_primitives::DeckId;
+use core_ui::draggable::Draggable;
+use core_ui::prelude::*;
+use protos::riftcaller::client_action::Action;
+use protos::riftcaller::element_selector::Selector;
+use protos::riftcaller::game_command::Command;
+use protos::riftcaller::interface_update::Update;
+use protos::riftcaller::{CommandList, DrawCardAction, FlexStyle};
+use test_utils::client_interface;
+use user_action_data::UserAction;
+
+#[test]
+fn card_drag_highlights_drop_targets() {
+    let node = Draggable::new("Card")
+        .drop_target(element_names::CARD_LIST)
+        .on_drop(Some(UserAction::CopyDeck(DeckId::Adventure)))
+        .build()
+        .expect("Node");
+    let draggable = client_interface::find_draggable(&node).expect("Draggable");
+    let targets = applied_styles(draggable.drag_detected_update.clone().expect("update"));
+    assert_eq!(targets.len(), 1);
+    assert_eq!(targets[0].0, String::from(element_names::CARD_LIST));
+    assert_eq!(targets[0].1.border_width.as_ref().map(|w| w.top), Some(4.0));
+}
+
+#[test]
+fn drag_end_clears_drop_target_highlights() {
+    let node = Draggable::new("Card")
+        .drop_target(element_names::CARD_LIST)
+        .on_drop(Some(UserAction::CopyDeck(DeckId::Adventure)))
+        .build()
+        .expect("Node");
+    let draggable = client_interface::find_draggable(&node).expect("Draggable");
+    let targets = applied_styles(draggable.drag_ended_update.clone().expect("update"));
+    assert_eq!(
+        targets,
+        vec![(String::from(element_names::CARD_LIST), FlexStyle::default())],
+        "Expected an empty style, which restores the element's original style"
+    );
+}
+
+#[test]
+fn highlight_preserves_drag_detected_action() {
+    let node = Draggable::new("Card")
+        .drop_target(element_names::CARD_LIST)
+        .on_drop(Some(UserAction::CopyDeck(DeckId::Adventure)))
+        .on_drag_detected(Some(Action::DrawCard(DrawCardAction {})))
+        .build()
+        .expect("Node");
+    let draggable = client_interface::find_draggable(&node).expect("Draggable");
+    assert_eq!(
+        draggable.on_drag_detected.as_ref().and_then(|a| a.action.clone()),
+        Some(Action::DrawCard(DrawCardAction {}))
+    );
+    assert!(draggable.drag_detected_update.is_some());
+}
+
+#[test]
+fn disabled_drag_does_not_highlight() {
+    let node = Draggable::new("Card")
+        .drop_target(element_names::CARD_LIST)
+        .on_drop(None::<UserAction>)
+        .build()
+        .expect("Node");
+    let draggable = client_interface::find_draggable(&node).expect("Draggable");
+    assert!(draggable.on_drag_detected.is_none());
+    assert!(draggable.drag_detected_update.is_none());
+    assert!(draggable.drag_ended_update.is_none());
+}
+
+/// Returns the name and applied style of each element styled by `update`.
+fn applied_styles(update: CommandList) -> Vec<(String, FlexStyle)> {
+    update
+        .commands
+        .into_iter()
+        .filter_map(|c| match c.command {
+            Some(Command::UpdateInterface(update)) => Some(update.steps),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|step| {
+            let Some(Selector::ElementName(name)) = step.element.and_then(|e| e.selector) else {
+                return None;
+            };
+            let Some(Update::ApplyStyle(style)) = step.update.and_then(|u| u.update) else {
+                return None;
+            };
+            Some((name, style))
+        })
+        .collect()
+}
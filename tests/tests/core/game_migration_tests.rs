@@ -0,0 +1,65 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_data::game_primitives::Side;
+use database::game_migration;
+use game_data::game_state::{GameState, GAME_STATE_VERSION};
+use game_data::undo_tracker::UndoTracker;
+use serde_json::Value;
+use test_utils::test_game::{TestGame, TestSide};
+use test_utils::test_helpers;
+
+#[test]
+fn migrate_unversioned_game() {
+    let game = new_game();
+    let mut raw = serde_json::to_value(&game).expect("Error serializing game");
+    raw.as_object_mut().expect("object").remove("version");
+
+    let migrated = game_migration::migrate(raw).expect("Error migrating game");
+    assert_eq!(migrated.version, GAME_STATE_VERSION);
+    assert_eq!(migrated.id, game.id);
+    assert_eq!(migrated.riftcaller_cards.len(), game.riftcaller_cards.len());
+}
+
+#[test]
+fn migrate_unversioned_game_with_undo_snapshot() {
+    let mut game = new_game();
+    game.undo_tracker = Some(UndoTracker { undo: Some(Box::new(new_game())) });
+    let mut raw = serde_json::to_value(&game).expect("Error serializing game");
+    raw.as_object_mut().expect("object").remove("version");
+    raw["undo_tracker"]["undo"].as_object_mut().expect("undo snapshot").remove("version");
+
+    let migrated = game_migration::migrate(raw).expect("Error migrating game");
+    let undo = migrated.undo_tracker.and_then(|tracker| tracker.undo).expect("undo snapshot");
+    assert_eq!(undo.version, GAME_STATE_VERSION);
+}
+
+#[test]
+fn migrate_unsupported_version() {
+    let game = new_game();
+    let mut raw = serde_json::to_value(&game).expect("Error serializing game");
+    raw.as_object_mut()
+        .expect("object")
+        .insert("version".to_string(), Value::from(GAME_STATE_VERSION + 1));
+    assert!(game_migration::migrate(raw).is_err());
+}
+
+fn new_game() -> GameState {
+    let (game_id, user_id, opponent_id) = test_helpers::generate_ids();
+    TestGame::new(TestSide::new(Side::Riftcaller)).build_game_state_internal(
+        game_id,
+        user_id,
+        opponent_id,
+    )
+}
@@ -12,10 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use core_data::game_primitives::Side;
+use std::time::SystemTime;
+
+use core_data::game_primitives::{GameId, Side};
 use core_ui::actions::InterfaceAction;
+use database::sled_database::SledDatabase;
+use database::Database;
+use game_data::card_name::CardName;
 use game_data::game_actions::GameAction;
 use insta::assert_snapshot;
+use player_data::{MatchRecord, PlayerState};
 use test_utils::summarize::Summary;
 use test_utils::test_game::{TestGame, TestSide};
 use test_utils::*;
@@ -41,6 +47,76 @@ fn leave_game() {
     assert_snapshot!(Summary::run(&response));
 }
 
+#[test]
+fn leave_game_records_match() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant)).build();
+    g.perform(UserAction::GameAction(GameAction::Resign).as_client_action(), g.user_id());
+    g.perform(UserAction::LeaveGame(GameOutcome::Defeat).as_client_action(), g.user_id());
+    let history = g.query_player(|player| player.match_history.clone());
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].game_id, g.game_id());
+    assert_eq!(history[0].opponent, g.opponent_id());
+    assert_eq!(history[0].side, Side::Covenant);
+    assert_eq!(history[0].outcome, GameOutcome::Defeat);
+    assert_eq!(history[0].deck, None);
+}
+
+#[test]
+fn game_over_records_match_for_both_players() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant)).build();
+    g.perform(UserAction::GameAction(GameAction::Resign).as_client_action(), g.user_id());
+    let history = g.query_player(|player| player.match_history.clone());
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].outcome, GameOutcome::Defeat);
+    let opponent_history = g.query_opponent_player(|player| player.match_history.clone());
+    assert_eq!(opponent_history.len(), 1);
+    assert_eq!(opponent_history[0].game_id, g.game_id());
+    assert_eq!(opponent_history[0].opponent, g.user_id());
+    assert_eq!(opponent_history[0].side, Side::Riftcaller);
+    assert_eq!(opponent_history[0].outcome, GameOutcome::Victory);
+
+    g.perform(UserAction::LeaveGame(GameOutcome::Defeat).as_client_action(), g.user_id());
+    assert_eq!(g.query_player(|player| player.match_history.len()), 1);
+}
+
+#[test]
+fn leave_game_records_actual_winner() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant)).build();
+    g.perform(UserAction::GameAction(GameAction::Resign).as_client_action(), g.user_id());
+    g.perform(UserAction::LeaveGame(GameOutcome::Victory).as_client_action(), g.user_id());
+    let history = g.query_player(|player| player.match_history.clone());
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].outcome, GameOutcome::Defeat);
+}
+
+#[test]
+fn match_history_persisted() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant)).build();
+    g.perform(UserAction::GameAction(GameAction::Resign).as_client_action(), g.user_id());
+    g.perform(UserAction::LeaveGame(GameOutcome::Defeat).as_client_action(), g.user_id());
+    let player = g.query_player(|player| player.clone());
+    let loaded = sled_round_trip(&player);
+    assert_eq!(loaded.match_history, player.match_history);
+}
+
+#[test]
+fn match_history_capped() {
+    let (_, user_id, opponent_id) = test_helpers::generate_ids();
+    let mut player = PlayerState::new(user_id);
+    for i in 0..=player_data::MAX_MATCH_HISTORY {
+        assert!(player.record_match(MatchRecord {
+            game_id: GameId::new_from_u128(i as u128),
+            opponent: opponent_id,
+            side: Side::Covenant,
+            outcome: GameOutcome::Victory,
+            deck: None,
+            timestamp: SystemTime::now(),
+        }));
+    }
+    assert_eq!(player.match_history.len(), player_data::MAX_MATCH_HISTORY);
+    assert_eq!(player.match_history[0].game_id, GameId::new_from_u128(1));
+}
+
 #[test]
 fn draw_all_covenant_cards() {
     let mut g = TestGame::new(TestSide::new(Side::Covenant)).deck_sizes(3).build();
@@ -59,6 +135,33 @@ fn draw_all_covenant_cards() {
     }
 }
 
+#[test]
+fn covenant_forced_draw_from_empty_deck() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant)).deck_sizes(1).build();
+    g.create_and_play(CardName::TestRitualDrawCard);
+    assert!(g.is_victory_for_player(Side::Riftcaller));
+}
+
+#[test]
+fn riftcaller_forced_draw_from_empty_deck() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).deck_sizes(1).build();
+    let id = g.create_and_play(CardName::TestSacrificeDrawCardArtifact);
+    g.activate_ability(id, 0);
+    assert!(g.client.cards.hand().real_cards().is_empty());
+    assert!(g.client.this_player.can_take_action());
+}
+
+#[tokio::main]
+async fn sled_round_trip(player: &PlayerState) -> PlayerState {
+    let path = std::env::temp_dir().join(format!("riftcaller_match_history_{}", player.id));
+    let database = SledDatabase::new(path.to_string_lossy());
+    database.write_player(player).await.expect("Error writing player");
+    let loaded = database.fetch_player(player.id).await.expect("Error fetching player");
+    drop(database);
+    std::fs::remove_dir_all(path).expect("Error removing database");
+    loaded.expect("Player not found")
+}
+
 // #[test]
 // fn win_game() {
 //     let position = TilePosition::new(1, 1);
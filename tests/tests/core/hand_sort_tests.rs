@@ -0,0 +1,80 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_data::game_primitives::Side;
+use core_ui::actions::InterfaceAction;
+use display::set_display_preference;
+use game_data::card_name::CardName;
+use game_data::game_actions::{DisplayPreference, GameAction};
+use game_data::game_state::HandSortOrder;
+use test_utils::test_game::{TestGame, TestSide};
+use test_utils::test_session::TestSession;
+use test_utils::*;
+use user_action_data::{DebugAction, UserAction};
+
+#[test]
+fn sort_hand_by_cost() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    g.add_to_hand(CardName::Test5CostSpell);
+    g.add_to_hand(CardName::Test0CostSpell);
+    g.add_to_hand(CardName::Test1CostSpell);
+    g.perform(
+        UserAction::GameAction(GameAction::SetDisplayPreference(DisplayPreference::SortHand(
+            Some(HandSortOrder::ByCost),
+        )))
+        .as_client_action(),
+        g.user_id(),
+    );
+
+    let mut hand = g.client.cards.hand();
+    hand.sort_by(|a, b| a.partial_cmp(b).expect("sorting key"));
+    assert_eq!(vec!["0", "1", "5"], hand.iter().map(|card| card.cost_icon()).collect::<Vec<_>>());
+}
+
+#[test]
+fn display_preference_button_cycles_hand_sort_order() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    let button = |g: &TestSession| {
+        g.query_game(|game| set_display_preference::button(game, Side::Riftcaller, None))
+    };
+    assert_eq!(Some(DisplayPreference::SortHand(Some(HandSortOrder::ByCost))), button(&g));
+
+    g.perform(
+        UserAction::GameAction(GameAction::SetDisplayPreference(DisplayPreference::SortHand(
+            Some(HandSortOrder::ByCost),
+        )))
+        .as_client_action(),
+        g.user_id(),
+    );
+    assert_eq!(Some(DisplayPreference::SortHand(Some(HandSortOrder::ByType))), button(&g));
+}
+
+#[test]
+fn undo_sort_hand() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    g.perform(
+        UserAction::GameAction(GameAction::SetDisplayPreference(DisplayPreference::SortHand(
+            Some(HandSortOrder::ByCost),
+        )))
+        .as_client_action(),
+        g.user_id(),
+    );
+    assert_eq!(
+        Some(HandSortOrder::ByCost),
+        g.query_game(|game| game.player(Side::Riftcaller).hand_sort_order)
+    );
+
+    g.perform(DebugAction::DebugUndo.as_client_action(), g.user_id());
+    assert_eq!(None, g.query_game(|game| game.player(Side::Riftcaller).hand_sort_order));
+}
@@ -12,7 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod ability_tests;
 mod action_tests;
+mod card_search_tests;
+mod command_compaction_tests;
+mod command_validator_tests;
+mod cost_tests;
 mod create_game_tests;
+mod deck_list_tests;
+mod deck_suggestion_tests;
+mod deck_tests;
+mod drag_and_drop_tests;
+mod game_migration_tests;
 mod game_over_tests;
+mod hand_sort_tests;
+mod panel_tests;
+mod polling_tests;
+mod prompt_tests;
+mod public_snapshot_tests;
 mod raid_tests;
+mod scoring_tests;
+mod toast_tests;
+mod tutorial_sequence_tests;
@@ -0,0 +1,48 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::panels;
+use panel_address::PlayerPanel;
+use protos::riftcaller::conditional_query::Query;
+use protos::riftcaller::element_selector::Selector;
+use protos::riftcaller::game_command::Command;
+use protos::riftcaller::toggle_panel_command::ToggleCommand;
+use protos::riftcaller::InterfacePanelAddress;
+
+#[test]
+fn ensure_open_is_conditional_on_element_existence() {
+    let Command::Conditional(conditional) =
+        panels::ensure_open(PlayerPanel::AdventureSlots, element_names::CARD_LIST)
+    else {
+        panic!("Expected conditional command");
+    };
+
+    let query = conditional.query.expect("query").query.expect("query");
+    assert!(matches!(
+        query,
+        Query::ElementExists(selector)
+            if selector.selector == Some(Selector::ElementName(element_names::CARD_LIST.into()))
+    ));
+    assert!(conditional.if_true.expect("if_true").commands.is_empty());
+    let if_false = conditional.if_false.expect("if_false").commands;
+    assert_eq!(if_false.len(), 1);
+    let Some(Command::TogglePanel(toggle)) = &if_false[0].command else {
+        panic!("Expected toggle panel command");
+    };
+    let Some(ToggleCommand::Transition(transition)) = &toggle.toggle_command else {
+        panic!("Expected transition");
+    };
+    assert_eq!(transition.open, Some(InterfacePanelAddress::from(PlayerPanel::AdventureSlots)));
+    assert_eq!(transition.close, None);
+}
@@ -0,0 +1,43 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_data::game_primitives::Side;
+use game_data::player_name::{AIPlayer, PlayerId};
+use test_utils::test_game::{TestGame, TestSide};
+use test_utils::test_session_builder::TestSessionBuilder;
+use test_utils::*;
+
+#[test]
+fn poll_for_ai_turn() {
+    let mut g = TestSessionBuilder::new()
+        .game(TestGame::new(TestSide::new(Side::Riftcaller)))
+        .opponent_id(PlayerId::AI(AIPlayer::TestAlphaBetaScores))
+        .do_not_connect(true)
+        .build();
+    g.connect_for_polling(g.user_id()).expect("Connection failed");
+    assert!(g.poll().expect("Poll failed").is_none());
+
+    g.move_to_end_step(Side::Riftcaller);
+
+    let mut updates = 0;
+    while g.poll().expect("Poll failed").is_some() {
+        updates += 1;
+    }
+    assert!(updates > 0);
+    assert_eq!(Side::Riftcaller, g.query_game(|game| game.info.turn.side));
+    assert_eq!(
+        g.client.other_player.mana(),
+        g.query_game(|game| game.covenant.mana_state.base_mana)
+    );
+}
@@ -0,0 +1,213 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_data::game_primitives::{AbilityId, Side};
+use core_ui::actions::InterfaceAction;
+use game_data::card_name::CardName;
+use game_data::card_state::CardCounter;
+use game_data::game_actions::GameAction;
+use game_data::prompt_data::PromptAction;
+use protos::riftcaller::client_debug_command::DebugCommand;
+use protos::riftcaller::game_command::Command;
+use protos::riftcaller::ClientDebugCommand;
+use rules::prompts;
+use test_utils::test_game::{TestGame, TestSide};
+use test_utils::test_session::TestSession;
+use test_utils::*;
+use user_action_data::{DebugAction, UserAction};
+
+#[test]
+fn distribute_cannot_over_allocate() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    let weapon = g.create_and_play(CardName::TestWeapon2Attack);
+    g.create_and_play(CardName::TestSpellDistributePowerCharges);
+    let card_id = test_helpers::server_card_id(weapon);
+    for _ in 0..3 {
+        prompt_action(&mut g, PromptAction::DistributeAllocate(card_id)).expect("Allocate");
+    }
+    test_helpers::assert_error(prompt_action(&mut g, PromptAction::DistributeAllocate(card_id)));
+}
+
+#[test]
+fn distribute_power_charges() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    let first = test_helpers::server_card_id(g.create_and_play(CardName::TestWeapon2Attack));
+    let second = test_helpers::server_card_id(g.create_and_play(CardName::TestWeapon2Attack));
+    g.create_and_play(CardName::TestSpellDistributePowerCharges);
+    prompt_action(&mut g, PromptAction::DistributeAllocate(first)).expect("Allocate");
+    test_helpers::assert_error(prompt_action(&mut g, PromptAction::DistributeSubmit));
+    prompt_action(&mut g, PromptAction::DistributeAllocate(first)).expect("Allocate");
+    prompt_action(&mut g, PromptAction::DistributeAllocate(second)).expect("Allocate");
+    prompt_action(&mut g, PromptAction::DistributeSubmit).expect("Submit");
+    assert_eq!(2, g.query_game(|game| game.card(first).counters(CardCounter::PowerCharges)));
+    assert_eq!(1, g.query_game(|game| game.card(second).counters(CardCounter::PowerCharges)));
+}
+
+#[test]
+fn pending_abilities_in_resolution_order() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant)).build();
+    let id = g.create_and_play(CardName::TestRitualTwoPromptTriggers);
+    let card_id = test_helpers::server_card_id(id);
+    let abilities = g.query_game(|game| prompts::pending_abilities(game, Side::Covenant));
+    assert_eq!(
+        abilities,
+        vec![AbilityId::new(card_id, 1), AbilityId::new(card_id, 0)],
+        "Most recently queued ability resolves first"
+    );
+    g.click(Button::NoPromptAction);
+    let abilities = g.query_game(|game| prompts::pending_abilities(game, Side::Covenant));
+    assert_eq!(abilities, vec![AbilityId::new(card_id, 0)]);
+    g.click(Button::NoPromptAction);
+    assert!(g.query_game(|game| prompts::pending_abilities(game, Side::Covenant)).is_empty());
+}
+
+#[test]
+fn log_pending_abilities() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant)).build();
+    g.create_and_play(CardName::TestRitualTwoPromptTriggers);
+    let response = g
+        .perform_action(DebugAction::LogPendingAbilities.as_client_action(), g.user_id())
+        .expect("Request failed");
+    let messages = response
+        .user_response
+        .commands
+        .iter()
+        .filter_map(|c| match c.command.as_ref() {
+            Some(Command::Debug(ClientDebugCommand {
+                debug_command: Some(DebugCommand::LogMessage(message)),
+            })) => Some(message.text.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec![
+            "Pending abilities for Covenant: Test Ritual Two Prompt Triggers (ability 1), \
+            Test Ritual Two Prompt Triggers (ability 0)"
+        ]
+    );
+}
+
+#[test]
+fn destroy_up_to_two_artifacts() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant)).current_turn(Side::Riftcaller).build();
+    let weapons = (0..3)
+        .map(|_| test_helpers::server_card_id(g.create_and_play(CardName::TestWeapon2Attack)))
+        .collect::<Vec<_>>();
+    g.pass_turn(Side::Riftcaller);
+    g.create_and_play(CardName::TestRitualDestroyUpToTwoArtifacts);
+    prompt_action(&mut g, PromptAction::ChooseMultipleToggle(weapons[0])).expect("Select");
+    prompt_action(&mut g, PromptAction::ChooseMultipleToggle(weapons[1])).expect("Select");
+    prompt_action(&mut g, PromptAction::ChooseMultipleSubmit).expect("Submit");
+    g.query_game(|game| {
+        assert!(game.card(weapons[0]).position().in_discard_pile());
+        assert!(game.card(weapons[1]).position().in_discard_pile());
+        assert!(game.card(weapons[2]).position().in_play());
+    });
+}
+
+#[test]
+fn cannot_select_more_than_allowed() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant)).current_turn(Side::Riftcaller).build();
+    let weapons = (0..3)
+        .map(|_| test_helpers::server_card_id(g.create_and_play(CardName::TestWeapon2Attack)))
+        .collect::<Vec<_>>();
+    g.pass_turn(Side::Riftcaller);
+    g.create_and_play(CardName::TestRitualDestroyUpToTwoArtifacts);
+    prompt_action(&mut g, PromptAction::ChooseMultipleToggle(weapons[0])).expect("Select");
+    prompt_action(&mut g, PromptAction::ChooseMultipleToggle(weapons[1])).expect("Select");
+    test_helpers::assert_error(prompt_action(
+        &mut g,
+        PromptAction::ChooseMultipleToggle(weapons[2]),
+    ));
+}
+
+#[test]
+fn return_chosen_card_from_discard() {
+    let mut g = TestGame::new(
+        TestSide::new(Side::Riftcaller)
+            .in_discard_face_up(CardName::TestSpell)
+            .in_discard_face_up(CardName::TestWeapon2Attack),
+    )
+    .build();
+    let weapon = g.client.cards.discard_pile().find_card(CardName::TestWeapon2Attack).id();
+    g.create_and_play(CardName::TestSpellReturnCardFromDiscard);
+    g.click_card_button(g.user_id(), weapon, Button::ReturnToHand);
+    assert_eq!(g.client.cards.hand().find_card(CardName::TestWeapon2Attack).id(), weapon);
+    assert!(g.client.cards.discard_pile().iter().any(|c| c.title() == "Test Spell"));
+}
+
+#[test]
+fn banish_chosen_card_from_opponent_discard() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant))
+        .opponent(
+            TestSide::new(Side::Riftcaller)
+                .in_discard_face_up(CardName::TestSpell)
+                .in_discard_face_up(CardName::TestWeapon2Attack)
+                .in_hand(CardName::TestSpellReturnCardFromDiscard),
+        )
+        .build();
+    let weapon = g.client.cards.opponent_discard_pile().find_card(CardName::TestWeapon2Attack).id();
+    let test_spell = g.client.cards.opponent_discard_pile().find_card(CardName::TestSpell).id();
+    let spell = g.client.cards.opponent_hand()[0].id();
+    g.create_and_play(CardName::TestRitualBanishFromOpponentDiscard);
+    g.click_card_button(g.user_id(), weapon, Button::Banish);
+    assert!(g.client.cards.opponent_discard_pile().iter().all(|c| c.id() != weapon));
+    assert!(g.opponent.cards.discard_pile().iter().all(|c| c.id() != weapon));
+
+    g.pass_turn(Side::Covenant);
+    g.play_card(spell, g.opponent_id(), None);
+    g.click_card_button(g.opponent_id(), test_spell, Button::ReturnToHand);
+    assert!(g.opponent.cards.hand().iter().any(|c| c.id() == test_spell));
+    assert!(g.opponent.cards.hand().iter().all(|c| c.id() != weapon));
+    assert!(g.opponent.cards.discard_pile().iter().all(|c| c.id() != weapon));
+}
+
+#[test]
+fn prompt_stack_depth_limit() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    let id = g.add_to_hand(CardName::TestSpellRepeatedlyPushPrompts);
+    test_helpers::assert_error(g.play_card_with_result(id, g.user_id(), None));
+}
+
+#[test]
+fn continue_only_prompt_skipped() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller).mana(0)).actions(3).build();
+    let hand_size = g.client.cards.hand().real_cards().len();
+    g.create_and_play(CardName::TestSpellOptionalPayToDraw);
+    assert!(!g.has(Button::NoPromptAction));
+    assert!(g.query_game(|game| prompts::current(game, Side::Riftcaller).is_none()));
+    assert!(g.client.cards.discard_pile().contains_card(CardName::TestSpellOptionalPayToDraw));
+    assert_eq!(2, g.me().actions());
+    g.draw_card();
+    assert_eq!(hand_size + 1, g.client.cards.hand().real_cards().len());
+}
+
+#[test]
+fn optional_payment_prompt_shown() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller).mana(3)).build();
+    g.create_and_play(CardName::TestSpellOptionalPayToDraw);
+    assert!(g.has(Button::NoPromptAction));
+    g.click(Button::NoPromptAction);
+    assert_eq!(3, g.me().mana());
+}
+
+fn prompt_action(g: &mut TestSession, action: PromptAction) -> anyhow::Result<()> {
+    let user_id = g.user_id();
+    g.perform_action(
+        UserAction::GameAction(GameAction::PromptAction(action)).as_client_action(),
+        user_id,
+    )
+    .map(|_| ())
+}
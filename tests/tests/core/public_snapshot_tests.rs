@@ -0,0 +1,52 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_data::game_primitives::Side;
+use game_data::card_name::CardName;
+use test_utils::test_game::{TestGame, TestSide};
+use test_utils::TestSessionHelpers;
+
+#[test]
+fn face_down_minion_hidden_from_riftcaller() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant)).build();
+    g.create_and_play(CardName::TestMinionEndRaid);
+    let snapshot = g.query_game(|game| game.public_snapshot(Side::Riftcaller));
+    assert!(snapshot.cards.iter().any(|c| c.position.is_defender_of(test_constants::ROOM_ID)));
+    assert!(snapshot
+        .cards
+        .iter()
+        .all(|c| c.variant.map(|v| v.name) != Some(CardName::TestMinionEndRaid)));
+}
+
+#[test]
+fn face_down_minion_visible_to_covenant() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant)).build();
+    g.create_and_play(CardName::TestMinionEndRaid);
+    let snapshot = g.query_game(|game| game.public_snapshot(Side::Covenant));
+    assert!(snapshot
+        .cards
+        .iter()
+        .any(|c| c.variant.map(|v| v.name) == Some(CardName::TestMinionEndRaid)));
+}
+
+#[test]
+fn deck_order_hidden() {
+    let g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    let snapshot = g.query_game(|game| game.public_snapshot(Side::Riftcaller));
+    assert!(snapshot
+        .cards
+        .iter()
+        .filter(|c| c.position.in_deck())
+        .all(|c| c.sorting_key.is_none() && c.variant.is_none()));
+}
@@ -12,12 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use core_data::game_primitives::{RoomId, Side};
+use card_helpers::history::AccessResult;
+use card_helpers::{history, raids};
+use core_data::game_primitives::{Resonance, RoomId, RoomLocation, Side};
+use core_ui::actions::InterfaceAction;
 use core_ui::icons;
-use game_data::card_name::CardName;
+use game_data::card_name::{CardName, CardVariant};
+use game_data::card_state::{CardCounter, CardPosition};
 use game_data::game_actions::{GameAction, RaidAction};
 use insta::assert_snapshot;
 use protos::riftcaller::client_action::Action;
+use protos::riftcaller::game_command::Command;
 use protos::riftcaller::game_object_identifier::Id;
 use protos::riftcaller::object_position::Position;
 use protos::riftcaller::{
@@ -25,10 +30,12 @@ use protos::riftcaller::{
     ObjectPositionCharacter, ObjectPositionCharacterContainer, ObjectPositionDiscardPile,
     ObjectPositionRaid, ObjectPositionRoom, PlayerName,
 };
+use rules::queries;
 use test_utils::client_interface::HasText;
 use test_utils::summarize::Summary;
 use test_utils::test_game::{TestGame, TestSide};
 use test_utils::*;
+use user_action_data::DebugAction;
 
 #[test]
 fn initiate_raid() {
@@ -651,3 +658,509 @@ fn raid_add_defender() {
     let response = g.click_on(g.user_id(), "Test Weapon");
     assert_snapshot!(Summary::summarize(&response));
 }
+
+#[test]
+fn weapon_cost_to_defeat_matching_resonance() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    let weapon_id = g.create_and_play(CardName::TestWeapon3Attack12Boost3Cost);
+    let (_, minion_id) = g.setup_raid_target(CardName::TestMinionEndRaid);
+    let cost = g.query_game(|game| {
+        queries::weapon_cost_to_defeat(
+            game,
+            test_helpers::server_card_id(weapon_id),
+            test_helpers::server_card_id(minion_id),
+        )
+    });
+    assert_eq!(cost, Some(1));
+}
+
+#[test]
+fn weapon_cost_to_defeat_includes_shield() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    let weapon_id = g.create_and_play(CardName::TestAstralWeapon);
+    let (_, minion_id) = g.setup_raid_target(CardName::TestMinionShield2Astral);
+    let cost = g.query_game(|game| {
+        queries::weapon_cost_to_defeat(
+            game,
+            test_helpers::server_card_id(weapon_id),
+            test_helpers::server_card_id(minion_id),
+        )
+    });
+    assert_eq!(cost, Some(3));
+}
+
+#[test]
+fn weapon_cost_to_defeat_mismatched_resonance() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    let weapon_id = g.create_and_play(CardName::TestWeapon3Attack12Boost3Cost);
+    let (_, minion_id) = g.setup_raid_target(CardName::TestMinionShield2Astral);
+    let cost = g.query_game(|game| {
+        queries::weapon_cost_to_defeat(
+            game,
+            test_helpers::server_card_id(weapon_id),
+            test_helpers::server_card_id(minion_id),
+        )
+    });
+    assert_eq!(cost, None);
+}
+
+#[test]
+fn universal_weapon_defeats_any_resonance() {
+    for minion in
+        [CardName::TestMortalMinion, CardName::TestAstralMinion, CardName::TestInfernalMinion]
+    {
+        let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+        let weapon_id = g.create_and_play(CardName::TestUniversalWeapon);
+        let (_, minion_id) = g.setup_raid_target(minion);
+        let cost = g.query_game(|game| {
+            queries::weapon_cost_to_defeat(
+                game,
+                test_helpers::server_card_id(weapon_id),
+                test_helpers::server_card_id(minion_id),
+            )
+        });
+        assert_eq!(cost, Some(0));
+    }
+}
+
+#[test]
+fn prismatic_minion_requires_every_resonance() {
+    for (weapon, expected) in
+        [(CardName::TestAstralWeapon, None), (CardName::TestUniversalWeapon, Some(0))]
+    {
+        let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+        let weapon_id = g.create_and_play(weapon);
+        let (_, minion_id) = g.setup_raid_target(CardName::TestPrismaticMinion);
+        let cost = g.query_game(|game| {
+            queries::weapon_cost_to_defeat(
+                game,
+                test_helpers::server_card_id(weapon_id),
+                test_helpers::server_card_id(minion_id),
+            )
+        });
+        assert_eq!(cost, expected, "{weapon:?}");
+    }
+}
+
+#[test]
+fn universal_weapon_is_not_prismatic() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    let weapon_id = g.create_and_play(CardName::TestUniversalWeapon);
+    let (_, minion_id) = g.setup_raid_target(CardName::TestPrismaticMinion);
+    let weapon = test_helpers::server_card_id(weapon_id);
+    let resonance = g.query_game(|game| queries::resonance(game, weapon));
+    assert!(!resonance.contains(Resonance::Prismatic));
+    let cost = g.query_game(|game| {
+        queries::weapon_cost_to_defeat(game, weapon, test_helpers::server_card_id(minion_id))
+    });
+    assert_eq!(cost, Some(0));
+}
+
+#[test]
+fn pay_on_access() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller).hand_size(1))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .face_up_room_occupant(RoomId::RoomA, CardName::TestProjectPayOrDiscardOnAccess),
+        )
+        .build();
+    g.initiate_raid(RoomId::RoomA);
+    g.click(Button::Pay);
+    assert_eq!(g.me().mana(), test_constants::STARTING_MANA - 2);
+    assert_eq!(g.client.cards.hand().len(), 1);
+    assert!(g.client.cards.discard_pile().is_empty());
+}
+
+#[test]
+fn discard_on_access() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller).hand_size(1))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .face_up_room_occupant(RoomId::RoomA, CardName::TestProjectPayOrDiscardOnAccess),
+        )
+        .build();
+    g.initiate_raid(RoomId::RoomA);
+    g.click(Button::Discard);
+    assert_eq!(g.me().mana(), test_constants::STARTING_MANA);
+    assert!(g.client.cards.hand().is_empty());
+    assert_eq!(g.client.cards.discard_pile().len(), 1);
+}
+
+#[test]
+fn reveal_hand_on_access_with_weapon() {
+    let mut g =
+        TestGame::new(TestSide::new(Side::Riftcaller))
+            .opponent(TestSide::new(Side::Covenant).face_up_room_occupant(
+                RoomId::RoomA,
+                CardName::TestProjectEndRaidUnlessWeaponRevealed,
+            ))
+            .build();
+    g.add_to_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    g.initiate_raid(RoomId::RoomA);
+    assert!(g.client.data.raid_active());
+    assert!(g.opponent.cards.opponent_hand().iter().all(|card| card.revealed_to_me()));
+}
+
+#[test]
+fn reveal_hand_on_access_without_weapon() {
+    let mut g =
+        TestGame::new(TestSide::new(Side::Riftcaller))
+            .opponent(TestSide::new(Side::Covenant).face_up_room_occupant(
+                RoomId::RoomA,
+                CardName::TestProjectEndRaidUnlessWeaponRevealed,
+            ))
+            .build();
+    g.add_to_hand(CardName::TestSpell);
+    g.initiate_raid(RoomId::RoomA);
+    assert!(!g.client.data.raid_active());
+    assert!(g.opponent.cards.opponent_hand().iter().all(|card| card.revealed_to_me()));
+}
+
+#[test]
+fn room_access_result_counts_scored_and_razed() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .room_occupant(RoomId::RoomA, CardName::TestScheme3_10)
+                .room_occupant(RoomId::RoomA, CardName::TestDuskboundProject),
+        )
+        .build();
+    g.initiate_raid(RoomId::RoomA);
+    g.click(Button::Score);
+    g.click(Button::Destroy);
+    let result = g.query_game(|game| {
+        history::room_access_result(game, game.raid.as_ref().unwrap().room_access_id.unwrap())
+    });
+    assert_eq!(AccessResult { accessed: 2, razed: 1, scored: 1 }, result);
+}
+
+#[test]
+fn current_encounter_context() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .face_up_defender(RoomId::Vault, CardName::TestMinionEndRaid)
+                .face_up_defender(RoomId::Vault, CardName::TestMinionDealDamage),
+        )
+        .build();
+    assert!(g.query_game(|game| raids::current_encounter(game).is_none()));
+    g.initiate_raid(RoomId::Vault);
+    let (context, raid_id, defenders) = g.query_game(|game| {
+        (
+            raids::current_encounter(game).expect("Encounter"),
+            game.raid.as_ref().unwrap().raid_id,
+            game.defender_list(RoomId::Vault),
+        )
+    });
+    assert_eq!(raid_id, context.raid_id);
+    assert_eq!(RoomId::Vault, context.target);
+    assert_eq!(1, context.defender_index);
+    assert_eq!(defenders[1], context.minion_id);
+    assert!(context.minion_encounter_id.is_some());
+}
+
+#[test]
+fn minion_summoned_from_discard_defends() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .current_turn(Side::Covenant)
+        .actions(1)
+        .opponent(TestSide::new(Side::Covenant).in_discard_face_up(CardName::TestMinionEndRaid))
+        .build();
+    g.create_and_play_with_target(CardName::TestRitualSummonMinionFromDiscard, RoomId::Vault);
+    g.pass_turn(Side::Covenant);
+
+    g.initiate_raid(RoomId::Vault);
+    assert_eq!(
+        Some(CardName::TestMinionEndRaid),
+        g.query_game(|game| raids::current_encounter(game)
+            .map(|encounter| game.card(encounter.minion_id).variant.name))
+    );
+    g.click(Button::NoWeapon);
+    assert!(!g.client.data.raid_active());
+}
+
+#[test]
+fn no_usable_weapon_offers_end_raid() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    g.perform(
+        DebugAction::AddToZone {
+            variant: CardVariant::standard(CardName::TutorialDisableRaidContinue),
+            position: CardPosition::GameModifier,
+            turn_face_up: true,
+        }
+        .as_client_action(),
+        g.user_id(),
+    );
+    g.setup_raid_target(CardName::TestMinionEndRaid);
+    g.initiate_raid(test_constants::ROOM_ID);
+    g.opponent_click(Button::Summon);
+
+    assert_eq!(
+        g.legal_actions(Side::Riftcaller),
+        vec![GameAction::RaidAction(RaidAction { index: 0 })]
+    );
+    assert!(g.client.data.toast().contains("no weapon"));
+    g.click(Button::EndRaid);
+    assert!(!g.client.data.raid_active());
+}
+
+#[test]
+fn access_limited_to_one_card() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .room_occupant(RoomId::RoomA, CardName::TestScheme3_10)
+                .face_up_room_occupant(RoomId::RoomA, CardName::TestProjectLimitAccessToOne),
+        )
+        .build();
+    g.initiate_raid(RoomId::RoomA);
+    assert!(g.has(Button::Score));
+    assert!(g.has(Button::Destroy));
+    g.click(Button::Score);
+    assert!(!g.has(Button::Destroy));
+    g.click(Button::EndRaid);
+    assert!(!g.client.data.raid_active());
+    assert_eq!(g.me().score(), 10);
+}
+
+#[test]
+fn weapon_usable_once_per_raid() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .room_occupant(RoomId::RoomA, CardName::TestScheme3_10)
+                .face_up_defender(RoomId::RoomA, CardName::TestInfernalMinion)
+                .face_up_defender(RoomId::RoomA, CardName::TestInfernalMinion),
+        )
+        .build();
+    g.create_and_play(CardName::TestWeaponOncePerRaid);
+    g.initiate_raid(RoomId::RoomA);
+    let weapon = CardName::TestWeaponOncePerRaid.displayed_name();
+    assert!(g.client.interface.controls().has_text(&weapon));
+    g.click_on(g.user_id(), &weapon);
+    assert!(g.client.data.raid_active());
+    assert!(!g.client.interface.controls().has_text(&weapon));
+    assert!(g.has(Button::NoWeapon));
+}
+
+#[test]
+fn minion_banished_until_end_of_turn() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .room_occupant(RoomId::RoomA, CardName::TestScheme3_10)
+                .face_up_defender(RoomId::RoomA, CardName::TestMinionBanishUntilEndOfTurn),
+        )
+        .build();
+    let minion_id =
+        g.query_game(|game| game.defenders_unordered(RoomId::RoomA).next().expect("Minion").id);
+    let play_id = g.query_game(|game| game.card(minion_id).last_card_play_id);
+    g.initiate_raid(RoomId::RoomA);
+    g.click(Button::NoWeapon);
+    assert!(!g.client.data.raid_active());
+    assert!(g.query_game(|game| game.card(minion_id).position().is_banished()));
+
+    g.pass_turn(Side::Riftcaller);
+    assert!(g.dusk());
+    assert!(g.query_game(|game| game.card(minion_id).position().is_banished()));
+
+    g.pass_turn(Side::Covenant);
+    assert!(g.dawn());
+    let position = g.query_game(|game| game.card(minion_id).position());
+    assert!(matches!(position, CardPosition::Room(_, RoomId::RoomA, RoomLocation::Defender)));
+    assert_ne!(position.card_play_id(), play_id);
+    assert!(g.query_game(|game| game.card(minion_id).is_face_up()));
+}
+
+#[test]
+fn turn_mana_on_vault_access() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    g.create_and_play(CardName::TestArtifactTurnManaOnVaultAccess);
+    let mana = test_constants::STARTING_MANA - test_constants::ARTIFACT_COST;
+    assert_eq!(g.me().mana(), mana);
+
+    g.initiate_raid(RoomId::Vault);
+    assert_eq!(g.me().mana(), mana);
+    assert_eq!(g.me().bonus_mana(), 2);
+    g.click(Button::EndRaid);
+    assert_eq!(g.me().bonus_mana(), 2);
+
+    // Turn mana is spent before base mana
+    g.create_and_play(CardName::TestSacrificeDrawTwoArtifact);
+    assert_eq!(g.me().mana(), mana);
+    assert_eq!(g.me().bonus_mana(), 2 - test_constants::ARTIFACT_COST);
+
+    g.pass_turn(Side::Riftcaller);
+    assert_eq!(g.me().mana(), mana);
+    assert_eq!(g.me().bonus_mana(), 0);
+}
+
+#[test]
+fn minion_encounter_ability_fires_each_encounter() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller).hand_size(5))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .face_up_defender(RoomId::Vault, CardName::TestMinionDamageOnEncounter),
+        )
+        .build();
+    g.initiate_raid(RoomId::Vault);
+    assert_eq!(g.client.cards.hand().len(), 4);
+    g.click(Button::NoWeapon);
+    g.click(Button::EndRaid);
+    assert_eq!(g.client.cards.hand().len(), 4);
+
+    g.initiate_raid(RoomId::Vault);
+    assert_eq!(g.client.cards.hand().len(), 3);
+    g.click(Button::NoWeapon);
+    g.click(Button::EndRaid);
+    assert_eq!(g.client.cards.hand().len(), 3);
+}
+
+#[test]
+fn weapon_without_projectile_uses_fallback() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    g.create_and_play(CardName::TestWeapon3Attack12Boost3Cost);
+    g.setup_raid_target(CardName::TestMinionEndRaid);
+    g.initiate_raid(test_constants::ROOM_ID);
+    g.opponent_click(Button::Summon);
+
+    let response = g.click_on(g.user_id(), "Test Weapon");
+    let projectiles = response
+        .user_response
+        .commands
+        .iter()
+        .filter_map(|c| match c.command.as_ref() {
+            Some(Command::FireProjectile(fire)) => Some(fire.projectile.clone()?.address),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(projectiles, vec!["HovlStudio/Projectiles1/Projectile 3.prefab"]);
+}
+
+#[test]
+fn access_top_two_vault_cards() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .deck_top(CardName::TestScheme3_10)
+                .deck_top(CardName::TestScheme3_10),
+        )
+        .build();
+    g.create_and_play(CardName::TestSpellRaidVaultAccessTopTwo);
+    assert_eq!(g.client.cards.browser().len(), 2);
+    g.click(Button::Score);
+    g.click(Button::Score);
+    g.click(Button::EndRaid);
+    assert_eq!(g.me().score(), 20);
+}
+
+#[test]
+fn minion_moves_to_another_room_when_not_defeated() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .room_occupant(RoomId::RoomA, CardName::TestScheme3_10)
+                .room_occupant(RoomId::RoomB, CardName::TestScheme3_10)
+                .face_up_defender(RoomId::RoomA, CardName::TestMinionMoveOnEncounter),
+        )
+        .build();
+    let minion_id =
+        g.query_game(|game| game.defenders_unordered(RoomId::RoomA).next().expect("Minion").id);
+    g.initiate_raid(RoomId::RoomA);
+    g.click(Button::NoWeapon);
+    assert!(g.query_game(|game| game.card(minion_id).position().is_defender_of(RoomId::RoomB)));
+    assert!(g.client.interface.controls().has_text("Score"));
+    g.click(Button::Score);
+    g.click(Button::EndRaid);
+
+    // Minion now defends its new room
+    g.initiate_raid(RoomId::RoomB);
+    assert!(g.has(Button::NoWeapon));
+    g.click(Button::NoWeapon);
+    assert!(g.query_game(|game| game.card(minion_id).position().is_defender_of(RoomId::RoomA)));
+}
+
+#[test]
+fn minion_health_rises_with_other_summoned_minions() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .room_occupant(RoomId::RoomA, CardName::TestScheme3_10)
+                .room_occupant(RoomId::RoomB, CardName::TestScheme3_10)
+                .face_up_defender(RoomId::RoomA, CardName::TestMinionHealthPerOtherSummonedMinion)
+                .face_down_defender(RoomId::RoomA, CardName::TestMinionMoveOnEncounter),
+        )
+        .build();
+    let anthem_id = g.query_game(|game| {
+        game.defenders_unordered(RoomId::RoomA)
+            .find(|c| c.variant.name == CardName::TestMinionHealthPerOtherSummonedMinion)
+            .expect("Minion")
+            .id
+    });
+    let health = test_constants::MINION_HEALTH;
+    assert_eq!(g.query_game(|game| queries::health(game, anthem_id)), health);
+    g.initiate_raid(RoomId::RoomA);
+    g.opponent_click(Button::Summon);
+    assert_eq!(g.query_game(|game| queries::health(game, anthem_id)), health + 1);
+    g.click(Button::NoWeapon);
+    assert_eq!(g.query_game(|game| queries::health(game, anthem_id)), health);
+}
+
+#[test]
+fn first_raid_each_turn_free() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).actions(4).build();
+    g.create_and_play(CardName::TestEvocationFirstRaidEachTurnFree);
+    assert_eq!(g.me().actions(), 3);
+    g.initiate_raid(RoomId::Vault);
+    g.click(Button::EndRaid);
+    assert_eq!(g.me().actions(), 3);
+    g.initiate_raid(RoomId::Vault);
+    g.click(Button::EndRaid);
+    assert_eq!(g.me().actions(), 2);
+}
+
+#[test]
+fn first_raid_each_turn_free_after_card_raid() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .actions(4)
+        .opponent(TestSide::new(Side::Covenant).deck_top(CardName::TestScheme3_10))
+        .build();
+    g.create_and_play(CardName::TestEvocationFirstRaidEachTurnFree);
+    g.create_and_play(CardName::TestSpellRaidVaultAccessTopTwo);
+    g.click(Button::EndRaid);
+    assert_eq!(g.me().actions(), 2);
+    g.initiate_raid(RoomId::Vault);
+    g.click(Button::EndRaid);
+    assert_eq!(g.me().actions(), 2);
+}
+
+#[test]
+fn minion_returned_from_banishment_fires_summon_triggers() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller))
+        .opponent(
+            TestSide::new(Side::Covenant)
+                .room_occupant(RoomId::RoomA, CardName::TestScheme3_10)
+                .face_up_defender(RoomId::RoomA, CardName::TestMinionBanishUntilEndOfTurn)
+                .face_up_room_occupant(RoomId::RoomB, CardName::TestProjectCountMinionSummons),
+        )
+        .build();
+    let project_id = g.query_game(|game| game.occupants(RoomId::RoomB).next().expect("Project").id);
+    g.initiate_raid(RoomId::RoomA);
+    g.click(Button::NoWeapon);
+    g.pass_turn(Side::Riftcaller);
+    assert_eq!(0, g.query_game(|game| game.card(project_id).counters(CardCounter::Growth)));
+
+    g.pass_turn(Side::Covenant);
+    assert_eq!(1, g.query_game(|game| game.card(project_id).counters(CardCounter::Growth)));
+}
+
+#[test]
+fn turn_mana_accumulates_within_turn() {
+    let mut g = TestGame::new(TestSide::new(Side::Riftcaller)).actions(4).build();
+    g.create_and_play(CardName::TestArtifactTurnManaOnVaultAccess);
+    g.initiate_raid(RoomId::Vault);
+    g.click(Button::EndRaid);
+    g.initiate_raid(RoomId::Vault);
+    g.click(Button::EndRaid);
+    assert_eq!(g.me().bonus_mana(), 4);
+}
@@ -0,0 +1,55 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_data::game_primitives::Side;
+use game_data::card_name::CardName;
+use test_utils::test_game::{TestGame, TestSide};
+use test_utils::*;
+
+#[test]
+fn scheme_scores_points_at_dusk() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant)).build();
+    g.create_and_play(CardName::TestSchemeScorePointsAtDusk);
+    g.pass_turns(2);
+    assert!(g.dusk());
+    assert_eq!(g.me().score(), 10);
+
+    g.pass_turn(Side::Covenant);
+    g.initiate_raid(test_constants::ROOM_ID);
+    g.opponent_click(Button::Score);
+    g.opponent_click(Button::EndRaid);
+    assert_eq!(g.you().score(), 10);
+    g.pass_turn(Side::Riftcaller);
+    assert_eq!(g.me().score(), 10);
+}
+
+#[test]
+fn scheme_scores_points_at_dusk_maximum() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant)).build();
+    g.create_and_play(CardName::TestSchemeScorePointsAtDusk);
+    g.pass_turns(5);
+    assert_eq!(g.me().score(), 20);
+}
+
+#[test]
+fn scheme_draws_card_when_scored() {
+    let mut g = TestGame::new(TestSide::new(Side::Covenant)).actions(4).build();
+    g.create_and_play(CardName::TestSchemeDrawCardOnScore);
+    let hand_size = g.client.cards.hand().len();
+    g.progress_room(test_constants::ROOM_ID);
+    g.progress_room(test_constants::ROOM_ID);
+    g.progress_room(test_constants::ROOM_ID);
+    assert_eq!(g.me().score(), 10);
+    assert_eq!(g.client.cards.hand().len(), hand_size + 1);
+}
@@ -0,0 +1,70 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use adapters::response_builder::{ResponseBuilder, ResponseState};
+use core_data::game_primitives::{Milliseconds, Side};
+use display::tutorial_display;
+use game_data::tutorial_data::{GameTutorialState, Toast, TutorialDisplay};
+use protos::riftcaller::tutorial_effect::TutorialEffectType;
+use protos::riftcaller::TimeValue;
+use test_utils::client_interface::HasText;
+
+#[test]
+fn toast_with_timing() {
+    let effect = tutorial_display::toast(
+        "Opponent disconnected",
+        Milliseconds(500),
+        Some(Milliseconds(3000)),
+    );
+    let Some(TutorialEffectType::ShowToast(toast)) = effect.tutorial_effect_type else {
+        panic!("Expected toast");
+    };
+    assert!(toast.node.expect("Node").has_text("Opponent disconnected"));
+    assert_eq!(Some(TimeValue { milliseconds: 500 }), toast.idle_timer);
+    assert_eq!(Some(TimeValue { milliseconds: 3000 }), toast.hide_time);
+}
+
+#[test]
+fn toast_without_hide_time() {
+    let effect = tutorial_display::toast("Reconnecting", Milliseconds(0), None);
+    let Some(TutorialEffectType::ShowToast(toast)) = effect.tutorial_effect_type else {
+        panic!("Expected toast");
+    };
+    assert_eq!(None, toast.hide_time);
+}
+
+#[test]
+fn tutorial_step_toast() {
+    let builder = ResponseBuilder::new(
+        Side::Riftcaller,
+        ResponseState { animate: false, is_final_update: true, display_preference: None },
+    );
+    let state = GameTutorialState {
+        display: vec![TutorialDisplay::Toast(Toast {
+            text: "Tips: Mana lets you play cards".to_string(),
+            delay: Milliseconds(1000),
+            hide_after: None,
+        })],
+        ..GameTutorialState::default()
+    };
+    let effects = tutorial_display::render(&builder, &state).collect::<Vec<_>>();
+    assert_eq!(
+        effects,
+        vec![tutorial_display::toast("Tips: Mana lets you play cards", Milliseconds(1000), None)]
+    );
+    let Some(TutorialEffectType::ShowToast(toast)) = &effects[0].tutorial_effect_type else {
+        panic!("Expected toast");
+    };
+    assert_eq!(Some(TimeValue { milliseconds: 1000 }), toast.idle_timer);
+}
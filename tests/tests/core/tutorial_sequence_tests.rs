@@ -0,0 +1,72 @@
+// Copyright © Riftcaller 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_data::game_primitives::{Milliseconds, Side};
+use game_data::game_actions::GameAction;
+use game_data::game_state::GameState;
+use game_data::tutorial_data::{
+    Toast, TutorialDisplay, TutorialSequence, TutorialStep, TutorialTrigger,
+};
+use test_utils::test_game::{TestGame, TestSide};
+use tutorial::tutorial_actions;
+
+#[test]
+fn two_step_sequence() {
+    let sequence = TutorialSequence {
+        steps: vec![
+            TutorialStep::AwaitTriggers(vec![TutorialTrigger::DrawCardAction]),
+            TutorialStep::Display(vec![toast("Now gain mana")]),
+            TutorialStep::AwaitTriggers(vec![TutorialTrigger::GainManaAction]),
+            TutorialStep::Display(vec![toast("Done")]),
+        ],
+        messages: vec![],
+    };
+    let g = TestGame::new(TestSide::new(Side::Riftcaller)).build();
+    let mut game = g.query_game(|game| game.clone());
+
+    tutorial_actions::run_sequence(&mut game, &sequence, Some(&GameAction::GainMana))
+        .expect("Error running sequence");
+    assert_eq!(0, game.info.tutorial_state.index);
+    assert!(displayed(&game).is_empty());
+
+    tutorial_actions::run_sequence(&mut game, &sequence, Some(&GameAction::DrawCard))
+        .expect("Error running sequence");
+    assert_eq!(2, game.info.tutorial_state.index);
+    assert_eq!(vec!["Now gain mana"], displayed(&game));
+
+    tutorial_actions::run_sequence(&mut game, &sequence, Some(&GameAction::GainMana))
+        .expect("Error running sequence");
+    assert_eq!(4, game.info.tutorial_state.index);
+    assert_eq!(vec!["Done"], displayed(&game));
+}
+
+fn toast(text: &str) -> TutorialDisplay {
+    TutorialDisplay::Toast(Toast {
+        text: text.to_string(),
+        delay: Milliseconds(0),
+        hide_after: None,
+    })
+}
+
+fn displayed(game: &GameState) -> Vec<&str> {
+    game.info
+        .tutorial_state
+        .display
+        .iter()
+        .filter_map(|display| match display {
+            TutorialDisplay::Toast(toast) => Some(toast.text.as_str()),
+            _ => None,
+        })
+        .collect()
+}